@@ -0,0 +1,19 @@
+//! Fails the build when the configured default Notary key is invalid, rather than letting the
+//! app fall back to the test fixture key, whose private key is public.
+
+#[allow(dead_code)]
+#[path = "src/notary_key.rs"]
+mod notary_key;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/notary_key.rs");
+    println!("cargo:rerun-if-env-changed=PROOF_VIZ_DEFAULT_NOTARY_KEY");
+    if let Ok(key) = std::env::var("PROOF_VIZ_DEFAULT_NOTARY_KEY") {
+        if let Err(err) = notary_key::NotaryKey::parse(&key) {
+            panic!(
+                "PROOF_VIZ_DEFAULT_NOTARY_KEY is not a valid Notary key: {}",
+                err
+            );
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#!/bin/sh
+# Write sw.js into the build: the template pwa/sw.js with the list of files to precache and a
+# cache version which changes whenever any of them does. Run as a Trunk post_build hook.
+set -eu
+
+cd "$TRUNK_STAGING_DIR"
+
+files=$(find . -type f ! -name sw.js | sed 's|^\./||' | sort)
+precache=$(printf '%s\n' "$files" | sed 's/.*/  "&",/')
+version=$(printf '%s\n' "$files" | xargs sha256sum | sha256sum | cut -c1-16)
+
+{
+  sed -n '1,/__PRECACHE__/p' "$TRUNK_SOURCE_DIR/pwa/sw.js" | sed '$d'
+  printf '%s\n' "$precache"
+  sed -n '/__PRECACHE__/,$p' "$TRUNK_SOURCE_DIR/pwa/sw.js" | sed '1d'
+} | sed "s/__VERSION__/$version/" > sw.js
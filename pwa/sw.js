@@ -0,0 +1,44 @@
+// Service worker of the proof visualizer, so it loads and verifies proofs offline once visited.
+// pwa/service_worker.sh fills in the files of the build and a version when building.
+
+const CACHE = "proof_viz-__VERSION__";
+const PRECACHE = [
+  "./",
+__PRECACHE__
+];
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(CACHE)
+      .then((cache) => cache.addAll(PRECACHE))
+      .then(() => self.skipWaiting()),
+  );
+});
+
+// Drop the caches of earlier builds
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) => Promise.all(keys.filter((key) => key !== CACHE).map((key) => caches.delete(key))))
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  // Proofs fetched from other sites and the Notary registry are never cached
+  if (request.method !== "GET" || new URL(request.url).origin !== self.location.origin) {
+    return;
+  }
+
+  if (request.mode === "navigate") {
+    // The page itself comes from the network when online, so a new build is picked up; every
+    // route is the same page
+    event.respondWith(fetch(request).catch(() => caches.match("./", { ignoreSearch: true })));
+  } else {
+    // Trunk puts a hash into the names of the built files, a cached one is never stale
+    event.respondWith(caches.match(request).then((cached) => cached || fetch(request)));
+  }
+});
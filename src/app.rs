@@ -0,0 +1,998 @@
+use gloo::events::EventListener;
+use gloo::file::File;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+#[allow(unused_imports)]
+use gloo::console::log;
+
+use wasm_bindgen::JsCast;
+use web_sys::{
+    ClipboardEvent, DragEvent, Event, FileList, HtmlInputElement, HtmlSelectElement,
+    HtmlTextAreaElement, KeyboardEvent,
+};
+use yew::html::TargetCast;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yew_router::scope_ext::LocationHandle;
+
+use crate::assertions::Assertions;
+use crate::card_order::CardOrder;
+use crate::components::advanced_options::AdvancedOptions;
+use crate::components::assertions_panel::AssertionsPanel;
+use crate::components::ca_store_input::CaStoreInput;
+use crate::components::diagnostics_drawer::DiagnosticsDrawer;
+use crate::components::example_gallery::ExampleGallery;
+use crate::components::history_panel::HistoryPanel;
+use crate::components::pem_input::PemInputComponent;
+use crate::components::proof_diff::ProofDiff;
+use crate::components::proof_toolbar::ProofToolbar;
+use crate::components::qr_scanner::QrScanner;
+use crate::components::self_test_page::SelfTestPage;
+use crate::components::substrings_pairing::SubstringsPairing;
+use crate::components::summary::Summary;
+use crate::components::timeline::{self, Timeline};
+use crate::components::view_file::ViewFile;
+use crate::config;
+use crate::diagnostics;
+use crate::embed::{self, EmbedParams, PostedProof};
+use crate::events;
+use crate::handoff;
+use crate::history::{self, HistoryEntry};
+use crate::i18n::Locale;
+use crate::input;
+use crate::input::chunked::Upload;
+use crate::input::classify::{Detected, MAX_SIZE};
+use crate::network_guard;
+use crate::notary_key::{KeySource, NotaryKey};
+use crate::notary_registry::Registry;
+use crate::offline::{self, InstallPrompt};
+use crate::parsed_proof::ParsedProof;
+use crate::proof_list::{self, ListView, Outcome, Outcomes, SortBy};
+use crate::qr::Scanned;
+use crate::report;
+use crate::route::Route;
+use crate::settings;
+use crate::theme::{self, Theme};
+use crate::verify::VerifyOptions;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct FileDetails {
+    pub name: String,
+    pub file_type: String,
+    /// Parsed once and shared by every view of the proof, the file itself is not kept
+    pub proof: Rc<ParsedProof>,
+    /// Hex encoded SHA-256 of the file, which identifies a proof uploaded more than once
+    pub digest: String,
+    /// How often this content was uploaded
+    pub uploads: usize,
+}
+
+impl FileDetails {
+    pub fn new(name: String, file_type: String, data: Vec<u8>) -> Self {
+        let proof = ParsedProof::parse(&data);
+        Self {
+            digest: proof.file_digest.clone(),
+            name,
+            file_type,
+            proof: Rc::new(proof),
+            uploads: 1,
+        }
+    }
+}
+
+pub enum Msg {
+    Loaded(String, String, Vec<u8>),
+    /// The next chunk of the named upload was read
+    Chunk(usize, Result<Vec<u8>, String>),
+    /// The proof of `?proof_url=` could not be fetched
+    FetchFailed(String),
+    /// The text of a scanned QR code
+    Scanned(String),
+    /// A proof handed over with `postMessage`
+    Posted(PostedProof),
+    Files(Vec<File>),
+    /// Stop reading an upload, or dismiss a failed one, by id
+    CancelUpload(usize),
+    /// Remove a loaded proof, by file digest, cancelling its verification if it is queued
+    RemoveFile(String),
+    Pem(NotaryKey, KeySource),
+    ExtraRoots(Vec<Vec<u8>>),
+    SkipCertVerification(bool),
+    Assertions(Assertions),
+    ToggleCompare(usize),
+    /// A result card, by file digest, was dropped on another one
+    MoveCard(String, String),
+    TogglePin(String),
+    /// Filters or sorting changed in the toolbar
+    ListView(ListView),
+    /// A listed proof, by file digest, was verified
+    Outcome(String, Outcome),
+    ToggleTheme,
+    /// Language chosen in the switcher
+    Locale(Locale),
+    /// Open or close the navigation menu on small screens
+    ToggleMenu,
+    /// A verification's history entry and report
+    Verified(HistoryEntry, serde_json::Value),
+    ClearHistory,
+    /// The published Notary registry was fetched
+    Registry(Registry),
+    /// The browser location changed
+    Navigated,
+    /// The browser offers to install the app
+    InstallAvailable(InstallPrompt),
+    Install,
+}
+
+pub struct App {
+    /// Files being read, or which failed to be read, by id: names are not unique
+    uploads: BTreeMap<usize, Upload>,
+    next_upload: usize,
+    files: Vec<FileDetails>,
+    pem: NotaryKey,
+    /// Where `pem` came from
+    key_source: KeySource,
+    verify_options: VerifyOptions,
+    /// Checked against every proof after verification
+    assertions: Assertions,
+    /// A proof is fetched from `?proof_url=` or a scanned URL
+    is_fetching: bool,
+    /// Indices into `files` of (at most two) proofs selected for comparison
+    compare: Vec<usize>,
+    /// The order of the result cards, rearranged by dragging and pinning
+    card_order: CardOrder,
+    /// How the results are filtered and sorted
+    list_view: ListView,
+    /// What the verifications so far found, by file digest
+    outcomes: Outcomes,
+    theme: Theme,
+    locale: Locale,
+    /// Known Notaries, bundled or fetched
+    registry: Registry,
+    /// Navigation menu shown on small screens
+    menu_open: bool,
+    /// Past verifications, persisted in localStorage
+    history: Vec<HistoryEntry>,
+    /// Outcome of the latest verification, read out by screen readers
+    announcement: String,
+    /// The hidden file input, opened from the drop zone with the keyboard
+    file_input: NodeRef,
+    embed: EmbedParams,
+    /// Posted proofs by file digest, replied to with the report of their card's verification
+    awaiting_reply: BTreeMap<String, Vec<PostedProof>>,
+    /// Shows the browser's install dialog, until used
+    install_prompt: Option<InstallPrompt>,
+    _message_listener: EventListener,
+    _install_listener: EventListener,
+    _paste_listener: EventListener,
+    _location_handle: Option<LocationHandle>,
+}
+
+fn upload_files(files: Option<FileList>) -> Msg {
+    if let Some(files) = files {
+        let files = js_sys::try_iter(&files)
+            .unwrap()
+            .unwrap()
+            .map(|v| web_sys::File::from(v.unwrap()))
+            .map(File::from)
+            .collect();
+        Msg::Files(files)
+    } else {
+        Msg::Files(Vec::with_capacity(0))
+    }
+}
+
+fn read_next_chunk(ctx: &Context<App>, id: usize, upload: &mut Upload) {
+    let link = ctx.link().clone();
+    upload.read_next(move |chunk| {
+        link.send_message(Msg::Chunk(id, chunk.map_err(|err| err.to_string())))
+    });
+}
+
+/// A pasted file is read like an uploaded one, pasted text is taken as proof JSON.
+/// Pastes into text fields are left alone.
+fn paste(event: &ClipboardEvent) -> Option<Msg> {
+    let target = event.target()?;
+    if target.is_instance_of::<HtmlInputElement>() || target.is_instance_of::<HtmlTextAreaElement>()
+    {
+        return None;
+    }
+    let clipboard = event.clipboard_data()?;
+    event.prevent_default();
+
+    match clipboard.files() {
+        Some(files) if files.length() > 0 => Some(upload_files(Some(files))),
+        _ => {
+            let text = clipboard.get_data("text").ok()?;
+            let text = text.trim();
+            (!text.is_empty()).then(|| {
+                Msg::Loaded(
+                    "pasted proof.json".to_string(),
+                    "application/json".to_string(),
+                    text.as_bytes().to_vec(),
+                )
+            })
+        }
+    }
+}
+
+fn fetch(ctx: &Context<App>, url: String) {
+    let name = embed::file_name(&url);
+    embed::fetch_proof(
+        url,
+        ctx.link().callback(move |result| match result {
+            Ok(data) => Msg::Loaded(name.clone(), "application/json".to_string(), data),
+            Err(err) => Msg::FetchFailed(err),
+        }),
+    );
+}
+
+impl App {
+    fn announce(&mut self, entry: &HistoryEntry) {
+        self.announcement = match &entry.error {
+            None => self.locale.format(
+                "{file}: verified, {server}",
+                &[
+                    ("file", &entry.file_name),
+                    ("server", &entry.server_name.clone().unwrap_or_default()),
+                ],
+            ),
+            Some(error) => self.locale.format(
+                "{file}: verification failed, {error}",
+                &[("file", &entry.file_name), ("error", error)],
+            ),
+        };
+    }
+
+    /// A proof is fetched or a file is read
+    fn is_processing(&self) -> bool {
+        self.is_fetching || self.uploads.values().any(Upload::is_pending)
+    }
+
+    /// Add a loaded file, counting a file with the same content as another upload of it rather
+    /// than verifying and showing it again
+    fn add_file(&mut self, file: FileDetails) {
+        match self
+            .files
+            .iter_mut()
+            .find(|known| known.digest == file.digest)
+        {
+            Some(known) => known.uploads += 1,
+            None => {
+                self.card_order.insert(&file.digest);
+                self.files.push(file);
+            }
+        }
+    }
+
+    /// The listed proofs verified so far, for the timeline
+    fn timeline_points(&self, shown: &[usize]) -> Vec<timeline::Point> {
+        shown
+            .iter()
+            .filter_map(|index| {
+                let file = &self.files[*index];
+                let outcome = self.outcomes.get(&file.digest).filter(|o| o.verified)?;
+                Some(timeline::Point {
+                    file_name: file.name.clone(),
+                    server_name: outcome.server_name.clone()?,
+                    time: outcome.time?,
+                    upload: *index,
+                })
+            })
+            .collect()
+    }
+
+    /// Show the loaded proof with this hash, or what the history knows about it
+    fn find_proof(&self, ctx: &Context<Self>, hash: &str) {
+        if self.files.iter().any(|file| file.digest == hash) {
+            if let Some(navigator) = ctx.link().navigator() {
+                navigator.push(&Route::Verify);
+            }
+            return;
+        }
+
+        let locale = self.locale;
+        let message = match self
+            .history
+            .iter()
+            .rev()
+            .find(|entry| entry.proof_hash == hash)
+        {
+            Some(entry) => match &entry.error {
+                None => locale.format(
+                    "\"{file}\" was verified on {date}: {server}",
+                    &[
+                        ("file", &entry.file_name),
+                        ("date", &entry.verified_at),
+                        ("server", &entry.server_name.clone().unwrap_or_default()),
+                    ],
+                ),
+                Some(error) => locale.format(
+                    "\"{file}\" failed verification on {date}: {error}",
+                    &[
+                        ("file", &entry.file_name),
+                        ("date", &entry.verified_at),
+                        ("error", error),
+                    ],
+                ),
+            },
+            None => locale.format(
+                "No proof with hash {hash} was verified in this browser, load the proof to verify it",
+                &[("hash", &hash)],
+            ),
+        };
+        gloo::dialogs::alert(&message);
+    }
+
+    fn view_home(&self, ctx: &Context<Self>) -> Html {
+        let t = |message| self.locale.t(message);
+        let upload_icon = html! {
+            <svg class="w-16 h-16 text-white-50" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 20 16">
+                <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13 13h3a3 3 0 0 0 0-6h-.025A5.56 5.56 0 0 0 16 6.5 5.5 5.5 0 0 0 5.207 5.021C5.137 5.017 5.071 5 5 5a4 4 0 0 0 0 8h2.167M10 15V6m0 0L8 8m2-2 2 2"/>
+            </svg>
+        };
+
+        let progress_icon = html! {
+            <svg aria-hidden="true" class="w-8 h-8 mr-2 text-gray-200 animate-spin dark:text-gray-600 fill-blue-600" viewBox="0 0 100 101" fill="none" xmlns="http://www.w3.org/2000/svg">
+                <path d="M100 50.5908C100 78.2051 77.6142 100.591 50 100.591C22.3858 100.591 0 78.2051 0 50.5908C0 22.9766 22.3858 0.59082 50 0.59082C77.6142 0.59082 100 22.9766 100 50.5908ZM9.08144 50.5908C9.08144 73.1895 27.4013 91.5094 50 91.5094C72.5987 91.5094 90.9186 73.1895 90.9186 50.5908C90.9186 27.9921 72.5987 9.67226 50 9.67226C27.4013 9.67226 9.08144 27.9921 9.08144 50.5908Z" fill="currentColor"/>
+                <path d="M93.9676 39.0409C96.393 38.4038 97.8624 35.9116 97.0079 33.5539C95.2932 28.8227 92.871 24.3692 89.8167 20.348C85.8452 15.1192 80.8826 10.7238 75.2124 7.41289C69.5422 4.10194 63.2754 1.94025 56.7698 1.05124C51.7666 0.367541 46.6976 0.446843 41.7345 1.27873C39.2613 1.69328 37.813 4.19778 38.4501 6.62326C39.0873 9.04874 41.5694 10.4717 44.0505 10.1071C47.8511 9.54855 51.7191 9.52689 55.5402 10.0491C60.8642 10.7766 65.9928 12.5457 70.6331 15.2552C75.2735 17.9648 79.3347 21.5619 82.5849 25.841C84.9175 28.9121 86.7997 32.2913 88.1811 35.8758C89.083 38.2158 91.5421 39.6781 93.9676 39.0409Z" fill="currentFill"/>
+            </svg>
+        };
+
+        // The embedding page chose the proof, users do not upload one
+        let loading_embedded = self.embed.embed && self.embed.proof_url.is_some();
+
+        // Enter and space open the file picker, like on a button
+        let open_picker = {
+            let file_input = self.file_input.clone();
+            Callback::from(move |event: KeyboardEvent| {
+                if event.key() == "Enter" || event.key() == " " {
+                    event.prevent_default();
+                    if let Some(input) = file_input.cast::<HtmlInputElement>() {
+                        input.click();
+                    }
+                }
+            })
+        };
+
+        let key_hint = html! {
+            <p class="text-sm text-center text-gray-400">
+                {self.locale.format("Proofs are verified with the {scheme} Notary key configured under ", &[("scheme", &self.pem)])}
+                <Link<Route> classes="underline" to={Route::Keys}>{t("Keys")}</Link<Route>>
+            </p>
+        };
+
+        html! {
+            <>
+                // <p class="text-2xl text-center">{ "Upload Your TLSNotary Proof" }</p>
+
+                if !loading_embedded {
+                    <label for="file-upload">
+                        <div class={classes!("p-8", "md:p-16", "flex", "flex-col", "justify-center", "items-center", theme::CARD)}
+                            id="drop-container"
+                            tabindex="0"
+                            role="button"
+                            aria-label={t("Select proof files, or drop them here")}
+                            onkeydown={open_picker}
+                            ondrop={ctx.link().callback(|event: DragEvent| {
+                                event.prevent_default();
+                                let files = event.data_transfer().unwrap().files();
+                                upload_files(files)
+                            })}
+                            ondragover={Callback::from(|event: DragEvent| event.prevent_default())}
+                            ondragenter={Callback::from(|event: DragEvent| event.prevent_default())}
+                        >
+                            {upload_icon}
+                            <p class="text-base text-white-50"><span class="font-semibold">{t("Drop your \"")}<span class="font-mono">{"proof.json"}</span>{t("\" file (or a ")}<span class="font-mono">{".zip"}</span>{t(" of proofs) here")}</span>{t(" or click to select, or paste it")}</p>
+                            <br/>
+                            <p class="text-sm text-gray-400 text-center">{t("🕵️ Your proof is ")}<strong>{t("checked locally in the browser")}</strong>{" 🕵️"}<br />{t("Your file will not be uploaded")}</p>
+                        </div>
+                    </label>
+                    <input
+                        ref={self.file_input.clone()}
+                        id="file-upload"
+                        tabindex="-1"
+                        class="invisible"
+                        type="file"
+                        accept="application/json,application/zip,.zip"
+                        multiple={true}
+                        onchange={ctx.link().callback(move |e: Event| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            upload_files(input.files())
+                        })}
+                    />
+                }
+
+                if !self.embed.embed {
+                    <QrScanner on_scan={ctx.link().callback(Msg::Scanned)} />
+                    <ExampleGallery
+                        on_load={ctx.link().callback(|(name, data)| Msg::Loaded(name, "application/json".to_string(), data))}
+                        fixture_key={config::is_test_key(self.pem)}
+                    />
+                }
+
+                if self.is_fetching || !self.uploads.is_empty() {
+                    <div role="status" class={classes!("p-8", "md:p-16", "flex", "flex-col", "justify-center", "items-center", theme::CARD)}>
+                        if self.is_processing() {
+                            {progress_icon}
+                            <span>{t("Processing...")}</span>
+                        }
+                        {for self.uploads.iter().filter(|(_, upload)| upload.is_big() || upload.error().is_some()).map(|(id, upload)| {
+                            let onclick = ctx.link().callback({
+                                let id = *id;
+                                move |_| Msg::CancelUpload(id)
+                            });
+                            html! {
+                                <div class="w-full md:w-1/2 mt-2 text-sm">
+                                    <div class="flex justify-between gap-2">
+                                        <span class="truncate">{upload.name()}</span>
+                                        if let Some(error) = upload.error() {
+                                            <span class="flex-1 text-right text-red-500" role="alert">{error.to_string()}</span>
+                                            <button class="px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" {onclick}>{t("Dismiss")}</button>
+                                        } else {
+                                            <span class="flex-1 text-right">{format!("{}%", upload.percent())}</span>
+                                            <button class="px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" title={self.locale.format("Stop reading {file}", &[("file", &upload.name())])} {onclick}>{t("Cancel")}</button>
+                                        }
+                                    </div>
+                                    if upload.is_pending() {
+                                        <progress class="w-full" max="100" value={upload.percent().to_string()} aria-label={upload.name()}></progress>
+                                    }
+                                </div>
+                            }
+                        })}
+                    </div>
+                    }
+
+                if !self.embed.embed {
+                    {key_hint}
+                }
+            </>
+        }
+    }
+
+    fn view_verify(&self, ctx: &Context<Self>) -> Html {
+        if self.files.is_empty() {
+            let t = |message| self.locale.t(message);
+            return html! {
+                <div class={classes!("p-16", "my-4", "text-center", theme::CARD)}>
+                    <p>{t("No proofs loaded yet, ")}<Link<Route> classes="underline" to={Route::Home}>{t("upload a proof")}</Link<Route>></p>
+                </div>
+            };
+        }
+        let shown = self
+            .list_view
+            .apply(&self.files, &self.card_order, &self.outcomes);
+
+        html! {
+            <>
+            if !self.embed.embed {
+                <AssertionsPanel assertions={self.assertions.clone()} on_change={ctx.link().callback(Msg::Assertions)} />
+            }
+            <SubstringsPairing files={self.files.clone()} on_combine={ctx.link().callback(|(name, data)| Msg::Loaded(name, "application/json".to_string(), data))} />
+            if self.files.len() > 1 {
+                <ProofToolbar
+                    view={self.list_view.clone()}
+                    servers={proof_list::servers(&self.outcomes)}
+                    shown={shown.len()}
+                    total={self.files.len()}
+                    on_change={ctx.link().callback(Msg::ListView)}
+                />
+                <Summary
+                    files={self.files.clone()}
+                    pem={self.pem}
+                    compare={self.compare.clone()}
+                    on_compare={ctx.link().callback(Msg::ToggleCompare)}
+                    on_outcome={ctx.link().callback(|(digest, outcome)| Msg::Outcome(digest, outcome))}
+                    shown={shown.clone()}
+                    groups={self.list_view.group.then(|| proof_list::groups(&shown, &self.files, &self.outcomes))}
+                    order={self.card_order.clone()}
+                    reorderable={self.list_view.sort == SortBy::Custom}
+                    on_move={ctx.link().callback(|(dragged, target)| Msg::MoveCard(dragged, target))}
+                    on_pin={ctx.link().callback(Msg::TogglePin)}
+                    on_verified={ctx.link().callback(|(entry, report)| Msg::Verified(entry, report))}
+                    on_cancel={ctx.link().callback(Msg::RemoveFile)}
+                />
+                <Timeline points={self.timeline_points(&shown)} />
+                if let [left, right] = self.compare[..] {
+                    <ProofDiff left={self.files[left].clone()} right={self.files[right].clone()} pem={self.pem} />
+                }
+            } else {
+                <div>
+                    {for self.files.iter().rev().map(|file| {
+                        let digest = file.digest.clone();
+                        html! {
+                            <ViewFile key={file.digest.clone()} name={file.name.clone()} file_type={file.file_type.clone()} proof={file.proof.clone()} uploads={file.uploads} pem={self.pem} on_verified={ctx.link().callback(|(entry, report)| Msg::Verified(entry, report))} on_cancel={ctx.link().callback(move |_| Msg::RemoveFile(digest.clone()))} />
+                        }
+                    })}
+                </div>
+            }
+            </>
+        }
+    }
+}
+
+fn view_about(locale: Locale) -> Html {
+    let t = |message| locale.t(message);
+    html! {
+        <div class={classes!("p-8", "my-4", "flex", "flex-col", "gap-4", theme::CARD)}>
+            <p>
+                {t("This page checks ")}<a class="underline" href="https://tlsnotary.org">{"TLSNotary"}</a>
+                {t(" proofs: it verifies the Notary's signature on the TLS session, the server certificate and the disclosed parts of the transcript, and shows what was sent and received.")}
+            </p>
+            <p>{t("Everything happens locally in your browser, proofs are never uploaded. Only a digest of each verification is kept, in your browser's local storage, for the history page.")}</p>
+            <p>
+                {t("Redacted bytes are shown as ")}<span class="font-mono text-red-500">{"X"}</span>
+                {t(". A valid proof only shows that the Notary attested to the session, check that you trust the Notary key in use.")}
+            </p>
+            <p>
+                {t("To confirm that this deployment verifies correctly, ")}
+                <Link<Route> classes="underline" to={Route::SelfTest}>{t("run the self-test")}</Link<Route>>{"."}
+            </p>
+        </div>
+    }
+}
+
+impl Component for App {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(ctx: &Context<Self>) -> Self {
+        diagnostics::install();
+        // Before anything makes requests, so all of them are recorded
+        network_guard::install();
+        let theme = Theme::load();
+        theme.apply();
+        let locale = Locale::load();
+        locale.apply();
+
+        Registry::refresh(ctx.link().callback(Msg::Registry));
+        offline::register();
+
+        let embed = EmbedParams::current();
+        if let Some(url) = embed.proof_url.clone() {
+            fetch(ctx, url);
+        }
+        let fragment_proof = handoff::take_fragment_proof();
+        if let Some(data) = fragment_proof.clone() {
+            ctx.link().send_message(Msg::Loaded(
+                "proof.json".to_string(),
+                "application/json".to_string(),
+                data,
+            ));
+        }
+        let (pem, key_source) =
+            settings::load_notary_key().unwrap_or_else(config::default_notary_key);
+        let message_listener = embed::listen(&embed, ctx.link().callback(Msg::Posted));
+        // Once listening, ask the extension which opened the page for the proof
+        if embed.handoff {
+            handoff::announce_ready();
+        }
+
+        Self {
+            uploads: BTreeMap::default(),
+            next_upload: 0,
+            files: Vec::default(),
+            pem,
+            key_source,
+            verify_options: if embed.embed {
+                // A persisted setting of the user must not weaken what an embedding page is told
+                VerifyOptions {
+                    skip_cert_verification: false,
+                    ..settings::load_verify_options()
+                }
+            } else {
+                settings::load_verify_options()
+            },
+            assertions: Assertions::default(),
+            is_fetching: embed.proof_url.is_some() || fragment_proof.is_some(),
+            compare: Vec::default(),
+            card_order: CardOrder::default(),
+            list_view: ListView::default(),
+            outcomes: Outcomes::default(),
+            theme,
+            locale,
+            registry: Registry::load(),
+            menu_open: false,
+            history: history::load(),
+            announcement: String::new(),
+            file_input: NodeRef::default(),
+            install_prompt: None,
+            _message_listener: message_listener,
+            _paste_listener: {
+                let link = ctx.link().clone();
+                EventListener::new(&gloo::utils::document(), "paste", move |event| {
+                    if let Some(msg) = paste(event.unchecked_ref()) {
+                        link.send_message(msg);
+                    }
+                })
+            },
+            _install_listener: offline::listen_install_prompt(
+                ctx.link().callback(Msg::InstallAvailable),
+            ),
+            embed,
+            awaiting_reply: BTreeMap::new(),
+            _location_handle: ctx
+                .link()
+                .add_location_listener(ctx.link().callback(|_| Msg::Navigated)),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Loaded(file_name, file_type, data) => {
+                tracing::info!(file = %file_name, r#type = %file_type, bytes = data.len(), "file loaded");
+                if input::zip::is_zip(&file_name, &file_type) {
+                    match input::zip::unpack(&file_name, &data) {
+                        Ok(unpacked) => {
+                            for (entry, err) in &unpacked.skipped {
+                                gloo::dialogs::alert(&self.locale.format(
+                                    "Skipped \"{file}\" of \"{archive}\": {error}",
+                                    &[("file", entry), ("archive", &file_name), ("error", err)],
+                                ));
+                            }
+                            unpacked
+                                .files
+                                .into_iter()
+                                .for_each(|file| self.add_file(file))
+                        }
+                        Err(err) => gloo::dialogs::alert(&self.locale.format(
+                            "Failed to unpack \"{file}\": {error}",
+                            &[("file", &file_name), ("error", &err)],
+                        )),
+                    }
+                } else {
+                    self.add_file(FileDetails::new(file_name, file_type, data));
+                }
+                self.is_fetching = false;
+                // show the results once all files are read
+                if !self.is_processing() {
+                    if let Some(navigator) = ctx.link().navigator() {
+                        navigator.push(&Route::Verify);
+                    }
+                }
+                true
+            }
+            Msg::FetchFailed(err) => {
+                tracing::warn!(error = %err, "fetching the proof failed");
+                self.is_fetching = false;
+                gloo::dialogs::alert(
+                    &self
+                        .locale
+                        .format("Failed to fetch the proof: {error}", &[("error", &err)]),
+                );
+                true
+            }
+            Msg::Scanned(text) => {
+                match Scanned::parse(&text) {
+                    Scanned::ProofUrl(url) => {
+                        self.is_fetching = true;
+                        fetch(ctx, url);
+                    }
+                    Scanned::ProofHash(hash) => self.find_proof(ctx, &hash),
+                    Scanned::Unknown(text) => gloo::dialogs::alert(&self.locale.format(
+                        "The QR code is not a proof URL or hash: {text}",
+                        &[("text", &text)],
+                    )),
+                }
+                true
+            }
+            Msg::Posted(mut proof) => {
+                let file = FileDetails::new(
+                    proof.name.clone(),
+                    "application/json".to_string(),
+                    std::mem::take(&mut proof.data),
+                );
+                match self.files.iter().find(|known| known.digest == file.digest) {
+                    // Its card verified it already and does not again, so it is verified here
+                    Some(known) => {
+                        let result = known.proof.verify(self.pem, &self.verify_options).result;
+                        proof.reply(&report::to_json(&known.proof, self.pem, &result));
+                    }
+                    None => self
+                        .awaiting_reply
+                        .entry(file.digest.clone())
+                        .or_default()
+                        .push(proof),
+                }
+                self.add_file(file);
+                if !self.is_processing() {
+                    if let Some(navigator) = ctx.link().navigator() {
+                        navigator.push(&Route::Verify);
+                    }
+                }
+                true
+            }
+            Msg::Pem(pem, key_source) => {
+                settings::save_notary_key(pem, &key_source);
+                self.pem = pem;
+                self.key_source = key_source;
+                true
+            }
+            Msg::ExtraRoots(roots) => {
+                self.verify_options.extra_roots = roots;
+                settings::save_verify_options(&self.verify_options);
+                true
+            }
+            Msg::SkipCertVerification(skip) => {
+                self.verify_options.skip_cert_verification = skip;
+                settings::save_verify_options(&self.verify_options);
+                true
+            }
+            Msg::Assertions(assertions) => {
+                self.assertions = assertions;
+                true
+            }
+            Msg::ToggleCompare(index) => {
+                if let Some(position) = self.compare.iter().position(|i| *i == index) {
+                    self.compare.remove(position);
+                } else {
+                    self.compare.push(index);
+                    // only two proofs can be compared, drop the oldest selection
+                    if self.compare.len() > 2 {
+                        self.compare.remove(0);
+                    }
+                }
+                true
+            }
+            Msg::MoveCard(dragged, target) => {
+                self.card_order.move_to(&dragged, &target);
+                true
+            }
+            Msg::TogglePin(digest) => {
+                self.card_order.toggle_pin(&digest);
+                true
+            }
+            Msg::ListView(list_view) => {
+                self.list_view = list_view;
+                true
+            }
+            Msg::Outcome(digest, outcome) => {
+                let changed = self.outcomes.get(&digest) != Some(&outcome);
+                self.outcomes.insert(digest, outcome);
+                changed
+            }
+            Msg::Files(files) => {
+                for file in files.into_iter() {
+                    let id = self.next_upload;
+                    self.next_upload += 1;
+                    let mut upload = Upload::new(file);
+                    // Too large to be a proof, not worth reading into memory
+                    if upload.size() > MAX_SIZE as u64 {
+                        let size = usize::try_from(upload.size()).unwrap_or(usize::MAX);
+                        upload.fail(Detected::TooLarge(size).describe(self.locale));
+                    } else {
+                        read_next_chunk(ctx, id, &mut upload);
+                    }
+                    self.uploads.insert(id, upload);
+                }
+                true
+            }
+            Msg::CancelUpload(id) => {
+                // Dropping the upload cancels the read of its current chunk
+                let Some(upload) = self.uploads.remove(&id) else {
+                    return false;
+                };
+                tracing::info!(file = %upload.name(), "upload cancelled");
+                true
+            }
+            Msg::RemoveFile(digest) => {
+                let Some(index) = self.files.iter().position(|file| file.digest == digest) else {
+                    return false;
+                };
+                // Unmounting its card drops the queued verification
+                self.files.remove(index);
+                self.card_order.remove(&digest);
+                self.outcomes.remove(&digest);
+                self.awaiting_reply.remove(&digest);
+                // Selections are indices into `files`
+                self.compare = self
+                    .compare
+                    .iter()
+                    .filter(|selected| **selected != index)
+                    .map(|selected| {
+                        if *selected > index {
+                            selected - 1
+                        } else {
+                            *selected
+                        }
+                    })
+                    .collect();
+                tracing::info!(file = %digest, "proof removed");
+                true
+            }
+            Msg::Chunk(id, chunk) => {
+                let Some(upload) = self.uploads.get_mut(&id) else {
+                    return false;
+                };
+                match chunk {
+                    Ok(chunk) => upload.push(chunk),
+                    Err(err) => {
+                        tracing::warn!(file = %upload.name(), error = %err, "reading the file failed");
+                        upload.fail(
+                            self.locale
+                                .format("Reading the file failed: {error}", &[("error", &err)]),
+                        );
+                        return true;
+                    }
+                }
+                if upload.is_complete() {
+                    // only parse the file once it is read completely
+                    let upload = self.uploads.remove(&id).unwrap();
+                    let (file_name, file_type) = (upload.name(), upload.file_type());
+                    ctx.link()
+                        .send_message(Msg::Loaded(file_name, file_type, upload.into_data()));
+                } else {
+                    read_next_chunk(ctx, id, upload);
+                }
+                true
+            }
+            Msg::ToggleTheme => {
+                self.theme = self.theme.toggle();
+                self.theme.save();
+                self.theme.apply();
+                true
+            }
+            Msg::Locale(locale) => {
+                self.locale = locale;
+                self.locale.save();
+                self.locale.apply();
+                true
+            }
+            Msg::Verified(entry, report) => {
+                for proof in self
+                    .awaiting_reply
+                    .remove(&entry.proof_hash)
+                    .unwrap_or_default()
+                {
+                    proof.reply(&report);
+                }
+                if self.embed.embed {
+                    embed::post_result(&report);
+                    events::dispatch(&report);
+                }
+                self.announce(&entry);
+                history::record(&mut self.history, entry);
+                history::save(&self.history);
+                true
+            }
+            Msg::ClearHistory => {
+                self.history.clear();
+                history::clear();
+                true
+            }
+            Msg::Registry(registry) => {
+                self.registry = registry;
+                true
+            }
+            Msg::ToggleMenu => {
+                self.menu_open = !self.menu_open;
+                true
+            }
+            Msg::Navigated => {
+                self.menu_open = false;
+                true
+            }
+            Msg::InstallAvailable(prompt) => {
+                self.install_prompt = Some(prompt);
+                true
+            }
+            Msg::Install => {
+                if let Some(prompt) = self.install_prompt.take() {
+                    prompt.prompt();
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let t = |message| self.locale.t(message);
+        let link_classes =
+            "block px-4 py-2 hover:bg-black hover:text-white rounded border-black border";
+        let links = [
+            ("About TLSNotary", "https://tlsnotary.org"),
+            ("Source", "https://github.com/tlsnotary/proof_viz"),
+            // ("PSE", "https://pse.dev"),
+        ];
+
+        let theme_icon = match self.theme {
+            Theme::Light => "🌙",
+            Theme::Dark => "☀️",
+        };
+
+        html! {
+        <ContextProvider<Theme> context={self.theme}>
+        <ContextProvider<Locale> context={self.locale}>
+        <ContextProvider<VerifyOptions> context={self.verify_options.clone()}>
+        <ContextProvider<Assertions> context={self.assertions.clone()}>
+        <ContextProvider<Registry> context={self.registry.clone()}>
+        <ContextProvider<KeySource> context={self.key_source.clone()}>
+        <div class="flex flex-col h-screen">
+            if !self.embed.embed {
+                <nav aria-label={t("Main")} class="bg-zinc-700 px-4 md:px-8 py-2">
+                    <div class="container flex flex-wrap mx-auto gap-x-6 gap-y-2 items-center">
+                        <svg class="w-8 h-8" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24">
+                            <g>
+                                <path fill="#ffffff" d="m15 22-.693 1.04A1.25 1.25 0 0 0 16.25 22H15Zm-3-2 .693-1.04a1.25 1.25 0 0 0-1.386 0L12 20Zm-3 2H7.75a1.25 1.25 0 0 0 1.943 1.04L9 22ZM8.75 3.537l-.1 1.246.1-1.246Zm1.685-.697-.952-.81.952.81ZM6.532 5.686l-1.246.1 1.246-.1Zm2.154-2.154.1-1.246-.1 1.246ZM5.84 7.435l.81.952-.81-.952Zm.697-1.684 1.246-.1-1.246.1Zm-.747 4.772-.81.952.81-.952Zm0-3.046-.81-.952.81.952Zm.747 4.772-1.246-.1 1.246.1Zm-.697-1.684.81-.952-.81.952Zm2.846 3.903.1 1.246-.1-1.246Zm-2.154-2.154 1.246.1-1.246-.1Zm3.903 2.846.952-.81-.952.81Zm-1.684-.697-.1-1.246.1 1.246Zm4.772.747.952.81-.952-.81Zm-3.046 0-.952.81.952-.81Zm4.772-.747.1-1.246-.1 1.246Zm-1.684.697-.952-.81.952.81Zm3.903-2.846 1.246-.1-1.246.1Zm-2.154 2.154-.1 1.246.1-1.246Zm2.846-3.903.81.952-.81-.952Zm-.697 1.684-1.246.1 1.246-.1Zm.747-4.772.81-.952-.81.952Zm0 3.046-.81-.952.81.952Zm-.747-4.772-1.246-.1 1.246.1Zm.697 1.684-.81.952.81-.952Zm-2.846-3.903-.1-1.246.1 1.246Zm2.154 2.154 1.246.1-1.246-.1ZM13.565 2.84l.952-.81-.952.81Zm1.684.697.1 1.246-.1-1.246Zm-1.726-.747-.952.81.952-.81Zm-3.046 0 .952.81-.952-.81ZM9 14.458l.055-1.248L9 14.458Zm6.693 6.502-3-2-1.386 2.08 3 2 1.386-2.08Zm-4.386-2-3 2 1.386 2.08 3-2-1.386-2.08ZM12.57 3.6l.042.05 1.904-1.62-.042-.05L12.57 3.6Zm2.779 1.183.064-.005-.2-2.492-.065.005.2 2.492Zm.872.803-.005.064 2.492.201.005-.065-2.492-.2Zm1.128 2.8.05.043 1.62-1.904-.05-.042-1.62 1.904Zm.05 1.185-.05.042 1.62 1.904.05-.042-1.62-1.904Zm-1.183 2.779.005.064 2.492-.2-.005-.065-2.492.2Zm-.803.872-.064-.005-.201 2.492.065.005.2-2.492Zm-2.8 1.128-.043.05 1.904 1.62.042-.05-1.904-1.62Zm-1.185.05-.042-.05-1.904 1.62.042.05 1.904-1.62ZM8.65 13.217l-.064.005.2 2.492.065-.005-.2-2.492Zm-.872-.803.005-.064-2.492-.201-.005.065 2.492.2Zm-1.128-2.8L6.6 9.57l-1.62 1.904.05.042 1.62-1.904ZM6.6 8.428l.05-.042-1.62-1.904-.05.042L6.6 8.429ZM7.783 5.65l-.005-.064-2.492.2.005.065 2.492-.2Zm.803-.872.064.005.201-2.492-.065-.005-.2 2.492Zm2.8-1.128.043-.05-1.904-1.62-.042.05 1.904 1.62ZM8.65 4.783a3.25 3.25 0 0 0 2.737-1.133L9.483 2.03a.75.75 0 0 1-.632.261l-.2 2.492Zm-.872.803a.75.75 0 0 1 .808-.808l.2-2.492a3.25 3.25 0 0 0-3.5 3.5l2.492-.2Zm-1.128 2.8A3.25 3.25 0 0 0 7.783 5.65l-2.492.201a.75.75 0 0 1-.261.632l1.62 1.904ZM6.6 9.572a.75.75 0 0 1 0-1.142L4.98 6.525a3.25 3.25 0 0 0 0 4.95L6.6 9.571Zm1.183 2.779A3.25 3.25 0 0 0 6.65 9.613l-1.62 1.904a.75.75 0 0 1 .261.632l2.492.2Zm.803.872a.75.75 0 0 1-.808-.808l-2.492-.2a3.25 3.25 0 0 0 3.5 3.5l-.2-2.492ZM12.57 14.4a.75.75 0 0 1-1.142 0l-1.904 1.62a3.25 3.25 0 0 0 4.95 0L12.57 14.4Zm3.651-1.986a.75.75 0 0 1-.808.808l-.2 2.492a3.25 3.25 0 0 0 3.5-3.5l-2.492.2Zm1.128-2.8a3.25 3.25 0 0 0-1.133 2.736l2.492-.201a.75.75 0 0 1 .261-.632l-1.62-1.904Zm.05-1.185a.75.75 0 0 1 0 1.142l1.62 1.904a3.25 3.25 0 0 0 0-4.95L17.4 8.429ZM16.217 5.65a3.25 3.25 0 0 0 1.133 2.737l1.62-1.904a.75.75 0 0 1-.261-.632l-2.492-.2Zm-.803-.872a.75.75 0 0 1 .808.808l2.492.2a3.25 3.25 0 0 0-3.5-3.5l.2 2.492Zm-2.8-1.128a3.25 3.25 0 0 0 2.736 1.133l-.201-2.492a.75.75 0 0 1-.632-.261l-1.904 1.62Zm1.861-1.67a3.25 3.25 0 0 0-4.95 0l1.904 1.62a.75.75 0 0 1 1.142 0l1.904-1.62Zm-3.088 12.37a3.25 3.25 0 0 0-2.332-1.14l-.11 2.497a.75.75 0 0 1 .538.263l1.904-1.62Zm-2.332-1.14a3.26 3.26 0 0 0-.405.007l.201 2.492a.732.732 0 0 1 .094-.002l.11-2.497ZM10.25 22v-7.542h-2.5V22h2.5Zm5.1-8.783a3.26 3.26 0 0 0-.405-.007l.11 2.497a.99.99 0 0 1 .094.002l.2-2.492Zm-.405-.007a3.25 3.25 0 0 0-2.332 1.14l1.904 1.62a.75.75 0 0 1 .538-.263l-.11-2.497Zm-1.195 1.248V22h2.5v-7.542h-2.5Z"/>
+                                <path stroke="#ffffff" stroke-linecap="round" stroke-linejoin="round" stroke-width="2.5" d="m14 8-3 3-1-1"/>
+                        </g>
+                        </svg>
+                        <Link<Route> to={Route::Home}>
+                            <h1 class="font-bold text-xl md:text-2xl text-white">{t("Check TLSNotary proofs")}</h1>
+                        </Link<Route>>
+                        <div class="flex-1"></div>
+                        <button class="md:hidden px-3 py-1 text-white rounded border border-white" title={t("Menu")} aria-label={t("Menu")} aria-expanded={self.menu_open.to_string()} aria-controls="nav-menu" onclick={ctx.link().callback(|_| Msg::ToggleMenu)}>{"☰"}</button>
+                        <div id="nav-menu" class={classes!("w-full", "md:w-auto", "flex-col", "md:flex-row", "gap-2", "md:gap-6", "md:flex", if self.menu_open { "flex" } else { "hidden" })}>
+                            {for Route::NAVIGATION.iter().map(|(label, route)| html! {
+                                <Link<Route> classes={link_classes} to={route.clone()}>{t(label)}</Link<Route>>
+                            })}
+                            {for links.iter().map(|(label, href)| html! {
+                                <a class={link_classes} href={*href}>{t(label)}</a>
+                            })}
+                            if self.install_prompt.is_some() {
+                                <button class={link_classes} title={t("Install the app, it works offline")} onclick={ctx.link().callback(|_| Msg::Install)}>{t("Install")}</button>
+                            }
+                            <button class={link_classes} title={t("Toggle dark/light theme")} aria-label={t("Toggle dark/light theme")} onclick={ctx.link().callback(|_| Msg::ToggleTheme)}>{theme_icon}</button>
+                            <select class="px-2 py-2 rounded border-black border bg-zinc-700 text-white" title={t("Language")} aria-label={t("Language")} onchange={ctx.link().batch_callback(|e: Event| {
+                                let select: HtmlSelectElement = e.target_unchecked_into();
+                                Locale::from_code(&select.value()).map(Msg::Locale)
+                            })}>
+                                {for Locale::ALL.iter().map(|locale| html! {
+                                    <option value={locale.code()} selected={*locale == self.locale}>{locale.name()}</option>
+                                })}
+                            </select>
+                        </div>
+                    </div>
+                </nav>
+            }
+            <div class="sr-only" role="status" aria-live="polite">{self.announcement.clone()}</div>
+            <main class={if self.embed.embed { "w-full p-2" } else { theme::PAGE }}>
+                {match ctx.link().route::<Route>().unwrap_or(Route::NotFound) {
+                    Route::Home => self.view_home(ctx),
+                    Route::Verify => self.view_verify(ctx),
+                    Route::History => html! {
+                        <HistoryPanel entries={self.history.clone()} on_clear={ctx.link().callback(|_| Msg::ClearHistory)} />
+                    },
+                    Route::Keys => html! {
+                        <>
+                            <PemInputComponent pem={self.pem} pem_callback={ctx.link().callback(|(pem, source)| Msg::Pem(pem, source))}/>
+                            <CaStoreInput roots={self.verify_options.extra_roots.clone()} on_change={ctx.link().callback(Msg::ExtraRoots)} />
+                            <AdvancedOptions
+                                skip_cert_verification={self.verify_options.skip_cert_verification}
+                                on_skip_cert_verification={ctx.link().callback(Msg::SkipCertVerification)}
+                            />
+                        </>
+                    },
+                    Route::About => view_about(self.locale),
+                    Route::SelfTest => html! { <SelfTestPage /> },
+                    Route::NotFound => html! {
+                        <div class={classes!("p-16", "my-4", "text-center", theme::CARD)}>
+                            <p>{t("Page not found, ")}<Link<Route> classes="underline" to={Route::Home}>{t("upload a proof")}</Link<Route>></p>
+                        </div>
+                    },
+                }}
+            </main>
+            if !self.embed.embed {
+                <DiagnosticsDrawer />
+            }
+        </div>
+        </ContextProvider<KeySource>>
+        </ContextProvider<Registry>>
+        </ContextProvider<Assertions>>
+        </ContextProvider<VerifyOptions>>
+        </ContextProvider<Locale>>
+        </ContextProvider<Theme>>
+        }
+    }
+}
+
+/// `App` reads the current route, so it has to be rendered inside the router
+#[function_component]
+pub fn Main() -> Html {
+    html! {
+        <BrowserRouter>
+            <App />
+        </BrowserRouter>
+    }
+}
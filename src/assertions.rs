@@ -0,0 +1,323 @@
+//! Checks on the content of a proof, evaluated after its cryptographic verification.
+//!
+//! A valid proof only shows that the Notary attested to *some* TLS session, assertions check that
+//! it is the session the user expects.
+
+use std::fmt;
+
+use spansy::http::parse_response;
+
+use crate::json_query;
+use crate::policy::{self, Policy};
+use crate::verify::{Transcript, VerifiedOutput};
+
+/// A user defined check on the disclosed transcript
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rule {
+    /// The disclosed sent bytes contain the text
+    SentContains(String),
+    /// The disclosed received bytes contain the text
+    ReceivedContains(String),
+    /// The disclosed received bytes do not contain the text
+    ReceivedNotContains(String),
+    /// The values a JSONPath expression selects from the JSON response body include `value`
+    JsonEquals { path: String, value: String },
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::SentContains(text) => write!(f, "Sent bytes contain \"{}\"", text),
+            Rule::ReceivedContains(text) => write!(f, "Received bytes contain \"{}\"", text),
+            Rule::ReceivedNotContains(text) => {
+                write!(f, "Received bytes do not contain \"{}\"", text)
+            }
+            Rule::JsonEquals { path, value } => {
+                write!(f, "Received body JSON field {} equals {}", path, value)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Assertions {
+    /// The domain the proof must be for, `*.example.com` matches exactly one subdomain level
+    pub expected_server_name: Option<String>,
+    pub rules: Vec<Rule>,
+    /// Loaded from a policy file, evaluated as a separate compliance checklist
+    pub policy: Option<Policy>,
+}
+
+/// The result of evaluating one assertion
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub description: String,
+    pub passed: bool,
+    /// What was actually found
+    pub detail: String,
+}
+
+fn normalize(name: &str) -> String {
+    name.trim().trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// Does `server_name` match `pattern`, exactly or with a leading `*.` wildcard label?
+pub fn server_name_matches(pattern: &str, server_name: &str) -> bool {
+    let (pattern, server_name) = (normalize(pattern), normalize(server_name));
+    match pattern.strip_prefix("*.") {
+        Some(parent) => server_name
+            .split_once('.')
+            .is_some_and(|(label, rest)| !label.is_empty() && rest == parent),
+        None => pattern == server_name,
+    }
+}
+
+impl Assertions {
+    /// Is there an expected server name which `server_name` does not match?
+    pub fn server_name_mismatch(&self, server_name: &str) -> bool {
+        self.expected_server_name
+            .as_ref()
+            .filter(|expected| !expected.trim().is_empty())
+            .is_some_and(|expected| !server_name_matches(expected, server_name))
+    }
+
+    /// Do the assertions and the policy, if any, all pass?
+    pub fn all_passed(&self, verified: &VerifiedOutput) -> bool {
+        let policy_outcomes = self
+            .policy
+            .iter()
+            .flat_map(|policy| policy::evaluate(policy, verified));
+        evaluate(self, verified)
+            .into_iter()
+            .chain(policy_outcomes)
+            .all(|outcome| outcome.passed)
+    }
+}
+
+/// Does `needle` occur in the disclosed part of the transcript? Redacted bytes never match.
+fn contains_disclosed(transcript: &Transcript, needle: &str) -> bool {
+    let needle = needle.as_bytes();
+    if needle.is_empty() {
+        return true;
+    }
+    transcript
+        .bytes
+        .windows(needle.len())
+        .enumerate()
+        .any(|(start, window)| {
+            window == needle
+                && !transcript
+                    .redacted_ranges
+                    .iter()
+                    .any(|r| r.start < start + needle.len() && start < r.end)
+        })
+}
+
+// Compare a JSON value with what the user typed: strings by their content, anything else as JSON
+fn json_value_equals(value: &serde_json::Value, expected: &str) -> bool {
+    match value {
+        serde_json::Value::String(string) => string == expected,
+        value => serde_json::from_str::<serde_json::Value>(expected).is_ok_and(|e| *value == e),
+    }
+}
+
+fn evaluate_json(recv: &Transcript, path: &str, expected: &str) -> (bool, String) {
+    let body = match parse_response(&recv.bytes) {
+        Ok(response) => match response.body {
+            Some(body) => body.as_bytes().to_vec(),
+            None => return (false, "the response has no body".to_string()),
+        },
+        Err(err) => return (false, format!("the response can not be parsed: {}", err)),
+    };
+    let json = match serde_json::from_slice::<serde_json::Value>(&body) {
+        Ok(json) => json,
+        Err(err) => return (false, format!("the body is not valid JSON: {}", err)),
+    };
+
+    match json_query::query(&json, path) {
+        Ok(values) if values.is_empty() => (false, "no such field".to_string()),
+        Ok(values) => (
+            values
+                .iter()
+                .any(|value| json_value_equals(value, expected)),
+            format!(
+                "found {}",
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ),
+        Err(err) => (false, format!("invalid path: {}", err)),
+    }
+}
+
+fn evaluate_rule(rule: &Rule, verified: &VerifiedOutput) -> Outcome {
+    let (passed, detail) = match rule {
+        Rule::SentContains(text) => (
+            contains_disclosed(&verified.sent, text),
+            "not in the disclosed sent bytes".to_string(),
+        ),
+        Rule::ReceivedContains(text) => (
+            contains_disclosed(&verified.recv, text),
+            "not in the disclosed received bytes".to_string(),
+        ),
+        Rule::ReceivedNotContains(text) => (
+            !contains_disclosed(&verified.recv, text),
+            "disclosed in the received bytes".to_string(),
+        ),
+        Rule::JsonEquals { path, value } => evaluate_json(&verified.recv, path, value),
+    };
+
+    Outcome {
+        description: rule.to_string(),
+        passed,
+        detail,
+    }
+}
+
+pub fn evaluate(assertions: &Assertions, verified: &VerifiedOutput) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+
+    if let Some(expected) = assertions
+        .expected_server_name
+        .as_ref()
+        .filter(|expected| !expected.trim().is_empty())
+    {
+        outcomes.push(Outcome {
+            description: format!("Server name is {}", expected.trim()),
+            passed: server_name_matches(expected, &verified.server_name),
+            detail: verified.server_name.clone(),
+        });
+    }
+
+    outcomes.extend(
+        assertions
+            .rules
+            .iter()
+            .map(|rule| evaluate_rule(rule, verified)),
+    );
+
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expecting(server_name: &str) -> Assertions {
+        Assertions {
+            expected_server_name: Some(server_name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn server_names() {
+        assert!(server_name_matches("example.com", "example.com"));
+        assert!(server_name_matches(" Example.COM. ", "example.com"));
+        assert!(server_name_matches("*.example.com", "api.example.com"));
+        assert!(!server_name_matches("*.example.com", "example.com"));
+        assert!(!server_name_matches("*.example.com", "a.b.example.com"));
+        assert!(!server_name_matches("example.com", "example.com.evil.org"));
+    }
+
+    #[test]
+    fn expected_server_name() {
+        let verified = VerifiedOutput::for_test("", "");
+        let outcomes = evaluate(&expecting("example.com"), &verified);
+        assert_eq!(outcomes.len(), 1);
+        assert!(outcomes[0].passed);
+        assert!(!expecting("example.com").server_name_mismatch("example.com"));
+
+        let outcomes = evaluate(&expecting("other.com"), &verified);
+        assert!(!outcomes[0].passed);
+        assert_eq!(outcomes[0].detail, "example.com");
+        assert!(expecting("other.com").server_name_mismatch("example.com"));
+
+        // an empty expectation is no assertion
+        assert!(evaluate(&expecting("  "), &verified).is_empty());
+        assert!(!expecting("").server_name_mismatch("example.com"));
+    }
+
+    fn outcomes(rules: Vec<Rule>, verified: &VerifiedOutput) -> Vec<(bool, String)> {
+        let assertions = Assertions {
+            rules,
+            ..Default::default()
+        };
+        evaluate(&assertions, verified)
+            .into_iter()
+            .map(|outcome| (outcome.passed, outcome.detail))
+            .collect()
+    }
+
+    #[test]
+    fn contains_rules() {
+        let verified = VerifiedOutput::for_test("GET /balance", "balance: 100");
+        let passed: Vec<bool> = outcomes(
+            vec![
+                Rule::SentContains("/balance".to_string()),
+                Rule::ReceivedContains("100".to_string()),
+                Rule::ReceivedContains("200".to_string()),
+                Rule::ReceivedNotContains("200".to_string()),
+                Rule::ReceivedNotContains("100".to_string()),
+            ],
+            &verified,
+        )
+        .into_iter()
+        .map(|(passed, _)| passed)
+        .collect();
+        assert_eq!(passed, [true, true, false, true, false]);
+    }
+
+    #[test]
+    fn redacted_bytes_never_match() {
+        let mut verified = VerifiedOutput::for_test("", "balance: XXX, limit: 100");
+        verified.recv.redacted_ranges.push(9..12);
+        let transcript = &verified.recv;
+        assert!(!contains_disclosed(transcript, "XXX"));
+        // overlapping the redaction by a single byte is enough
+        assert!(!contains_disclosed(transcript, " X"));
+        assert!(!contains_disclosed(transcript, "X,"));
+        assert!(contains_disclosed(transcript, "balance: "));
+        assert!(contains_disclosed(transcript, ", limit"));
+
+        let outcomes = outcomes(
+            vec![
+                Rule::ReceivedContains("balance: XXX".to_string()),
+                Rule::ReceivedNotContains("XXX".to_string()),
+            ],
+            &verified,
+        );
+        assert_eq!(
+            outcomes[0],
+            (false, "not in the disclosed received bytes".to_string())
+        );
+        assert!(outcomes[1].0);
+    }
+
+    #[test]
+    fn json_values() {
+        let value = serde_json::json!({"name": "alice", "balance": 100, "ok": true});
+        assert!(json_value_equals(&value["name"], "alice"));
+        assert!(!json_value_equals(&value["name"], "\"alice\""));
+        assert!(json_value_equals(&value["balance"], "100"));
+        assert!(json_value_equals(&value["balance"], " 100 "));
+        assert!(!json_value_equals(&value["balance"], "100.5"));
+        assert!(json_value_equals(&value["ok"], "true"));
+        assert!(!json_value_equals(&value["ok"], "yes"));
+    }
+
+    #[test]
+    fn json_rule_on_unreadable_response() {
+        let verified = VerifiedOutput::for_test("", "not http");
+        let rule = Rule::JsonEquals {
+            path: "$.balance".to_string(),
+            value: "100".to_string(),
+        };
+        let outcomes = outcomes(vec![rule], &verified);
+        assert!(!outcomes[0].0);
+        assert!(outcomes[0].1.starts_with("the response can not be parsed"));
+    }
+}
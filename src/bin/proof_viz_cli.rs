@@ -0,0 +1,135 @@
+//! Verify a proof from the command line, with the same verification as the web page.
+//!
+//! ```text
+//! proof_viz_cli --proof proof.json --notary-key notary.pub [--json] [--skip-cert-verification]
+//! ```
+//!
+//! Exits with 0 if the proof is valid, 1 if it is not and 2 on usage errors.
+
+use std::fs;
+use std::process::ExitCode;
+
+use proof_viz::error_explainer;
+use proof_viz::notary_key::NotaryKey;
+use proof_viz::parsed_proof::ParsedProof;
+use proof_viz::report;
+use proof_viz::verify::{VerifiedOutput, VerifyError, VerifyOptions};
+
+const USAGE: &str = "usage: proof_viz_cli --proof <file.json> --notary-key <key.pem> [--json] [--skip-cert-verification]";
+
+struct Args {
+    proof: String,
+    notary_key: String,
+    json: bool,
+    skip_cert_verification: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut proof = None;
+    let mut notary_key = None;
+    let mut json = false;
+    let mut skip_cert_verification = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--proof" => proof = args.next(),
+            "--notary-key" => notary_key = args.next(),
+            "--json" => json = true,
+            "--skip-cert-verification" => skip_cert_verification = true,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            other => return Err(format!("unknown argument {}\n{}", other, USAGE)),
+        }
+    }
+
+    Ok(Args {
+        proof: proof.ok_or_else(|| format!("--proof is required\n{}", USAGE))?,
+        notary_key: notary_key.ok_or_else(|| format!("--notary-key is required\n{}", USAGE))?,
+        json,
+        skip_cert_verification,
+    })
+}
+
+fn text_report(proof: &ParsedProof, result: &Result<VerifiedOutput, VerifyError>) -> String {
+    match result {
+        Ok(verified) => {
+            let mut report = if verified.cert_chain_verified {
+                "Proof successfully verified\n".to_string()
+            } else {
+                "Notary signature verified, certificate chain NOT checked\n".to_string()
+            };
+            report += &format!("Format:      {}\n", verified.format);
+            report += &format!("Server:      {}\n", verified.server_name);
+            report += &format!("Notarized:   {}\n", verified.time);
+            report += &format!("SHA-256:     {}\n", proof.file_digest);
+            if let Some(canonical) = &proof.canonical_digest {
+                report += &format!("Canonical:   {}\n", canonical);
+            }
+            report += &format!(
+                "\nSent ({} bytes):\n{}\n",
+                verified.sent.bytes.len(),
+                String::from_utf8_lossy(&verified.sent.bytes)
+            );
+            report += &format!(
+                "\nReceived ({} bytes):\n{}\n",
+                verified.recv.bytes.len(),
+                String::from_utf8_lossy(&verified.recv.bytes)
+            );
+            report
+        }
+        Err(err) => {
+            let diagnosis = error_explainer::explain(err, proof);
+            let mut report = format!("Invalid proof: {}\n", diagnosis.summary);
+            for suggestion in &diagnosis.suggestions {
+                report += &format!("  - {}\n", suggestion);
+            }
+            report += &format!("Error: {}\n", diagnosis.detail);
+            report
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::from(2);
+        }
+    };
+
+    let read_error = |path: &str, err| format!("could not read {}: {}", path, err);
+    let inputs = fs::read(&args.proof)
+        .map_err(|err| read_error(&args.proof, err))
+        .and_then(|proof| {
+            let key = fs::read_to_string(&args.notary_key)
+                .map_err(|err| read_error(&args.notary_key, err))?;
+            let key =
+                NotaryKey::parse(&key).map_err(|err| format!("invalid Notary key: {}", err))?;
+            Ok((ParsedProof::parse(&proof), key))
+        });
+    let (proof, key) = match inputs {
+        Ok(inputs) => inputs,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::from(2);
+        }
+    };
+
+    let options = VerifyOptions {
+        skip_cert_verification: args.skip_cert_verification,
+        ..Default::default()
+    };
+    let result = proof.verify(key, &options).result;
+
+    if args.json {
+        println!("{:#}", report::to_json(&proof, key, &result));
+    } else {
+        print!("{}", text_report(&proof, &result));
+    }
+
+    if result.is_ok() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
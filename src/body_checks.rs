@@ -0,0 +1,208 @@
+//! Consistency of the HTTP message lengths: the `Content-Length` header against the parsed body,
+//! and bytes after the last message. A body shorter than declared, or a transcript which ends in
+//! the middle of a message, means the proof does not hold the whole response.
+
+use std::ops::Range;
+
+use crate::components::redacted_bytes_component::Direction;
+use crate::exchanges::{Exchanges, HeaderInfo};
+use crate::verify::{Transcript, VerifiedOutput};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Finding {
+    /// The body length differs from the `Content-Length` header
+    LengthMismatch {
+        direction: Direction,
+        /// 0-based index of the message in its direction
+        index: usize,
+        declared: usize,
+        actual: usize,
+    },
+    /// The `Content-Length` value is (partially) redacted, so the body length can not be checked
+    LengthRedacted { direction: Direction, index: usize },
+    /// The body is redacted up to the end of the transcript, e.g. only a prefix of the response
+    /// was opened
+    RedactedToEnd { direction: Direction, index: usize },
+    /// Bytes after the last message which do not parse as HTTP, e.g. a truncated message
+    UnparsedTail { direction: Direction, len: usize },
+}
+
+impl Finding {
+    /// Is the finding a problem rather than a limit of the check?
+    pub fn is_warning(&self) -> bool {
+        !matches!(self, Finding::LengthRedacted { .. })
+    }
+}
+
+/// The findings of one message with `headers` and `body`
+fn check_message(
+    transcript: &Transcript,
+    direction: Direction,
+    index: usize,
+    headers: &[HeaderInfo],
+    body: &Option<Range<usize>>,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let actual = body.as_ref().map_or(0, |body| body.len());
+
+    let content_length = headers
+        .iter()
+        .find(|header| header.name.eq_ignore_ascii_case("content-length"));
+    if let Some(header) = content_length {
+        if !transcript.redactions_within(&header.value).is_empty() {
+            findings.push(Finding::LengthRedacted { direction, index });
+        } else {
+            let value = String::from_utf8_lossy(&transcript.bytes[header.value.clone()]);
+            if let Ok(declared) = value.trim().parse::<usize>() {
+                if declared != actual {
+                    findings.push(Finding::LengthMismatch {
+                        direction,
+                        index,
+                        declared,
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+
+    if let Some(body) = body.as_ref().filter(|body| !body.is_empty()) {
+        let at_end = body.end == transcript.bytes.len();
+        let redacted_to_end = transcript
+            .redacted_ranges
+            .iter()
+            .any(|range| range.end == body.end && range.start > body.start);
+        if at_end && redacted_to_end {
+            findings.push(Finding::RedactedToEnd { direction, index });
+        }
+    }
+    findings
+}
+
+/// The findings of the `exchanges` of `verified`, as split by [`crate::exchanges::split`]
+pub fn check(verified: &VerifiedOutput, exchanges: &Exchanges) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let requests = exchanges
+        .exchanges
+        .iter()
+        .filter_map(|e| e.request.as_ref());
+    for (index, request) in requests.enumerate() {
+        findings.extend(check_message(
+            &verified.sent,
+            Direction::Sent,
+            index,
+            &request.headers,
+            &request.body,
+        ));
+    }
+    let responses = exchanges
+        .exchanges
+        .iter()
+        .filter_map(|e| e.response.as_ref());
+    for (index, response) in responses.enumerate() {
+        findings.extend(check_message(
+            &verified.recv,
+            Direction::Received,
+            index,
+            &response.headers,
+            &response.body,
+        ));
+    }
+
+    // Nothing parsed at all is not HTTP, which other views handle
+    if !exchanges.exchanges.is_empty() {
+        for (direction, transcript, parsed) in [
+            (Direction::Sent, &verified.sent, exchanges.sent_parsed),
+            (Direction::Received, &verified.recv, exchanges.recv_parsed),
+        ] {
+            let len = transcript.bytes.len() - parsed;
+            if len > 0 {
+                findings.push(Finding::UnparsedTail { direction, len });
+            }
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::{Exchange, ResponseInfo};
+    use crate::verify::find;
+
+    fn verified(recv: &str, redacted_ranges: Vec<Range<usize>>) -> VerifiedOutput {
+        let mut verified = VerifiedOutput::for_test("", recv);
+        verified.recv.redacted_ranges = redacted_ranges;
+        verified
+    }
+
+    fn exchanges(recv: &str, length: &str, body: &str, recv_parsed: usize) -> Exchanges {
+        Exchanges {
+            exchanges: vec![Exchange {
+                request: None,
+                response: Some(ResponseInfo {
+                    range: 0..recv_parsed,
+                    code: "200".to_string(),
+                    reason: "OK".to_string(),
+                    headers: vec![HeaderInfo {
+                        name: "Content-Length".to_string(),
+                        value: find(recv, length),
+                    }],
+                    content_type: None,
+                    body: Some(find(recv, body)),
+                }),
+            }],
+            sent_parsed: 0,
+            recv_parsed,
+        }
+    }
+
+    #[test]
+    fn consistent() {
+        let recv = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello";
+        let verified = verified(recv, Vec::new());
+        assert!(check(&verified, &exchanges(recv, "5", "hello", recv.len())).is_empty());
+    }
+
+    #[test]
+    fn truncated() {
+        let recv = "HTTP/1.1 200 OK\r\nContent-Length: 12\r\n\r\nhello";
+        let verified = verified(recv, vec![find(recv, "llo")]);
+        assert_eq!(
+            check(&verified, &exchanges(recv, "12", "hello", recv.len())),
+            [
+                Finding::LengthMismatch {
+                    direction: Direction::Received,
+                    index: 0,
+                    declared: 12,
+                    actual: 5
+                },
+                Finding::RedactedToEnd {
+                    direction: Direction::Received,
+                    index: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn redacted_length_and_tail() {
+        let recv = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhelloHTTP/1.1 2";
+        let verified = verified(recv, vec![find(recv, "5\r\n")]);
+        let findings = check(&verified, &exchanges(recv, "5", "hello", recv.len() - 10));
+        assert_eq!(
+            findings,
+            [
+                Finding::LengthRedacted {
+                    direction: Direction::Received,
+                    index: 0
+                },
+                Finding::UnparsedTail {
+                    direction: Direction::Received,
+                    len: 10
+                },
+            ]
+        );
+        assert!(!findings[0].is_warning());
+    }
+}
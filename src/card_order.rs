@@ -0,0 +1,124 @@
+//! The order in which loaded proofs are listed. Proofs are keyed on their file digest, so the
+//! order survives a proof being uploaded again. Pinned proofs stay above the others, new ones
+//! are listed first.
+
+use std::collections::BTreeSet;
+
+use crate::app::FileDetails;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CardOrder {
+    /// File digests, top first
+    order: Vec<String>,
+    pinned: BTreeSet<String>,
+}
+
+impl CardOrder {
+    /// List the proof with `digest` first, unless it is already known
+    pub fn insert(&mut self, digest: &str) {
+        if !self.order.iter().any(|known| known == digest) {
+            self.order.insert(0, digest.to_string());
+        }
+    }
+
+    pub fn remove(&mut self, digest: &str) {
+        self.order.retain(|known| known != digest);
+        self.pinned.remove(digest);
+    }
+
+    fn position(&self, digest: &str) -> Option<usize> {
+        self.order.iter().position(|known| known == digest)
+    }
+
+    /// Put `dragged` where `target` is: after it when moving down, before it when moving up
+    pub fn move_to(&mut self, dragged: &str, target: &str) {
+        if let (Some(from), Some(to)) = (self.position(dragged), self.position(target)) {
+            let dragged = self.order.remove(from);
+            self.order.insert(to, dragged);
+        }
+    }
+
+    pub fn is_pinned(&self, digest: &str) -> bool {
+        self.pinned.contains(digest)
+    }
+
+    pub fn toggle_pin(&mut self, digest: &str) {
+        if !self.pinned.remove(digest) {
+            self.pinned.insert(digest.to_string());
+        }
+    }
+
+    /// `files` with their indices, in display order
+    pub fn arrange<'a>(&self, files: &'a [FileDetails]) -> Vec<(usize, &'a FileDetails)> {
+        let mut arranged: Vec<(usize, &FileDetails)> = files.iter().enumerate().collect();
+        // Files missing from the order, which `insert` prevents, go last
+        arranged.sort_by_key(|(_, file)| {
+            (
+                !self.is_pinned(&file.digest),
+                self.position(&file.digest).unwrap_or(usize::MAX),
+            )
+        });
+        arranged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(names: &[&str]) -> (Vec<FileDetails>, CardOrder) {
+        let files: Vec<FileDetails> = names
+            .iter()
+            .map(|name| FileDetails::new(name.to_string(), String::new(), name.as_bytes().into()))
+            .collect();
+        let mut order = CardOrder::default();
+        files.iter().for_each(|file| order.insert(&file.digest));
+        (files, order)
+    }
+
+    fn names(order: &CardOrder, files: &[FileDetails]) -> Vec<String> {
+        order
+            .arrange(files)
+            .into_iter()
+            .map(|(_, file)| file.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn newest_first_and_moves() {
+        let (files, mut order) = files(&["a", "b", "c"]);
+        assert_eq!(names(&order, &files), ["c", "b", "a"]);
+        // Moving down puts the card after the target, moving up before it
+        order.move_to(&files[2].digest, &files[1].digest);
+        assert_eq!(names(&order, &files), ["b", "c", "a"]);
+        order.move_to(&files[0].digest, &files[1].digest);
+        assert_eq!(names(&order, &files), ["a", "b", "c"]);
+        // Uploading a proof again keeps its place
+        order.insert(&files[1].digest);
+        assert_eq!(names(&order, &files), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn pinned_stay_on_top() {
+        let (files, mut order) = files(&["a", "b", "c"]);
+        order.toggle_pin(&files[0].digest);
+        assert_eq!(names(&order, &files), ["a", "c", "b"]);
+        assert_eq!(order.arrange(&files)[0].0, 0);
+        order.move_to(&files[1].digest, &files[2].digest);
+        assert_eq!(names(&order, &files), ["a", "b", "c"]);
+        order.toggle_pin(&files[0].digest);
+        assert_eq!(names(&order, &files), ["b", "c", "a"]);
+    }
+    #[test]
+    fn removed_is_forgotten() {
+        let (files, mut order) = files(&["a", "b"]);
+        order.toggle_pin(&files[0].digest);
+        order.remove(&files[0].digest);
+        assert!(!order.is_pinned(&files[0].digest));
+        // Loaded again, it is listed as a new proof
+        order.insert(&files[0].digest);
+        assert_eq!(names(&order, &files), ["a", "b"]);
+        order.remove(&files[1].digest);
+        assert_eq!(order.arrange(&files)[1].1.name, "b");
+    }
+}
@@ -0,0 +1,198 @@
+//! Details of the server certificate chain in a proof's handshake data.
+//!
+//! `verify_with_default_cert_verifier` only tells whether the chain is valid, this digs the
+//! certificates out of the handshake decommitment so users can inspect them.
+
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use x509_cert::der::asn1::UintRef;
+use x509_cert::der::oid::{
+    db::{rfc5912, rfc8410},
+    AssociatedOid, ObjectIdentifier,
+};
+use x509_cert::der::{Decode, Encode, Reader, SliceReader};
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::ext::pkix::SubjectAltName;
+use x509_cert::Certificate;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    pub serial: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    /// Subject alternative names, DNS names and IP addresses
+    pub sans: Vec<String>,
+    pub key_algorithm: String,
+}
+
+impl CertificateInfo {
+    pub fn is_valid_at(&self, time: DateTime<Utc>) -> bool {
+        self.not_before <= time && time <= self.not_after
+    }
+
+    /// Whether one of the subject alternative names is `server_name`, a wildcard standing for
+    /// exactly one leftmost label
+    pub fn is_valid_for(&self, server_name: &str) -> bool {
+        let server_name = server_name.trim_end_matches('.').to_ascii_lowercase();
+        self.sans.iter().any(|san| {
+            let san = san.to_ascii_lowercase();
+            match san.strip_prefix("*.") {
+                Some(domain) => server_name
+                    .split_once('.')
+                    .is_some_and(|(label, rest)| !label.is_empty() && rest == domain),
+                None => san == server_name,
+            }
+        })
+    }
+}
+
+fn to_date_time(time: x509_cert::time::Time) -> DateTime<Utc> {
+    DateTime::UNIX_EPOCH + time.to_unix_duration()
+}
+
+/// The size of an RSAPublicKey `SEQUENCE { modulus INTEGER, publicExponent INTEGER }`
+fn rsa_modulus_bits(key: &[u8]) -> Option<usize> {
+    let mut reader = SliceReader::new(key).ok()?;
+    reader
+        .sequence(|sequence| {
+            let modulus = UintRef::decode(sequence)?;
+            UintRef::decode(sequence)?;
+            Ok(modulus.as_bytes().len() * 8)
+        })
+        .ok()
+}
+
+fn key_algorithm(cert: &Certificate) -> String {
+    let spki = &cert.tbs_certificate.subject_public_key_info;
+    let curve = spki
+        .algorithm
+        .parameters
+        .as_ref()
+        .and_then(|parameters| parameters.decode_as::<ObjectIdentifier>().ok());
+
+    match spki.algorithm.oid {
+        rfc5912::ID_EC_PUBLIC_KEY => match curve {
+            Some(rfc5912::SECP_256_R_1) => "ECDSA P-256".to_string(),
+            Some(rfc5912::SECP_384_R_1) => "ECDSA P-384".to_string(),
+            Some(rfc5912::SECP_521_R_1) => "ECDSA P-521".to_string(),
+            Some(curve) => format!("ECDSA ({})", curve),
+            None => "ECDSA".to_string(),
+        },
+        rfc5912::RSA_ENCRYPTION => match rsa_modulus_bits(spki.subject_public_key.raw_bytes()) {
+            Some(bits) => format!("RSA {} bits", bits),
+            None => "RSA".to_string(),
+        },
+        rfc8410::ID_ED_25519 => "Ed25519".to_string(),
+        oid => oid.to_string(),
+    }
+}
+
+fn subject_alt_names(cert: &Certificate) -> Vec<String> {
+    let extensions = cert.tbs_certificate.extensions.iter().flatten();
+    extensions
+        .filter(|extension| extension.extn_id == SubjectAltName::OID)
+        .filter_map(|extension| SubjectAltName::from_der(extension.extn_value.as_bytes()).ok())
+        .flat_map(|sans| sans.0)
+        .filter_map(|name| match name {
+            GeneralName::DnsName(name) => Some(name.to_string()),
+            GeneralName::IpAddress(ip) => match ip.as_bytes().len() {
+                4 => <[u8; 4]>::try_from(ip.as_bytes()).ok().map(IpAddr::from),
+                16 => <[u8; 16]>::try_from(ip.as_bytes()).ok().map(IpAddr::from),
+                _ => None,
+            }
+            .map(|ip| ip.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn parse_certificate(der: &[u8]) -> Result<CertificateInfo, String> {
+    let cert = Certificate::from_der(der).map_err(|e| e.to_string())?;
+    let tbs = &cert.tbs_certificate;
+
+    Ok(CertificateInfo {
+        subject: tbs.subject.to_string(),
+        issuer: tbs.issuer.to_string(),
+        serial: tbs.serial_number.to_string(),
+        not_before: to_date_time(tbs.validity.not_before),
+        not_after: to_date_time(tbs.validity.not_after),
+        sans: subject_alt_names(&cert),
+        key_algorithm: key_algorithm(&cert),
+    })
+}
+
+/// Read one or more certificates, PEM encoded or a single DER encoded one, returning them as DER
+pub fn parse_certificates(data: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    if data.trim_ascii_start().starts_with(b"-----BEGIN") {
+        let certs = Certificate::load_pem_chain(data).map_err(|e| e.to_string())?;
+        if certs.is_empty() {
+            return Err("no certificates found".to_string());
+        }
+        certs
+            .iter()
+            .map(|cert| cert.to_der().map_err(|e| e.to_string()))
+            .collect()
+    } else {
+        Certificate::from_der(data).map_err(|e| e.to_string())?;
+        Ok(vec![data.to_vec()])
+    }
+}
+
+/// The certificate chain of a proof, leaf certificate first
+pub fn cert_chain(proof: &Value) -> Result<Vec<CertificateInfo>, String> {
+    let chain = proof
+        .pointer("/session/handshake_data_decommitment/data/server_cert_details/cert_chain")
+        .and_then(Value::as_array)
+        .ok_or_else(|| "proof contains no certificate chain".to_string())?;
+
+    chain
+        .iter()
+        .map(|cert| {
+            let der: Vec<u8> = serde_json::from_value(cert.clone())
+                .map_err(|_| "certificate is not a byte array".to_string())?;
+            parse_certificate(&der)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn certificate(sans: &[&str]) -> CertificateInfo {
+        CertificateInfo {
+            subject: "CN=example.com".to_string(),
+            issuer: "CN=Example CA".to_string(),
+            serial: "01".to_string(),
+            not_before: DateTime::from_timestamp(1704067200, 0).unwrap(),
+            not_after: DateTime::from_timestamp(1735689599, 0).unwrap(),
+            sans: sans.iter().map(|san| san.to_string()).collect(),
+            key_algorithm: "P-256".to_string(),
+        }
+    }
+
+    #[test]
+    fn validity_window() {
+        let cert = certificate(&[]);
+        let second = chrono::Duration::seconds(1);
+        assert!(cert.is_valid_at(cert.not_before));
+        assert!(cert.is_valid_at(cert.not_after));
+        assert!(!cert.is_valid_at(cert.not_before - second));
+        assert!(!cert.is_valid_at(cert.not_after + second));
+    }
+
+    #[test]
+    fn valid_for() {
+        let cert = certificate(&["example.com", "*.API.example.com"]);
+        assert!(cert.is_valid_for("example.com"));
+        assert!(cert.is_valid_for("Example.com."));
+        assert!(cert.is_valid_for("v1.api.example.com"));
+        assert!(!cert.is_valid_for("api.example.com"));
+        assert!(!cert.is_valid_for("a.v1.api.example.com"));
+        assert!(!cert.is_valid_for("www.example.com"));
+    }
+}
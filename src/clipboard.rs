@@ -0,0 +1,7 @@
+/// Copy text to the clipboard, failures (e.g. no permission) are ignored
+pub fn copy(text: &str) {
+    let _ = gloo::utils::window()
+        .navigator()
+        .clipboard()
+        .write_text(text);
+}
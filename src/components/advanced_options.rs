@@ -0,0 +1,44 @@
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub skip_cert_verification: bool,
+    pub on_skip_cert_verification: Callback<bool>,
+}
+
+/// Options which weaken verification, for testing only
+#[function_component]
+pub fn AdvancedOptions(
+    Props {
+        skip_cert_verification,
+        on_skip_cert_verification,
+    }: &Props,
+) -> Html {
+    let onclick = {
+        let skip = *skip_cert_verification;
+        on_skip_cert_verification.reform(move |_: MouseEvent| !skip)
+    };
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    html! {
+        <div class="container flex mx-auto p-4">
+            <details class="w-full" open={*skip_cert_verification}>
+                <summary class={classes!("px-4", "md:px-8", theme::SUMMARY)}><b>{t("Advanced options")}</b>{if *skip_cert_verification {" ⚠️"} else {""}}</summary>
+                <div class="px-4 md:px-8">
+                    <label class="flex gap-2 items-center py-2">
+                        <input type="checkbox" checked={*skip_cert_verification} {onclick} />
+                        {t("Skip certificate chain verification")}
+                    </label>
+                    <p class="text-sm text-yellow-600 dark:text-yellow-400">
+                        {t("Reduced assurance: only the Notary signature and the handshake commitment are checked. ")}
+                        {t("A proof then no longer shows which server it came from. Use this only to test proofs of servers with self-signed or expired certificates.")}
+                    </p>
+                </div>
+            </details>
+        </div>
+    }
+}
@@ -0,0 +1,238 @@
+use gloo::file::callbacks::FileReader;
+use gloo::file::File;
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::assertions::{Assertions, Rule};
+use crate::i18n::Locale;
+use crate::policy::Policy;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub assertions: Assertions,
+    pub on_change: Callback<Assertions>,
+}
+
+/// The kinds of rules offered in the rule builder
+const RULE_KINDS: [(&str, &str); 4] = [
+    ("sent-contains", "Sent bytes contain"),
+    ("received-contains", "Received bytes contain"),
+    ("received-not-contains", "Received bytes do not contain"),
+    ("json-equals", "Received body JSON field equals"),
+];
+
+fn build_rule(kind: &str, path: &str, text: &str) -> Option<Rule> {
+    if text.is_empty() {
+        return None;
+    }
+    let text = text.to_string();
+    match kind {
+        "sent-contains" => Some(Rule::SentContains(text)),
+        "received-contains" => Some(Rule::ReceivedContains(text)),
+        "received-not-contains" => Some(Rule::ReceivedNotContains(text)),
+        "json-equals" if !path.trim().is_empty() => Some(Rule::JsonEquals {
+            path: path.trim().to_string(),
+            value: text,
+        }),
+        _ => None,
+    }
+}
+
+/// Expectations every loaded proof is checked against
+#[function_component]
+pub fn AssertionsPanel(
+    Props {
+        assertions,
+        on_change,
+    }: &Props,
+) -> Html {
+    let kind = use_state(|| RULE_KINDS[0].0.to_string());
+    let path = use_state(String::new);
+    let text = use_state(String::new);
+    let policy_error = use_state(|| None::<String>);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    // keep the file read alive until it completes
+    let reader = use_mut_ref(|| None::<FileReader>);
+
+    let load_policy = {
+        let assertions = assertions.clone();
+        let on_change = on_change.clone();
+        let policy_error = policy_error.clone();
+        Callback::from(move |json: Vec<u8>| match Policy::parse(&json) {
+            Ok(policy) => {
+                let mut assertions = assertions.clone();
+                assertions.policy = Some(policy);
+                policy_error.set(None);
+                on_change.emit(assertions);
+            }
+            Err(err) => policy_error.set(Some(
+                locale.format("Invalid policy: {error}", &[("error", &err)]),
+            )),
+        })
+    };
+    let on_policy_paste = {
+        let load_policy = load_policy.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            if !input.value().trim().is_empty() {
+                load_policy.emit(input.value().into_bytes());
+            }
+        })
+    };
+    let read_policy = {
+        let load_policy = load_policy.clone();
+        Callback::from(move |file: web_sys::File| {
+            let load_policy = load_policy.clone();
+            let task = gloo::file::callbacks::read_as_bytes(&File::from(file), move |res| {
+                load_policy.emit(res.expect("failed to read file"))
+            });
+            *reader.borrow_mut() = Some(task);
+        })
+    };
+    let on_policy_file = {
+        let read_policy = read_policy.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                read_policy.emit(file);
+            }
+        })
+    };
+    let on_policy_drop = Callback::from(move |e: DragEvent| {
+        e.prevent_default();
+        let file = e
+            .data_transfer()
+            .and_then(|transfer| transfer.files())
+            .and_then(|files| files.get(0));
+        if let Some(file) = file {
+            read_policy.emit(file);
+        }
+    });
+    let clear_policy = {
+        let assertions = assertions.clone();
+        on_change.reform(move |_: MouseEvent| {
+            let mut assertions = assertions.clone();
+            assertions.policy = None;
+            assertions
+        })
+    };
+
+    let on_server_name = {
+        let assertions = assertions.clone();
+        on_change.reform(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let value = input.value();
+            let mut assertions = assertions.clone();
+            assertions.expected_server_name = (!value.trim().is_empty()).then_some(value);
+            assertions
+        })
+    };
+
+    let on_kind = {
+        let kind = kind.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            kind.set(select.value());
+        })
+    };
+    let on_path = {
+        let path = path.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            path.set(input.value());
+        })
+    };
+    let on_text = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            text.set(input.value());
+        })
+    };
+
+    let add_rule = {
+        let assertions = assertions.clone();
+        let on_change = on_change.clone();
+        let (kind, path, text) = (kind.clone(), path.clone(), text.clone());
+        Callback::from(move |_: MouseEvent| {
+            if let Some(rule) = build_rule(&kind, &path, &text) {
+                let mut assertions = assertions.clone();
+                assertions.rules.push(rule);
+                on_change.emit(assertions);
+                text.set(String::new());
+            }
+        })
+    };
+
+    let remove_rule = |index: usize| {
+        let assertions = assertions.clone();
+        on_change.reform(move |_: MouseEvent| {
+            let mut assertions = assertions.clone();
+            assertions.rules.remove(index);
+            assertions
+        })
+    };
+
+    let button = "px-2 hover:bg-black hover:text-white rounded border-black border";
+
+    html! {
+        <details class={classes!("p-4", "my-4", theme::CARD)} open={true}>
+            <summary class={theme::SUMMARY}><b>{t("Assertions")}</b>{locale.format(" ({count} rules)", &[("count", &assertions.rules.len())])}</summary>
+            <label class="flex gap-2 items-center py-2 text-sm">
+                {t("Expected server name:")}
+                <input class={classes!("p-1", "flex-1", "font-mono", theme::INPUT)}
+                    placeholder="api.twitter.com or *.example.com"
+                    value={assertions.expected_server_name.clone().unwrap_or_default()}
+                    oninput={on_server_name} />
+            </label>
+            <ul class="text-sm">
+                {for assertions.rules.iter().enumerate().map(|(index, rule)| html! {
+                    <li class="flex gap-2 items-center py-1">
+                        <span class="font-mono flex-1">{rule.to_string()}</span>
+                        <button class={button} onclick={remove_rule(index)}>{t("Remove")}</button>
+                    </li>
+                })}
+            </ul>
+            <div class="flex gap-2 items-center py-2 text-sm">
+                <select class={classes!("p-1", theme::INPUT)} aria-label={t("Rule")} onchange={on_kind}>
+                    {for RULE_KINDS.iter().map(|(value, label)| html! {
+                        <option value={*value} selected={*kind == *value}>{t(label)}</option>
+                    })}
+                </select>
+                if *kind == "json-equals" {
+                    <input class={classes!("p-1", "w-48", "font-mono", theme::INPUT)} placeholder="$.status" aria-label={t("JSON path")} value={(*path).clone()} oninput={on_path} />
+                }
+                <input class={classes!("p-1", "flex-1", "font-mono", theme::INPUT)}
+                    placeholder={if *kind == "json-equals" { "ok" } else { "GET /api/v1/balance" }}
+                    aria-label={t("Expected text")}
+                    value={(*text).clone()}
+                    oninput={on_text} />
+                <button class={button} onclick={add_rule}>{t("Add rule")}</button>
+            </div>
+            <div class="py-2 text-sm"
+                ondrop={on_policy_drop}
+                ondragover={Callback::from(|e: DragEvent| e.prevent_default())}>
+                <b>{t("Policy: ")}</b>
+                if let Some(policy) = &assertions.policy {
+                    <span class="font-mono">{serde_json::to_string(policy).unwrap_or_default()}</span>
+                    {" "}
+                    <button class={button} onclick={clear_policy}>{t("Clear")}</button>
+                } else {
+                    <span class="text-gray-400">{t("drop or paste a JSON policy file")}</span>
+                    <textarea class={classes!("block", "p-2", "w-full", "mt-2", "font-mono", theme::INPUT)}
+                        rows="3"
+                        aria-label={t("JSON policy")}
+                        placeholder={r#"{"expected_server_name": "api.example.com", "max_age_days": 30, "required_disclosed_paths": ["$.status"], "forbidden_disclosed_headers": ["cookie"]}"#}
+                        oninput={on_policy_paste}>
+                    </textarea>
+                    <input type="file" class="mt-2" accept="application/json,.json" aria-label={t("JSON policy file")} onchange={on_policy_file} />
+                }
+                if let Some(error) = policy_error.as_ref() {
+                    <p class="mt-2 text-red-500" role="alert">{error}</p>
+                }
+            </div>
+        </details>
+    }
+}
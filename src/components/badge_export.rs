@@ -0,0 +1,49 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+use crate::export::{self, badge::Badge};
+use crate::i18n::Locale;
+use crate::notary_key::NotaryKey;
+use crate::notary_registry::Registry;
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+    pub pem: NotaryKey,
+}
+
+/// A badge of the verified proof to embed in a web page, as a download or copied SVG markup
+#[function_component]
+pub fn BadgeExport(Props { verified, pem }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let registry = use_context::<Registry>().unwrap_or_else(Registry::bundled);
+    let badge = Badge::new(verified, *pem, &registry);
+    let svg = badge.to_svg();
+
+    let on_download = {
+        let svg = svg.clone();
+        let name = badge.file_name();
+        Callback::from(move |_: MouseEvent| export::download(&name, "image/svg+xml", &svg))
+    };
+
+    html! {
+        <details class={theme::PANEL}>
+            <summary class={theme::SUMMARY}><b>{locale.t("Badge")}</b></summary>
+            <div class={classes!("p-4", "rounded-md", "flex", "flex-col", "gap-2", theme::CODE)}>
+                <p class="text-sm text-gray-400">{locale.t("A badge to put next to a link to this proof, e.g. in a blog post")}</p>
+                // Generated by `Badge::to_svg`, which escapes the server name
+                <div>{Html::from_html_unchecked(AttrValue::from(svg.clone()))}</div>
+                <div class="flex gap-2">
+                    <button class="px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" onclick={on_download}>
+                        {locale.t("Download SVG")}
+                    </button>
+                    <CopyButton value={svg} what={locale.t("the SVG markup of the badge")} label={locale.t("Copy SVG")} />
+                </div>
+            </div>
+        </details>
+    }
+}
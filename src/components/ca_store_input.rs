@@ -0,0 +1,147 @@
+use gloo::file::callbacks::FileReader;
+use gloo::file::File;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::certificate::{parse_certificate, parse_certificates};
+use crate::i18n::Locale;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// DER encoded root certificates trusted besides the webpki roots
+    pub roots: Vec<Vec<u8>>,
+    pub on_change: Callback<Vec<Vec<u8>>>,
+}
+
+fn render_root(locale: Locale, index: usize, root: &[u8], on_remove: Callback<usize>) -> Html {
+    let (subject, valid_until) = match parse_certificate(root) {
+        Ok(cert) => (cert.subject, cert.not_after.to_string()),
+        Err(err) => (err, "-".to_string()),
+    };
+    let onclick = on_remove.reform(move |_: MouseEvent| index);
+
+    html! {
+        <tr class="border-t border-gray-600">
+            <td class="px-2 py-1 font-mono break-all">{subject}</td>
+            <td class="px-2 py-1 font-mono">{valid_until}</td>
+            <td class="px-2 py-1">
+                <button class="px-2 hover:bg-black hover:text-white rounded border-black border" {onclick}>{locale.t("Remove")}</button>
+            </td>
+        </tr>
+    }
+}
+
+/// Extra trusted root certificates, for proofs of servers with a private or internal CA
+#[function_component]
+pub fn CaStoreInput(Props { roots, on_change }: &Props) -> Html {
+    let pasted = use_state(String::new);
+    let error = use_state(|| None::<String>);
+    // keep the file read alive until it completes
+    let reader = use_mut_ref(|| None::<FileReader>);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    let add = {
+        let roots = roots.clone();
+        let on_change = on_change.clone();
+        let error = error.clone();
+        Callback::from(move |data: Vec<u8>| match parse_certificates(&data) {
+            Ok(certs) => {
+                let mut roots = roots.clone();
+                for cert in certs {
+                    if !roots.contains(&cert) {
+                        roots.push(cert);
+                    }
+                }
+                error.set(None);
+                on_change.emit(roots);
+            }
+            Err(err) => error.set(Some(
+                locale.format("Not a valid certificate: {error}", &[("error", &err)]),
+            )),
+        })
+    };
+
+    let oninput = {
+        let pasted = pasted.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            pasted.set(input.value());
+        })
+    };
+
+    let add_pasted = {
+        let pasted = pasted.clone();
+        let add = add.clone();
+        Callback::from(move |_: MouseEvent| {
+            add.emit(pasted.as_bytes().to_vec());
+            pasted.set(String::new());
+        })
+    };
+
+    let onchange = {
+        let add = add.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                let add = add.clone();
+                let task = gloo::file::callbacks::read_as_bytes(&File::from(file), move |res| {
+                    add.emit(res.expect("failed to read file"))
+                });
+                *reader.borrow_mut() = Some(task);
+            }
+        })
+    };
+
+    let on_remove = {
+        let roots = roots.clone();
+        let on_change = on_change.clone();
+        Callback::from(move |index: usize| {
+            let mut roots = roots.clone();
+            roots.remove(index);
+            on_change.emit(roots);
+        })
+    };
+
+    html! {
+        <div class="container flex mx-auto p-4">
+            <details class="w-full" open={true}>
+                <summary class={classes!("px-4", "md:px-8", theme::SUMMARY)}><b>{t("Trusted root certificates:")}</b>{locale.format(" webpki roots + {count} custom", &[("count", &roots.len())])}</summary>
+                <div class="px-4 md:px-8">
+                    if !roots.is_empty() {
+                        <table class="w-full table-auto text-left text-sm my-2">
+                            <thead>
+                                <tr>
+                                    <th class="px-2 py-1">{t("Subject")}</th>
+                                    <th class="px-2 py-1">{t("Valid until")}</th>
+                                    <th class="px-2 py-1"></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {for roots.iter().enumerate().map(|(index, root)| render_root(locale, index, root, on_remove.clone()))}
+                            </tbody>
+                        </table>
+                    }
+                    <textarea class={classes!("block", "p-2.5", "w-full", "mt-2", "text-sm", "font-mono", theme::INPUT)}
+                        rows="4"
+                        aria-label={t("Root certificate in PEM format")}
+                        placeholder="-----BEGIN CERTIFICATE-----"
+                        value={(*pasted).clone()}
+                        {oninput}>
+                    </textarea>
+                    if let Some(error) = error.as_ref() {
+                        <p class="mt-2 text-red-500" role="alert">{error}</p>
+                    }
+                    <p class="mt-2 text-sm text-gray-400">{t("Certificates of private or internal CAs, to verify proofs of servers the webpki roots do not cover")}</p>
+                    <div class="flex gap-2 justify-end items-center">
+                        <input type="file" class="text-sm" accept=".pem,.crt,.cer,.der" aria-label={t("Root certificate file")} {onchange} />
+                        <button class="px-4 py-2 hover:bg-black hover:text-white rounded border-black border" onclick={add_pasted}>
+                            {t("Add")}
+                        </button>
+                    </div>
+                </div>
+            </details>
+        </div>
+    }
+}
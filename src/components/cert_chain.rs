@@ -0,0 +1,82 @@
+use std::rc::Rc;
+
+use chrono::{DateTime, Utc};
+use yew::prelude::*;
+
+use crate::certificate::{cert_chain, CertificateInfo};
+use crate::i18n::Locale;
+use crate::parsed_proof::ParsedProof;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub proof: Rc<ParsedProof>,
+    /// Notarization time, the certificates should be valid at this time
+    pub time: DateTime<Utc>,
+}
+
+fn render_certificate(
+    locale: Locale,
+    index: usize,
+    cert: &CertificateInfo,
+    time: DateTime<Utc>,
+) -> Html {
+    let t = |message| locale.t(message);
+    let role = if index == 0 {
+        t("Server certificate")
+    } else {
+        t("Intermediate / root certificate")
+    };
+    let validity = if cert.is_valid_at(time) {
+        html! { <span class="text-green-700 dark:text-green-400">{t("✅ valid at notarization time")}</span> }
+    } else {
+        html! { <span class="text-red-500">{t("❌ not valid at notarization time")}</span> }
+    };
+
+    html! {
+        <div class={classes!("p-4", "my-2", "rounded-md", theme::CODE)}>
+            <p class="font-bold">{format!("#{} {}", index, role)}</p>
+            <table class="table-auto text-left text-sm font-mono">
+                <tbody>
+                    <tr><th class="pr-4 align-top">{t("Subject")}</th><td class="break-all">{cert.subject.clone()}</td></tr>
+                    <tr><th class="pr-4 align-top">{t("Issuer")}</th><td class="break-all">{cert.issuer.clone()}</td></tr>
+                    <tr><th class="pr-4 align-top">{t("Serial")}</th><td class="break-all">{cert.serial.clone()}</td></tr>
+                    <tr>
+                        <th class="pr-4 align-top">{t("Validity")}</th>
+                        <td>{format!("{} – {} ", cert.not_before, cert.not_after)}{validity}</td>
+                    </tr>
+                    if !cert.sans.is_empty() {
+                        <tr><th class="pr-4 align-top">{t("SANs")}</th><td class="break-all">{cert.sans.join(", ")}</td></tr>
+                    }
+                    <tr><th class="pr-4 align-top">{t("Key")}</th><td>{cert.key_algorithm.clone()}</td></tr>
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+/// The certificate chain the server presented in the notarized TLS handshake
+#[function_component]
+pub fn CertChain(Props { proof, time }: &Props) -> Html {
+    let chain = use_memo(proof.clone(), |proof| cert_chain(&proof.value));
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    let content = match chain.as_ref() {
+        Ok(chain) => chain
+            .iter()
+            .enumerate()
+            .map(|(index, cert)| render_certificate(locale, index, cert, *time))
+            .collect::<Html>(),
+        Err(err) => {
+            html! { <p class="text-red-500">{locale.format("Could not read the certificates: {error}", &[("error", err)])}</p> }
+        }
+    };
+    let count = chain.as_ref().as_ref().map_or(0, |chain| chain.len());
+
+    html! {
+        <details class={theme::PANEL}>
+            <summary class={theme::SUMMARY}><b>{locale.t("Certificate chain: ")}</b>{locale.format("({count} certificates)", &[("count", &count)])}</summary>
+            {content}
+        </details>
+    }
+}
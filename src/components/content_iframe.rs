@@ -1,31 +1,57 @@
 // use gloo::console::log;
 // use std::fmt;
+use std::ops::Range;
 
+use base64::{engine::general_purpose, Engine as _};
+use sha2::{Digest, Sha256};
 use spansy::http::parse_response;
-use wasm_bindgen::prelude::*;
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
+use crate::components::json_tree::JsonTree;
+use crate::i18n::Locale;
+use crate::json_query;
+use crate::theme;
+
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
     pub bytes: Vec<u8>,
+    pub redacted_ranges: Vec<Range<usize>>,
 }
 
-fn render_json(content: String) -> String {
-    let json = serde_json::from_str::<serde_json::Value>(content.as_str());
-    if let Ok(json) = json {
-        serde_json::to_string_pretty(&json).unwrap()
-    } else {
-        content
-    }
+/// The redacted ranges of the body, relative to the start of the body
+fn body_redactions(
+    bytes: &[u8],
+    body: &[u8],
+    redacted_ranges: &[Range<usize>],
+) -> Vec<Range<usize>> {
+    // The body directly follows the headers, unless it is e.g. chunked
+    let header_end = bytes
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .map(|pos| pos + 4);
+    let offset = match header_end {
+        Some(offset) if bytes[offset..].starts_with(body) => offset,
+        _ => return Vec::new(),
+    };
+
+    redacted_ranges
+        .iter()
+        .filter(|r| r.end > offset && r.start < offset + body.len())
+        .map(|r| r.start.max(offset) - offset..r.end.min(offset + body.len()) - offset)
+        .collect()
 }
 
 #[derive(Debug)]
 enum ContentType {
     Html,
     Json,
+    /// An image, with its mime type
+    Image(String),
+    Pdf,
     Other,
 }
-fn get_content_type(bytes: &[u8]) -> (ContentType, String) {
+fn get_content_type(bytes: &[u8]) -> (ContentType, Vec<u8>) {
     match parse_response(bytes) {
         Ok(x) => {
             // log!(format!("Test {:?}", x.headers));
@@ -36,56 +62,178 @@ fn get_content_type(bytes: &[u8]) -> (ContentType, String) {
                 .find(|h| h.name.as_str().to_lowercase() == "content-type")
                 .map_or(ContentType::Other, |header| {
                     let type_string = String::from_utf8_lossy(header.value.as_bytes());
+                    let mime = type_string
+                        .split(';')
+                        .next()
+                        .unwrap_or("")
+                        .trim()
+                        .to_lowercase();
                     match type_string {
                         s if s.contains("text/html") => ContentType::Html,
                         s if s.contains("application/json") => ContentType::Json,
-                        _ => ContentType::Other,
+                        _ => match mime.as_str() {
+                            "image/png" | "image/jpeg" | "image/svg+xml" => {
+                                ContentType::Image(mime)
+                            }
+                            "application/pdf" => ContentType::Pdf,
+                            _ => ContentType::Other,
+                        },
                     }
                 });
 
-            let body = x.body.map_or(String::new(), |body| {
-                String::from_utf8_lossy(body.as_bytes()).to_string()
-            });
+            let body = x.body.map_or(Vec::new(), |body| body.as_bytes().to_vec());
 
             // log!(format!("Test {:?}", content_type));
 
             (content_type, body)
         }
-        Err(e) => (ContentType::Other, e.to_string()),
+        Err(e) => (ContentType::Other, e.to_string().into_bytes()),
+    }
+}
+
+fn data_url(mime: &str, body: &[u8]) -> String {
+    format!(
+        "data:{};base64,{}",
+        mime,
+        general_purpose::STANDARD.encode(body)
+    )
+}
+
+fn binary_caption(body: &[u8]) -> String {
+    format!(
+        "{} bytes, sha256: {}",
+        body.len(),
+        hex::encode(Sha256::digest(body))
+    )
+}
+
+// Show the values a JSONPath expression selects from the JSON body
+fn render_json_query(locale: Locale, content: &str, expression: &str) -> Html {
+    if expression.trim().is_empty() {
+        return html! {};
+    }
+
+    let json = match serde_json::from_str::<serde_json::Value>(content) {
+        Ok(json) => json,
+        Err(e) => {
+            return html! { <p class="text-red-500">{locale.format("Body is not valid JSON: {error}", &[("error", &e)])}</p> }
+        }
+    };
+
+    match json_query::query(&json, expression) {
+        Ok(values) if values.is_empty() => {
+            html! { <p class="text-gray-400">{locale.t("No match")}</p> }
+        }
+        Ok(values) => html! {
+            <div class={classes!("p-4", "rounded-md", "overflow-x-auto", theme::CODE)}>
+                {for values.iter().map(|value| html! {
+                    <pre>{serde_json::to_string_pretty(value).unwrap()}</pre>
+                })}
+            </div>
+        },
+        Err(e) => html! { <p class="text-red-500">{e}</p> },
     }
 }
 
 #[function_component]
 pub fn ContentIFrame(props: &Props) -> Html {
-    // JavaScript function to trigger Prism highlighting
-    use_effect(highlight_code);
+    // The notarized page may contain scripts, only show it unsanitized when asked to
+    let raw_preview = use_state(|| false);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    let toggle_raw_preview = {
+        let raw_preview = raw_preview.clone();
+        Callback::from(move |_| raw_preview.set(!*raw_preview))
+    };
+
+    let json_query = use_state(String::new);
+    let on_json_query = {
+        let json_query = json_query.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            json_query.set(input.value());
+        })
+    };
 
     match get_content_type(&props.bytes) {
-        (ContentType::Html, content_html) => html! {
-            <details class="p-4 w-5/6" open={true}>
-                <summary><b>{"Received HTML content:"}</b></summary>
-                <iframe class="w-full h-64" srcdoc={content_html} src="demo_iframe_srcdoc.htm">
-                    <p>{">Your browser does not support iframes."}</p>
-                </iframe>
+        (ContentType::Html, body) => {
+            let content_html = String::from_utf8_lossy(&body).to_string();
+            let srcdoc = if *raw_preview {
+                content_html
+            } else {
+                ammonia::clean(&content_html)
+            };
+            html! {
+                <details class={theme::PANEL} open={true}>
+                    <summary class={theme::SUMMARY}><b>{t("Received HTML content:")}</b></summary>
+                    <label class="flex gap-2 items-center py-2 text-sm">
+                        <input type="checkbox" checked={*raw_preview} onclick={toggle_raw_preview} />
+                        {t("Raw preview (unsanitized, scripts and forms stay blocked by the sandbox)")}
+                    </label>
+                    // An empty sandbox attribute applies all restrictions: no scripts, no forms, unique origin
+                    <iframe class="w-full h-64" title={t("Received HTML content")} sandbox="" srcdoc={srcdoc} src="demo_iframe_srcdoc.htm">
+                        <p>{t("Your browser does not support iframes.")}</p>
+                    </iframe>
+                </details>
+            }
+        }
+        (ContentType::Json, body) => {
+            let content_json = String::from_utf8_lossy(&body).to_string();
+            let body_redacted_ranges = body_redactions(&props.bytes, &body, &props.redacted_ranges);
+            html! {
+                <details class={theme::PANEL} open={true}>
+                    <summary class={theme::SUMMARY}><b>{t("Received JSON content:")}</b></summary>
+                    <label class="flex gap-2 items-center py-2 text-sm">
+                        {t("Query:")}
+                        <input class={classes!("p-1", "flex-1", "font-mono", theme::INPUT)} placeholder="$.data.balance" oninput={on_json_query} />
+                    </label>
+                    {render_json_query(locale, &content_json, &json_query)}
+                    <div class={classes!("p-4", "rounded-md", "overflow-x-auto", "mt-2", theme::CODE)}>
+                        <JsonTree bytes={body} redacted_ranges={body_redacted_ranges} />
+                    </div>
+                </details>
+            }
+        }
+        (ContentType::Image(mime), body) => html! {
+            <details class={theme::PANEL} open={true}>
+                <summary class={theme::SUMMARY}><b>{t("Received image:")}</b></summary>
+                <img class="max-w-full bg-white" src={data_url(&mime, &body)} alt={mime.clone()} />
+                <p class="text-sm text-gray-400 font-mono">{mime}{", "}{binary_caption(&body)}</p>
             </details>
         },
-        (ContentType::Json, content_json) => html! {
-            <details class="p-4 w-5/6" open={true}>
-                <summary><b>{"Received JSON content:"}</b></summary>
-                <div class="bg-black text-white p-4 rounded-md overflow-x-auto">
-                    <pre>
-                        <code class="lang-json">
-                            {render_json(content_json)}
-                        </code>
-                    </pre>
-                </div>
+        (ContentType::Pdf, body) => html! {
+            <details class={theme::PANEL} open={true}>
+                <summary class={theme::SUMMARY}><b>{t("Received PDF:")}</b></summary>
+                <object class="w-full h-96" type="application/pdf" data={data_url("application/pdf", &body)}>
+                    <p>{t("Your browser can not display PDF files.")}</p>
+                </object>
+                <p class="text-sm text-gray-400 font-mono">{binary_caption(&body)}</p>
             </details>
         },
         _ => html! {},
     }
 }
 
-#[wasm_bindgen(inline_js = "export function highlight_code() { Prism.highlightAll(); }")]
-extern "C" {
-    fn highlight_code();
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::components::harness::{render, text};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    async fn json_is_pretty_printed() {
+        let bytes = b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"user\":{\"name\":\"alice\"}}";
+        let root = render::<ContentIFrame>(Props {
+            bytes: bytes.to_vec(),
+            redacted_ranges: Vec::new(),
+        })
+        .await;
+        assert!(text(&root).contains("Received JSON content:"));
+        // The nested member is on a line of its own, indented below its object
+        let nested = root
+            .query_selector(".pl-6 .pl-6")
+            .unwrap()
+            .expect("nested members are indented");
+        assert_eq!(text(&nested), "\"name\": \"alice\"");
+    }
 }
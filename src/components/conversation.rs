@@ -0,0 +1,105 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::components::http_exchanges::render_endpoint;
+use crate::components::line_view::LineView;
+use crate::components::mail_view::MailView;
+use crate::components::redacted_bytes_component::render_range;
+use crate::endpoints::Endpoint;
+use crate::exchanges::{self, Exchange};
+use crate::i18n::Locale;
+use crate::theme;
+use crate::verify::{Transcript, VerifiedOutput};
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn render_message(title: String, transcript: &Transcript, range: Option<&Range<usize>>) -> Html {
+    html! {
+        <div class="flex-1 min-w-0">
+            <b class="text-sm">{title}</b>
+            <pre class={classes!("p-2", "rounded-md", "overflow-x-auto", "whitespace-pre-wrap", "break-all", "text-sm", theme::CODE)}>
+                if let Some(range) = range {
+                    {render_range(transcript, range)}
+                } else {
+                    <span class="text-gray-400">{"–"}</span>
+                }
+            </pre>
+        </div>
+    }
+}
+
+fn render_exchange(
+    locale: Locale,
+    verified: &VerifiedOutput,
+    index: usize,
+    exchange: &Exchange,
+) -> Html {
+    let request = exchange.request.as_ref();
+    let response = exchange.response.as_ref();
+    let request_title = request.map_or(locale.t("(no request)").to_string(), |request| {
+        format!("→ {} {}", request.method, request.path)
+    });
+    let response_title = response.map_or(locale.t("(no response)").to_string(), |response| {
+        format!("← {} {}", response.code, response.reason)
+    });
+    html! {
+        <li class="py-2">
+            <p class="text-sm">
+                <span class="text-gray-400">{format!("#{} ", index + 1)}</span>
+                if let Some(endpoint) = Endpoint::new(exchange) {
+                    {render_endpoint(&endpoint)}
+                }
+            </p>
+            <div class="flex flex-col lg:flex-row gap-2">
+                {render_message(request_title, &verified.sent, request.map(|request| &request.range))}
+                {render_message(response_title, &verified.recv, response.map(|response| &response.range))}
+            </div>
+        </li>
+    }
+}
+
+/// Bytes after the last message of a direction which do not parse as HTTP
+fn render_unparsed(title: String, transcript: &Transcript, parsed: usize) -> Html {
+    let range = parsed..transcript.bytes.len();
+    if range.is_empty() {
+        return html! {};
+    }
+    render_message(title, transcript, Some(&range))
+}
+
+/// The transcript as a conversation, like a proxy tool shows it: each request next to its
+/// response, in the order they were sent
+#[function_component]
+pub fn Conversation(Props { verified }: &Props) -> Html {
+    let split = use_memo(verified.clone(), |verified| exchanges::split(verified));
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    if split.exchanges.is_empty() {
+        // Not HTTP, the views of other protocols show their own conversation
+        return html! {
+            <>
+                <MailView verified={verified.clone()} />
+                <LineView verified={verified.clone()} />
+            </>
+        };
+    }
+
+    html! {
+        <div class={theme::PANEL}>
+            <ol>
+                {for split.exchanges.iter().enumerate().map(|(index, exchange)| {
+                    render_exchange(locale, verified, index, exchange)
+                })}
+            </ol>
+            <div class="flex flex-col lg:flex-row gap-2">
+                {render_unparsed(locale.t("Sent bytes which are not HTTP:").to_string(), &verified.sent, split.sent_parsed)}
+                {render_unparsed(locale.t("Received bytes which are not HTTP:").to_string(), &verified.recv, split.recv_parsed)}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,73 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::components::redacted_bytes_component::Direction;
+use crate::i18n::Locale;
+use crate::privacy_lints::{self, Cookie};
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn render_cookie(locale: Locale, cookie: &Cookie) -> Html {
+    let header = match cookie.direction {
+        Direction::Sent => "Cookie",
+        Direction::Received => "Set-Cookie",
+    };
+    let leaked = cookie.is_sensitive() && cookie.is_fully_disclosed();
+
+    html! {
+        <tr class="border-t border-gray-600">
+            <td class="px-2 py-1">{header}</td>
+            <td class="px-2 py-1">
+                if leaked {
+                    <span title={locale.t("Looks like a session credential and is fully disclosed")}>{"⚠️ "}</span>
+                }
+                {cookie.name.clone()}
+            </td>
+            <td class={classes!("px-2", "py-1", "break-all", (!cookie.is_fully_disclosed()).then_some("text-red-500"))}>
+                {cookie.value.clone()}
+            </td>
+            <td class="px-2 py-1 text-gray-400">{cookie.attributes.join("; ")}</td>
+        </tr>
+    }
+}
+
+/// The cookies sent to and set by the server
+#[function_component]
+pub fn CookieList(Props { verified }: &Props) -> Html {
+    let cookies = use_memo(verified.clone(), |verified| {
+        privacy_lints::cookies(verified)
+    });
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    if cookies.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <details class={theme::PANEL}>
+            <summary class={theme::SUMMARY}><b>{locale.format("Cookies ({count}):", &[("count", &cookies.len())])}</b></summary>
+            <div class={classes!("p-2", "rounded-md", "overflow-x-auto", theme::CODE)}>
+                <table class="w-full table-auto text-left text-sm font-mono">
+                    <thead>
+                        <tr>
+                            <th class="px-2 py-1">{t("Header")}</th>
+                            <th class="px-2 py-1">{t("Name")}</th>
+                            <th class="px-2 py-1">{t("Value")}</th>
+                            <th class="px-2 py-1">{t("Attributes")}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {for cookies.iter().map(|cookie| render_cookie(locale, cookie))}
+                    </tbody>
+                </table>
+            </div>
+        </details>
+    }
+}
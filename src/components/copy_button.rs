@@ -0,0 +1,67 @@
+use gloo::timers::callback::Timeout;
+use yew::prelude::*;
+
+use crate::clipboard;
+use crate::i18n::Locale;
+
+// How long the button shows that the value was copied, in milliseconds
+const COPIED_FEEDBACK: u32 = 1500;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// The text put on the clipboard
+    #[prop_or_default]
+    pub value: AttrValue,
+    /// Computes the text put on the clipboard when clicked, instead of `value`, for texts too
+    /// large to prepare on every render
+    #[prop_or_default]
+    pub compute: Option<Callback<(), String>>,
+    /// What is copied, for the tooltip and screen readers
+    pub what: AttrValue,
+    /// Button text, a copy icon if not set
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+}
+
+/// A button which copies `value` to the clipboard and briefly confirms it
+#[function_component]
+pub fn CopyButton(
+    Props {
+        value,
+        compute,
+        what,
+        label,
+    }: &Props,
+) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let copied = use_state(|| false);
+    let timeout = use_mut_ref(|| None::<Timeout>);
+
+    let onclick = {
+        let (value, compute) = (value.clone(), compute.clone());
+        let copied = copied.clone();
+        Callback::from(move |_: MouseEvent| {
+            match &compute {
+                Some(compute) => clipboard::copy(&compute.emit(())),
+                None => clipboard::copy(&value),
+            }
+            copied.set(true);
+            let copied = copied.clone();
+            *timeout.borrow_mut() = Some(Timeout::new(COPIED_FEEDBACK, move || copied.set(false)));
+        })
+    };
+
+    let title = locale.format("Copy {what}", &[("what", what)]);
+    let text = match (*copied, label) {
+        (true, _) => AttrValue::from("✓"),
+        (false, Some(label)) => label.clone(),
+        (false, None) => AttrValue::from("⧉"),
+    };
+
+    html! {
+        <button class="px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border"
+            title={title.clone()} aria-label={title} {onclick}>
+            {text}
+        </button>
+    }
+}
@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::coverage::{format_size, Coverage};
+use crate::i18n::Locale;
+use crate::parsed_proof::ParsedProof;
+use crate::session_header::session_header;
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// The proof, for the committed lengths of the session header
+    pub proof: Rc<ParsedProof>,
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn render_direction(locale: Locale, label: &'static str, coverage: &Coverage) -> Html {
+    let width = |bytes: usize| format!("width: {:.2}%", coverage.percent(bytes));
+    let title = locale.format(
+        "{disclosed} disclosed, {redacted} redacted, {uncovered} not covered",
+        &[
+            ("disclosed", &format_size(coverage.disclosed)),
+            ("redacted", &format_size(coverage.redacted)),
+            ("uncovered", &format_size(coverage.uncovered)),
+        ],
+    );
+    html! {
+        <div class="py-1">
+            <p class="text-sm">
+                <b>{locale.t(label)}</b>{" "}
+                {locale.format("disclosed {disclosed} of {committed} committed", &[("disclosed", &format_size(coverage.disclosed)), ("committed", &format_size(coverage.committed))])}
+                <span class="text-gray-400">{format!(" ({:.0}%)", coverage.percent(coverage.disclosed))}</span>
+            </p>
+            <div class="flex h-3 w-full rounded overflow-hidden bg-gray-300 dark:bg-zinc-600" role="img" aria-label={title.clone()} {title}>
+                <div class="bg-green-500" style={width(coverage.disclosed)}></div>
+                <div class="bg-red-500" style={width(coverage.redacted)}></div>
+            </div>
+            if coverage.uncovered > 0 {
+                <p class="text-sm text-yellow-600 dark:text-yellow-400">
+                    {locale.format("⚠️ {uncovered} are neither disclosed nor redacted: the proof says nothing about them but that they exist", &[("uncovered", &format_size(coverage.uncovered))])}
+                </p>
+            }
+        </div>
+    }
+}
+
+/// How much of the transcript lengths committed in the session header the proof opens
+#[function_component]
+pub fn CoverageBar(Props { proof, verified }: &Props) -> Html {
+    let header = use_memo(proof.clone(), |proof| session_header(&proof.value).ok());
+    let locale = use_context::<Locale>().unwrap_or_default();
+    // Without a session header, e.g. in newer formats, the transcripts have the committed length
+    let (sent_len, recv_len) = match header.as_ref() {
+        Some(header) => (header.sent_len, header.recv_len),
+        None => (verified.sent.bytes.len(), verified.recv.bytes.len()),
+    };
+    let sent = Coverage::new(sent_len, &verified.sent);
+    let recv = Coverage::new(recv_len, &verified.recv);
+
+    html! {
+        <div class={theme::PANEL}>
+            <b>{locale.t("Coverage:")}</b>
+            <div class={classes!("p-4", "rounded-md", theme::CODE)}>
+                {render_direction(locale, "Sent:", &sent)}
+                {render_direction(locale, "Received:", &recv)}
+                <p class="text-xs text-gray-400">
+                    <span class="text-green-500">{"■ "}</span>{locale.t("disclosed")}
+                    <span class="ml-2 text-red-500">{"■ "}</span>{locale.t("redacted")}
+                    <span class="ml-2 text-gray-400">{"■ "}</span>{locale.t("not covered")}
+                </p>
+            </div>
+        </div>
+    }
+}
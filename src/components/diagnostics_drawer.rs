@@ -0,0 +1,88 @@
+use gloo::timers::callback::Interval;
+use tracing::Level;
+use yew::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+use crate::diagnostics::{self, Entry};
+use crate::i18n::Locale;
+
+// How often the open drawer shows new events, in milliseconds
+const REFRESH_INTERVAL: u32 = 1000;
+
+fn level_class(level: Level) -> &'static str {
+    match level {
+        Level::ERROR => "text-red-500",
+        Level::WARN => "text-orange-500",
+        Level::INFO => "",
+        _ => "text-gray-400",
+    }
+}
+
+fn render_entry(entry: &Entry) -> Html {
+    html! {
+        <li class={classes!("whitespace-pre-wrap", "break-all", level_class(entry.level))}>{entry.to_string()}</li>
+    }
+}
+
+/// A drawer with the diagnostic log, to copy into a problem report
+#[function_component]
+pub fn DiagnosticsDrawer() -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    let open = use_state(|| false);
+    let refresh = use_force_update();
+
+    {
+        let refresh = refresh.clone();
+        use_effect_with(*open, move |open| {
+            let interval =
+                open.then(|| Interval::new(REFRESH_INTERVAL, move || refresh.force_update()));
+            move || drop(interval)
+        });
+    }
+
+    let toggle = {
+        let open = open.clone();
+        Callback::from(move |_: MouseEvent| open.set(!*open))
+    };
+    let on_clear = Callback::from(move |_: MouseEvent| {
+        diagnostics::clear();
+        refresh.force_update();
+    });
+    let button_class =
+        "px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border";
+
+    if !*open {
+        return html! {
+            <button class="fixed bottom-2 right-2 z-10 px-2 py-1 text-sm rounded border border-gray-400 bg-white dark:bg-zinc-800 shadow" title={t("Diagnostics, to attach to a problem report")} aria-expanded="false" onclick={toggle}>
+                {t("🩺 Diagnostics")}
+            </button>
+        };
+    }
+
+    let entries = diagnostics::entries();
+    let user_agent = gloo::utils::window()
+        .navigator()
+        .user_agent()
+        .unwrap_or_default();
+    let dump = diagnostics::dump(&entries, &user_agent);
+
+    html! {
+        <section class="fixed inset-x-0 bottom-0 z-10 max-h-[50vh] flex flex-col gap-2 p-4 border-t border-gray-400 bg-white dark:bg-zinc-800 shadow-lg" aria-label={t("Diagnostics")}>
+            <div class="flex flex-wrap gap-2 items-center">
+                <b class="flex-1">{t("Diagnostics")}</b>
+                <CopyButton value={dump} what={t("the diagnostic log")} label={AttrValue::from(t("Copy"))} />
+                <button class={button_class} onclick={on_clear}>{t("Clear")}</button>
+                <button class={button_class} aria-expanded="true" onclick={toggle}>{t("Close")}</button>
+            </div>
+            <p class="text-sm text-gray-400">{t("What this page did: files loaded, formats detected, verification steps and their timings, and errors. Copy it into your problem report; it contains file names but no proof contents.")}</p>
+            if entries.is_empty() {
+                <p class="text-sm text-gray-400">{t("Nothing logged yet.")}</p>
+            } else {
+                <ol class="overflow-auto font-mono text-xs">
+                    {for entries.iter().map(render_entry)}
+                </ol>
+            }
+        </section>
+    }
+}
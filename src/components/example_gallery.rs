@@ -0,0 +1,66 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::fixtures::{Example, EXAMPLES};
+use crate::i18n::Locale;
+use crate::route::Route;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// Called with the file name and content of the chosen example
+    pub on_load: Callback<(String, Vec<u8>)>,
+    /// Whether the selected Notary key is the test fixture key the examples are signed with
+    pub fixture_key: bool,
+}
+
+fn render_example(
+    locale: Locale,
+    example: &Example,
+    on_load: &Callback<(String, Vec<u8>)>,
+) -> Html {
+    let onclick = {
+        let example = *example;
+        on_load.reform(move |_: MouseEvent| {
+            (
+                example.file_name.to_string(),
+                (example.proof)().into_bytes(),
+            )
+        })
+    };
+
+    html! {
+        <button class="flex-1 p-4 text-left rounded border border-black dark:border-gray-400 hover:bg-black hover:text-white" {onclick}>
+            <p class="font-bold">{locale.t(example.title)}</p>
+            <p class="text-sm">{locale.t(example.description)}</p>
+        </button>
+    }
+}
+
+/// Bundled proofs to load like an upload, to see what verified and failed proofs look like
+#[function_component]
+pub fn ExampleGallery(
+    Props {
+        on_load,
+        fixture_key,
+    }: &Props,
+) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    html! {
+        <div class={classes!("p-4", "my-4", "flex", "flex-col", "gap-2", theme::CARD)}>
+            <p class="text-center"><b>{t("No proof at hand? Try an example")}</b></p>
+            <div class="flex flex-col md:flex-row gap-2">
+                {for EXAMPLES.iter().map(|example| render_example(locale, example, on_load))}
+            </div>
+            if !*fixture_key {
+                <p class="text-sm text-yellow-600 dark:text-yellow-400">
+                    {t("The examples are signed with the TLSNotary test fixture key, select it under ")}
+                    <Link<Route> classes="underline" to={Route::Keys}>{t("Keys")}</Link<Route>>
+                    {t(" to verify them")}
+                </p>
+            }
+        </div>
+    }
+}
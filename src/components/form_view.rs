@@ -0,0 +1,127 @@
+use std::ops::Range;
+
+use yew::prelude::*;
+
+use crate::components::redacted_bytes_component::segments;
+use crate::exchanges::Exchange;
+use crate::forms::{self, Form, FormField, Part};
+use crate::i18n::Locale;
+use crate::theme;
+use crate::verify::{Transcript, VerifiedOutput};
+
+// Above this size, multipart contents are summarized instead of shown
+const MAX_PART_PREVIEW: usize = 4096;
+
+fn redaction_label(text: &str) -> Html {
+    html! {
+        <span class="ml-2 px-1 text-xs rounded bg-red-200 text-red-800 dark:bg-red-900 dark:text-red-300">{text.to_string()}</span>
+    }
+}
+
+/// The raw bytes of `range`, with the redacted parts in red
+fn render_raw(body: &Transcript, range: &Range<usize>) -> Html {
+    let part = body.slice(range);
+    html! {
+        <>
+            {for segments(part.bytes.len(), &part.redacted_ranges).into_iter().map(|(start, end, is_redacted)| html! {
+                <span class={if is_redacted { "text-red-500" } else { "" }}>{String::from_utf8_lossy(&part.bytes[start..end])}</span>
+            })}
+        </>
+    }
+}
+
+fn render_value(locale: Locale, body: &Transcript, field: &FormField) -> Html {
+    let redacted: usize = body
+        .redactions_within(&field.value_range)
+        .iter()
+        .map(|r| r.len())
+        .sum();
+
+    if redacted == 0 {
+        html! { {field.value.clone()} }
+    } else if !body.is_disclosed(&field.value_range) {
+        html! {
+            <>
+                <span class="text-red-500">{render_raw(body, &field.value_range)}</span>
+                {redaction_label(&locale.format("redacted, {count} bytes", &[("count", &redacted)]))}
+            </>
+        }
+    } else {
+        // decoding a partially redacted value would hide which characters are redacted
+        html! {
+            <>
+                {render_raw(body, &field.value_range)}
+                {redaction_label(locale.t("partially redacted"))}
+            </>
+        }
+    }
+}
+
+fn render_urlencoded(locale: Locale, body: &Transcript, fields: &[FormField]) -> Html {
+    html! {
+        <table class={classes!("w-full", "table-auto", "text-left", "text-sm", "font-mono", theme::CODE)}>
+            {for fields.iter().map(|field| html! {
+                <tr class="border-t border-gray-600">
+                    <td class="px-2 py-1 align-top">{field.name.clone()}</td>
+                    <td class="px-2 py-1 break-all">{render_value(locale, body, field)}</td>
+                </tr>
+            })}
+        </table>
+    }
+}
+
+fn render_part(locale: Locale, body: &Transcript, part: &Part) -> Html {
+    let is_text = !matches!(&part.content_type, Some(content_type) if !content_type.starts_with("text/"))
+        && std::str::from_utf8(&body.bytes[part.content.clone()]).is_ok();
+
+    let content = if is_text && part.content.len() <= MAX_PART_PREVIEW {
+        html! {
+            <pre class="whitespace-pre-wrap break-all">{render_raw(body, &part.content)}</pre>
+        }
+    } else {
+        html! { <p class="text-gray-400">{locale.format("{count} bytes", &[("count", &part.content.len())])}</p> }
+    };
+
+    html! {
+        <div class={classes!("p-2", "my-1", "rounded-md", "text-sm", theme::CODE)}>
+            <p class="font-mono">
+                <b>{part.name.clone().unwrap_or_else(|| locale.t("(unnamed part)").to_string())}</b>
+                if let Some(filename) = &part.filename {
+                    {locale.format(" file: {name}", &[("name", filename)])}
+                }
+                if let Some(content_type) = &part.content_type {
+                    <span class="text-gray-400">{format!(" ({})", content_type)}</span>
+                }
+                if !body.is_disclosed(&part.content) && !part.content.is_empty() {
+                    {redaction_label(locale.t("redacted"))}
+                } else if !body.redactions_within(&part.content).is_empty() {
+                    {redaction_label(locale.t("partially redacted"))}
+                }
+            </p>
+            {content}
+        </div>
+    }
+}
+
+/// A form submission decoded into its fields, `None` if the request body is not a form
+pub fn render(locale: Locale, verified: &VerifiedOutput, exchange: &Exchange) -> Option<Html> {
+    let request = exchange.request.as_ref()?;
+    let body = verified.sent.slice(request.body.as_ref()?);
+
+    let form = forms::decode(request.content_type.as_deref()?, &body.bytes)?;
+
+    Some(match form {
+        Form::UrlEncoded(fields) => html! {
+            <>
+                <b>{locale.t("Form fields:")}</b>
+                {render_urlencoded(locale, &body, &fields)}
+            </>
+        },
+        Form::Multipart(parts) => html! {
+            <>
+                <b>{locale.format("Multipart form ({count} parts):", &[("count", &parts.len())])}</b>
+                {for parts.iter().map(|part| render_part(locale, &body, part))}
+            </>
+        },
+    })
+}
@@ -0,0 +1,295 @@
+use std::ops::Range;
+
+use yew::prelude::*;
+
+use crate::components::json_tree::JsonTree;
+use crate::exchanges::Exchange;
+use crate::i18n::Locale;
+use crate::json_spans::{self, JsonSpan, JsonValue};
+use crate::theme;
+use crate::verify::{Transcript, VerifiedOutput};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Token {
+    Keyword,
+    Name,
+    Variable,
+    String,
+    Comment,
+    Punct,
+}
+
+fn token_class(token: Token) -> &'static str {
+    match token {
+        Token::Keyword => "text-purple-600 dark:text-purple-400",
+        Token::Name => "",
+        Token::Variable => "text-orange-600 dark:text-orange-400",
+        Token::String => "text-green-700 dark:text-green-400",
+        Token::Comment => "text-gray-500",
+        Token::Punct => "text-gray-500 dark:text-gray-400",
+    }
+}
+
+const KEYWORDS: [&str; 8] = [
+    "query",
+    "mutation",
+    "subscription",
+    "fragment",
+    "on",
+    "true",
+    "false",
+    "null",
+];
+
+/// Split a GraphQL document into tokens, each with whether whitespace preceded it
+fn tokenize(query: &str) -> Vec<(Token, String, bool)> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut space = false;
+    let is_name = |c: char| c == '_' || c.is_ascii_alphanumeric();
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        let token = if c.is_whitespace() {
+            i += 1;
+            space = true;
+            continue;
+        } else if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            Token::Comment
+        } else if c == '"' {
+            let block = chars[i..].starts_with(&['"', '"', '"']);
+            i += if block { 3 } else { 1 };
+            while i < chars.len() {
+                if block && chars[i..].starts_with(&['"', '"', '"']) {
+                    i += 3;
+                    break;
+                } else if !block && chars[i] == '"' {
+                    i += 1;
+                    break;
+                }
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            Token::String
+        } else if c == '$' || is_name(c) || c == '-' {
+            i += 1;
+            while i < chars.len() && (is_name(chars[i]) || chars[i] == '.') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            if c == '$' {
+                Token::Variable
+            } else if KEYWORDS.contains(&text.as_str()) {
+                Token::Keyword
+            } else {
+                Token::Name
+            }
+        } else if chars[i..].starts_with(&['.', '.', '.']) {
+            i += 3;
+            Token::Punct
+        } else {
+            i += 1;
+            Token::Punct
+        };
+
+        let text: String = chars[start..i.min(chars.len())].iter().collect();
+        tokens.push((token, text, space));
+        space = false;
+    }
+    tokens
+}
+
+/// Re-indent a GraphQL document: one field per line, nested selection sets indented
+fn format_query(query: &str) -> Vec<Vec<(Token, String)>> {
+    let mut lines: Vec<Vec<(Token, String)>> = Vec::new();
+    let mut line: Vec<(Token, String)> = Vec::new();
+    let mut depth = 0usize;
+    let mut parens = 0usize;
+    let mut previous = String::new();
+
+    let mut newline = |line: &mut Vec<(Token, String)>, depth: usize| {
+        if !line.is_empty() {
+            line.insert(0, (Token::Punct, "  ".repeat(depth)));
+            lines.push(std::mem::take(line));
+        }
+    };
+
+    for (token, text, space) in tokenize(query) {
+        // a name following a complete field within a selection set starts the next field
+        let field_ended = previous == ")"
+            || previous == "}"
+            || !matches!(
+                previous.as_str(),
+                "" | "..." | "on" | ":" | "@" | "(" | "[" | "="
+            ) && previous
+                .chars()
+                .all(|c| c == '_' || c.is_ascii_alphanumeric());
+        let starts_field =
+            depth > 0 && parens == 0 && (token == Token::Name || text == "...") && field_ended;
+
+        match text.as_str() {
+            // commas are insignificant, only keep them between arguments
+            "," if parens == 0 => continue,
+            "{" if parens == 0 => {
+                let brace = if line.is_empty() { "{" } else { " {" };
+                line.push((Token::Punct, brace.to_string()));
+                newline(&mut line, depth);
+                depth += 1;
+            }
+            "}" if parens == 0 => {
+                newline(&mut line, depth);
+                depth = depth.saturating_sub(1);
+                line.push((Token::Punct, "}".to_string()));
+                newline(&mut line, depth);
+            }
+            _ => {
+                if starts_field || token == Token::Comment && !line.is_empty() {
+                    newline(&mut line, depth);
+                }
+                let tight = matches!(previous.as_str(), "(" | "[" | "{" | "@" | "$")
+                    || matches!(text.as_str(), ")" | "]" | "}" | ":" | "!" | ",");
+                if !line.is_empty() && !tight && (space || previous == ":" || previous == ",") {
+                    line.push((Token::Punct, " ".to_string()));
+                }
+                match text.as_str() {
+                    "(" => parens += 1,
+                    ")" => parens = parens.saturating_sub(1),
+                    _ => {}
+                }
+                line.push((token, text.clone()));
+                if token == Token::Comment {
+                    newline(&mut line, depth);
+                }
+            }
+        }
+        previous = text;
+    }
+    newline(&mut line, depth);
+    lines
+}
+
+fn member<'a>(object: &'a JsonSpan, key: &str) -> Option<&'a JsonSpan> {
+    match &object.value {
+        JsonValue::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+        _ => None,
+    }
+}
+
+fn render_variable(
+    locale: Locale,
+    bytes: &[u8],
+    value: &JsonSpan,
+    redacted_ranges: &[Range<usize>],
+) -> Html {
+    match &value.value {
+        JsonValue::Redacted => html! { <span class="text-red-500">{locale.t("redacted")}</span> },
+        JsonValue::String(s) if !value.is_redacted(redacted_ranges) => html! { {s.clone()} },
+        _ => {
+            let class = if value.is_redacted(redacted_ranges) {
+                "text-red-500"
+            } else {
+                ""
+            };
+            html! { <span class={class}>{String::from_utf8_lossy(&bytes[value.range.clone()])}</span> }
+        }
+    }
+}
+
+/// The response `data` member, or the whole response if it has none
+fn render_response(body: Transcript) -> Html {
+    let data = json_spans::parse(&body.bytes, &body.redacted_ranges)
+        .ok()
+        .and_then(|json| member(&json, "data").cloned());
+    let (bytes, redacted_ranges) = match data {
+        Some(data) => (
+            body.bytes[data.range.clone()].to_vec(),
+            body.redactions_within(&data.range),
+        ),
+        None => (body.bytes, body.redacted_ranges),
+    };
+
+    html! {
+        <div class={classes!("p-2", "rounded-md", "overflow-x-auto", theme::CODE)}>
+            <JsonTree {bytes} {redacted_ranges} />
+        </div>
+    }
+}
+
+/// A GraphQL POST shown as its formatted query and variables, next to the response data.
+/// `None` if the request is not a GraphQL request.
+pub fn render(locale: Locale, verified: &VerifiedOutput, exchange: &Exchange) -> Option<Html> {
+    let t = |message| locale.t(message);
+    let request = exchange.request.as_ref()?;
+    if !request
+        .content_type
+        .as_deref()?
+        .to_ascii_lowercase()
+        .contains("application/json")
+    {
+        return None;
+    }
+    let Transcript {
+        bytes,
+        redacted_ranges,
+    } = verified.sent.slice(request.body.as_ref()?);
+    let json = json_spans::parse(&bytes, &redacted_ranges).ok()?;
+
+    let query = member(&json, "query")?;
+    let query = match &query.value {
+        JsonValue::String(query) => html! {
+            <pre class={classes!("p-2", "rounded-md", "overflow-x-auto", theme::CODE)}>
+                {for format_query(query).into_iter().map(|line| html! {
+                    <div>
+                        {for line.into_iter().map(|(token, text)| html! {
+                            <span class={token_class(token)}>{text}</span>
+                        })}
+                    </div>
+                })}
+            </pre>
+        },
+        JsonValue::Redacted => html! { <p class="text-red-500">{t("The query is redacted")}</p> },
+        _ => return None,
+    };
+
+    let variables = match member(&json, "variables").map(|v| &v.value) {
+        Some(JsonValue::Object(variables)) if !variables.is_empty() => html! {
+            <table class="w-full table-auto text-left text-sm font-mono">
+                {for variables.iter().map(|(name, value)| html! {
+                    <tr class="border-t border-gray-600">
+                        <td class="px-2 py-1 text-orange-600 dark:text-orange-400">{format!("${}", name)}</td>
+                        <td class="px-2 py-1 break-all">{render_variable(locale, &bytes, value, &redacted_ranges)}</td>
+                    </tr>
+                })}
+            </table>
+        },
+        _ => html! { <p class="text-gray-400">{t("No variables")}</p> },
+    };
+
+    let response = exchange
+        .response
+        .as_ref()
+        .and_then(|response| response.body.as_ref())
+        .map(|body| verified.recv.slice(body));
+
+    Some(html! {
+        <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+            <div>
+                <b>{t("GraphQL query:")}</b>
+                {query}
+                <b>{t("Variables:")}</b>
+                {variables}
+            </div>
+            <div>
+                <b>{t("Response data:")}</b>
+                {match response {
+                    Some(body) => render_response(body),
+                    None => html! { <p class="text-gray-400">{t("No response")}</p> },
+                }}
+            </div>
+        </div>
+    })
+}
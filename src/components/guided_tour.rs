@@ -0,0 +1,134 @@
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::theme;
+use crate::tour::TourStep;
+use crate::verify::{Step, StepOutcome};
+
+// Marks the highlighted section, see styles.css
+const ACTIVE: &str = "data-tour-active";
+
+/// Remove the highlight from every section within `card`
+fn clear(card: &Element) {
+    if let Ok(active) = card.query_selector_all(&format!("[{}]", ACTIVE)) {
+        for i in 0..active.length() {
+            if let Some(element) = active
+                .item(i)
+                .and_then(|node| node.dyn_into::<Element>().ok())
+            {
+                let _ = element.remove_attribute(ACTIVE);
+            }
+        }
+    }
+}
+
+/// Highlight the section `target` within `card`, expanded and scrolled into view
+fn highlight(card: &Element, target: &str) {
+    clear(card);
+    let Ok(Some(section)) = card.query_selector(&format!("[data-tour='{}']", target)) else {
+        return;
+    };
+    // A `display: contents` wrapper has no box, the component inside is what is highlighted
+    let section = match section.first_element_child() {
+        Some(child) if section.matches(".contents").unwrap_or(false) => child,
+        _ => section,
+    };
+    if section.tag_name().eq_ignore_ascii_case("details") {
+        let _ = section.set_attribute("open", "");
+    }
+    let _ = section.set_attribute(ACTIVE, "");
+    section.scroll_into_view();
+}
+
+fn render_outcome(locale: Locale, step: Step, outcome: &StepOutcome) -> Html {
+    let check = locale.t(step.description());
+    let (color, text) = match outcome {
+        StepOutcome::Passed => ("text-green-500", format!("✓ {}", check)),
+        StepOutcome::Failed(err) => ("text-red-500", format!("✗ {}: {}", check, err)),
+        StepOutcome::Skipped => (
+            "text-gray-400",
+            locale.format("– {check}: skipped", &[("check", &check)]),
+        ),
+        StepOutcome::NotChecked => (
+            "text-gray-400",
+            locale.format("– {check}: not checked", &[("check", &check)]),
+        ),
+        StepOutcome::NotApplicable => (
+            "text-gray-400",
+            locale.format("– {check}: nothing to check", &[("check", &check)]),
+        ),
+    };
+    html! { <li class={color}>{text}</li> }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub steps: Vec<TourStep>,
+    /// The card of the proof, whose sections the steps point at
+    pub card: NodeRef,
+    pub on_close: Callback<()>,
+}
+
+/// Walks through the sections of a proof's card one step at a time, highlighting each
+#[function_component]
+pub fn GuidedTour(
+    Props {
+        steps,
+        card,
+        on_close,
+    }: &Props,
+) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    let index = use_state(|| 0);
+
+    {
+        let card = card.clone();
+        let target = steps.get(*index).map(|step| step.target);
+        use_effect_with(target, move |target| {
+            if let (Some(card), Some(target)) = (card.cast::<Element>(), target) {
+                highlight(&card, target);
+            }
+            move || {
+                if let Some(card) = card.cast::<Element>() {
+                    clear(&card);
+                }
+            }
+        });
+    }
+
+    let Some(step) = steps.get(*index) else {
+        return html! {};
+    };
+    let go = |to: usize| {
+        let index = index.clone();
+        Callback::from(move |_: MouseEvent| index.set(to))
+    };
+    let is_last = *index + 1 == steps.len();
+    let button = "px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border disabled:opacity-50";
+
+    html! {
+        <div role="dialog" aria-label={t("Guided tour")}
+            class={classes!("fixed", "bottom-4", "inset-x-4", "md:left-auto", "md:w-96", "z-10", "p-4", "flex", "flex-col", "gap-2", "shadow-lg", "rounded-lg", "border", "border-amber-500", theme::CARD)}>
+            <p class="text-sm text-gray-400">{locale.format("Step {number} of {count}", &[("number", &(*index + 1)), ("count", &steps.len())])}</p>
+            <p class="font-bold">{step.title.clone()}</p>
+            <p class="text-sm">{step.text.clone()}</p>
+            if !step.outcomes.is_empty() {
+                <ul class="text-sm">
+                    {for step.outcomes.iter().map(|(check, outcome)| render_outcome(locale, *check, outcome))}
+                </ul>
+            }
+            <div class="flex gap-2 justify-end">
+                <button class={button} disabled={*index == 0} onclick={go(index.saturating_sub(1))}>{t("← Back")}</button>
+                if is_last {
+                    <button class={button} onclick={on_close.reform(|_: MouseEvent| ())}>{t("Done")}</button>
+                } else {
+                    <button class={button} onclick={go(*index + 1)}>{t("Next →")}</button>
+                    <button class={button} onclick={on_close.reform(|_: MouseEvent| ())}>{t("Close")}</button>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,49 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+use crate::exchanges;
+use crate::export::{self, har::Har};
+use crate::i18n::Locale;
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+/// The HTTP exchanges of the transcript as a HAR file, for browser devtools and other tools
+#[function_component]
+pub fn HarExport(Props { verified }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let har = use_memo(verified.clone(), |verified| {
+        let split = exchanges::split(verified);
+        (!split.exchanges.is_empty()).then(|| Har::new(verified, &split).to_json())
+    });
+    let Some(har) = har.as_ref().clone() else {
+        return html! {};
+    };
+
+    let on_download = {
+        let har = har.clone();
+        let name = Har::file_name(verified);
+        Callback::from(move |_: MouseEvent| export::download(&name, "application/json", &har))
+    };
+
+    html! {
+        <details class={theme::PANEL}>
+            <summary class={theme::SUMMARY}><b>{locale.t("HAR export")}</b></summary>
+            <div class={classes!("p-4", "rounded-md", "flex", "flex-col", "gap-2", theme::CODE)}>
+                <p class="text-sm text-gray-400">{locale.t("The HTTP exchanges as an HTTP Archive, to import into browser devtools. Redacted bytes are replaced by █.")}</p>
+                <div class="flex gap-2">
+                    <button class="px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" onclick={on_download}>
+                        {locale.t("Download HAR")}
+                    </button>
+                    <CopyButton value={har} what={locale.t("the HAR file")} label={locale.t("Copy HAR")} />
+                </div>
+            </div>
+        </details>
+    }
+}
@@ -0,0 +1,30 @@
+//! Mounting components in a browser, for the rendering tests of the components. These run with
+//! `wasm-pack test --headless --firefox` (or `--chrome`); `cargo test` skips them.
+
+use std::time::Duration;
+
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+use web_sys::Element;
+use yew::{BaseComponent, Renderer};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Render `C` with `props` into a new element of the page, after its first render and effects
+pub async fn render<C: BaseComponent>(props: C::Properties) -> Element {
+    let document = gloo::utils::document();
+    let root = document
+        .create_element("div")
+        .expect("a div can be created");
+    gloo::utils::body()
+        .append_child(&root)
+        .expect("the root can be added to the page");
+    Renderer::<C>::with_root_and_props(root.clone(), props).render();
+    // Yew renders on the next tick, effects and their re-renders right after
+    yew::platform::time::sleep(Duration::from_millis(50)).await;
+    root
+}
+
+/// The text of `root`, as the user reads it
+pub fn text(root: &Element) -> String {
+    root.text_content().unwrap_or_default()
+}
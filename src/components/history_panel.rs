@@ -0,0 +1,75 @@
+use yew::prelude::*;
+
+use crate::history::HistoryEntry;
+use crate::i18n::Locale;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub entries: Vec<HistoryEntry>,
+    pub on_clear: Callback<()>,
+}
+
+fn render_entry(locale: Locale, entry: &HistoryEntry, entries: &[HistoryEntry]) -> Html {
+    let status = match &entry.error {
+        None => locale.t("✅ Verified"),
+        Some(_) => locale.t("❌ Failed"),
+    };
+    let conflict = entry.conflicts_with(entries);
+
+    html! {
+        <tr class="border-t border-gray-600">
+            <td class="px-2 py-1 font-mono">{entry.verified_at.clone()}</td>
+            <td class="px-2 py-1">{entry.file_name.clone()}</td>
+            <td class="px-2 py-1 font-mono" title={entry.proof_hash.clone()}>{&entry.proof_hash[..16.min(entry.proof_hash.len())]}</td>
+            <td class="px-2 py-1 font-mono">{entry.server_name.clone().unwrap_or("-".to_string())}</td>
+            <td class="px-2 py-1 font-mono">{entry.time.clone().unwrap_or("-".to_string())}</td>
+            <td class="px-2 py-1" title={entry.error.clone().unwrap_or_default()}>
+                {status}
+                if conflict {
+                    <span class="ml-2 px-1 text-xs rounded bg-yellow-200 text-yellow-900" title={locale.t("This proof was verified before with a different result")}>
+                        {locale.t("⚠️ result changed")}
+                    </span>
+                }
+            </td>
+        </tr>
+    }
+}
+
+/// Past verifications, newest first
+#[function_component]
+pub fn HistoryPanel(Props { entries, on_clear }: &Props) -> Html {
+    let onclick = on_clear.reform(|_: MouseEvent| ());
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    html! {
+        <div class={classes!("p-4", "my-4", theme::CARD)}>
+            <p class="text-center"><b>{locale.format("History ({count} verifications)", &[("count", &entries.len())])}</b></p>
+            if entries.is_empty() {
+                <p class="text-center text-gray-400 py-4">{t("No proofs verified in this browser yet")}</p>
+            } else {
+                <div class="overflow-x-auto">
+                    <table class="w-full table-auto text-left text-sm my-2">
+                        <thead>
+                            <tr>
+                                <th class="px-2 py-1">{t("Verified at")}</th>
+                                <th class="px-2 py-1">{t("File")}</th>
+                                <th class="px-2 py-1">{t("Proof hash")}</th>
+                                <th class="px-2 py-1">{t("Server domain")}</th>
+                                <th class="px-2 py-1">{t("Notarization time")}</th>
+                                <th class="px-2 py-1">{t("Result")}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for entries.iter().rev().map(|entry| render_entry(locale, entry, entries))}
+                        </tbody>
+                    </table>
+                </div>
+                <button class="px-4 py-2 hover:bg-black hover:text-white rounded border-black border" {onclick}>
+                    {t("Clear history")}
+                </button>
+            }
+        </div>
+    }
+}
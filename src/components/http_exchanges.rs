@@ -0,0 +1,144 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::components::form_view;
+use crate::components::graphql_view;
+use crate::components::redacted_bytes_component::segments;
+use crate::endpoints::Endpoint;
+use crate::exchanges::{self, Exchange};
+use crate::i18n::Locale;
+use crate::theme;
+use crate::verify::{Transcript, VerifiedOutput};
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn render_body(locale: Locale, transcript: &Transcript, range: &Option<Range<usize>>) -> Html {
+    let range = match range {
+        Some(range) if !range.is_empty() => range,
+        _ => return html! { <p class="text-gray-400">{locale.t("No body")}</p> },
+    };
+    let bytes = &transcript.bytes[range.clone()];
+    let redacted = transcript.redactions_within(range);
+
+    html! {
+        <pre class={classes!("p-2", "rounded-md", "overflow-x-auto", "whitespace-pre-wrap", "break-all", theme::CODE)}>
+            {for segments(bytes.len(), &redacted).into_iter().map(|(start, end, is_redacted)| html! {
+                <span class={if is_redacted { "text-red-500" } else { "" }}>{String::from_utf8_lossy(&bytes[start..end])}</span>
+            })}
+        </pre>
+    }
+}
+
+fn render_response(locale: Locale, verified: &VerifiedOutput, exchange: &Exchange) -> Html {
+    match &exchange.response {
+        Some(response) => html! {
+            <>
+                <b>{locale.t("Response body:")}</b>
+                {render_body(locale, &verified.recv, &response.body)}
+            </>
+        },
+        None => html! {},
+    }
+}
+
+/// A decoded view of the exchange, if its bodies are in a format we understand
+fn decode(locale: Locale, verified: &VerifiedOutput, exchange: &Exchange) -> Option<Html> {
+    graphql_view::render(locale, verified, exchange).or_else(|| {
+        let form = form_view::render(locale, verified, exchange)?;
+        Some(html! { <>{form}{render_response(locale, verified, exchange)}</> })
+    })
+}
+
+/// The endpoint of an exchange as a one-line header, e.g. `GET /users/{id}?page → 200 json`
+pub fn render_endpoint(endpoint: &Endpoint) -> Html {
+    html! {
+        <span class="inline-flex flex-wrap items-baseline gap-x-2 font-mono">
+            <span class="font-bold">{endpoint.method.clone()}</span>
+            <span class="break-all">{endpoint.template.clone()}</span>
+            if !endpoint.query.is_empty() {
+                <span class="text-gray-400">{format!("?{}", endpoint.query.join("&"))}</span>
+            }
+            if let Some(request_type) = &endpoint.request_type {
+                <span class="text-xs text-gray-400">{format!("({})", request_type)}</span>
+            }
+            if let Some(status) = &endpoint.status {
+                <span>{format!("→ {}", status)}</span>
+            }
+            if let Some(response_type) = &endpoint.response_type {
+                <span class="text-xs text-gray-400">{response_type.clone()}</span>
+            }
+        </span>
+    }
+}
+
+fn render_exchange(
+    locale: Locale,
+    index: usize,
+    exchange: &Exchange,
+    verified: &VerifiedOutput,
+    decoded: Option<Html>,
+    open: bool,
+) -> Html {
+    let header = match Endpoint::new(exchange) {
+        Some(endpoint) => render_endpoint(&endpoint),
+        None => html! { {locale.t("(no request)")} },
+    };
+
+    html! {
+        <details class="py-1" {open}>
+            <summary class={theme::SUMMARY}>{format!("#{} ", index + 1)}{header}</summary>
+            <div class="pl-4 py-2">
+                if let Some(decoded) = decoded {
+                    {decoded}
+                } else {
+                    if let Some(request) = &exchange.request {
+                        <b>{locale.t("Request body:")}</b>
+                        {render_body(locale, &verified.sent, &request.body)}
+                    }
+                    {render_response(locale, verified, exchange)}
+                }
+            </div>
+        </details>
+    }
+}
+
+/// The transcript split into request/response pairs, for sessions with more than one request
+/// or with bodies in a format that can be decoded, e.g. GraphQL or forms
+#[function_component]
+pub fn HttpExchanges(Props { verified }: &Props) -> Html {
+    let split = use_memo(verified.clone(), |verified| exchanges::split(verified));
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let decoded: Vec<Option<Html>> = split
+        .exchanges
+        .iter()
+        .map(|exchange| decode(locale, verified, exchange))
+        .collect();
+
+    // a single plain exchange is already shown by the byte views
+    let single = split.exchanges.len() < 2;
+    if single && decoded.iter().all(Option::is_none) {
+        return html! {};
+    }
+
+    let unparsed = (verified.sent.bytes.len() - split.sent_parsed)
+        + (verified.recv.bytes.len() - split.recv_parsed);
+
+    html! {
+        <details class={theme::PANEL} open={true}>
+            <summary class={theme::SUMMARY}><b>{locale.format("HTTP exchanges ({count}):", &[("count", &split.exchanges.len())])}</b></summary>
+            <div class={classes!("p-2", "rounded-md", theme::CARD)}>
+                {for split.exchanges.iter().zip(decoded).enumerate().map(|(index, (exchange, decoded))| {
+                    render_exchange(locale, index, exchange, verified, decoded, single)
+                })}
+            </div>
+            if unparsed > 0 {
+                <p class="text-sm text-gray-400">{locale.format("{count} trailing bytes could not be parsed as HTTP", &[("count", &unparsed)])}</p>
+            }
+        </details>
+    }
+}
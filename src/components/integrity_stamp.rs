@@ -0,0 +1,39 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+use crate::i18n::Locale;
+use crate::parsed_proof::ParsedProof;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub proof: Rc<ParsedProof>,
+}
+
+/// The SHA-256 digests of the proof file and its canonical JSON, to reference the proof by
+#[function_component]
+pub fn IntegrityStamp(Props { proof }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    let row = |label: &'static str, title: &'static str, what: &'static str, digest: &String| {
+        html! {
+            <div class="flex gap-2 items-start">
+                <span class="w-28 shrink-0 text-gray-400" title={t(title)}>{t(label)}</span>
+                <pre class="flex-1 whitespace-pre-wrap break-all">{digest.clone()}</pre>
+                <CopyButton value={digest.clone()} what={t(what)} />
+            </div>
+        }
+    };
+
+    html! {
+        <div class={classes!("p-4", "rounded-md", "flex", "flex-col", "gap-1", theme::CODE)}>
+            {row("File", "SHA-256 of the proof file as uploaded", "the SHA-256 of the file", &proof.file_digest)}
+            if let Some(canonical) = &proof.canonical_digest {
+                {row("Canonical JSON", "SHA-256 of the proof JSON without whitespace and with sorted keys, which survives reformatting", "the SHA-256 of the canonical JSON", canonical)}
+            }
+        </div>
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::json_spans::{self, JsonSpan, JsonValue};
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// The JSON document
+    pub bytes: Vec<u8>,
+    /// Redacted ranges, relative to the start of `bytes`
+    pub redacted_ranges: Vec<Range<usize>>,
+}
+
+const REDACTED_CHAR: char = 'X';
+
+/// Position of a node in the tree, as the child indices from the root
+type Path = Vec<usize>;
+
+struct Context<'a> {
+    redacted_ranges: &'a [Range<usize>],
+    collapsed: &'a HashSet<Path>,
+    on_toggle: &'a Callback<Path>,
+    locale: Locale,
+}
+
+fn redaction_marker(node: &JsonSpan, ctx: &Context) -> Html {
+    if matches!(node.value, JsonValue::Redacted) || !node.is_redacted(ctx.redacted_ranges) {
+        return html! {};
+    }
+    html! {
+        <span class="ml-2 px-1 text-xs rounded bg-red-200 text-red-800 dark:bg-red-900 dark:text-red-300" title={ctx.locale.format("bytes {range}", &[("range", &format!("{:?}", node.range))])}>
+            {ctx.locale.t("partially redacted")}
+        </span>
+    }
+}
+
+fn render_scalar(node: &JsonSpan) -> Html {
+    match &node.value {
+        JsonValue::Null => html! { <span class="text-gray-500 dark:text-gray-400">{"null"}</span> },
+        JsonValue::Bool(b) => {
+            html! { <span class="text-purple-700 dark:text-purple-400">{b.to_string()}</span> }
+        }
+        JsonValue::Number(n) => html! { <span class="text-blue-700 dark:text-blue-400">{n}</span> },
+        JsonValue::String(s) => {
+            html! { <span class="text-green-700 dark:text-green-400">{format!("{:?}", s)}</span> }
+        }
+        JsonValue::Redacted => html! {
+            <span class="text-red-500" title={format!("{} bytes redacted: {:?}", node.range.len(), node.range)}>
+                {REDACTED_CHAR.to_string().repeat(node.range.len())}
+            </span>
+        },
+        JsonValue::Array(_) | JsonValue::Object(_) => unreachable!("not a scalar"),
+    }
+}
+
+fn render_node(node: &JsonSpan, key: Option<&str>, comma: bool, path: Path, ctx: &Context) -> Html {
+    let key = key.map(|key| {
+        html! { <><span class="text-sky-700 dark:text-sky-300">{format!("{:?}", key)}</span>{": "}</> }
+    });
+    let comma = if comma { "," } else { "" };
+
+    let (open, close, children): (_, _, Vec<(Option<&str>, &JsonSpan)>) = match &node.value {
+        JsonValue::Array(items) => ("[", "]", items.iter().map(|item| (None, item)).collect()),
+        JsonValue::Object(members) => (
+            "{",
+            "}",
+            members.iter().map(|(k, v)| (Some(k.as_str()), v)).collect(),
+        ),
+        _ => {
+            return html! {
+                <div>{key}{render_scalar(node)}{comma}{redaction_marker(node, ctx)}</div>
+            }
+        }
+    };
+
+    if children.is_empty() {
+        return html! { <div>{key}{open}{close}{comma}</div> };
+    }
+
+    let is_collapsed = ctx.collapsed.contains(&path);
+    let onclick = {
+        let path = path.clone();
+        ctx.on_toggle.reform(move |_: MouseEvent| path.clone())
+    };
+    let toggle = html! {
+        <button class="select-none text-gray-500 dark:text-gray-400" aria-expanded={(!is_collapsed).to_string()} aria-label={ctx.locale.t(if is_collapsed { "Expand" } else { "Collapse" })} {onclick}>
+            {if is_collapsed {"▸ "} else {"▾ "}}
+        </button>
+    };
+
+    if is_collapsed {
+        return html! {
+            <div>
+                {toggle}{key}{open}
+                <span class="text-gray-500 dark:text-gray-400">{ctx.locale.format(" … {count} items ", &[("count", &children.len())])}</span>
+                {close}{comma}{redaction_marker(node, ctx)}
+            </div>
+        };
+    }
+
+    let count = children.len();
+    html! {
+        <div>
+            <div>{toggle}{key}{open}{redaction_marker(node, ctx)}</div>
+            <div class="pl-6">
+                {for children.into_iter().enumerate().map(|(i, (key, child))| {
+                    let mut child_path = path.clone();
+                    child_path.push(i);
+                    render_node(child, key, i + 1 < count, child_path, ctx)
+                })}
+            </div>
+            <div>{close}{comma}</div>
+        </div>
+    }
+}
+
+/// Collapsible JSON viewer which marks the values that were (partially) redacted
+#[function_component]
+pub fn JsonTree(props: &Props) -> Html {
+    let collapsed = use_state(HashSet::<Path>::new);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let on_toggle = {
+        let collapsed = collapsed.clone();
+        Callback::from(move |path: Path| {
+            let mut paths = (*collapsed).clone();
+            if !paths.remove(&path) {
+                paths.insert(path);
+            }
+            collapsed.set(paths);
+        })
+    };
+
+    match json_spans::parse(&props.bytes, &props.redacted_ranges) {
+        Ok(root) => {
+            let ctx = Context {
+                redacted_ranges: &props.redacted_ranges,
+                collapsed: &collapsed,
+                on_toggle: &on_toggle,
+                locale,
+            };
+            html! {
+                <div class="font-mono text-sm whitespace-pre-wrap break-all">
+                    {render_node(&root, None, false, Vec::new(), &ctx)}
+                </div>
+            }
+        }
+        // Not (redacted) JSON after all, show it as is
+        Err(_) => html! { <pre>{String::from_utf8_lossy(&props.bytes)}</pre> },
+    }
+}
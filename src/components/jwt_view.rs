@@ -0,0 +1,115 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use serde_json::Value;
+use yew::prelude::*;
+
+use crate::components::redacted_bytes_component::Direction;
+use crate::i18n::Locale;
+use crate::jwt::{self, Token};
+use crate::privacy_lints::{Jwt, LintRule};
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+/// A JWT found in the disclosed bytes
+struct Found {
+    direction: Direction,
+    range: Range<usize>,
+    token: Result<Token, String>,
+}
+
+fn find_tokens(verified: &VerifiedOutput) -> Vec<Found> {
+    Jwt.check(verified)
+        .into_iter()
+        .map(|found| {
+            let transcript = match found.direction {
+                Direction::Sent => &verified.sent,
+                Direction::Received => &verified.recv,
+            };
+            Found {
+                direction: found.direction,
+                token: jwt::decode(&transcript.bytes[found.range.clone()]),
+                range: found.range,
+            }
+        })
+        .collect()
+}
+
+fn render_claim(locale: Locale, name: &str, value: &Value) -> Html {
+    let time = value
+        .as_i64()
+        .filter(|_| jwt::is_time_claim(name))
+        .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0));
+    let value = match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    };
+
+    html! {
+        <tr class="border-t border-gray-600">
+            <td class="px-2 py-1 align-top">{name.to_string()}</td>
+            <td class="px-2 py-1 break-all">
+                {value}
+                if let Some(time) = time {
+                    <span class="text-gray-400">{format!(" ({})", time)}</span>
+                }
+            </td>
+            <td class="px-2 py-1 text-gray-400">{jwt::claim_description(name).map(|description| locale.t(description)).unwrap_or_default()}</td>
+        </tr>
+    }
+}
+
+fn render_token(locale: Locale, token: &Token) -> Html {
+    let claims = match &token.claims {
+        Value::Object(claims) => html! {
+            <table class="w-full table-auto text-left text-sm font-mono">
+                {for claims.iter().map(|(name, value)| render_claim(locale, name, value))}
+            </table>
+        },
+        claims => html! { <pre>{serde_json::to_string_pretty(claims).unwrap_or_default()}</pre> },
+    };
+
+    html! {
+        <>
+            <b>{locale.t("Header:")}</b>
+            <pre class="text-sm">{serde_json::to_string_pretty(&token.header).unwrap_or_default()}</pre>
+            <b>{locale.t("Claims:")}</b>
+            {claims}
+            <p class="text-sm text-gray-400">{locale.format("Signature: {count} bytes", &[("count", &token.signature_len)])}</p>
+        </>
+    }
+}
+
+/// The JSON Web Tokens disclosed in the transcript, decoded
+#[function_component]
+pub fn JwtList(Props { verified }: &Props) -> Html {
+    let tokens = use_memo(verified.clone(), |verified| find_tokens(verified));
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    if tokens.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <details class={theme::PANEL} open={true}>
+            <summary class={theme::SUMMARY}><b>{locale.format("JSON Web Tokens ({count}):", &[("count", &tokens.len())])}</b></summary>
+            <p class="text-yellow-600 dark:text-yellow-400">
+                {locale.t("⚠️ The token signatures are NOT verified, the Notary only attests that these bytes were exchanged with the server")}
+            </p>
+            {for tokens.iter().map(|found| html! {
+                <div class={classes!("p-4", "my-2", "rounded-md", "overflow-x-auto", theme::CODE)}>
+                    <p class="text-sm text-gray-400">{locale.format("{direction} bytes {start}..{end}", &[("direction", &locale.t(found.direction.name())), ("start", &found.range.start), ("end", &found.range.end)])}</p>
+                    {match &found.token {
+                        Ok(token) => render_token(locale, token),
+                        Err(err) => html! { <p class="text-red-500">{locale.format("Not a valid JWT: {error}", &[("error", err)])}</p> },
+                    }}
+                </div>
+            })}
+        </details>
+    }
+}
@@ -0,0 +1,61 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::components::redacted_bytes_component::{render_range, Direction};
+use crate::exchanges;
+use crate::i18n::Locale;
+use crate::line_protocol::{self, Line};
+use crate::mail;
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn render_line(verified: &VerifiedOutput, line: &Line) -> Html {
+    let (arrow, transcript, class) = match line.direction {
+        Direction::Sent => ("→", &verified.sent, "text-sky-600 dark:text-sky-400"),
+        Direction::Received => ("←", &verified.recv, ""),
+    };
+    html! {
+        <tr class={class}>
+            <td class="pr-2 text-right text-gray-400 select-none">{line.number}</td>
+            <td class="pr-2 select-none" title={line.direction.name()}>{arrow}</td>
+            <td class="whitespace-pre-wrap break-all">{render_range(transcript, &line.range)}</td>
+        </tr>
+    }
+}
+
+/// Transcripts which are neither HTTP nor email, as numbered lines of both directions in one
+/// conversation
+#[function_component]
+pub fn LineView(Props { verified }: &Props) -> Html {
+    let lines = use_memo(verified.clone(), |verified| {
+        let is_http = !exchanges::split(verified).exchanges.is_empty();
+        if is_http || mail::detect(verified).is_some() {
+            return Vec::new();
+        }
+        line_protocol::lines(verified)
+    });
+    let locale = use_context::<Locale>().unwrap_or_default();
+    if lines.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <details class={theme::PANEL} open={true}>
+            <summary class={theme::SUMMARY}><b>{locale.format("Lines ({count}), not HTTP:", &[("count", &lines.len())])}</b></summary>
+            <div class={classes!("p-2", "rounded-md", "overflow-x-auto", theme::CODE)}>
+                <table class="table-auto font-mono text-sm">
+                    <tbody>
+                        {for lines.iter().map(|line| render_line(verified, line))}
+                    </tbody>
+                </table>
+            </div>
+            <p class="text-sm text-gray-400">{locale.t("The order of sent and received lines is estimated from their positions; the proof does not record when they were sent.")}</p>
+        </details>
+    }
+}
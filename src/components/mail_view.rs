@@ -0,0 +1,96 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::components::redacted_bytes_component::{render_range, Direction};
+use crate::i18n::Locale;
+use crate::mail::{self, Exchange, Message};
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn render_exchange(verified: &VerifiedOutput, exchange: &Exchange) -> Html {
+    html! {
+        <li class="py-1">
+            if let Some(command) = &exchange.command {
+                <div class="text-sky-600 dark:text-sky-400">{"C: "}{render_range(&verified.sent, &command.range)}</div>
+            }
+            {for exchange.response.iter().map(|line| html! {
+                <div>{"S: "}{render_range(&verified.recv, &line.range)}</div>
+            })}
+        </li>
+    }
+}
+
+fn render_message(locale: Locale, verified: &VerifiedOutput, message: &Message) -> Html {
+    let t = |message| locale.t(message);
+    let transcript = match message.direction {
+        Direction::Sent => &verified.sent,
+        Direction::Received => &verified.recv,
+    };
+    let subject = message
+        .header("subject")
+        .map_or(t("(no subject)").to_string(), str::to_string);
+    html! {
+        <details class="py-1" open={true}>
+            <summary class={classes!("font-mono", theme::SUMMARY)}>{subject}</summary>
+            <div class="pl-4 py-2">
+                <table class="table-auto text-left text-sm">
+                    <tbody>
+                        {for message.headers.iter().map(|(name, value)| html! {
+                            <tr>
+                                <th class="pr-4 align-top font-mono">{name.clone()}</th>
+                                <td class="break-all">{value.clone()}</td>
+                            </tr>
+                        })}
+                    </tbody>
+                </table>
+                {for message.parts.iter().map(|part| html! {
+                    <>
+                        <b>{locale.format("Part {type}:", &[("type", &part.content_type)])}</b>
+                        <pre class={classes!("p-2", "rounded-md", "overflow-x-auto", "whitespace-pre-wrap", "break-all", theme::CODE)}>
+                            if let (Some(text), false) = (&part.text, part.redacted) {
+                                {text.clone()}
+                            } else {
+                                {render_range(transcript, &part.range)}
+                            }
+                        </pre>
+                        if part.redacted && part.text.is_none() {
+                            <p class="text-sm text-gray-400">{t("Partially redacted, shown encoded")}</p>
+                        }
+                    </>
+                })}
+            </div>
+        </details>
+    }
+}
+
+/// SMTP and IMAP sessions as commands with their responses, and the messages they carry with
+/// their MIME parts decoded
+#[function_component]
+pub fn MailView(Props { verified }: &Props) -> Html {
+    let session = use_memo(verified.clone(), |verified| mail::parse(verified));
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let Some(session) = session.as_ref() else {
+        return html! {};
+    };
+
+    html! {
+        <details class={theme::PANEL} open={true}>
+            <summary class={theme::SUMMARY}><b>{locale.format("{protocol} session ({count} commands):", &[("protocol", &session.protocol.name()), ("count", &session.exchanges.len())])}</b></summary>
+            <ol class={classes!("p-2", "rounded-md", "font-mono", "text-sm", "whitespace-pre-wrap", "break-all", theme::CODE)}>
+                {for session.exchanges.iter().map(|exchange| render_exchange(verified, exchange))}
+            </ol>
+            if !session.messages.is_empty() {
+                <b>{locale.format("Messages ({count}):", &[("count", &session.messages.len())])}</b>
+                <div class={classes!("p-2", "rounded-md", theme::CARD)}>
+                    {for session.messages.iter().map(|message| render_message(locale, verified, message))}
+                </div>
+            }
+        </details>
+    }
+}
@@ -1,4 +1,49 @@
+pub mod advanced_options;
+pub mod assertions_panel;
+pub mod badge_export;
+pub mod ca_store_input;
+pub mod cert_chain;
 pub mod content_iframe;
+pub mod conversation;
+pub mod cookie_list;
+pub mod copy_button;
+pub mod coverage_bar;
+pub mod diagnostics_drawer;
+pub mod example_gallery;
+pub mod form_view;
+pub mod graphql_view;
+pub mod guided_tour;
+pub mod har_export;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod harness;
+pub mod history_panel;
+pub mod http_exchanges;
+pub mod integrity_stamp;
+pub mod json_tree;
+pub mod jwt_view;
+pub mod line_view;
+pub mod mail_view;
+pub mod network_indicator;
 pub mod pem_input;
+pub mod print_report;
+pub mod proof_diff;
+pub mod proof_toolbar;
+pub mod qr_scanner;
+pub mod range_map;
+pub mod raw_proof;
 pub mod redacted_bytes_component;
+pub mod redaction_summary;
+pub mod selection_tooltip;
+pub mod self_test_page;
+pub mod signature_details;
+pub mod site_summary;
+pub mod status_chips;
+pub mod substrings_pairing;
+pub mod summary;
+pub mod tampering_playground;
+pub mod technical_details;
+pub mod test_key_badge;
+pub mod timeline;
+pub mod verification_steps;
 pub mod view_file;
+pub mod zk_claims;
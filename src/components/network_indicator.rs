@@ -0,0 +1,68 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::network_guard::{self, NetworkRequest};
+use crate::theme;
+
+fn render_request(locale: Locale, request: &NetworkRequest) -> Html {
+    html! {
+        <li class="break-all">
+            <span class="font-mono">{request.url.clone()}</span>
+            if request.during_verification {
+                <span class="text-red-500">{" "}{locale.t("(during verification)")}</span>
+            }
+            if request.blocked {
+                <span class="text-gray-400">{" "}{locale.t("(blocked)")}</span>
+            }
+        </li>
+    }
+}
+
+/// Whether the page made network requests while verifying, with every request it made and the
+/// option to block them
+#[function_component]
+pub fn NetworkIndicator() -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    let blocking = use_state(network_guard::is_blocking);
+    let on_block = {
+        let blocking = blocking.clone();
+        Callback::from(move |e: Event| {
+            let checked = e.target_unchecked_into::<HtmlInputElement>().checked();
+            network_guard::set_blocking(checked);
+            blocking.set(checked);
+        })
+    };
+
+    let requests = network_guard::requests();
+    let during_verification = requests.iter().filter(|r| r.during_verification).count();
+
+    html! {
+        <details class={theme::PANEL}>
+            <summary class={theme::SUMMARY}>
+                if during_verification == 0 {
+                    <b class="text-green-500">{t("🔒 No network requests were made during verification")}</b>
+                } else {
+                    <b class="text-red-500">{locale.format("⚠️ {count} network requests were made during verification", &[("count", &during_verification)])}</b>
+                }
+            </summary>
+            <div class={classes!("p-4", "rounded-md", "flex", "flex-col", "gap-2", "text-sm", theme::CODE)}>
+                <p>{t("Every fetch and XMLHttpRequest of this page is recorded. Proofs are verified in your browser, they are never uploaded.")}</p>
+                if requests.is_empty() {
+                    <p class="text-gray-400">{t("This page has not made any requests.")}</p>
+                } else {
+                    <p>{locale.format("Requests of this page ({count}):", &[("count", &requests.len())])}</p>
+                    <ul class="list-disc pl-6">
+                        {for requests.iter().map(|request| render_request(locale, request))}
+                    </ul>
+                }
+                <label class="flex gap-2 items-center">
+                    <input type="checkbox" checked={*blocking} onchange={on_block} />
+                    {t("Block all network requests")}
+                </label>
+                <p class="text-gray-400">{t("Loading proofs by URL and refreshing the Notary registry then fail.")}</p>
+            </div>
+        </details>
+    }
+}
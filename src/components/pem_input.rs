@@ -1,32 +1,34 @@
-use elliptic_curve::pkcs8::DecodePublicKey;
-
 #[allow(unused_imports)]
 use gloo::console::log;
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
+use crate::components::test_key_badge::TestKeyBadge;
+use crate::config;
+use crate::i18n::Locale;
+use crate::notary_key::{KeySource, NotaryKey};
+use crate::notary_registry::{KeyCheck, Registry};
+use crate::theme;
+
 #[derive(Properties, Clone, PartialEq)]
 pub struct Props {
-    pub pem_callback: Callback<p256::PublicKey>,
+    /// The key currently in use
+    pub pem: NotaryKey,
+    /// Called with a new valid key and where it came from
+    pub pem_callback: Callback<(NotaryKey, KeySource)>,
 }
 
-// from https://github.com/tlsnotary/notary-server/tree/main/src/fixture/notary/notary.key
-// converted with `openssl ec -in notary.key -pubout -outform PEM`
-pub const DEFAULT_PEM: &str = "-----BEGIN PUBLIC KEY-----
-MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEBv36FI4ZFszJa0DQFJ3wWCXvVLFr
-cRzMG5kaTeHGoSzDu6cFqx3uEWYpFGo6C0EOUgf+mEgbktLrXocv5yHzKg==
------END PUBLIC KEY-----";
-
-// from https://notary.pse.dev/info
-pub const NOTARY_PSE_PEM: &str = "-----BEGIN PUBLIC KEY-----
-MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAExpX/4R4z40gI6C/j9zAM39u58LJu
-3Cx5tXTuqhhu/tirnBi5GniMmspOTEsps4ANnPLpMmMSfhJ+IFHbc3qVOA==
------END PUBLIC KEY-----";
-
 #[function_component(PemInputComponent)]
-pub fn pem_input_component(Props { pem_callback }: &Props) -> Html {
-    let input_value = use_state(|| DEFAULT_PEM.to_string());
+pub fn pem_input_component(Props { pem, pem_callback }: &Props) -> Html {
+    let input_value = use_state(|| pem.to_public_key_pem().trim().to_string());
     let invalid_input = use_state(|| None);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let registry = use_context::<Registry>().unwrap_or_else(Registry::bundled);
+    // URL of the Notary picked from the registry, the keys pasted afterwards are checked against it
+    let claimed = {
+        let registry = registry.clone();
+        use_state(move || registry.find(*pem).map(|entry| entry.url.clone()))
+    };
 
     let oninput = {
         let input_value = input_value.clone();
@@ -37,53 +39,59 @@ pub fn pem_input_component(Props { pem_callback }: &Props) -> Html {
             let input: HtmlInputElement = e.target_unchecked_into();
             let value = input.value().trim().to_string();
 
-            let result = p256::PublicKey::from_public_key_pem(value.as_str());
+            let result = NotaryKey::parse(value.as_str());
             match result {
                 Ok(public_key) => {
                     input_value.set(value.clone());
                     invalid_input.set(None);
-                    callback.emit(public_key);
+                    callback.emit((public_key, KeySource::Custom));
                 }
                 Err(err) => {
                     input_value.set(value.clone());
-                    invalid_input.set(Some(err.to_string()));
+                    invalid_input.set(Some(err));
                     // do not emit a false pem here
                 }
             }
         })
     };
 
-    let notary_pse_dev = {
+    let on_registry = {
         let input_value = input_value.clone();
         let callback = pem_callback.clone();
         let invalid_input = invalid_input.clone();
+        let registry = registry.clone();
+        let claimed = claimed.clone();
 
-        Callback::from(move |_| {
-            let public_key = p256::PublicKey::from_public_key_pem(NOTARY_PSE_PEM)
-                .expect("should be a valid public key");
-            input_value.set(NOTARY_PSE_PEM.into());
-            invalid_input.set(None);
-            callback.emit(public_key);
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let entry = select
+                .value()
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| registry.notaries.get(index));
+            claimed.set(entry.map(|entry| entry.url.clone()));
+            if let Some(entry) = entry {
+                input_value.set(entry.public_key.clone());
+                invalid_input.set(None);
+                callback.emit((
+                    entry.key(),
+                    KeySource::Registry {
+                        name: entry.name.clone(),
+                        url: entry.url.clone(),
+                    },
+                ));
+            }
         })
     };
-
-    let default = {
-        let input_value = input_value.clone();
-        let callback = pem_callback.clone();
-        let invalid_input = invalid_input.clone();
-
-        Callback::from(move |_| {
-            let public_key = p256::PublicKey::from_public_key_pem(DEFAULT_PEM)
-                .expect("should be a valid public key");
-            input_value.set(DEFAULT_PEM.into());
-            invalid_input.set(None);
-            callback.emit(public_key);
-        })
+    let known = claimed.as_ref().and_then(|url| registry.find_by_url(url));
+    let mismatch = match claimed.as_ref().map(|url| registry.check_key(url, *pem)) {
+        Some(KeyCheck::Mismatch(entry)) => Some(entry),
+        _ => None,
     };
 
     // Toggling styles based on the presence of an error
     let style = if invalid_input.is_none() {
-        "text-sm text-white border-gray-600 focus:ring-blue-500 focus:border-blue-500"
+        "text-sm text-black dark:text-white border-gray-400 dark:border-gray-600 focus:ring-blue-500 focus:border-blue-500"
     } else {
         "text-sm text-red-500 border-red-500 focus:border-red-500 focus:ring-red-500"
     };
@@ -91,26 +99,48 @@ pub fn pem_input_component(Props { pem_callback }: &Props) -> Html {
     html! {
         <div class="container flex mx-auto p-4">
             <div class="w-full">
-                <details class="w-full" open={false}>
-                    <summary class="cursor-pointer px-8 py-2"><b>{"Change Notary Public Key:" }</b>{if invalid_input.as_ref().is_some() {" ❌"} else {""}}</summary>
-                    <div class="px-8">
-                        <textarea class={style.to_string() + " block p-2.5 w-full bg-zinc-700 mt-2 border rounded"}
+                <details class="w-full" open={true}>
+                    <summary class={classes!("px-4", "md:px-8", theme::SUMMARY)}><b>{locale.t("Change Notary Public Key:")}</b>{if invalid_input.as_ref().is_some() {" ❌"} else {""}}</summary>
+                    <div class="px-4 md:px-8">
+                        <textarea class={style.to_string() + " block p-2.5 w-full bg-white dark:bg-zinc-700 mt-2 border rounded"}
                             id="pem-input"
                             rows="4"
+                            aria-label={locale.t("Notary public key")}
+                            aria-invalid={invalid_input.is_some().to_string()}
+                            aria-describedby="pem-input-hint"
                             value={input_value.to_string()}
                             oninput={oninput} >
                         </textarea>
                         if let Some(error_message) = invalid_input.as_ref() {
-                            <p class="mt-2 text-red-500">{error_message}</p>
+                            <p class="mt-2 text-red-500" role="alert">{error_message}</p>
+                        }
+                        if config::is_test_key(*pem) {
+                            <p class="mt-2 text-sm text-red-500 flex flex-wrap gap-2 items-center">
+                                <TestKeyBadge />
+                                {locale.t("This is the TLSNotary test fixture key, only use it for proofs of a local development Notary")}
+                            </p>
                         }
-                        <div class="h-fit min-h-full flex justify-end">
-                          <button class="float-right px-4 py-2 hover:bg-black hover:text-white rounded border-black border"
-                           onclick={notary_pse_dev}>{ "notary.pse.dev" }
-                           </button>
-                           <button class="float-right px-4 py-2 hover:bg-black hover:text-white rounded border-black border"
-                           onclick={default}>{ "default" }
-                           </button>
+                        <p id="pem-input-hint" class="mt-2 text-sm text-gray-400">{locale.t("PEM, or a hex/base64 encoded DER public key, SEC1 point or raw Ed25519 key")}</p>
+                        <div class="mt-2 flex flex-wrap gap-2 items-center justify-end text-sm">
+                            <label for="notary-registry">{locale.t("Known Notaries:")}</label>
+                            <select id="notary-registry" class={classes!("p-1", theme::INPUT)} onchange={on_registry}>
+                                <option value="" selected={known.is_none()}>{locale.t("Custom key")}</option>
+                                {for registry.notaries.iter().enumerate().map(|(index, entry)| html! {
+                                    <option value={index.to_string()} selected={known == Some(entry)}>{entry.name.clone()}</option>
+                                })}
+                            </select>
+                            if let Some(entry) = known {
+                                <a class="underline" href={entry.url.clone()} target="_blank" rel="noopener noreferrer">{entry.url.clone()}</a>
+                            }
                         </div>
+                        if let Some(entry) = mismatch {
+                            <div role="alert" class="mt-2 border-2 border-red-500 rounded bg-red-100 px-4 py-3 text-red-800">
+                                <p class="font-bold">{locale.t("⚠️ Key mismatch: possible MITM or rotated key")}</p>
+                                <p>{locale.format("This key is not the one the registry lists for {name} ({url}). Someone may be intercepting the connection to the Notary, or the Notary rotated its key. Check with the Notary's operator before trusting proofs verified with it.", &[("name", &entry.name), ("url", &entry.url)])}</p>
+                                <p class="mt-2 font-mono text-sm break-all">{locale.format("Expected fingerprint: {fingerprint}", &[("fingerprint", &entry.fingerprint)])}</p>
+                                <p class="font-mono text-sm break-all">{locale.format("Key in use: {fingerprint}", &[("fingerprint", &pem.fingerprint())])}</p>
+                            </div>
+                        }
                     </div>
                 </details>
             </div>
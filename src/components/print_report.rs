@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::notary_key::NotaryKey;
+use crate::parsed_proof::ParsedProof;
+
+// The attributes the print stylesheet in styles.css keys on
+const PRINTING: &str = "data-printing";
+const PRINT_REPORT: &str = "data-print-report";
+
+/// Print only `card`, with all its panels expanded, and restore the page once printed
+fn print_report(card: Element) {
+    let body = gloo::utils::body();
+    let closed: Vec<Element> = card
+        .query_selector_all("details:not([open])")
+        .map(|details| {
+            (0..details.length())
+                .filter_map(|i| details.item(i)?.dyn_into().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    for details in &closed {
+        let _ = details.set_attribute("open", "");
+    }
+    let _ = body.set_attribute(PRINTING, "");
+    let _ = card.set_attribute(PRINT_REPORT, "");
+
+    // print() blocks in most browsers but not all, so the page is restored after printing
+    EventListener::once(&gloo::utils::window(), "afterprint", move |_| {
+        for details in &closed {
+            let _ = details.remove_attribute("open");
+        }
+        let _ = body.remove_attribute(PRINTING);
+        let _ = card.remove_attribute(PRINT_REPORT);
+    })
+    .forget();
+    let _ = gloo::utils::window().print();
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// The card of the proof to print
+    pub card: NodeRef,
+}
+
+/// Prints the verification card of a proof as a black on white report
+#[function_component]
+pub fn PrintButton(Props { card }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let onclick = {
+        let card = card.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(card) = card.cast::<Element>() {
+                print_report(card);
+            }
+        })
+    };
+
+    html! {
+        <button class="px-2 text-sm hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" {onclick}>
+            {locale.t("🖨 Print report")}
+        </button>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FooterProps {
+    pub proof: Rc<ParsedProof>,
+    pub pem: NotaryKey,
+}
+
+/// Identifies the proof on every printed page, hidden on screen
+#[function_component]
+pub fn PrintFooter(FooterProps { proof, pem }: &FooterProps) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    html! {
+        <div class="print-footer font-mono break-all">
+            <p>{locale.format("Proof SHA-256: {digest}", &[("digest", &proof.file_digest)])}</p>
+            <p>{locale.format("Notary key: {scheme} {fingerprint}", &[("scheme", &pem.scheme()), ("fingerprint", &pem.fingerprint())])}</p>
+        </div>
+    }
+}
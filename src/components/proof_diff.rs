@@ -0,0 +1,345 @@
+use std::ops::Range;
+
+use yew::prelude::*;
+
+use crate::app::FileDetails;
+use crate::components::redacted_bytes_component::Direction;
+use crate::components::view_file::use_verification;
+use crate::i18n::Locale;
+use crate::notary_key::NotaryKey;
+use crate::settings;
+use crate::theme;
+use crate::verify::Transcript;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub left: FileDetails,
+    pub right: FileDetails,
+    pub pem: NotaryKey,
+}
+
+/// How a byte compares to the byte at the same position in the other proof
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Same,
+    Differs,
+    /// Redacted in both proofs
+    Redacted,
+    /// Redacted in this proof, disclosed in the other
+    RedactedHere,
+    /// Disclosed in this proof, redacted in the other
+    RedactedThere,
+    /// There is no corresponding byte in the other proof
+    Unpaired,
+}
+
+fn mark_class(mark: Mark) -> &'static str {
+    match mark {
+        Mark::Same => "",
+        Mark::Differs => "bg-yellow-700",
+        Mark::Redacted => "text-red-500",
+        Mark::RedactedHere => "text-red-500 bg-red-900",
+        Mark::RedactedThere => "bg-orange-700",
+        Mark::Unpaired => "bg-zinc-300 dark:bg-zinc-600",
+    }
+}
+
+/// A line based diff operation, with indices into the lines of the left and right transcript
+enum Op {
+    Equal(usize, usize),
+    Changed(usize, usize),
+    Removed(usize),
+    Added(usize),
+}
+
+// Above this many lines x lines, a full LCS diff is too slow and lines are compared positionally
+const MAX_LCS_CELLS: usize = 4_000_000;
+const SIDE_BY_SIDE_VIEW: &str = "side_by_side";
+
+fn split_lines(bytes: &[u8]) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for (i, byte) in bytes.iter().enumerate() {
+        if *byte == b'\n' {
+            lines.push(start..i + 1);
+            start = i + 1;
+        }
+    }
+    if start < bytes.len() {
+        lines.push(start..bytes.len());
+    }
+    lines
+}
+
+// Pair up the lines removed and added between two equal lines as changed lines
+fn flush(ops: &mut Vec<Op>, removed: &mut Vec<usize>, added: &mut Vec<usize>) {
+    let paired = removed.len().min(added.len());
+    ops.extend((0..paired).map(|k| Op::Changed(removed[k], added[k])));
+    ops.extend(removed[paired..].iter().map(|&i| Op::Removed(i)));
+    ops.extend(added[paired..].iter().map(|&j| Op::Added(j)));
+    removed.clear();
+    added.clear();
+}
+
+fn diff_lines(
+    left: &Transcript,
+    left_lines: &[Range<usize>],
+    right: &Transcript,
+    right_lines: &[Range<usize>],
+) -> Vec<Op> {
+    let equal = |i: usize, j: usize| {
+        left.bytes[left_lines[i].clone()] == right.bytes[right_lines[j].clone()]
+    };
+    let (n, m) = (left_lines.len(), right_lines.len());
+    let mut ops = Vec::new();
+
+    if n * m > MAX_LCS_CELLS {
+        for k in 0..n.max(m) {
+            ops.push(match (k < n, k < m) {
+                (true, true) if equal(k, k) => Op::Equal(k, k),
+                (true, true) => Op::Changed(k, k),
+                (true, false) => Op::Removed(k),
+                _ => Op::Added(k),
+            });
+        }
+        return ops;
+    }
+
+    // lcs[i][j] is the length of the longest common subsequence of left[i..] and right[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if equal(i, j) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && equal(i, j) {
+            flush(&mut ops, &mut removed, &mut added);
+            ops.push(Op::Equal(i, j));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            removed.push(i);
+            i += 1;
+        } else {
+            added.push(j);
+            j += 1;
+        }
+    }
+    flush(&mut ops, &mut removed, &mut added);
+
+    ops
+}
+
+fn is_redacted(transcript: &Transcript, pos: usize) -> bool {
+    transcript
+        .redacted_ranges
+        .iter()
+        .any(|range| range.contains(&pos))
+}
+
+/// Mark every byte of a line by comparing it with the corresponding line of the other proof
+fn mark_line(
+    this: &Transcript,
+    line: &Range<usize>,
+    other: &Transcript,
+    other_line: Option<&Range<usize>>,
+) -> Vec<Mark> {
+    line.clone()
+        .enumerate()
+        .map(|(k, pos)| {
+            let here = is_redacted(this, pos);
+            match other_line.filter(|other_line| k < other_line.len()) {
+                None if here => Mark::Redacted,
+                None => Mark::Unpaired,
+                Some(other_line) => {
+                    let other_pos = other_line.start + k;
+                    match (here, is_redacted(other, other_pos)) {
+                        (true, true) => Mark::Redacted,
+                        (true, false) => Mark::RedactedHere,
+                        (false, true) => Mark::RedactedThere,
+                        (false, false) if this.bytes[pos] == other.bytes[other_pos] => Mark::Same,
+                        (false, false) => Mark::Differs,
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+fn render_line(
+    this: &Transcript,
+    line: &Range<usize>,
+    other: &Transcript,
+    other_line: Option<&Range<usize>>,
+) -> Html {
+    let marks = mark_line(this, line, other, other_line);
+
+    // group consecutive bytes with the same mark into one span
+    let mut spans = Vec::new();
+    let mut start = 0;
+    for k in 1..=marks.len() {
+        if k == marks.len() || marks[k] != marks[start] {
+            let bytes = &this.bytes[line.start + start..line.start + k];
+            spans.push(html! {
+                <span class={mark_class(marks[start])}>{String::from_utf8_lossy(bytes)}</span>
+            });
+            start = k;
+        }
+    }
+
+    html! { <>{ for spans }</> }
+}
+
+#[derive(Properties, PartialEq)]
+struct TranscriptDiffProps {
+    direction: Direction,
+    left: Transcript,
+    right: Transcript,
+    side_by_side: bool,
+}
+
+#[function_component]
+fn TranscriptDiff(props: &TranscriptDiffProps) -> Html {
+    let TranscriptDiffProps {
+        direction,
+        left,
+        right,
+        side_by_side,
+    } = props;
+
+    let left_lines = split_lines(&left.bytes);
+    let right_lines = split_lines(&right.bytes);
+    let ops = diff_lines(left, &left_lines, right, &right_lines);
+
+    let differences = ops.iter().filter(|op| !matches!(op, Op::Equal(..))).count();
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    let left_line = |i: usize, j: Option<usize>| {
+        render_line(left, &left_lines[i], right, j.map(|j| &right_lines[j]))
+    };
+    let right_line = |j: usize, i: Option<usize>| {
+        render_line(right, &right_lines[j], left, i.map(|i| &left_lines[i]))
+    };
+
+    let rows = if *side_by_side {
+        ops.iter()
+            .map(|op| {
+                let (l, r) = match *op {
+                    Op::Equal(i, j) | Op::Changed(i, j) => {
+                        (left_line(i, Some(j)), right_line(j, Some(i)))
+                    }
+                    Op::Removed(i) => (left_line(i, None), html! {}),
+                    Op::Added(j) => (html! {}, right_line(j, None)),
+                };
+                html! {
+                    <>
+                        <pre class="whitespace-pre-wrap break-all">{l}</pre>
+                        <pre class="whitespace-pre-wrap break-all">{r}</pre>
+                    </>
+                }
+            })
+            .collect::<Html>()
+    } else {
+        ops.iter()
+            .map(|op| match *op {
+                Op::Equal(i, j) => html! {
+                    <pre class="whitespace-pre-wrap break-all">{"  "}{left_line(i, Some(j))}</pre>
+                },
+                Op::Changed(i, j) => html! {
+                    <>
+                        <pre class="whitespace-pre-wrap break-all bg-red-100 dark:bg-red-950">{"- "}{left_line(i, Some(j))}</pre>
+                        <pre class="whitespace-pre-wrap break-all bg-green-100 dark:bg-green-950">{"+ "}{right_line(j, Some(i))}</pre>
+                    </>
+                },
+                Op::Removed(i) => html! {
+                    <pre class="whitespace-pre-wrap break-all bg-red-100 dark:bg-red-950">{"- "}{left_line(i, None)}</pre>
+                },
+                Op::Added(j) => html! {
+                    <pre class="whitespace-pre-wrap break-all bg-green-100 dark:bg-green-950">{"+ "}{right_line(j, None)}</pre>
+                },
+            })
+            .collect::<Html>()
+    };
+
+    let layout = if *side_by_side {
+        "grid grid-cols-2 gap-x-4"
+    } else {
+        ""
+    };
+
+    html! {
+        <details class="p-2 md:p-4 w-full" open={true}>
+            <summary class={theme::SUMMARY}>
+                <b>{locale.format("Bytes {direction}: ", &[("direction", &locale.t(direction.name()))])}</b>
+                {locale.format("({count} lines differ)", &[("count", &differences)])}
+            </summary>
+            <div class={classes!("p-4", "rounded-md", "overflow-x-auto", theme::CODE, layout)}>
+                {rows}
+            </div>
+        </details>
+    }
+}
+
+/// Compare the transcripts of two proofs, e.g. two notarizations of the same endpoint
+#[function_component]
+pub fn ProofDiff(Props { left, right, pem }: &Props) -> Html {
+    let side_by_side = use_state(|| settings::load_view(SIDE_BY_SIDE_VIEW, true));
+    let left_verification = use_verification(&left.proof, *pem);
+    let right_verification = use_verification(&right.proof, *pem);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    let toggle = {
+        let side_by_side = side_by_side.clone();
+        Callback::from(move |_| {
+            settings::save_view(SIDE_BY_SIDE_VIEW, !*side_by_side);
+            side_by_side.set(!*side_by_side)
+        })
+    };
+
+    let (Some(left_verification), Some(right_verification)) =
+        (left_verification, right_verification)
+    else {
+        return html! {
+            <div role="status" class={classes!("p-4", "my-4", "text-center", theme::CARD)}>{t("⏳ Verifying...")}</div>
+        };
+    };
+    let content = match (&left_verification.result, &right_verification.result) {
+        (Ok(l), Ok(r)) => html! {
+            <>
+                <TranscriptDiff direction={Direction::Sent} left={l.sent.clone()} right={r.sent.clone()} side_by_side={*side_by_side} />
+                <TranscriptDiff direction={Direction::Received} left={l.recv.clone()} right={r.recv.clone()} side_by_side={*side_by_side} />
+            </>
+        },
+        (Err(err), _) => {
+            html! { <p class="text-red-500">{format!("❌ {}: {}", left.name, err)}</p> }
+        }
+        (_, Err(err)) => {
+            html! { <p class="text-red-500">{format!("❌ {}: {}", right.name, err)}</p> }
+        }
+    };
+
+    html! {
+        <div class={classes!("p-4", "my-4", "flex", "flex-col", "items-center", theme::CARD)}>
+            <p class="text-center"><b>{t("Compare ")}</b><span class="font-mono">{left.name.clone()}</span>{" ↔ "}<span class="font-mono">{right.name.clone()}</span></p>
+            <div class="flex gap-4 items-center text-sm py-2">
+                <span class={mark_class(Mark::Differs)}>{t("differs")}</span>
+                <span class={mark_class(Mark::RedactedHere)}>{t("redacted here only")}</span>
+                <span class={mark_class(Mark::RedactedThere)}>{t("redacted in the other proof only")}</span>
+                <span class={mark_class(Mark::Unpaired)}>{t("only in this proof")}</span>
+                <button class="px-4 py-2 hover:bg-black hover:text-white rounded border-black border" onclick={toggle}>
+                    {t(if *side_by_side { "Inline" } else { "Side by side" })}
+                </button>
+            </div>
+            {content}
+        </div>
+    }
+}
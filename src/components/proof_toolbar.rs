@@ -0,0 +1,105 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::proof_list::{ListView, SortBy, StatusFilter};
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub view: ListView,
+    /// The servers of the proofs, to filter on
+    pub servers: Vec<String>,
+    /// How many proofs are listed, of `total`
+    pub shown: usize,
+    pub total: usize,
+    pub on_change: Callback<ListView>,
+}
+
+/// Filters and sorting for the loaded proofs, shown above the results
+#[function_component]
+pub fn ProofToolbar(
+    Props {
+        view,
+        servers,
+        shown,
+        total,
+        on_change,
+    }: &Props,
+) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    // Every select changes one field of the view, by option index
+    let change = |update: fn(&mut ListView, usize, &[String])| {
+        let view = view.clone();
+        let servers = servers.clone();
+        on_change.reform(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let mut view = view.clone();
+            update(&mut view, select.selected_index().max(0) as usize, &servers);
+            view
+        })
+    };
+    let on_status = change(|view, index, _| view.status = StatusFilter::ALL[index]);
+    let on_server = change(|view, index, servers| {
+        view.server = index.checked_sub(1).map(|index| servers[index].clone())
+    });
+    let on_sort = change(|view, index, _| view.sort = SortBy::ALL[index]);
+    let on_group = {
+        let view = view.clone();
+        on_change.reform(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            ListView {
+                group: input.checked(),
+                ..view.clone()
+            }
+        })
+    };
+    let on_reset = {
+        let view = view.clone();
+        on_change.reform(move |_| ListView {
+            sort: view.sort,
+            group: view.group,
+            ..ListView::default()
+        })
+    };
+
+    html! {
+        <div class={classes!("p-2", "my-4", "flex", "flex-wrap", "items-center", "gap-4", "text-sm", theme::CARD)} role="toolbar" aria-label={t("Filter and sort proofs")}>
+            <label class="flex items-center gap-1">
+                {t("Show")}
+                <select class={classes!("p-1", theme::INPUT)} onchange={on_status}>
+                    {for StatusFilter::ALL.iter().map(|status| html! {
+                        <option selected={view.status == *status}>{t(status.name())}</option>
+                    })}
+                </select>
+            </label>
+            <label class="flex items-center gap-1">
+                {t("Server")}
+                <select class={classes!("p-1", theme::INPUT)} onchange={on_server}>
+                    <option selected={view.server.is_none()}>{t("All servers")}</option>
+                    {for servers.iter().map(|server| html! {
+                        <option selected={view.server.as_ref() == Some(server)}>{server.clone()}</option>
+                    })}
+                </select>
+            </label>
+            <label class="flex items-center gap-1">
+                {t("Sort by")}
+                <select class={classes!("p-1", theme::INPUT)} onchange={on_sort}>
+                    {for SortBy::ALL.iter().map(|sort| html! {
+                        <option selected={view.sort == *sort}>{t(sort.name())}</option>
+                    })}
+                </select>
+            </label>
+            <label class="flex items-center gap-1">
+                <input type="checkbox" checked={view.group} onchange={on_group} />
+                {t("Group by server")}
+            </label>
+            if view.is_filtered() {
+                <span aria-live="polite">{locale.format("{shown} of {total} proofs shown", &[("shown", shown), ("total", total)])}</span>
+                <button class="px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" onclick={on_reset}>{t("Clear filters")}</button>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,193 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use gloo::timers::callback::Interval;
+use gloo::utils::window;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{HtmlVideoElement, MediaStream, MediaStreamConstraints, MediaStreamTrack};
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::theme;
+
+// How often the video is searched for a QR code, in milliseconds
+const SCAN_INTERVAL: u32 = 300;
+
+// The browser's Barcode Detection API, not in web-sys yet
+#[wasm_bindgen]
+extern "C" {
+    type BarcodeDetector;
+
+    #[wasm_bindgen(constructor, catch)]
+    fn new(options: &JsValue) -> Result<BarcodeDetector, JsValue>;
+
+    #[wasm_bindgen(method)]
+    fn detect(this: &BarcodeDetector, source: &HtmlVideoElement) -> js_sys::Promise;
+}
+
+fn qr_detector() -> Result<BarcodeDetector, String> {
+    if !js_sys::Reflect::has(&window(), &"BarcodeDetector".into()).unwrap_or(false) {
+        return Err("this browser can not read QR codes, try Chrome on Android".to_string());
+    }
+    let options = js_sys::Object::new();
+    let formats = js_sys::Array::of1(&"qr_code".into());
+    let _ = js_sys::Reflect::set(&options, &"formats".into(), &formats);
+    BarcodeDetector::new(&options).map_err(|e| format!("{:?}", e))
+}
+
+async fn start_camera(video: &HtmlVideoElement) -> Result<MediaStream, String> {
+    let devices = window()
+        .navigator()
+        .media_devices()
+        .map_err(|_| "no camera available".to_string())?;
+
+    // Prefer the back camera on phones
+    let video_constraints = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(
+        &video_constraints,
+        &"facingMode".into(),
+        &"environment".into(),
+    );
+    let constraints = MediaStreamConstraints::new();
+    constraints.set_video(&video_constraints);
+
+    let promise = devices
+        .get_user_media_with_constraints(&constraints)
+        .map_err(|e| format!("{:?}", e))?;
+    let stream: MediaStream = JsFuture::from(promise)
+        .await
+        .map_err(|_| "camera access was denied".to_string())?
+        .unchecked_into();
+    video.set_src_object(Some(&stream));
+    if let Ok(playing) = video.play() {
+        let _ = JsFuture::from(playing).await;
+    }
+    Ok(stream)
+}
+
+fn stop_camera(stream: &MediaStream) {
+    for track in stream.get_tracks().iter() {
+        track.unchecked_into::<MediaStreamTrack>().stop();
+    }
+}
+
+// The text of the first detected code
+fn raw_value(detected: &JsValue) -> Option<String> {
+    let first = js_sys::Array::from(detected).get(0);
+    js_sys::Reflect::get(&first, &"rawValue".into())
+        .ok()?
+        .as_string()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// Called with the text of the scanned QR code
+    pub on_scan: Callback<String>,
+}
+
+/// Reads a QR code with the camera
+#[function_component]
+pub fn QrScanner(Props { on_scan }: &Props) -> Html {
+    let scanning = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let video = use_node_ref();
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    {
+        let scanning = scanning.clone();
+        let error = error.clone();
+        let video = video.clone();
+        let on_scan = on_scan.clone();
+        use_effect_with(*scanning, move |active| {
+            let stream = Rc::new(RefCell::new(None::<MediaStream>));
+            let interval = Rc::new(RefCell::new(None::<Interval>));
+            // Scanning may be stopped while the camera is still starting
+            let cancelled = Rc::new(Cell::new(false));
+
+            if *active {
+                let stream = stream.clone();
+                let interval = interval.clone();
+                let cancelled = cancelled.clone();
+                yew::platform::spawn_local(async move {
+                    let Some(element) = video.cast::<HtmlVideoElement>() else {
+                        return;
+                    };
+                    let started = match qr_detector() {
+                        Ok(detector) => start_camera(&element)
+                            .await
+                            .map(|camera| (detector, camera)),
+                        Err(err) => Err(err),
+                    };
+                    let (detector, camera) = match started {
+                        Ok(started) => started,
+                        Err(err) => {
+                            error.set(Some(err));
+                            scanning.set(false);
+                            return;
+                        }
+                    };
+                    if cancelled.get() {
+                        stop_camera(&camera);
+                        return;
+                    }
+                    *stream.borrow_mut() = Some(camera);
+
+                    let detector = Rc::new(detector);
+                    let busy = Rc::new(Cell::new(false));
+                    *interval.borrow_mut() = Some(Interval::new(SCAN_INTERVAL, move || {
+                        if busy.replace(true) {
+                            return;
+                        }
+                        let promise = detector.detect(&element);
+                        let busy = busy.clone();
+                        let scanning = scanning.clone();
+                        let on_scan = on_scan.clone();
+                        yew::platform::spawn_local(async move {
+                            if let Some(text) = JsFuture::from(promise)
+                                .await
+                                .ok()
+                                .as_ref()
+                                .and_then(raw_value)
+                            {
+                                scanning.set(false);
+                                on_scan.emit(text);
+                            }
+                            busy.set(false);
+                        });
+                    }));
+                });
+            }
+
+            move || {
+                cancelled.set(true);
+                interval.borrow_mut().take();
+                if let Some(stream) = stream.borrow_mut().take() {
+                    stop_camera(&stream);
+                }
+            }
+        });
+    }
+
+    let toggle = {
+        let scanning = scanning.clone();
+        let error = error.clone();
+        Callback::from(move |_: MouseEvent| {
+            error.set(None);
+            scanning.set(!*scanning);
+        })
+    };
+
+    html! {
+        <div class={classes!("p-4", "my-4", "flex", "flex-col", "items-center", "gap-2", theme::CARD)}>
+            <button class="px-4 py-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" onclick={toggle}>
+                {locale.t(if *scanning { "Stop scanning" } else { "📷 Scan a QR code" })}
+            </button>
+            <p class="text-sm text-gray-400">{locale.t("of a proof URL or a proof hash")}</p>
+            <video ref={video} class={if *scanning { "w-full max-w-sm rounded" } else { "hidden" }} playsinline={true} muted={true} aria-label={locale.t("Camera preview")} />
+            if let Some(error) = error.as_ref() {
+                <p class="text-red-500" role="alert">{error.clone()}</p>
+            }
+        </div>
+    }
+}
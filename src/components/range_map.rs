@@ -0,0 +1,62 @@
+use std::ops::Range;
+
+use yew::prelude::*;
+
+use crate::components::redacted_bytes_component::segments;
+use crate::i18n::Locale;
+use crate::theme::Theme;
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    /// Length of the transcript
+    pub len: usize,
+    pub redacted_ranges: Vec<Range<usize>>,
+    /// Called with the start offset of the clicked segment
+    pub on_select: Callback<usize>,
+}
+
+/// A strip representing the whole transcript, with disclosed bytes in green and redacted bytes in red
+#[function_component]
+pub fn RangeMap(props: &Props) -> Html {
+    let Props {
+        len,
+        redacted_ranges,
+        on_select,
+    } = props;
+
+    let theme = use_context::<Theme>().unwrap_or(Theme::Dark);
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    if *len == 0 {
+        return html! {};
+    }
+
+    let (red, green) = match theme {
+        Theme::Light => ("#dc2626", "#16a34a"),
+        Theme::Dark => ("#ef4444", "#22c55e"),
+    };
+
+    let rects = segments(*len, redacted_ranges)
+        .into_iter()
+        .map(|(start, end, is_redacted)| {
+            let (fill, label) = if is_redacted {
+                (red, locale.t("redacted"))
+            } else {
+                (green, locale.t("disclosed"))
+            };
+            let onclick = on_select.reform(move |_: MouseEvent| start);
+            html! {
+                <rect class="cursor-pointer" x={start.to_string()} y="0" width={(end - start).to_string()} height="1" fill={fill} {onclick}>
+                    <title>{locale.format("bytes {start}..{end} ({count} bytes {label})", &[("start", &start), ("end", &end), ("count", &(end - start)), ("label", &label)])}</title>
+                </rect>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        // The bytes themselves are accessible, the map is a visual shortcut only
+        <svg class="w-full h-3 mb-2 rounded" aria-hidden="true" viewBox={format!("0 0 {} 1", len)} preserveAspectRatio="none">
+            {rects}
+        </svg>
+    }
+}
@@ -0,0 +1,119 @@
+use std::rc::Rc;
+
+use serde_json::Value;
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::parsed_proof::ParsedProof;
+use crate::proof_schema;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub proof: Rc<ParsedProof>,
+}
+
+// Byte arrays are shown as hex, cut off after this many bytes
+const MAX_HEX_BYTES: usize = 48;
+
+// Levels of the tree which are expanded initially
+const OPEN_DEPTH: usize = 2;
+
+/// Arrays of numbers which all fit in a byte are serialized byte strings
+fn as_bytes(items: &[Value]) -> Option<Vec<u8>> {
+    if items.is_empty() {
+        return None;
+    }
+    items
+        .iter()
+        .map(|item| item.as_u64().and_then(|n| u8::try_from(n).ok()))
+        .collect()
+}
+
+fn render_annotation(path: &[String]) -> Html {
+    match proof_schema::describe(path) {
+        Some(description) => html! {
+            <span class="ml-2 text-gray-500 dark:text-gray-400 italic">{"// "}{description}</span>
+        },
+        None => html! {},
+    }
+}
+
+fn render_bytes(bytes: &[u8]) -> Html {
+    let shown = &bytes[..bytes.len().min(MAX_HEX_BYTES)];
+    html! {
+        <>
+            <span class="text-gray-400">{format!("[{} bytes] ", bytes.len())}</span>
+            <span class="break-all" title={hex::encode(bytes)}>
+                {hex::encode(shown)}
+                if shown.len() < bytes.len() {
+                    {"…"}
+                }
+            </span>
+        </>
+    }
+}
+
+fn render_node(key: &str, value: &Value, path: &mut Vec<String>) -> Html {
+    let label = if key.is_empty() {
+        html! {}
+    } else {
+        html! { <span class="text-blue-700 dark:text-blue-300">{format!("{}: ", key)}</span> }
+    };
+
+    let children: Vec<(String, &Value)> = match value {
+        Value::Array(items) => match as_bytes(items) {
+            Some(bytes) => {
+                return html! { <div class="pl-4">{label}{render_bytes(&bytes)}{render_annotation(path)}</div> };
+            }
+            None => items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (i.to_string(), item))
+                .collect(),
+        },
+        Value::Object(members) => members.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        scalar => {
+            return html! {
+                <div class="pl-4">{label}<span class="break-all">{scalar.to_string()}</span>{render_annotation(path)}</div>
+            };
+        }
+    };
+
+    let summary = match value {
+        Value::Array(_) => format!("[{} items]", children.len()),
+        _ => format!("{{{} fields}}", children.len()),
+    };
+    let rendered: Vec<Html> = children
+        .iter()
+        .map(|(key, child)| {
+            path.push(key.clone());
+            let html = render_node(key, child, path);
+            path.pop();
+            html
+        })
+        .collect();
+
+    html! {
+        <details class="pl-4" open={path.len() < OPEN_DEPTH}>
+            <summary>{label}<span class="text-gray-400">{summary}</span>{render_annotation(path)}</summary>
+            {for rendered}
+        </details>
+    }
+}
+
+/// The proof file's own JSON structure, annotated with what the fields mean. Rendered from the
+/// parsed proof, the file itself is not kept.
+#[function_component]
+pub fn RawProof(Props { proof }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    html! {
+        <div class={classes!("p-4", "w-full", "rounded-md", "overflow-x-auto", "text-sm", "font-mono", theme::CODE)}>
+            {match &proof.error {
+                None => render_node("", &proof.value, &mut Vec::new()),
+                Some(err) => html! { <p class="text-red-500">{locale.format("Not valid JSON: {error}", &[("error", err)])}</p> },
+            }}
+        </div>
+    }
+}
@@ -1,23 +1,199 @@
+use std::collections::HashSet;
 use std::{fmt, ops::Range};
 
+use base64::{engine::general_purpose, Engine as _};
 // use gloo::console::log;
+use gloo::events::EventListener;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlInputElement, HtmlSelectElement, Node};
 use yew::prelude::*;
 
-#[derive(Clone, PartialEq)]
+use crate::components::copy_button::CopyButton;
+use crate::components::range_map::RangeMap;
+use crate::components::selection_tooltip::{Selected, SelectionTooltip};
+use crate::deep_link::ByteLink;
+use crate::exchanges::{self, HttpPart};
+use crate::i18n::Locale;
+use crate::settings;
+use crate::theme;
+use crate::transcript_text::{self, Piece};
+use crate::verify::Transcript;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Direction {
     Sent,
     Received,
 }
 
+impl Direction {
+    pub fn name(self) -> &'static str {
+        match self {
+            Direction::Sent => "sent",
+            Direction::Received => "received",
+        }
+    }
+}
+
 impl fmt::Display for Direction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+const GUTTER_VIEW: &str = "gutter";
+
+/// How the byte offset of each line is shown in the gutter
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum Gutter {
+    Off,
+    Decimal,
+    Hex,
+}
+
+impl Gutter {
+    fn next(self) -> Self {
         match self {
-            Direction::Sent => write!(f, "sent"),
-            Direction::Received => write!(f, "received"),
+            Gutter::Off => Gutter::Decimal,
+            Gutter::Decimal => Gutter::Hex,
+            Gutter::Hex => Gutter::Off,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Gutter::Off => "off",
+            Gutter::Decimal => "decimal",
+            Gutter::Hex => "hex",
+        }
+    }
+}
+
+const REDACTION_STYLE_VIEW: &str = "redaction_style";
+const LAYOUT_VIEW: &str = "transcript_layout";
+
+/// How lines and whitespace of the transcript are laid out
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Layout {
+    /// Wrap long lines instead of scrolling horizontally
+    wrap: bool,
+    /// Mark carriage returns, line feeds and tabs
+    whitespace: bool,
+    /// Columns per tab stop
+    tab_width: u8,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout {
+            wrap: false,
+            whitespace: false,
+            tab_width: 8,
         }
     }
 }
 
+impl Layout {
+    fn next_tab_width(self) -> u8 {
+        match self.tab_width {
+            2 => 4,
+            4 => 8,
+            _ => 2,
+        }
+    }
+}
+
+/// Markers of whitespace characters, shown by CSS so they are not part of the text when copying
+/// or selecting
+fn whitespace_marker(c: char) -> Option<&'static str> {
+    match c {
+        '\r' => Some("before:content-['␍'] before:text-gray-500"),
+        '\n' => Some("before:content-['␊'] before:text-gray-500"),
+        '\t' => Some("before:content-['→'] before:text-gray-500"),
+        _ => None,
+    }
+}
+
+/// Text with its whitespace characters marked
+fn marked_whitespace(text: &str) -> Html {
+    let mut nodes = Vec::new();
+    let mut rest = text;
+    while let Some(i) = rest.find(['\r', '\n', '\t']) {
+        let (before, after) = rest.split_at(i);
+        let c = after.chars().next().unwrap_or_default();
+        if !before.is_empty() {
+            nodes.push(Html::from(before.to_string()));
+        }
+        nodes.push(html! { <span class={whitespace_marker(c)}>{c}</span> });
+        rest = &after[c.len_utf8()..];
+    }
+    if !rest.is_empty() {
+        nodes.push(Html::from(rest.to_string()));
+    }
+    nodes.into_iter().collect()
+}
+
+/// How redacted bytes are shown
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum RedactionStyle {
+    /// The redaction character of the transcript, one per byte
+    Char,
+    /// A block character per byte
+    Blocks,
+    /// Long redactions as a pill with their size, expanded on click
+    Collapsed,
+    /// The redaction characters blurred
+    Blurred,
+}
+
+impl RedactionStyle {
+    const ALL: [RedactionStyle; 4] = [
+        RedactionStyle::Char,
+        RedactionStyle::Blocks,
+        RedactionStyle::Collapsed,
+        RedactionStyle::Blurred,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            RedactionStyle::Char => "characters",
+            RedactionStyle::Blocks => "blocks",
+            RedactionStyle::Collapsed => "collapsed",
+            RedactionStyle::Blurred => "blurred",
+        }
+    }
+
+    /// The character standing for a redacted byte
+    fn char(self, redacted_char: char) -> char {
+        match self {
+            RedactionStyle::Blocks => '█',
+            _ => redacted_char,
+        }
+    }
+}
+
+/// Redactions shorter than this are never collapsed, a pill would take more room than they do
+const MIN_COLLAPSED: usize = 10;
+
+/// The offset of every line, one per line, so it lines up with the transcript
+fn gutter_text(bytes: &[u8], gutter: Gutter) -> String {
+    let line_starts = std::iter::once(0).chain(
+        bytes
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .map(|(i, _)| i + 1)
+            .filter(|start| *start < bytes.len()),
+    );
+    line_starts
+        .map(|offset| match gutter {
+            Gutter::Hex => format!("0x{:04x}", offset),
+            _ => offset.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
     pub direction: Direction,
@@ -26,21 +202,160 @@ pub struct Props {
     pub redacted_ranges: Vec<Range<usize>>,
 }
 
+/// The placeholder of `size` redacted bytes
 fn get_redacted_string(redacted_char: &char, size: usize) -> String {
-    if true
-    /*|| size < 10*/
-    {
-        redacted_char.to_string().repeat(size)
-    } else {
-        format! {"{}...{}", redacted_char.to_string().repeat(3), redacted_char.to_string().repeat(3)}
+    redacted_char.to_string().repeat(size)
+}
+
+/// The text of the pill a collapsed redaction of `size` bytes is shown as
+fn collapsed_text(locale: Locale, size: usize) -> String {
+    locale.format("«{count} bytes redacted»", &[("count", &size)])
+}
+
+/// Find all (ASCII case insensitive) occurrences of `query` in the disclosed parts of `bytes`
+fn find_matches(bytes: &[u8], redacted_ranges: &[Range<usize>], query: &[u8]) -> Vec<Range<usize>> {
+    let mut matches = Vec::new();
+    if query.is_empty() {
+        return matches;
     }
+
+    let mut start = 0;
+    while start + query.len() <= bytes.len() {
+        let candidate = start..start + query.len();
+        let is_redacted = redacted_ranges
+            .iter()
+            .any(|r| r.start < candidate.end && candidate.start < r.end);
+        if !is_redacted && bytes[candidate.clone()].eq_ignore_ascii_case(query) {
+            start = candidate.end;
+            matches.push(candidate);
+        } else {
+            start += 1;
+        }
+    }
+    matches
+}
+
+/// The transcript as shown, with the redacted bytes replaced by `redacted_char`, long redactions
+/// shortened if they are shown collapsed
+fn visible_text(
+    locale: Locale,
+    bytes: &[u8],
+    redacted_ranges: &[Range<usize>],
+    redacted_char: &char,
+    style: RedactionStyle,
+    expanded: &HashSet<usize>,
+) -> String {
+    segments(bytes.len(), redacted_ranges)
+        .into_iter()
+        .map(|(start, end, is_redacted)| {
+            let size = end - start;
+            if !is_redacted {
+                transcript_text::escaped(&bytes[start..end])
+            } else if style == RedactionStyle::Collapsed
+                && size >= MIN_COLLAPSED
+                && !expanded.contains(&start)
+            {
+                collapsed_text(locale, size)
+            } else {
+                get_redacted_string(&style.char(*redacted_char), size)
+            }
+        })
+        .collect()
+}
+
+/// The search matches, and the one scrolled to
+struct Matches<'a> {
+    ranges: &'a [Range<usize>],
+    current: Option<usize>,
+    current_ref: &'a NodeRef,
+}
+
+// Render disclosed bytes, the bytes of characters split by a redaction as hex escapes
+fn disclosed_text(bytes: &[u8], whitespace: bool) -> Html {
+    transcript_text::pieces(bytes)
+        .into_iter()
+        .map(|piece| match piece {
+            Piece::Text(text) if whitespace => marked_whitespace(&text),
+            Piece::Text(text) => Html::from(text),
+            Piece::Byte(byte) => {
+                html! { <span class="text-orange-500">{Piece::escape(byte)}</span> }
+            }
+        })
+        .collect()
 }
 
-fn redactions_in_red(bytes: &[u8], redacted_ranges: &[Range<usize>], redacted_char: &char) -> Html {
-    if redacted_ranges.is_empty() {
-        return Html::from(String::from_utf8(bytes.to_vec()).unwrap());
+// Render a disclosed part of the transcript, highlighting the search matches in it
+fn disclosed_with_matches(
+    bytes: &[u8],
+    range: Range<usize>,
+    matches: &Matches,
+    whitespace: bool,
+) -> Html {
+    let mut nodes = Vec::new();
+    let mut pos = range.start;
+    // a match may be split by the bounds of the highlight or of the HTTP parts
+    for (i, m) in matches
+        .ranges
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| m.start < range.end && range.start < m.end)
+    {
+        let starts_here = m.start >= range.start;
+        let m = m.start.max(range.start)..m.end.min(range.end);
+        if pos < m.start {
+            nodes.push(disclosed_text(&bytes[pos..m.start], whitespace));
+        }
+        let text = disclosed_text(&bytes[m.clone()], whitespace);
+        let is_current = Some(i) == matches.current;
+        let class = if is_current {
+            "bg-orange-500 text-black"
+        } else {
+            "bg-yellow-300 text-black"
+        };
+        nodes.push(if is_current && starts_here {
+            html! { <mark {class} ref={matches.current_ref.clone()}>{text}</mark> }
+        } else {
+            html! { <mark {class}>{text}</mark> }
+        });
+        pos = m.end;
+    }
+    if pos < range.end {
+        nodes.push(disclosed_text(&bytes[pos..range.end], whitespace));
     }
 
+    html! { <>{ for nodes }</> }
+}
+
+/// Clamp `redacted_ranges` to `0..len`, drop empty ones, and sort and merge the overlapping or
+/// adjacent ones. Proofs are not bound to hand over their ranges in order.
+pub fn normalize(len: usize, redacted_ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = redacted_ranges
+        .iter()
+        .map(|range| range.start.min(len)..range.end.min(len))
+        .filter(|range| range.start < range.end)
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+    ranges.into_iter().fold(Vec::new(), |mut merged, range| {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+        merged
+    })
+}
+
+/// The number of redacted bytes, counting overlapping ranges once
+fn redacted_size(len: usize, redacted_ranges: &[Range<usize>]) -> usize {
+    normalize(len, redacted_ranges)
+        .iter()
+        .map(|range| range.len())
+        .sum()
+}
+
+/// Split the transcript into alternating disclosed and redacted `(start, end, is_redacted)` segments
+pub fn segments(len: usize, redacted_ranges: &[Range<usize>]) -> Vec<(usize, usize, bool)> {
+    let redacted_ranges = normalize(len, redacted_ranges);
+
     // create ranges for non redacted parts and store last redacted position
     let (non_redacted_ranges, last_redacted_position) = redacted_ranges.iter().fold(
         (Vec::new(), 0), // (Accumulator vector, last redacted position)
@@ -54,7 +369,7 @@ fn redactions_in_red(bytes: &[u8], redacted_ranges: &[Range<usize>], redacted_ch
     );
 
     // interweave the redacted and non-redacted ranges
-    let all_ranges = non_redacted_ranges
+    non_redacted_ranges
         .into_iter()
         .zip(redacted_ranges.iter())
         .flat_map(|(non_redacted, redacted)| {
@@ -63,21 +378,204 @@ fn redactions_in_red(bytes: &[u8], redacted_ranges: &[Range<usize>], redacted_ch
                 (redacted.start, redacted.end, true),
             ]
         })
-        .chain(std::iter::once((
-            last_redacted_position,
-            bytes.len(),
-            false,
-        ))); // Handle remaining non-redacted part
+        .chain(std::iter::once((last_redacted_position, len, false))) // Handle remaining non-redacted part
+        .filter(|(start, end, _)| start < end)
+        .collect()
+}
 
-    let html_nodes = all_ranges
-        .map(|(start, end, is_redacted)| {
+/// The bytes of `range` as text, with the redacted ones in red
+pub fn render_range(transcript: &Transcript, range: &Range<usize>) -> Html {
+    let bytes = &transcript.bytes[range.clone()];
+    let redacted = transcript.redactions_within(range);
+    html! {
+        {for segments(bytes.len(), &redacted).into_iter().map(|(start, end, is_redacted)| html! {
+            <span class={if is_redacted { "text-red-500" } else { "" }}>{String::from_utf8_lossy(&bytes[start..end])}</span>
+        })}
+    }
+}
+
+/// Split the segments at the bounds of the highlighted range, marking the parts inside it
+fn split_at_highlight(
+    segments: Vec<(usize, usize, bool)>,
+    highlight: Option<&Range<usize>>,
+) -> Vec<(usize, usize, bool, bool)> {
+    let Some(highlight) = highlight else {
+        return segments
+            .into_iter()
+            .map(|(start, end, is_redacted)| (start, end, is_redacted, false))
+            .collect();
+    };
+    segments
+        .into_iter()
+        .flat_map(|(start, end, is_redacted)| {
+            let inner_start = highlight.start.clamp(start, end);
+            let inner_end = highlight.end.clamp(start, end);
+            [
+                (start, inner_start, is_redacted, false),
+                (inner_start, inner_end, is_redacted, true),
+                (inner_end, end, is_redacted, false),
+            ]
+        })
+        .filter(|(start, end, _, _)| start < end)
+        .collect()
+}
+
+const HIGHLIGHT_CLASS: &str = "bg-blue-200 dark:bg-blue-900";
+
+/// How many bytes are rendered at once, more are rendered when scrolling towards the end
+const WINDOW: usize = 64 * 1024;
+/// Transcripts larger than this are only rendered after confirmation
+const LARGE_TRANSCRIPT: usize = 1024 * 1024;
+
+/// The end of the rendered part of the transcript, covering at least `len` bytes. It is moved to
+/// the next line break, or at least past a split character, so the window ends cleanly.
+fn window_end(bytes: &[u8], len: usize) -> usize {
+    if len >= bytes.len() {
+        return bytes.len();
+    }
+    match bytes[len..].iter().take(WINDOW).position(|b| *b == b'\n') {
+        Some(i) => len + i + 1,
+        None => {
+            let mut end = len;
+            // skip UTF-8 continuation bytes
+            while end < bytes.len() && bytes[end] & 0xc0 == 0x80 {
+                end += 1;
+            }
+            end
+        }
+    }
+}
+
+/// The redacted ranges within the first `end` bytes
+fn clip_ranges(redacted_ranges: &[Range<usize>], end: usize) -> Vec<Range<usize>> {
+    redacted_ranges
+        .iter()
+        .filter(|r| r.start < end)
+        .map(|r| r.start..r.end.min(end))
+        .collect()
+}
+
+/// How the redactions are rendered
+struct Placeholders<'a> {
+    redacted_char: char,
+    style: RedactionStyle,
+    /// The start of the collapsed redactions which were expanded
+    expanded: &'a HashSet<usize>,
+    /// Called with the start of a collapsed redaction to expand or collapse it again
+    on_toggle: &'a Callback<usize>,
+}
+
+fn render_redaction(locale: Locale, range: Range<usize>, placeholders: &Placeholders) -> Html {
+    let Placeholders {
+        redacted_char,
+        style,
+        expanded,
+        on_toggle,
+    } = placeholders;
+    let size = range.len();
+    let chars = get_redacted_string(&style.char(*redacted_char), size);
+    match style {
+        RedactionStyle::Collapsed if size >= MIN_COLLAPSED => {
+            let start = range.start;
+            let onclick = on_toggle.reform(move |_: MouseEvent| start);
+            if expanded.contains(&start) {
+                html! { <span class="cursor-pointer" title={locale.t("Click to collapse")} {onclick}>{chars}</span> }
+            } else {
+                html! {
+                    <span class="cursor-pointer px-1 rounded-full bg-red-100 dark:bg-red-950" title={locale.t("Click to expand")} {onclick}>
+                        {collapsed_text(locale, size)}
+                    </span>
+                }
+            }
+        }
+        RedactionStyle::Blurred => html! { <span class="blur-sm select-none">{chars}</span> },
+        _ => html! { {chars} },
+    }
+}
+
+/// How the disclosed text is marked up
+struct Markup<'a> {
+    /// The HTTP message parts, ordered by position
+    parts: &'a [(Range<usize>, HttpPart)],
+    whitespace: bool,
+}
+
+fn part_class(part: HttpPart) -> Option<&'static str> {
+    match part {
+        HttpPart::StartLine => Some("font-bold text-purple-700 dark:text-purple-300"),
+        HttpPart::HeaderName => Some("text-blue-700 dark:text-blue-300"),
+        HttpPart::HeaderValue => Some("text-green-700 dark:text-green-300"),
+        HttpPart::Body => None,
+    }
+}
+
+/// The HTTP message part at `pos`
+fn part_at(parts: &[(Range<usize>, HttpPart)], pos: usize) -> Option<HttpPart> {
+    let i = parts.partition_point(|(range, _)| range.start <= pos);
+    let (range, part) = parts.get(i.checked_sub(1)?)?;
+    range.contains(&pos).then_some(*part)
+}
+
+/// Split the disclosed segments at the bounds of the HTTP message parts, adding the part of each
+fn split_at_parts(
+    segments: Vec<(usize, usize, bool, bool)>,
+    parts: &[(Range<usize>, HttpPart)],
+) -> Vec<(usize, usize, bool, bool, Option<HttpPart>)> {
+    let mut split = Vec::new();
+    for (start, end, is_redacted, highlighted) in segments {
+        if is_redacted {
+            split.push((start, end, is_redacted, highlighted, None));
+            continue;
+        }
+        let mut cuts = vec![start, end];
+        let first = parts.partition_point(|(range, _)| range.end <= start);
+        for (range, _) in parts[first..]
+            .iter()
+            .take_while(|(range, _)| range.start < end)
+        {
+            cuts.extend([range.start, range.end].map(|cut| cut.clamp(start, end)));
+        }
+        cuts.sort_unstable();
+        cuts.dedup();
+        split.extend(
+            cuts.windows(2)
+                .map(|cut| (cut[0], cut[1], false, highlighted, part_at(parts, cut[0]))),
+        );
+    }
+    split
+}
+
+fn redactions_in_red(
+    locale: Locale,
+    bytes: &[u8],
+    redacted_ranges: &[Range<usize>],
+    placeholders: &Placeholders,
+    matches: &Matches,
+    highlight: Option<&Range<usize>>,
+    markup: &Markup,
+) -> Html {
+    // every segment is anchored by its offset, so the range map and links can scroll to it
+    let segments = split_at_highlight(segments(bytes.len(), redacted_ranges), highlight);
+    let html_nodes = split_at_parts(segments, markup.parts)
+        .into_iter()
+        .map(|(start, end, is_redacted, highlighted, part)| {
+            let class = classes!(
+                highlighted.then_some(HIGHLIGHT_CLASS),
+                part.and_then(part_class)
+            );
             if is_redacted {
-                Html::from_html_unchecked(AttrValue::from(format!(
-                    "<span style=\"color:red;\">{}</span>",
-                    get_redacted_string(redacted_char, end - start)
-                )))
+                html! {
+                    <span style="color:red;" {class} data-offset={start.to_string()} data-redacted={(end - start).to_string()} title={locale.format("redacted {range} ({count} bytes)", &[("range", &format!("{:?}", start..end)), ("count", &(end - start))])}
+                        role="img" aria-label={locale.format("{count} bytes redacted", &[("count", &(end - start))])}>
+                        {render_redaction(locale, start..end, placeholders)}
+                    </span>
+                }
             } else {
-                Html::from(String::from_utf8_lossy(&bytes[start..end]))
+                html! {
+                    <span {class} data-offset={start.to_string()}>
+                        {disclosed_with_matches(bytes, start..end, matches, markup.whitespace)}
+                    </span>
+                }
             }
         })
         .collect::<Vec<_>>();
@@ -89,6 +587,105 @@ fn redactions_in_red(bytes: &[u8], redacted_ranges: &[Range<usize>], redacted_ch
     }
 }
 
+/// The byte offset of `units` UTF-16 code units into the rendered `bytes`, as the browser counts
+/// text offsets in UTF-16 code units
+fn utf16_to_byte_offset(bytes: &[u8], units: usize) -> usize {
+    let mut seen = 0;
+    let mut pos = 0;
+    for piece in transcript_text::pieces(bytes) {
+        match piece {
+            Piece::Text(text) => {
+                for c in text.chars() {
+                    if seen >= units {
+                        return pos;
+                    }
+                    seen += c.len_utf16();
+                    pos += c.len_utf8();
+                }
+            }
+            // shown as a hex escape
+            Piece::Byte(byte) => {
+                if seen >= units {
+                    return pos;
+                }
+                seen += Piece::escape(byte).len();
+                pos += 1;
+            }
+        }
+    }
+    pos
+}
+
+/// The transcript offset of a position in the rendered transcript, using the offset anchor of the
+/// enclosing segment
+fn byte_offset(bytes: &[u8], node: &Node, offset: u32) -> Option<usize> {
+    let element = match node.dyn_ref::<Element>() {
+        Some(element) => element.clone(),
+        None => node.parent_element()?,
+    };
+    let segment = element.closest("[data-offset]").ok()??;
+    let start: usize = segment.get_attribute("data-offset")?.parse().ok()?;
+
+    let range = gloo::utils::document().create_range().ok()?;
+    range.set_start(&segment, 0).ok()?;
+    range.set_end(node, offset).ok()?;
+    let units = String::from(range.to_string()).encode_utf16().count();
+    if let Some(size) = segment.get_attribute("data-redacted") {
+        // One placeholder per byte, unless collapsed into a pill which is selected as a whole
+        let size: usize = size.parse().ok()?;
+        let shown = segment.text_content()?.encode_utf16().count();
+        let offset = match units {
+            0 => 0,
+            units if shown == size => units.min(size),
+            _ => size,
+        };
+        return Some((start + offset).min(bytes.len()));
+    }
+    Some((start + utf16_to_byte_offset(bytes.get(start..)?, units)).min(bytes.len()))
+}
+
+/// The byte range and position of the current text selection, if it lies within `container`
+fn selected_range(bytes: &[u8], container: &Element) -> Option<Selected> {
+    let selection = gloo::utils::window().get_selection().ok()??;
+    if selection.is_collapsed() {
+        return None;
+    }
+    let anchor = selection.anchor_node()?;
+    let focus = selection.focus_node()?;
+    if !container.contains(Some(&anchor)) || !container.contains(Some(&focus)) {
+        return None;
+    }
+    let a = byte_offset(bytes, &anchor, selection.anchor_offset())?;
+    let b = byte_offset(bytes, &focus, selection.focus_offset())?;
+    let range = a.min(b)..a.max(b);
+    if range.is_empty() {
+        return None;
+    }
+
+    let rect = selection.get_range_at(0).ok()?.get_bounding_client_rect();
+    Some(Selected {
+        range,
+        left: rect.left(),
+        bottom: rect.bottom(),
+    })
+}
+
+/// The rendered segment starting at `offset`
+fn segment_at(bytes_ref: &NodeRef, offset: usize) -> Option<Element> {
+    bytes_ref
+        .cast::<Element>()?
+        .query_selector(&format!("[data-offset=\"{}\"]", offset))
+        .ok()
+        .flatten()
+}
+
+/// The range to highlight if the URL links into this transcript
+fn linked_range(direction: &Direction) -> Option<Range<usize>> {
+    ByteLink::current()
+        .filter(|link| link.direction == *direction)
+        .map(|link| link.range)
+}
+
 #[function_component]
 pub fn RedactedBytesComponent(props: &Props) -> Html {
     let Props {
@@ -98,17 +695,469 @@ pub fn RedactedBytesComponent(props: &Props) -> Html {
         redacted_ranges,
     } = props;
 
+    let query = use_state(String::new);
+    let current = use_state(|| 0usize);
+    let current_ref = use_node_ref();
+    let bytes_ref = use_node_ref();
+    let gutter = use_state(|| settings::load_view(GUTTER_VIEW, Gutter::Off));
+    let style = use_state(|| settings::load_view(REDACTION_STYLE_VIEW, RedactionStyle::Char));
+    let expanded = use_state(HashSet::<usize>::new);
+    let layout = use_state(|| settings::load_view(LAYOUT_VIEW, Layout::default()));
+    let parts = use_memo((bytes.clone(), *direction), |(bytes, direction)| {
+        exchanges::message_parts(bytes, *direction)
+    });
+    let highlight = use_state(|| linked_range(direction));
+    // Shared by the copy buttons, which only encode it when clicked: it may be large
+    let transcript = use_memo(
+        (bytes.clone(), redacted_ranges.clone()),
+        |(bytes, redacted_ranges)| Transcript {
+            bytes: bytes.clone(),
+            redacted_ranges: redacted_ranges.clone(),
+        },
+    );
+    let selection = use_state(|| None::<Selected>);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let render_anyway = use_state(|| false);
+    let shown = use_state(|| WINDOW);
+    let scroll_to = use_state(|| None::<usize>);
+    let more_ref = use_node_ref();
+
+    // Follow links into this transcript, also when only the fragment changes
+    {
+        let highlight = highlight.clone();
+        use_effect_with(*direction, move |direction| {
+            let direction = *direction;
+            let listener = EventListener::new(&gloo::utils::window(), "hashchange", move |_| {
+                highlight.set(linked_range(&direction))
+            });
+            move || drop(listener)
+        });
+    }
+
+    // Scroll to the start of the highlighted range
+    {
+        let bytes_ref = bytes_ref.clone();
+        use_effect_with((*highlight).clone(), move |highlight| {
+            let segment = highlight
+                .as_ref()
+                .and_then(|highlight| segment_at(&bytes_ref, highlight.start));
+            if let Some(segment) = segment {
+                segment.scroll_into_view();
+            }
+        });
+    }
+
+    let on_select = {
+        let bytes = bytes.clone();
+        let bytes_ref = bytes_ref.clone();
+        let selection = selection.clone();
+        Callback::from(move |_: MouseEvent| {
+            let selected = bytes_ref
+                .cast::<Element>()
+                .and_then(|pre| selected_range(&bytes, &pre));
+            selection.set(selected);
+        })
+    };
+    let close_selection = {
+        let selection = selection.clone();
+        Callback::from(move |_| selection.set(None))
+    };
+    let toggle_gutter = {
+        let gutter = gutter.clone();
+        Callback::from(move |_: MouseEvent| {
+            settings::save_view(GUTTER_VIEW, gutter.next());
+            gutter.set(gutter.next())
+        })
+    };
+
+    let on_style = {
+        let style = style.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(selected) = select
+                .value()
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| RedactionStyle::ALL.get(index))
+            {
+                settings::save_view(REDACTION_STYLE_VIEW, *selected);
+                style.set(*selected);
+            }
+        })
+    };
+    let change_layout = |change: fn(Layout) -> Layout| {
+        let layout = layout.clone();
+        Callback::from(move |_: MouseEvent| {
+            let changed = change(*layout);
+            settings::save_view(LAYOUT_VIEW, changed);
+            layout.set(changed);
+        })
+    };
+    let toggle_wrap = change_layout(|layout| Layout {
+        wrap: !layout.wrap,
+        ..layout
+    });
+    let toggle_whitespace = change_layout(|layout| Layout {
+        whitespace: !layout.whitespace,
+        ..layout
+    });
+    let next_tab_width = change_layout(|layout| Layout {
+        tab_width: layout.next_tab_width(),
+        ..layout
+    });
+    let on_toggle_redaction = {
+        let expanded = expanded.clone();
+        Callback::from(move |start: usize| {
+            let mut toggled = (*expanded).clone();
+            if !toggled.remove(&start) {
+                toggled.insert(start);
+            }
+            expanded.set(toggled);
+        })
+    };
+    let placeholders = Placeholders {
+        redacted_char: *redacted_char,
+        style: *style,
+        expanded: &expanded,
+        on_toggle: &on_toggle_redaction,
+    };
+
+    // Scroll to the segment starting at `offset`, as selected in the range map. If it is not
+    // rendered yet, the window is extended and the effect below scrolls once it is.
+    let on_select_offset = {
+        let bytes_ref = bytes_ref.clone();
+        let scroll_to = scroll_to.clone();
+        Callback::from(move |offset: usize| match segment_at(&bytes_ref, offset) {
+            Some(segment) => segment.scroll_into_view(),
+            None => scroll_to.set(Some(offset)),
+        })
+    };
+    {
+        let bytes_ref = bytes_ref.clone();
+        use_effect_with(*scroll_to, move |offset| {
+            if let Some(segment) = offset.and_then(|offset| segment_at(&bytes_ref, offset)) {
+                segment.scroll_into_view();
+            }
+        });
+    }
+
+    let matches = use_memo(
+        (bytes.clone(), redacted_ranges.clone(), (*query).clone()),
+        |(bytes, redacted_ranges, query)| find_matches(bytes, redacted_ranges, query.as_bytes()),
+    );
+    let current_match = (!matches.is_empty()).then(|| *current % matches.len());
+
+    // Render the first window, extended to whatever is linked, searched for or jumped to
     let size = bytes.len();
-    let redacted_size = redacted_ranges
-        .iter()
-        .fold(0, |acc, r| acc + r.end - r.start);
+    let guarded = size > LARGE_TRANSCRIPT && !*render_anyway;
+    let wanted = [
+        highlight.as_ref().map(|highlight| highlight.end),
+        current_match.map(|i| matches[i].end),
+        scroll_to.map(|offset| offset + 1),
+    ]
+    .into_iter()
+    .flatten()
+    .fold(*shown, usize::max);
+    let end = window_end(bytes, wanted);
+
+    // Render the next window when the end of the rendered part comes close to the viewport
+    {
+        let shown = shown.clone();
+        let more_ref = more_ref.clone();
+        use_effect_with((end, guarded), move |(end, _)| {
+            let end = *end;
+            let load_more = move || {
+                let viewport = gloo::utils::window()
+                    .inner_height()
+                    .ok()
+                    .and_then(|height| height.as_f64())
+                    .unwrap_or_default();
+                let near = more_ref
+                    .cast::<Element>()
+                    .is_some_and(|more| more.get_bounding_client_rect().top() < 2.0 * viewport);
+                if near {
+                    shown.set(end + WINDOW);
+                }
+            };
+            // the rendered part may not even fill the viewport yet
+            load_more();
+            let listener =
+                EventListener::new(&gloo::utils::window(), "scroll", move |_| load_more());
+            move || drop(listener)
+        });
+    }
+    let show_more = {
+        let shown = shown.clone();
+        Callback::from(move |_: MouseEvent| shown.set(end + WINDOW))
+    };
+    let on_render_anyway = {
+        let render_anyway = render_anyway.clone();
+        Callback::from(move |_: MouseEvent| render_anyway.set(true))
+    };
+
+    // Bring the current match into view when navigating
+    {
+        let current_ref = current_ref.clone();
+        use_effect_with(((*query).clone(), *current), move |_| {
+            if let Some(element) = current_ref.cast::<Element>() {
+                element.scroll_into_view_with_bool(false);
+            }
+        });
+    }
+
+    let oninput = {
+        let query = query.clone();
+        let current = current.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            query.set(input.value());
+            current.set(0);
+        })
+    };
+
+    let match_count = matches.len();
+    let step = |forward: bool| {
+        let current = current.clone();
+        move || {
+            if match_count > 0 {
+                let delta = if forward { 1 } else { match_count - 1 };
+                current.set((*current + delta) % match_count);
+            }
+        }
+    };
+    let next = {
+        let step = step(true);
+        Callback::from(move |_: MouseEvent| step())
+    };
+    let previous = {
+        let step = step(false);
+        Callback::from(move |_: MouseEvent| step())
+    };
+    let onkeydown = {
+        let step = step(true);
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Enter" {
+                step();
+            }
+        })
+    };
+
+    // The text as shown, with the collapsed redactions as their pills
+    let copy_text = {
+        let (transcript, redacted_char, style) = (transcript.clone(), *redacted_char, *style);
+        let expanded = (*expanded).clone();
+        Callback::from(move |_| {
+            visible_text(
+                locale,
+                &transcript.bytes,
+                &transcript.redacted_ranges,
+                &redacted_char,
+                style,
+                &expanded,
+            )
+        })
+    };
+    let copy = |encode: fn(&[u8]) -> String| {
+        let transcript = transcript.clone();
+        Callback::from(move |_| encode(&transcript.bytes))
+    };
+    let copy_hex = copy(|bytes| hex::encode(bytes));
+    let copy_base64 = copy(|bytes| general_purpose::STANDARD.encode(bytes));
+
+    let redacted_size = redacted_size(size, redacted_ranges);
+
+    let search_button = "px-2 hover:bg-black hover:text-white rounded border-black border";
+    let search_label = locale.format(
+        "Search {direction} bytes",
+        &[("direction", &locale.t(direction.name()))],
+    );
 
     html! {
-        <details class="p-4 w-5/6" open={true}>
-            <summary><b>{"Bytes "}{direction}{": " }</b>{"("}{size}{"B, redacted:"}{redacted_size}{"B)"}</summary>
-            <div class="bg-black text-white p-4 rounded-md overflow-x-auto">
-                <pre>{redactions_in_red(bytes, redacted_ranges, redacted_char)}</pre>
+        <details class={theme::PANEL} open={true}>
+            <summary class={theme::SUMMARY}>
+                <b>{locale.format("Bytes {direction}: ", &[("direction", &locale.t(direction.name()))])}</b>
+                {locale.format("({size}B, redacted:{redacted}B)", &[("size", &size), ("redacted", &redacted_size)])}
+            </summary>
+            <div class={classes!("flex", "flex-wrap", "gap-2", "items-center", "py-2", "text-sm", theme::STICKY)}>
+                <input type="search" class={classes!("p-1", theme::INPUT)} placeholder={search_label.clone()} aria-label={search_label} {oninput} {onkeydown} />
+                if !query.is_empty() {
+                    <span aria-live="polite">{match current_match {
+                        Some(i) => format!("{}/{}", i + 1, match_count),
+                        None => locale.t("no matches").to_string(),
+                    }}</span>
+                    <button class={search_button} onclick={previous} aria-label={locale.t("Previous match")}>{"↑"}</button>
+                    <button class={search_button} onclick={next} aria-label={locale.t("Next match")}>{"↓"}</button>
+                }
+                <div class="flex-1"></div>
+                <CopyButton compute={copy_text} what={locale.t("the visible text")} label={locale.t("Copy text")} />
+                <CopyButton compute={copy_hex} what={locale.t("the raw bytes as hex")} label={locale.t("Copy hex")} />
+                <CopyButton compute={copy_base64} what={locale.t("the raw bytes as base64")} label={locale.t("Copy base64")} />
+                <label class="flex gap-1 items-center">
+                    {locale.t("Redactions:")}
+                    <select class={classes!("p-1", theme::INPUT)} onchange={on_style}>
+                        {for RedactionStyle::ALL.iter().enumerate().map(|(index, option)| html! {
+                            <option value={index.to_string()} selected={*option == *style}>{locale.t(option.name())}</option>
+                        })}
+                    </select>
+                </label>
+                <button class={search_button} onclick={toggle_wrap} aria-pressed={layout.wrap.to_string()}>{locale.t("Wrap lines")}</button>
+                <button class={search_button} onclick={toggle_whitespace} aria-pressed={layout.whitespace.to_string()}>{locale.t("Show whitespace")}</button>
+                <button class={search_button} onclick={next_tab_width}>{locale.format("Tab width: {width}", &[("width", &layout.tab_width)])}</button>
+                // wrapped lines would not line up with the offsets of the lines
+                <button class={search_button} onclick={toggle_gutter} disabled={layout.wrap} title={layout.wrap.then(|| locale.t("Offsets are only shown without wrapping"))}>{locale.format("Offsets: {gutter}", &[("gutter", &locale.t(gutter.name()))])}</button>
             </div>
+            <RangeMap len={size} redacted_ranges={redacted_ranges.clone()} on_select={on_select_offset} />
+            if guarded {
+                <div class="p-2 md:p-4 text-sm" role="alert">
+                    <p class="text-yellow-600 dark:text-yellow-400">
+                        {locale.format("⚠️ This transcript is {size} bytes large, rendering it may make the page unresponsive.", &[("size", &size)])}
+                    </p>
+                    <button class={classes!("mt-2", search_button)} onclick={on_render_anyway}>{locale.t("Render anyway")}</button>
+                </div>
+            } else {
+                <div class={classes!("p-2", "md:p-4", "rounded-md", "overflow-x-auto", "flex", theme::CODE)}>
+                    if *gutter != Gutter::Off && !layout.wrap {
+                        <pre class="pr-4 mr-4 text-right text-gray-500 border-r border-gray-700 select-none">{gutter_text(&bytes[..end], *gutter)}</pre>
+                    }
+                    if let Some(selected) = selection.as_ref() {
+                        <SelectionTooltip direction={*direction} bytes={bytes.clone()} redacted_ranges={redacted_ranges.clone()} selected={selected.clone()} on_close={close_selection} />
+                    }
+                    <pre ref={bytes_ref} class={classes!(layout.wrap.then_some("whitespace-pre-wrap break-all"))} style={format!("tab-size: {}", layout.tab_width)} onmouseup={on_select}>{redactions_in_red(locale, &bytes[..end], &clip_ranges(redacted_ranges, end), &placeholders, &Matches { ranges: &matches, current: current_match, current_ref: &current_ref }, highlight.as_ref(), &Markup { parts: &parts, whitespace: layout.whitespace })}</pre>
+                </div>
+                if end < size {
+                    <div ref={more_ref} class="py-2 text-center text-sm">
+                        <button class={search_button} onclick={show_more}>
+                            {locale.format("Show more ({remaining} bytes left)", &[("remaining", &(size - end))])}
+                        </button>
+                    </div>
+                }
+            }
         </details>
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Ranges past the end, and each one again shifted so they overlap, and reversed
+    fn ranges() -> impl Strategy<Value = (usize, Vec<Range<usize>>)> {
+        (0..64usize)
+            .prop_flat_map(|len| {
+                let bound = len + 8;
+                (
+                    Just(len),
+                    prop::collection::vec((0..bound, 0..bound).prop_map(|(a, b)| a..b), 0..8),
+                )
+            })
+            .prop_map(|(len, mut ranges)| {
+                let more: Vec<Range<usize>> = ranges
+                    .iter()
+                    .flat_map(|range| [range.start + 1..range.end + 2, range.end..range.start])
+                    .collect();
+                ranges.extend(more);
+                (len, ranges)
+            })
+    }
+
+    #[test]
+    fn unordered_ranges() {
+        assert_eq!(
+            segments(10, &[6..8, 1..3, 2..4, 8..9, 5..5, 9..20]),
+            [(0, 1, false), (1, 4, true), (4, 6, false), (6, 10, true)]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn segments_cover_transcript((len, redacted_ranges) in ranges()) {
+            let segments = segments(len, &redacted_ranges);
+            let mut pos = 0;
+            for &(start, end, _) in &segments {
+                prop_assert_eq!(start, pos);
+                prop_assert!(start < end);
+                pos = end;
+            }
+            prop_assert_eq!(pos, len);
+            for pair in segments.windows(2) {
+                prop_assert_ne!(pair[0].2, pair[1].2);
+            }
+        }
+
+        #[test]
+        fn segments_redact_union((len, redacted_ranges) in ranges()) {
+            let expected: Vec<bool> = (0..len)
+                .map(|i| redacted_ranges.iter().any(|range| range.contains(&i)))
+                .collect();
+            let mut actual = vec![false; len];
+            for (start, end, is_redacted) in segments(len, &redacted_ranges) {
+                actual[start..end].fill(is_redacted);
+            }
+            prop_assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn redacted_size_counts_union((len, redacted_ranges) in ranges()) {
+            let expected = (0..len)
+                .filter(|i| redacted_ranges.iter().any(|range| range.contains(i)))
+                .count();
+            prop_assert_eq!(redacted_size(len, &redacted_ranges), expected);
+        }
+    }
+
+    #[test]
+    fn redacted_size_of_overlaps() {
+        assert_eq!(
+            redacted_size(10, &[2..6, 4..8, Range { start: 7, end: 3 }, 9..20]),
+            7
+        );
+    }
+
+    #[test]
+    fn visible_text_matches_collapsed_pills() {
+        let bytes = b"token=0123456789abcdef; id=12";
+        let redacted_ranges = [6..22, 27..29];
+        let text = |expanded: &[usize]| {
+            visible_text(
+                Locale::En,
+                bytes,
+                &redacted_ranges,
+                &'X',
+                RedactionStyle::Collapsed,
+                &expanded.iter().copied().collect(),
+            )
+        };
+        assert_eq!(text(&[]), "token=«16 bytes redacted»; id=XX");
+        assert_eq!(text(&[6]), "token=XXXXXXXXXXXXXXXX; id=XX");
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::components::harness::{render, text};
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    async fn redactions_are_marked() {
+        let root = render::<RedactedBytesComponent>(Props {
+            direction: Direction::Sent,
+            redacted_char: 'X',
+            bytes: b"GET /secret HTTP/1.1\r\n\r\n".to_vec(),
+            redacted_ranges: vec![5..11],
+        })
+        .await;
+        let redaction = root
+            .query_selector("span[role=img][data-offset='5']")
+            .unwrap()
+            .expect("the redaction has a span of its own");
+        assert_eq!(
+            redaction.get_attribute("aria-label").as_deref(),
+            Some("6 bytes redacted")
+        );
+        let text = text(&root);
+        assert!(text.contains("GET /"));
+        assert!(!text.contains("secret"));
+    }
+}
@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::redaction_semantics::{self, Redaction};
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn render_message(locale: Locale, message: String, redactions: &[&Redaction]) -> Html {
+    html! {
+        <div class="py-1">
+            <p class="font-bold">{message}</p>
+            <ul class="list-disc pl-6">
+                {for redactions.iter().map(|redaction| html! {
+                    <li class={classes!(redaction.fully.then_some("text-red-500"))}>{redaction.describe(locale)}</li>
+                })}
+            </ul>
+        </div>
+    }
+}
+
+/// What the Prover redacted, by request and response
+#[function_component]
+pub fn RedactionSummary(Props { verified }: &Props) -> Html {
+    let redactions = use_memo(verified.clone(), |verified| {
+        redaction_semantics::summarize(verified)
+    });
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    if redactions.is_empty() {
+        return html! {};
+    }
+
+    // The redactions come ordered by message, group consecutive ones of the same message
+    let mut messages: Vec<(String, Vec<&Redaction>)> = Vec::new();
+    for redaction in redactions.iter() {
+        let message = redaction.message(locale);
+        match messages.last_mut() {
+            Some((last, group)) if *last == message => group.push(redaction),
+            _ => messages.push((message, vec![redaction])),
+        }
+    }
+
+    html! {
+        <details class={theme::PANEL}>
+            <summary class={theme::SUMMARY}><b>{locale.format("What was redacted? ({count})", &[("count", &redactions.len())])}</b></summary>
+            <div class={classes!("p-4", "rounded-md", "text-sm", "font-mono", theme::CODE)}>
+                {for messages.into_iter().map(|(message, group)| render_message(locale, message, &group))}
+            </div>
+        </details>
+    }
+}
@@ -0,0 +1,103 @@
+use std::ops::Range;
+
+use yew::prelude::*;
+
+use crate::clipboard::copy;
+use crate::components::copy_button::CopyButton;
+use crate::components::redacted_bytes_component::Direction;
+use crate::deep_link::ByteLink;
+use crate::i18n::Locale;
+
+/// A text selection in a transcript view
+#[derive(Clone, Debug, PartialEq)]
+pub struct Selected {
+    pub range: Range<usize>,
+    /// Viewport position of the bottom left corner of the selection
+    pub left: f64,
+    pub bottom: f64,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub direction: Direction,
+    pub bytes: Vec<u8>,
+    pub redacted_ranges: Vec<Range<usize>>,
+    pub selected: Selected,
+    pub on_close: Callback<()>,
+}
+
+/// Details of the selected bytes, floating below the selection
+#[function_component]
+pub fn SelectionTooltip(props: &Props) -> Html {
+    let Props {
+        direction,
+        bytes,
+        redacted_ranges,
+        selected,
+        on_close,
+    } = props;
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    let range = selected.range.clone();
+    let selected_bytes = &bytes[range.clone()];
+
+    let redacted: usize = redacted_ranges
+        .iter()
+        .map(|r| {
+            r.end
+                .min(range.end)
+                .saturating_sub(r.start.max(range.start))
+        })
+        .sum();
+    let utf8 = match std::str::from_utf8(selected_bytes) {
+        Ok(_) => t("valid UTF-8").to_string(),
+        Err(e) => locale.format(
+            "not valid UTF-8 from byte {offset}",
+            &[("offset", &(range.start + e.valid_up_to()))],
+        ),
+    };
+
+    let copy_link = {
+        let link = ByteLink {
+            direction: *direction,
+            range: range.clone(),
+        };
+        Callback::from(move |_: MouseEvent| {
+            if let Some(url) = link.url() {
+                copy(&url);
+            }
+            let _ = gloo::utils::window().location().set_hash(&link.to_string());
+        })
+    };
+    let close = on_close.reform(|_: MouseEvent| ());
+
+    let button =
+        "px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border";
+    let style = format!(
+        "left: {}px; top: {}px;",
+        selected.left,
+        selected.bottom + 4.0
+    );
+
+    html! {
+        <div class="fixed z-10 p-2 rounded shadow-lg text-sm bg-white dark:bg-zinc-800 border border-gray-400" role="dialog" aria-label={t("Selected bytes")} {style}>
+            <div class="flex gap-4 items-center">
+                <span class="font-mono">{locale.format("bytes {start}..{end}", &[("start", &range.start), ("end", &range.end)])}</span>
+                <span>{format!("{} B", range.len())}</span>
+                <div class="flex-1"></div>
+                <button class="text-gray-400" onclick={close} title={t("Close")} aria-label={t("Close")}>{"×"}</button>
+            </div>
+            <p class="text-gray-400">
+                {utf8}
+                if redacted > 0 {
+                    <span class="text-red-500">{locale.format(", {count} B redacted", &[("count", &redacted)])}</span>
+                }
+            </p>
+            <div class="flex gap-2 pt-1">
+                <CopyButton value={String::from_utf8_lossy(selected_bytes).to_string()} what={t("the selected text")} label={t("Copy raw")} />
+                <CopyButton value={hex::encode(selected_bytes)} what={t("the selected bytes as hex")} label={t("Copy hex")} />
+                <button class={button} onclick={copy_link}>{t("🔗 Copy link")}</button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,96 @@
+use gloo::timers::callback::Timeout;
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::self_test::{self, CaseResult, Expected};
+use crate::theme;
+
+fn describe(locale: Locale, outcome: Expected) -> String {
+    match outcome {
+        Expected::Valid => locale.t("✅ Valid").to_string(),
+        Expected::FailsAt(step) => locale.format(
+            "❌ Fails: {step}",
+            &[("step", &locale.t(step.description()))],
+        ),
+    }
+}
+
+fn render_result(locale: Locale, result: &CaseResult) -> Html {
+    let (icon, color) = if result.passed() {
+        ("✓", "text-green-500")
+    } else {
+        ("✗", "text-red-500")
+    };
+
+    html! {
+        <tr class="border-t border-gray-600 align-top">
+            <td class={classes!("px-2", "py-1", "font-bold", color)}>{icon}</td>
+            <td class="px-2 py-1">{locale.t(result.case.name)}</td>
+            <td class="px-2 py-1">{describe(locale, result.case.expected)}</td>
+            <td class={classes!("px-2", "py-1", (!result.passed()).then_some("text-red-500"))}>{describe(locale, result.actual)}</td>
+        </tr>
+    }
+}
+
+/// Verifies the bundled known-good and known-bad proofs, and whether each comes out as it should
+#[function_component]
+pub fn SelfTestPage() -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    let results = use_state(|| None::<Vec<CaseResult>>);
+    let running = use_state(|| false);
+
+    let onclick = {
+        let results = results.clone();
+        let running = running.clone();
+        Callback::from(move |_: MouseEvent| {
+            running.set(true);
+            let results = results.clone();
+            let running = running.clone();
+            // Verifying takes a moment, let the page show that it is running first
+            Timeout::new(0, move || {
+                results.set(Some(
+                    self_test::cases().into_iter().map(self_test::run).collect(),
+                ));
+                running.set(false);
+            })
+            .forget();
+        })
+    };
+
+    html! {
+        <div class={classes!("p-4", "my-4", "flex", "flex-col", "gap-4", theme::CARD)}>
+            <p class="text-center"><b>{t("Self-test")}</b></p>
+            <p>{t("Verifies proofs bundled with this page, one valid and others tampered with, to confirm that this deployment accepts valid proofs and rejects tampered ones. The verification options you set are not used.")}</p>
+            <div class="flex justify-center">
+                <button class="px-4 py-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" disabled={*running} {onclick}>
+                    {if *running { t("Running…") } else { t("Run self-test") }}
+                </button>
+            </div>
+            if let Some(results) = &*results {
+                <div role="status">
+                    if results.iter().all(CaseResult::passed) {
+                        <p class="text-center text-green-500">{locale.format("All {count} proofs are verified as expected", &[("count", &results.len())])}</p>
+                    } else {
+                        <p class="text-center text-red-500">{locale.format("{failed} of {count} proofs are NOT verified as expected, do not trust this deployment", &[("failed", &results.iter().filter(|r| !r.passed()).count()), ("count", &results.len())])}</p>
+                    }
+                </div>
+                <div class="overflow-x-auto">
+                    <table class="w-full table-auto text-left text-sm">
+                        <thead>
+                            <tr>
+                                <th class="px-2 py-1"></th>
+                                <th class="px-2 py-1">{t("Proof")}</th>
+                                <th class="px-2 py-1">{t("Expected")}</th>
+                                <th class="px-2 py-1">{t("Actual")}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for results.iter().map(|result| render_result(locale, result))}
+                        </tbody>
+                    </table>
+                </div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,105 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::notary_key::NotaryKey;
+use crate::notary_registry::Registry;
+use crate::notary_signature::NotarySignature;
+use crate::parsed_proof::ParsedProof;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub proof: Rc<ParsedProof>,
+    /// The key currently in use
+    pub pem: NotaryKey,
+    /// Expanded initially, e.g. when verification failed
+    #[prop_or_default]
+    pub open: bool,
+}
+
+// The key in use and the keys of the known Notaries
+fn configured_keys(
+    locale: Locale,
+    registry: &Registry,
+    pem: NotaryKey,
+) -> Vec<(String, NotaryKey)> {
+    let known = registry
+        .notaries
+        .iter()
+        .map(|entry| (entry.name.clone(), entry.key()));
+    std::iter::once((locale.t("in use").to_string(), pem))
+        .chain(known)
+        .collect()
+}
+
+fn render_key(key: &NotaryKey) -> Html {
+    html! {
+        <span class="break-all" title={key.to_public_key_pem()}>
+            {format!("{} {}", key.scheme(), hex::encode(key.to_bytes()))}
+        </span>
+    }
+}
+
+fn render_signature(
+    locale: Locale,
+    registry: &Registry,
+    signature: &NotarySignature,
+    pem: NotaryKey,
+) -> Html {
+    let t = |message| locale.t(message);
+    let keys = configured_keys(locale, registry, pem);
+    let recovered = signature.recover_keys();
+
+    html! {
+        <>
+            <p><b>{t("Algorithm: ")}</b>{signature.algorithm()}</p>
+            <p><b>{t("Signature: ")}</b><span class="break-all">{hex::encode(&signature.bytes)}</span></p>
+            <p class="pt-2"><b>{t("Configured keys:")}</b></p>
+            {for keys.iter().map(|(name, key)| {
+                let verified = signature.verifies_with(key);
+                html! {
+                    <p class={if verified { "" } else { "text-red-500" }}>
+                        {if verified { "✅ " } else { "❌ " }}<b>{format!("{}: ", name)}</b>{render_key(key)}
+                    </p>
+                }
+            })}
+            if !recovered.is_empty() {
+                <p class="pt-2"><b>{t("Keys recovered from the signature:")}</b></p>
+                <p class="text-gray-400">{t("One of these made the signature, if none is configured the proof was signed by another Notary")}</p>
+                {for recovered.iter().map(|key| {
+                    let known = keys.iter().find(|(_, configured)| configured == key);
+                    html! {
+                        <p>
+                            {render_key(key)}
+                            if let Some((name, _)) = known {
+                                {format!(" ({})", name)}
+                            }
+                        </p>
+                    }
+                })}
+            }
+        </>
+    }
+}
+
+/// The Notary signature and which of the configured keys it verifies with
+#[function_component]
+pub fn SignatureDetails(Props { proof, pem, open }: &Props) -> Html {
+    let signature = use_memo(proof.clone(), |proof| NotarySignature::parse(&proof.value));
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let registry = use_context::<Registry>().unwrap_or_else(Registry::bundled);
+
+    html! {
+        <details class={theme::PANEL} open={*open}>
+            <summary class={theme::SUMMARY}><b>{locale.t("Notary signature")}</b></summary>
+            <div class={classes!("p-4", "rounded-md", "overflow-x-auto", "text-sm", "font-mono", theme::CODE)}>
+                {match signature.as_ref() {
+                    Ok(signature) => render_signature(locale, &registry, signature, *pem),
+                    Err(err) => html! { <p class="text-red-500">{err}</p> },
+                }}
+            </div>
+        </details>
+    }
+}
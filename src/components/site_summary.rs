@@ -0,0 +1,31 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::renderers;
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+/// The summary of a site-specific renderer, for transcripts of the sites they know
+#[function_component]
+pub fn SiteSummary(Props { verified }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let Some((site, summary)) = renderers::render(locale, verified) else {
+        return html! {};
+    };
+
+    html! {
+        <div class={theme::PANEL}>
+            <b>{locale.format("{site} summary:", &[("site", &site)])}</b>
+            <div class={classes!("p-4", "rounded-md", theme::CODE)}>
+                {summary}
+            </div>
+        </div>
+    }
+}
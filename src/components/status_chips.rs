@@ -0,0 +1,61 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::http_status::{self, Status, StatusClass};
+use crate::i18n::Locale;
+use crate::theme;
+use crate::verify::VerifiedOutput;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn chip_class(class: StatusClass) -> &'static str {
+    match class {
+        StatusClass::Success => "bg-green-600 text-white",
+        StatusClass::Redirect | StatusClass::Informational => "bg-sky-600 text-white",
+        StatusClass::ClientError => "bg-orange-500 text-white",
+        StatusClass::ServerError => "bg-red-600 text-white",
+        StatusClass::Unknown => "bg-gray-400 text-black",
+    }
+}
+
+fn render_chip(status: &Status) -> Html {
+    html! {
+        <span class={classes!("px-3", "py-1", "rounded-full", "font-mono", chip_class(status.class))} title={status.reason.clone()}>
+            <span class="text-2xl font-bold">{status.code.clone()}</span>
+            if !status.reason.is_empty() {
+                <span class="ml-2">{status.reason.clone()}</span>
+            }
+        </span>
+    }
+}
+
+/// The HTTP status of each response as a colored chip, with a warning for error statuses
+#[function_component]
+pub fn StatusChips(Props { verified }: &Props) -> Html {
+    let statuses = use_memo(verified.clone(), |verified| http_status::statuses(verified));
+    let locale = use_context::<Locale>().unwrap_or_default();
+    if statuses.is_empty() {
+        return html! {};
+    }
+    let any_error = statuses.iter().any(|status| status.class.is_error());
+
+    html! {
+        <>
+            <b>{locale.t("HTTP status:")}</b>
+            <div class={classes!("p-4", "rounded-md", theme::CODE)}>
+                <div class="flex flex-wrap gap-2">
+                    {for statuses.iter().map(render_chip)}
+                </div>
+                if any_error {
+                    <div role="alert" class="mt-2 border border-orange-400 rounded bg-orange-100 px-4 py-3 text-orange-800">
+                        {locale.t("The server answered with an error status. The proof shows that this response was received, not that the request succeeded.")}
+                    </div>
+                }
+            </div>
+        </>
+    }
+}
@@ -0,0 +1,139 @@
+use web_sys::{HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::app::FileDetails;
+use crate::formats::{self, ProofFormat};
+use crate::i18n::Locale;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub files: Vec<FileDetails>,
+    /// Called with the name and the data of a proof combined of a substrings proof and a session
+    pub on_combine: Callback<(String, Vec<u8>)>,
+}
+
+#[derive(Properties, PartialEq)]
+struct PairingProps {
+    file: FileDetails,
+    /// The loaded proofs with a session, to take the header from
+    sessions: Vec<FileDetails>,
+    on_combine: Callback<(String, Vec<u8>)>,
+}
+
+/// Pick the session of a substrings proof: from another loaded proof, or pasted
+#[function_component]
+fn Pairing(
+    PairingProps {
+        file,
+        sessions,
+        on_combine,
+    }: &PairingProps,
+) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    // Index into `sessions`, `None` to paste one
+    let source = use_state(|| (!sessions.is_empty()).then_some(0));
+    let pasted = use_state(String::new);
+    let error = use_state(|| None::<String>);
+
+    let on_source = {
+        let source = source.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            source.set((select.selected_index() as usize).checked_sub(1));
+        })
+    };
+    let on_paste = {
+        let pasted = pasted.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            pasted.set(input.value());
+        })
+    };
+    let on_verify = {
+        let (file, sessions, on_combine) = (file.clone(), sessions.clone(), on_combine.clone());
+        let (source, pasted, error) = (source.clone(), pasted.clone(), error.clone());
+        Callback::from(move |_: MouseEvent| {
+            let combined = match source.and_then(|index| sessions.get(index)) {
+                Some(session) => formats::combine(&session.proof.value, &file.proof.value)
+                    .map(|proof| (session.name.clone(), proof)),
+                None => serde_json::from_str(&pasted)
+                    .map_err(|err| err.to_string())
+                    .and_then(|pasted| {
+                        formats::combine(&formats::wrap_session_value(pasted), &file.proof.value)
+                    })
+                    .map(|proof| (locale.t("pasted session").to_string(), proof)),
+            };
+            match combined {
+                Ok((session_name, proof)) => {
+                    error.set(None);
+                    let name = format!("{} + {}", file.name, session_name);
+                    on_combine.emit((name, proof.to_string().into_bytes()));
+                }
+                Err(err) => error.set(Some(
+                    locale.format("Not a session proof: {error}", &[("error", &err)]),
+                )),
+            }
+        })
+    };
+
+    html! {
+        <div class="py-2 flex flex-col gap-2">
+            <p>{locale.format("\"{file}\" is a substrings proof, it can only be verified with the session header it was made for.", &[("file", &file.name)])}</p>
+            <label class="flex flex-wrap items-center gap-2">
+                {t("Session:")}
+                <select class={classes!("p-1", theme::INPUT)} onchange={on_source}>
+                    <option selected={source.is_none()}>{t("Paste a session proof")}</option>
+                    {for sessions.iter().enumerate().map(|(index, session)| html! {
+                        <option selected={*source == Some(index)}>{session.name.clone()}</option>
+                    })}
+                </select>
+            </label>
+            if source.is_none() {
+                <textarea class={classes!("block", "p-2", "w-full", "font-mono", "text-sm", theme::INPUT)}
+                    rows="4"
+                    aria-label={t("Session proof JSON")}
+                    placeholder={r#"{"header": {...}, "signature": {...}, "server_name": {...}, ...}"#}
+                    value={(*pasted).clone()}
+                    oninput={on_paste}>
+                </textarea>
+            }
+            if let Some(error) = error.as_ref() {
+                <p class="text-red-500" role="alert">{error}</p>
+            }
+            <div>
+                <button class="px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" onclick={on_verify}>{t("Verify with this session")}</button>
+            </div>
+        </div>
+    }
+}
+
+/// Substrings proofs distributed without their session proof, verified by pairing them with
+/// the session of another loaded proof or a pasted one. The pair is loaded as a new proof.
+#[function_component]
+pub fn SubstringsPairing(Props { files, on_combine }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    let substrings: Vec<&FileDetails> = files
+        .iter()
+        .filter(|file| formats::is_substrings_proof(&file.proof.value))
+        .collect();
+    if substrings.is_empty() {
+        return html! {};
+    }
+    let sessions: Vec<FileDetails> = files
+        .iter()
+        .filter(|file| formats::detect(&file.proof.value) == Some(ProofFormat::Alpha6))
+        .cloned()
+        .collect();
+
+    html! {
+        <div class={classes!("p-4", "my-4", theme::CARD)}>
+            <b>{locale.t("Substrings proofs without a session")}</b>
+            {for substrings.into_iter().map(|file| html! {
+                <Pairing key={file.digest.clone()} file={file.clone()} sessions={sessions.clone()} on_combine={on_combine.clone()} />
+            })}
+        </div>
+    }
+}
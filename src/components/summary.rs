@@ -0,0 +1,479 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+use yew::prelude::*;
+
+use crate::app::FileDetails;
+use crate::assertions::Assertions;
+use crate::card_order::CardOrder;
+use crate::components::test_key_badge::TestKeyBadge;
+use crate::components::view_file::{use_verification, ViewFile};
+use crate::config;
+use crate::history::HistoryEntry;
+use crate::i18n::Locale;
+use crate::notary_key::NotaryKey;
+use crate::proof_list::{Group, Outcome};
+use crate::report;
+use crate::theme;
+use crate::verify::VerifyError;
+
+/// The drag data of a card, its file digest
+const CARD_TYPE: &str = "application/x-proof-viz-card";
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub files: Vec<FileDetails>,
+    pub pem: NotaryKey,
+    /// Indices of the files selected for comparison
+    pub compare: Vec<usize>,
+    pub on_compare: Callback<usize>,
+    /// The history entry and the report of each verification
+    pub on_verified: Callback<(HistoryEntry, Value)>,
+    /// Called with the file digest
+    pub on_outcome: Callback<(String, Outcome)>,
+    /// Indices into `files` of the listed proofs, in display order
+    pub shown: Vec<usize>,
+    /// The listed proofs by server, if grouped
+    pub groups: Option<Vec<Group>>,
+    /// For pinning
+    pub order: CardOrder,
+    /// Cards can be dragged, only when listed in the custom order
+    pub reorderable: bool,
+    /// A card, by file digest, was dropped on another one
+    pub on_move: Callback<(String, String)>,
+    pub on_pin: Callback<String>,
+    /// Remove a proof, by file digest, whose verification is queued
+    pub on_cancel: Callback<String>,
+}
+
+#[derive(Properties, PartialEq)]
+struct RowProps {
+    file: FileDetails,
+    pem: NotaryKey,
+    selected: bool,
+    on_compare: Callback<()>,
+    on_verified: Callback<(HistoryEntry, Value)>,
+    on_outcome: Callback<(String, Outcome)>,
+    /// In a collapsed group, or filtered out
+    hidden: bool,
+    pinned: bool,
+    on_pin: Callback<()>,
+    reorderable: bool,
+    /// The digests of the cards listed before and after this one, to move it with the keyboard
+    previous: Option<String>,
+    next: Option<String>,
+    on_move: Callback<(String, String)>,
+    on_cancel: Callback<()>,
+}
+
+#[function_component]
+fn SummaryRow(
+    RowProps {
+        file,
+        pem,
+        selected,
+        on_compare,
+        on_verified,
+        on_outcome,
+        hidden,
+        pinned,
+        on_pin,
+        reorderable,
+        previous,
+        next,
+        on_move,
+        on_cancel,
+    }: &RowProps,
+) -> Html {
+    let expanded = use_state(|| false);
+    // Another card is dragged over this one
+    let drag_over = use_state(|| false);
+    let verification = use_verification(&file.proof, *pem);
+    let is_json = file.proof.is_json();
+    let assertions = use_context::<Assertions>().unwrap_or_default();
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    {
+        let on_verified = on_verified.clone();
+        let on_outcome = on_outcome.clone();
+        let file = file.clone();
+        let pem = *pem;
+        use_effect_with(verification.clone(), move |verification| {
+            if let (true, Some(verification)) = (is_json, verification) {
+                let report = report::to_json(&file.proof, pem, &verification.result);
+                on_verified.emit((
+                    HistoryEntry::new(&file.name, &file.digest, &verification.result),
+                    report,
+                ));
+                on_outcome.emit((file.digest.clone(), Outcome::new(verification)));
+            }
+        });
+    }
+
+    let onclick = {
+        let expanded = expanded.clone();
+        Callback::from(move |_| expanded.set(!*expanded))
+    };
+    let onkeydown = {
+        let expanded = expanded.clone();
+        let digest = file.digest.clone();
+        let (previous, next, on_move) = (previous.clone(), next.clone(), on_move.clone());
+        Callback::from(move |e: KeyboardEvent| {
+            let neighbour = match e.key().as_str() {
+                "Enter" | " " => {
+                    e.prevent_default();
+                    expanded.set(!*expanded);
+                    return;
+                }
+                "ArrowUp" if e.alt_key() => previous.clone(),
+                "ArrowDown" if e.alt_key() => next.clone(),
+                _ => return,
+            };
+            if let Some(neighbour) = neighbour {
+                e.prevent_default();
+                on_move.emit((digest.clone(), neighbour));
+            }
+        })
+    };
+
+    let ondragstart = {
+        let digest = file.digest.clone();
+        Callback::from(move |e: DragEvent| {
+            if let Some(transfer) = e.data_transfer() {
+                let _ = transfer.set_data(CARD_TYPE, &digest);
+                transfer.set_effect_allowed("move");
+            }
+        })
+    };
+    let ondragover = {
+        let drag_over = drag_over.clone();
+        Callback::from(move |e: DragEvent| {
+            // Only cards can be dropped, the data itself is hidden until the drop
+            let Some(transfer) = e.data_transfer() else {
+                return;
+            };
+            if transfer.types().includes(&CARD_TYPE.into(), 0) {
+                e.prevent_default();
+                transfer.set_drop_effect("move");
+                drag_over.set(true);
+            }
+        })
+    };
+    let ondragleave = {
+        let drag_over = drag_over.clone();
+        Callback::from(move |_: DragEvent| drag_over.set(false))
+    };
+    let ondrop = {
+        let drag_over = drag_over.clone();
+        let digest = file.digest.clone();
+        let on_move = on_move.clone();
+        Callback::from(move |e: DragEvent| {
+            e.prevent_default();
+            drag_over.set(false);
+            let dragged = e
+                .data_transfer()
+                .and_then(|transfer| transfer.get_data(CARD_TYPE).ok())
+                .unwrap_or_default();
+            if !dragged.is_empty() && dragged != digest {
+                on_move.emit((dragged, digest.clone()));
+            }
+        })
+    };
+    let on_pin = {
+        let on_pin = on_pin.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            on_pin.emit(());
+        })
+    };
+
+    let on_select = {
+        let on_compare = on_compare.clone();
+        Callback::from(move |e: MouseEvent| {
+            // selecting a file for comparison should not expand the row
+            e.stop_propagation();
+            on_compare.emit(());
+        })
+    };
+
+    let (status, error, server_name, time) = if !is_json {
+        (
+            locale.t("❔ Not a proof"),
+            file.proof.detected.describe(locale),
+            "-".to_string(),
+            "-".to_string(),
+        )
+    } else if let Some(verification) = &verification {
+        match &verification.result {
+            Ok(verified) if !assertions.all_passed(verified) => (
+                locale.t("❌ Assertions failed"),
+                String::new(),
+                verified.server_name.clone(),
+                verified.time.to_string(),
+            ),
+            Ok(verified) => (
+                if verified.cert_chain_verified {
+                    locale.t("✅ Verified")
+                } else {
+                    locale.t("⚠️ Verified without certificate check")
+                },
+                String::new(),
+                verified.server_name.clone(),
+                verified.time.to_string(),
+            ),
+            Err(error @ VerifyError::Parse(_)) => (
+                locale.t("❌ Parsing failed"),
+                error.to_string(),
+                "-".to_string(),
+                "-".to_string(),
+            ),
+            Err(error) => (
+                locale.t("❌ Invalid proof"),
+                error.to_string(),
+                "-".to_string(),
+                "-".to_string(),
+            ),
+        }
+    } else {
+        (
+            locale.t("⏳ Verifying"),
+            String::new(),
+            "-".to_string(),
+            "-".to_string(),
+        )
+    };
+    let on_cancel_click = {
+        let on_cancel = on_cancel.clone();
+        Callback::from(move |e: MouseEvent| {
+            // cancelling should not expand the row
+            e.stop_propagation();
+            on_cancel.emit(());
+        })
+    };
+
+    let pin_label = if *pinned {
+        locale.t("Unpin")
+    } else {
+        locale.t("Pin to the top")
+    };
+
+    html! {
+        <tbody class={classes!(drag_over.then_some(["border-t-4", "border-t-amber-500"]))} hidden={*hidden} {ondragover} {ondragleave} {ondrop}>
+            <tr class="cursor-pointer hover:bg-zinc-300 dark:hover:bg-black border-t border-gray-600" tabindex="0" draggable={reorderable.to_string()} title={reorderable.then(|| locale.t("Drag to reorder, or move with Alt+↑ and Alt+↓"))} aria-expanded={expanded.to_string()} {onclick} {onkeydown} {ondragstart}>
+                <td class="px-2 py-1" title={locale.t("Select to compare")}>
+                    <input type="checkbox" checked={*selected} onclick={on_select} aria-label={locale.format("Compare {file}", &[("file", &file.name)])} />
+                </td>
+                <td class="px-2 py-1">
+                    <button class={classes!("px-1", "rounded", (!*pinned).then_some("opacity-30"))} title={pin_label} aria-label={pin_label} aria-pressed={pinned.to_string()} onclick={on_pin}>{"📌"}</button>
+                </td>
+                <td class="px-2 py-1">
+                    {if *expanded {"▾ "} else {"▸ "}}{file.name.clone()}
+                    if file.uploads > 1 {
+                        <span class="text-gray-500">{locale.format(" (uploaded {count}×)", &[("count", &file.uploads)])}</span>
+                    }
+                </td>
+                <td class="px-2 py-1" title={error}>
+                    {status}
+                    if verification.as_ref().is_some_and(|verification| verification.result.is_ok()) && config::is_test_key(*pem) {
+                        {" "}<TestKeyBadge />
+                    }
+                    if is_json && verification.is_none() {
+                        {" "}<button class="px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" onclick={on_cancel_click}>{locale.t("Cancel")}</button>
+                    }
+                </td>
+                <td class="px-2 py-1 font-mono">{server_name}</td>
+                <td class="px-2 py-1 font-mono">{time}</td>
+            </tr>
+            // While queued, the row shows the progress and offers to cancel
+            if let (true, Some(verification)) = (*expanded, &verification) {
+                <tr>
+                    <td colspan="6" class="py-2">
+                        <ViewFile name={file.name.clone()} file_type={file.file_type.clone()} proof={file.proof.clone()} uploads={file.uploads} pem={*pem} verification={verification.clone()} />
+                    </td>
+                </tr>
+            }
+        </tbody>
+    }
+}
+
+/// Overview of all loaded proofs, each row expands into the full proof card
+#[function_component]
+pub fn Summary(props: &Props) -> Html {
+    let Props {
+        files,
+        shown,
+        groups,
+        ..
+    } = props;
+    // Rows of collapsed groups stay mounted, so their verification is kept
+    let collapsed = use_state(BTreeSet::<Option<String>>::new);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    let rows = match groups {
+        Some(groups) => groups
+            .iter()
+            .flat_map(|group| {
+                let is_collapsed = collapsed.contains(&group.server_name);
+                let on_toggle = {
+                    let collapsed = collapsed.clone();
+                    let server_name = group.server_name.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        let mut groups = (*collapsed).clone();
+                        if !groups.remove(&server_name) {
+                            groups.insert(server_name.clone());
+                        }
+                        collapsed.set(groups);
+                    })
+                };
+                let header = html! {
+                    <tbody key={format!("group {}", group.server_name.as_deref().unwrap_or_default())}>
+                        <GroupHeader group={group.clone()} collapsed={is_collapsed} {on_toggle} />
+                    </tbody>
+                };
+                std::iter::once(header).chain(
+                    (0..group.indices.len()).map(move |position| {
+                        summary_row(props, &group.indices, position, is_collapsed)
+                    }),
+                )
+            })
+            .collect::<Vec<Html>>(),
+        None => (0..shown.len())
+            .map(|position| summary_row(props, shown, position, false))
+            .collect(),
+    };
+    // Filtered out rows stay mounted as well, in the same keyed list so they move rather than
+    // mount again, which would verify and report their proofs again
+    let listed: BTreeSet<usize> = shown.iter().copied().collect();
+    let filtered: Vec<usize> = (0..files.len())
+        .filter(|index| !listed.contains(index))
+        .collect();
+    let rows = rows
+        .into_iter()
+        .chain((0..filtered.len()).map(|position| summary_row(props, &filtered, position, true)))
+        .collect::<Html>();
+
+    html! {
+        <div class={classes!("p-4", "my-4", theme::CARD)}>
+            <p class="text-center"><b>{locale.format("{count} proofs loaded", &[("count", &files.len())])}</b>{t(" (select two proofs to compare them)")}</p>
+            <div class="overflow-x-auto">
+                <table class="w-full table-auto text-left text-sm">
+                    <thead>
+                        <tr>
+                            <th class="px-2 py-1">{t("Compare")}</th>
+                            <th class="px-2 py-1">{t("Pin")}</th>
+                            <th class="px-2 py-1">{t("File")}</th>
+                            <th class="px-2 py-1">{t("Status")}</th>
+                            <th class="px-2 py-1">{t("Server domain")}</th>
+                            <th class="px-2 py-1">{t("Notarization time")}</th>
+                        </tr>
+                    </thead>
+                    if shown.is_empty() {
+                        <tbody>
+                            <tr class="border-t border-gray-600">
+                                <td colspan="6" class="px-2 py-4 text-center text-gray-500">{t("No proofs match the filters")}</td>
+                            </tr>
+                        </tbody>
+                    }
+                    {rows}
+                </table>
+            </div>
+        </div>
+    }
+}
+
+/// The row of the proof at `position` of `list`, which can be moved past its neighbours in
+/// `list`
+fn summary_row(props: &Props, list: &[usize], position: usize, hidden: bool) -> Html {
+    let Props {
+        files,
+        pem,
+        compare,
+        on_compare,
+        on_verified,
+        on_outcome,
+        order,
+        reorderable,
+        on_move,
+        on_pin,
+        on_cancel,
+        ..
+    } = props;
+    let digest = |position: Option<usize>| {
+        position
+            .filter(|_| *reorderable)
+            .and_then(|position| list.get(position))
+            .map(|index| files[*index].digest.clone())
+    };
+    let index = list[position];
+    let file = &files[index];
+    let digest_to_pin = file.digest.clone();
+    let digest_to_cancel = file.digest.clone();
+    html! {
+        <SummaryRow
+            key={file.digest.clone()}
+            file={file.clone()}
+            pem={*pem}
+            selected={compare.contains(&index)}
+            on_compare={on_compare.reform(move |_| index)}
+            on_verified={on_verified.clone()}
+            on_outcome={on_outcome.clone()}
+            {hidden}
+            pinned={order.is_pinned(&file.digest)}
+            on_pin={on_pin.reform(move |_| digest_to_pin.clone())}
+            reorderable={*reorderable}
+            previous={digest(position.checked_sub(1))}
+            next={digest(Some(position + 1))}
+            on_move={on_move.clone()}
+            on_cancel={on_cancel.reform(move |_| digest_to_cancel.clone())}
+        />
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct GroupHeaderProps {
+    group: Group,
+    collapsed: bool,
+    on_toggle: Callback<MouseEvent>,
+}
+
+/// The server of a group, with how many of its proofs verified
+#[function_component]
+fn GroupHeader(
+    GroupHeaderProps {
+        group,
+        collapsed,
+        on_toggle,
+    }: &GroupHeaderProps,
+) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let status = if group.failed > 0 {
+        "❌"
+    } else if group.pending() > 0 {
+        "⏳"
+    } else {
+        "✅"
+    };
+    let server_name = match &group.server_name {
+        Some(server_name) => server_name.clone(),
+        None => locale.t("No server").to_string(),
+    };
+
+    html! {
+        <tr class="border-t-2 border-gray-600 bg-zinc-200 dark:bg-zinc-900">
+            <td colspan="6" class="px-2 py-1">
+                <button class="font-bold" aria-expanded={(!*collapsed).to_string()} onclick={on_toggle.clone()}>
+                    {if *collapsed {"▸ "} else {"▾ "}}<span class="font-mono">{server_name}</span>
+                </button>
+                {" "}{status}{" "}
+                {locale.format(
+                    "{count} proofs: {verified} verified, {failed} failed",
+                    &[("count", &group.indices.len()), ("verified", &group.verified), ("failed", &group.failed)],
+                )}
+                if group.pending() > 0 {
+                    {locale.format(", {pending} pending", &[("pending", &group.pending())])}
+                }
+            </td>
+        </tr>
+    }
+}
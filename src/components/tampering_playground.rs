@@ -0,0 +1,148 @@
+use std::rc::Rc;
+
+use serde_json::Value;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::components::verification_steps::VerificationStepsPanel;
+use crate::i18n::Locale;
+use crate::notary_key::NotaryKey;
+use crate::parsed_proof::ParsedProof;
+use crate::tampering::{self, Change, Mutation, Target};
+use crate::theme;
+use crate::verify::{verify_value, Verification, VerifyOptions};
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub proof: Rc<ParsedProof>,
+    pub pem: NotaryKey,
+}
+
+/// The proof with all `mutations` applied, what each changed and how it verifies
+fn tamper(
+    proof: &Value,
+    mutations: &[Mutation],
+    pem: NotaryKey,
+    options: &VerifyOptions,
+) -> Result<(Vec<Change>, Verification), String> {
+    let mut proof = proof.clone();
+    let changes = mutations
+        .iter()
+        .map(|mutation| tampering::apply(&mut proof, *mutation))
+        .collect::<Result<_, _>>()?;
+    let verification = verify_value(&proof, pem, options);
+    Ok((changes, verification))
+}
+
+fn render_change(locale: Locale, mutation: &Mutation, change: &Change) -> Html {
+    html! {
+        <li class="font-mono">
+            {locale.format("{target} at {position}: {before} → {after}", &[
+                ("target", &locale.t(mutation.target.name())),
+                ("position", &mutation.position),
+                ("before", &change.before),
+                ("after", &change.after),
+            ])}
+        </li>
+    }
+}
+
+/// Flip bits of the loaded proof and watch which check of the verification catches it
+#[function_component]
+pub fn TamperingPlayground(Props { proof, pem }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    let options = use_context::<VerifyOptions>().unwrap_or_default();
+    let target = use_state(|| Target::Transcript);
+    let position = use_state(|| 0usize);
+    let mutations = use_state(Vec::<Mutation>::new);
+    let tampered = use_memo(
+        (proof.clone(), (*mutations).clone(), *pem, options),
+        |(proof, mutations, pem, options)| {
+            (!mutations.is_empty()).then(|| tamper(&proof.value, mutations, *pem, options))
+        },
+    );
+
+    let positions = tampering::positions(&proof.value, *target);
+    let on_target = {
+        let target = target.clone();
+        let position = position.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(selected) = Target::ALL.get(select.selected_index().max(0) as usize) {
+                target.set(*selected);
+                position.set(0);
+            }
+        })
+    };
+    let on_position = {
+        let position = position.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse() {
+                position.set(value);
+            }
+        })
+    };
+    let on_flip = {
+        let mutations = mutations.clone();
+        let mutation = Mutation {
+            target: *target,
+            position: *position,
+        };
+        Callback::from(move |_: MouseEvent| {
+            let mut changed = (*mutations).clone();
+            changed.push(mutation);
+            mutations.set(changed);
+        })
+    };
+    let on_reset = {
+        let mutations = mutations.clone();
+        Callback::from(move |_: MouseEvent| mutations.set(Vec::new()))
+    };
+    let button = "px-2 hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border disabled:opacity-50";
+
+    html! {
+        <details class={theme::PANEL}>
+            <summary class={theme::SUMMARY}><b>{t("Tampering playground")}</b></summary>
+            <div class={classes!("p-4", "rounded-md", "flex", "flex-col", "gap-2", "text-sm", theme::CODE)}>
+                <p>{t("Flip a bit of this proof and see which check catches it. Only a copy in your browser is changed.")}</p>
+                <div class="flex flex-wrap gap-2 items-center">
+                    <select class="px-2 rounded border border-black dark:border-gray-400 bg-transparent" aria-label={t("What to change")} onchange={on_target}>
+                        {for Target::ALL.iter().map(|option| html! {
+                            <option selected={*option == *target}>{t(option.name())}</option>
+                        })}
+                    </select>
+                    if let Some(positions) = positions {
+                        <label class="flex gap-2 items-center">
+                            {t("Position")}
+                            <input type="number" class="w-24 px-2 rounded border border-black dark:border-gray-400 bg-transparent"
+                                min="0" max={(positions - 1).to_string()} value={position.to_string()} oninput={on_position} />
+                        </label>
+                        <button class={button} disabled={*position >= positions} onclick={on_flip}>{t("Flip")}</button>
+                    } else {
+                        <span class="text-gray-400">{t("This proof has no such value")}</span>
+                    }
+                    <button class={button} disabled={mutations.is_empty()} onclick={on_reset}>{t("Reset")}</button>
+                </div>
+                {match tampered.as_ref() {
+                    None => html! {},
+                    Some(Err(err)) => html! { <p class="text-red-500">{err.clone()}</p> },
+                    Some(Ok((changes, verification))) => html! {
+                        <>
+                            <ul class="list-disc pl-6">
+                                {for mutations.iter().zip(changes).map(|(mutation, change)| render_change(locale, mutation, change))}
+                            </ul>
+                            if verification.result.is_ok() {
+                                <p class="text-yellow-600 dark:text-yellow-400">{t("The tampered proof still verifies")}</p>
+                            } else if let Some(step) = verification.steps.first_failure() {
+                                <p class="text-green-500">{locale.format("Caught: {check}", &[("check", &t(step.description()))])}</p>
+                            }
+                            <VerificationStepsPanel steps={verification.steps.clone()} open={true} />
+                        </>
+                    },
+                }}
+            </div>
+        </details>
+    }
+}
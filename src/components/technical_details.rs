@@ -0,0 +1,175 @@
+use std::rc::Rc;
+
+use web_time::Duration;
+use yew::prelude::*;
+
+use crate::body_checks::{self, Finding};
+use crate::components::copy_button::CopyButton;
+use crate::components::redacted_bytes_component::Direction;
+use crate::exchanges;
+use crate::i18n::Locale;
+use crate::parsed_proof::ParsedProof;
+use crate::session_header::session_header;
+use crate::theme;
+use crate::verify::{Timings, VerifiedOutput};
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub proof: Rc<ParsedProof>,
+    pub verified: Rc<VerifiedOutput>,
+}
+
+fn render_row(locale: Locale, label: &'static str, value: String) -> Html {
+    html! {
+        <tr>
+            <th class="pr-4 align-top">{locale.t(label)}</th>
+            <td class="break-all">{value.clone()}</td>
+            <td class="pl-2 align-top">
+                <CopyButton {value} what={locale.t(label)} />
+            </td>
+        </tr>
+    }
+}
+
+fn describe(locale: Locale, finding: &Finding) -> String {
+    let message = |direction: &Direction, index: &usize| match direction {
+        Direction::Sent => locale.format("Request #{number}", &[("number", &(index + 1))]),
+        Direction::Received => locale.format("Response #{number}", &[("number", &(index + 1))]),
+    };
+    match finding {
+        Finding::LengthMismatch {
+            direction,
+            index,
+            declared,
+            actual,
+        } => locale.format(
+            "{message}: Content-Length is {declared} bytes, but the body has {actual}",
+            &[
+                ("message", &message(direction, index)),
+                ("declared", declared),
+                ("actual", actual),
+            ],
+        ),
+        Finding::LengthRedacted { direction, index } => locale.format(
+            "{message}: the Content-Length value is redacted, the body length is not checked",
+            &[("message", &message(direction, index))],
+        ),
+        Finding::RedactedToEnd { direction, index } => locale.format(
+            "{message}: the body is redacted up to the end of the transcript, the proof may only open a part of it",
+            &[("message", &message(direction, index))],
+        ),
+        Finding::UnparsedTail { direction, len } => {
+            let text = match direction {
+                Direction::Sent => "{count} bytes after the last request do not parse, the transcript may end in the middle of a request",
+                Direction::Received => "{count} bytes after the last response do not parse, the transcript may end in the middle of a response",
+            };
+            locale.format(text, &[("count", len)])
+        }
+    }
+}
+
+fn render_findings(locale: Locale, findings: &[Finding]) -> Html {
+    html! {
+        <>
+            <p class="mt-4 font-bold">{locale.t("Body lengths:")}</p>
+            if findings.is_empty() {
+                <p class="text-sm">{locale.t("✅ The bodies match their Content-Length headers")}</p>
+            }
+            <ul class="text-sm">
+                {for findings.iter().map(|finding| html! {
+                    <li class={classes!(finding.is_warning().then_some("text-yellow-600 dark:text-yellow-400"))}>
+                        {if finding.is_warning() { "⚠️ " } else { "ℹ️ " }}{describe(locale, finding)}
+                    </li>
+                })}
+            </ul>
+        </>
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() >= 1 {
+        format!("{:.2} s", duration.as_secs_f64())
+    } else {
+        format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+    }
+}
+
+fn render_timings(locale: Locale, timings: &Timings) -> Html {
+    let row = |label: &'static str, took: Option<Duration>| {
+        html! {
+            <tr>
+                <th class="pr-4 font-normal">{locale.t(label)}</th>
+                <td class="text-right">{took.map_or_else(|| locale.t("not checked").to_string(), format_duration)}</td>
+            </tr>
+        }
+    };
+    html! {
+        <>
+            <p class="mt-4 font-bold">{locale.t("Verification time:")}</p>
+            <table class="table-auto text-left text-sm font-mono">
+                <tbody>
+                    {row("Notary signature", timings.signature)}
+                    {row("Server certificate", timings.certificate)}
+                    {row("Disclosed transcript", timings.substrings)}
+                    <tr class="border-t border-gray-400">
+                        <th class="pr-4">{locale.t("Total")}</th>
+                        <td class="text-right">{format_duration(timings.total())}</td>
+                    </tr>
+                </tbody>
+            </table>
+        </>
+    }
+}
+
+/// The session header fields: transcript lengths, commitments and the handshake summary; the
+/// consistency of the HTTP body lengths; and how long the verification took
+#[function_component]
+pub fn TechnicalDetails(Props { proof, verified }: &Props) -> Html {
+    let header = use_memo(proof.clone(), |proof| session_header(&proof.value));
+    let findings = use_memo(verified.clone(), |verified| {
+        let split = exchanges::split(verified);
+        // Only HTTP transcripts have lengths to check
+        (!split.exchanges.is_empty()).then(|| body_checks::check(verified, &split))
+    });
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    let content = match header.as_ref() {
+        Ok(header) => {
+            let summary = &header.handshake_summary;
+            html! {
+                <table class="table-auto text-left text-sm font-mono">
+                    <tbody>
+                        {render_row(locale, "Sent bytes", header.sent_len.to_string())}
+                        {render_row(locale, "Received bytes", header.recv_len.to_string())}
+                        {render_row(locale, "Merkle root", hex::encode(&header.merkle_root))}
+                        {render_row(locale, "Encoder seed", hex::encode(&header.encoder_seed))}
+                        {render_row(locale, "Handshake commitment", hex::encode(&summary.handshake_commitment))}
+                        {render_row(locale, "Server key group", summary.server_public_key.group.clone())}
+                        {render_row(locale, "Server ephemeral key", hex::encode(&summary.server_public_key.key))}
+                        {render_row(locale, "Handshake time", summary.time.to_string())}
+                    </tbody>
+                </table>
+            }
+        }
+        Err(err) => html! { <p class="text-red-500">{err}</p> },
+    };
+
+    // Opened for problems with the body lengths, which are easy to miss otherwise
+    let open = findings
+        .as_ref()
+        .as_ref()
+        .is_some_and(|findings| findings.iter().any(Finding::is_warning));
+
+    html! {
+        <details class={theme::PANEL} {open}>
+            <summary class={theme::SUMMARY}><b>{locale.t("Technical details")}</b></summary>
+            <div class={classes!("p-4", "rounded-md", "overflow-x-auto", theme::CODE)}>
+                {content}
+                if let Some(findings) = findings.as_ref() {
+                    {render_findings(locale, findings)}
+                }
+                {render_timings(locale, &verified.timings)}
+            </div>
+        </details>
+    }
+}
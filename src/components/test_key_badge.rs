@@ -0,0 +1,16 @@
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+
+/// Marks results verified with a test fixture key, whose private key anyone can have
+#[function_component]
+pub fn TestKeyBadge() -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    html! {
+        <span class="inline-block px-2 rounded bg-red-600 text-white text-xs font-bold tracking-wide"
+            title={locale.t("Verified with a test fixture key: its private key is public, so anyone can make such proofs")}>
+            {locale.t("TEST KEY")}
+        </span>
+    }
+}
@@ -0,0 +1,196 @@
+use chrono::{DateTime, Duration, Utc};
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::theme::{self, Theme};
+
+/// A verified proof, placed on the timeline
+#[derive(Clone, Debug, PartialEq)]
+pub struct Point {
+    pub file_name: String,
+    pub server_name: String,
+    pub time: DateTime<Utc>,
+    /// Position in the upload order
+    pub upload: usize,
+}
+
+#[derive(Clone, PartialEq, Properties)]
+pub struct Props {
+    pub points: Vec<Point>,
+}
+
+const WIDTH: f64 = 800.0;
+const LABEL_WIDTH: f64 = 180.0;
+const MARGIN: f64 = 16.0;
+const LANE_HEIGHT: f64 = 40.0;
+const AXIS_HEIGHT: f64 = 24.0;
+
+/// The points of one server, by notarization time
+struct Lane<'a> {
+    server_name: &'a str,
+    points: Vec<&'a Point>,
+}
+
+fn lanes(points: &[Point]) -> Vec<Lane<'_>> {
+    let mut lanes: Vec<Lane> = Vec::new();
+    for point in points {
+        match lanes
+            .iter_mut()
+            .find(|lane| lane.server_name == point.server_name)
+        {
+            Some(lane) => lane.points.push(point),
+            None => lanes.push(Lane {
+                server_name: &point.server_name,
+                points: vec![point],
+            }),
+        }
+    }
+    for lane in &mut lanes {
+        lane.points.sort_by_key(|point| (point.time, point.upload));
+    }
+    lanes.sort_by_key(|lane| lane.server_name);
+    lanes
+}
+
+/// Was `point` uploaded before one notarized earlier? In a series uploaded in order, that
+/// points at a proof out of sequence.
+fn out_of_order(lane: &Lane, index: usize) -> bool {
+    let upload = lane.points[index].upload;
+    lane.points[..index]
+        .iter()
+        .any(|earlier| earlier.upload > upload)
+}
+
+/// The longest time between two consecutive proofs of `lane`, as the index of the later one
+fn longest_gap(lane: &Lane) -> Option<(usize, Duration)> {
+    lane.points
+        .windows(2)
+        .enumerate()
+        .map(|(index, pair)| (index + 1, pair[1].time - pair[0].time))
+        .filter(|(_, gap)| *gap > Duration::zero())
+        .max_by_key(|(_, gap)| *gap)
+}
+
+fn duration_text(duration: Duration) -> String {
+    let (days, hours, minutes) = (
+        duration.num_days(),
+        duration.num_hours() % 24,
+        duration.num_minutes() % 60,
+    );
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", duration.num_seconds()),
+        (0, 0, minutes) => format!("{}m", minutes),
+        (0, hours, minutes) => format!("{}h {}m", hours, minutes),
+        (days, hours, _) => format!("{}d {}h", days, hours),
+    }
+}
+
+/// The notarization times of the verified proofs, one lane per server, to spot gaps and proofs
+/// out of sequence in a series
+#[function_component]
+pub fn Timeline(Props { points }: &Props) -> Html {
+    let theme = use_context::<Theme>().unwrap_or(Theme::Dark);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+
+    if points.len() < 2 {
+        return html! {};
+    }
+
+    let (green, amber) = match theme {
+        Theme::Light => ("#16a34a", "#d97706"),
+        Theme::Dark => ("#22c55e", "#f59e0b"),
+    };
+    let start = points.iter().map(|point| point.time).min().unwrap();
+    let end = points.iter().map(|point| point.time).max().unwrap();
+    let span = (end - start).num_seconds() as f64;
+    let plot_width = WIDTH - LABEL_WIDTH - 2.0 * MARGIN;
+    let x = |time: DateTime<Utc>| {
+        let offset = if span > 0.0 {
+            (time - start).num_seconds() as f64 / span
+        } else {
+            0.5
+        };
+        LABEL_WIDTH + MARGIN + offset * plot_width
+    };
+
+    let lanes = lanes(points);
+    let height = lanes.len() as f64 * LANE_HEIGHT + AXIS_HEIGHT;
+    let mut any_out_of_order = false;
+
+    let lanes_html = lanes
+        .iter()
+        .enumerate()
+        .map(|(row, lane)| {
+            let y = row as f64 * LANE_HEIGHT + LANE_HEIGHT / 2.0;
+            let gap = longest_gap(lane).filter(|_| lane.points.len() > 2);
+            let gap_html = match gap {
+                Some((index, duration)) => {
+                    let (from, to) = (x(lane.points[index - 1].time), x(lane.points[index].time));
+                    html! {
+                        <>
+                            <line x1={from.to_string()} x2={to.to_string()} y1={y.to_string()} y2={y.to_string()} stroke={amber} stroke-width="3" />
+                            <text x={((from + to) / 2.0).to_string()} y={(y - 8.0).to_string()} fill={amber} font-size="11" text-anchor="middle">
+                                {locale.format("longest gap: {duration}", &[("duration", &duration_text(duration))])}
+                            </text>
+                        </>
+                    }
+                }
+                None => html! {},
+            };
+            let dots = lane
+                .points
+                .iter()
+                .enumerate()
+                .map(|(index, point)| {
+                    let out_of_order = out_of_order(lane, index);
+                    any_out_of_order |= out_of_order;
+                    let title = format!(
+                        "{}: {}{}",
+                        point.file_name,
+                        point.time.format("%Y-%m-%d %H:%M:%S UTC"),
+                        if out_of_order { t(", uploaded out of sequence") } else { "" },
+                    );
+                    html! {
+                        <circle cx={x(point.time).to_string()} cy={y.to_string()} r="6" fill={green}
+                            stroke={out_of_order.then_some(amber)} stroke-width="3">
+                            <title>{title}</title>
+                        </circle>
+                    }
+                })
+                .collect::<Html>();
+            html! {
+                <g>
+                    <text x="0" y={(y + 4.0).to_string()} fill="currentColor" font-size="12" font-family="monospace">
+                        {lane.server_name.to_string()}
+                    </text>
+                    <line x1={(LABEL_WIDTH + MARGIN).to_string()} x2={(WIDTH - MARGIN).to_string()} y1={y.to_string()} y2={y.to_string()} stroke="currentColor" stroke-opacity="0.3" />
+                    {gap_html}
+                    {dots}
+                </g>
+            }
+        })
+        .collect::<Html>();
+
+    let axis_y = height - AXIS_HEIGHT / 2.0;
+    let date = |time: DateTime<Utc>| time.format("%Y-%m-%d %H:%M").to_string();
+
+    html! {
+        <div class={classes!("p-4", "my-4", theme::CARD)}>
+            <p class="text-center"><b>{t("Timeline")}</b>{t(" (notarization times of the verified proofs, by server)")}</p>
+            <svg class="w-full" role="img" aria-label={locale.format("Notarization times of {count} proofs, from {start} to {end}", &[("count", &points.len()), ("start", &date(start)), ("end", &date(end))])} viewBox={format!("0 0 {} {}", WIDTH, height)}>
+                {lanes_html}
+                <text x={(LABEL_WIDTH + MARGIN).to_string()} y={axis_y.to_string()} fill="currentColor" font-size="11">{date(start)}</text>
+                if span > 0.0 {
+                    <text x={(WIDTH - MARGIN).to_string()} y={axis_y.to_string()} fill="currentColor" font-size="11" text-anchor="end">{date(end)}</text>
+                }
+            </svg>
+            if any_out_of_order {
+                <p class="text-sm">
+                    <span style={format!("color: {}", amber)}>{"◯ "}</span>
+                    {t("Circled proofs were uploaded before a proof of the same server notarized earlier, they may be out of sequence.")}
+                </p>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,66 @@
+use yew::prelude::*;
+
+use crate::i18n::Locale;
+use crate::theme;
+use crate::verify::{Step, StepOutcome, VerificationSteps};
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub steps: VerificationSteps,
+    /// Expanded initially, e.g. when verification failed
+    #[prop_or_default]
+    pub open: bool,
+}
+
+fn render_step(locale: Locale, step: Step, outcome: &StepOutcome) -> Html {
+    let t = |message| locale.t(message);
+    let (icon, color, note) = match outcome {
+        StepOutcome::Passed => ("✓", "text-green-500", None),
+        StepOutcome::Failed(err) => ("✗", "text-red-500", Some(err.clone())),
+        StepOutcome::Skipped => (
+            "–",
+            "text-gray-400",
+            Some(
+                t("Skipped: certificate verification is disabled in the advanced options")
+                    .to_string(),
+            ),
+        ),
+        StepOutcome::NotChecked => (
+            "–",
+            "text-gray-400",
+            Some(t("Not checked, an earlier step failed").to_string()),
+        ),
+        StepOutcome::NotApplicable => (
+            "–",
+            "text-gray-400",
+            Some(t("Nothing to check, the proof discloses no part of the transcript").to_string()),
+        ),
+    };
+
+    html! {
+        <li class="flex gap-2 items-start">
+            <span class={classes!("w-4", "shrink-0", "font-bold", color)}>{icon}</span>
+            <div>
+                <p>{t(step.description())}</p>
+                if let Some(note) = note {
+                    <p class={classes!("text-sm", "break-all", color)}>{note}</p>
+                }
+            </div>
+        </li>
+    }
+}
+
+/// The steps of the verification of a proof, each with whether it passed
+#[function_component]
+pub fn VerificationStepsPanel(Props { steps, open }: &Props) -> Html {
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    html! {
+        <details class={theme::PANEL} open={*open}>
+            <summary class={theme::SUMMARY}><b>{locale.t("What was checked?")}</b></summary>
+            <ul class={classes!("p-4", "rounded-md", "flex", "flex-col", "gap-2", theme::CODE)}>
+                {for Step::ALL.iter().map(|step| render_step(locale, *step, &steps.outcome(*step)))}
+            </ul>
+        </details>
+    }
+}
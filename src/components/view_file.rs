@@ -1,153 +1,659 @@
-extern crate base64;
-use std::ops::Range;
-use std::str;
-use web_time::Duration;
+use std::rc::Rc;
 
-use yew::{function_component, html, Html, Properties};
-
-use tlsn_core::proof::{SessionProof, TlsProof};
+use gloo::timers::callback::Timeout;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use web_sys::HtmlInputElement;
+use yew::{
+    classes, function_component, hook, html, use_context, use_effect_with, use_memo, use_node_ref,
+    use_state, Callback, Html, InputEvent, MouseEvent, Properties, TargetCast,
+};
 
+use crate::assertions::{self, Assertions, Outcome};
+use crate::components::badge_export::BadgeExport;
+use crate::components::cert_chain::CertChain;
 use crate::components::content_iframe::ContentIFrame;
+use crate::components::conversation::Conversation;
+use crate::components::cookie_list::CookieList;
+use crate::components::copy_button::CopyButton;
+use crate::components::coverage_bar::CoverageBar;
+use crate::components::guided_tour::GuidedTour;
+use crate::components::har_export::HarExport;
+use crate::components::http_exchanges::HttpExchanges;
+use crate::components::integrity_stamp::IntegrityStamp;
+use crate::components::jwt_view::JwtList;
+use crate::components::line_view::LineView;
+use crate::components::mail_view::MailView;
+use crate::components::network_indicator::NetworkIndicator;
+use crate::components::print_report::{PrintButton, PrintFooter};
+use crate::components::raw_proof::RawProof;
 use crate::components::redacted_bytes_component::Direction;
 use crate::components::redacted_bytes_component::RedactedBytesComponent;
+use crate::components::redaction_summary::RedactionSummary;
+use crate::components::signature_details::SignatureDetails;
+use crate::components::site_summary::SiteSummary;
+use crate::components::status_chips::StatusChips;
+use crate::components::tampering_playground::TamperingPlayground;
+use crate::components::technical_details::TechnicalDetails;
+use crate::components::test_key_badge::TestKeyBadge;
+use crate::components::verification_steps::VerificationStepsPanel;
+use crate::components::zk_claims::ZkClaims;
+use crate::config;
+use crate::error_explainer;
+use crate::history::HistoryEntry;
+use crate::i18n::Locale;
+use crate::input::classify::Detected;
+use crate::network_guard;
+use crate::notary_key::{KeySource, NotaryKey};
+use crate::notary_registry::{KeyCheck, Registry, PSE_NOTARY_URL};
+use crate::parsed_proof::ParsedProof;
+use crate::policy;
+use crate::privacy_lints::{self, Warning};
+use crate::report;
+use crate::settings;
+use crate::theme;
+use crate::time_checks::{self, TimeWarning};
+use crate::tour;
+use crate::verify::{Verification, VerifiedOutput, VerifyError, VerifyOptions};
 
 const REDACTED_CHAR: char = 'X'; // '█' '🙈' 'X'
+const TAB_VIEW: &str = "proof_tab";
+
+/// The tabs of a proof
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum Tab {
+    Verified,
+    /// Requests next to their responses
+    Conversation,
+    Raw,
+}
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub name: String,
     pub file_type: String,
-    pub data: Vec<u8>,
-    pub pem: p256::PublicKey,
+    /// The proof, parsed once for all views, each only reads the part it shows
+    pub proof: Rc<ParsedProof>,
+    /// How often the same proof was uploaded
+    #[prop_or(1)]
+    pub uploads: usize,
+    pub pem: NotaryKey,
+    /// The history entry and the report of each verification, whenever the proof is (re)verified
+    #[prop_or_default]
+    pub on_verified: Callback<(HistoryEntry, Value)>,
+    /// The verification of the proof, if another component already verified it. The card then
+    /// neither verifies the proof again nor emits `on_verified`.
+    #[prop_or_default]
+    pub verification: Option<Rc<Verification>>,
+    /// Remove the proof, offered while its verification is queued
+    #[prop_or_default]
+    pub on_cancel: Option<Callback<()>>,
+}
+
+fn render_error(locale: Locale, error: &VerifyError, proof: &ParsedProof) -> Html {
+    let diagnosis = error_explainer::explain_in(locale, error, proof);
+    let title = match error {
+        VerifyError::Parse(_) => locale.t("Parsing failed"),
+        VerifyError::Session(_) | VerifyError::Substrings(_) => locale.t("Invalid Proof"),
+    };
+
+    html! {
+        <div role="alert" data-tour="summary">
+            <div class="bg-red-500 text-white font-bold rounded-t px-4 py-2">
+                {title}
+            </div>
+            <div class="border border-t-0 border-red-400 rounded-b bg-red-100 px-4 py-3 text-red-700">
+                <p>{ "❌ " }{diagnosis.summary.clone()}</p>
+                <ul class="list-disc pl-6 py-2">
+                    {for diagnosis.suggestions.iter().map(|suggestion| html! { <li>{suggestion.clone()}</li> })}
+                </ul>
+                <details class="text-sm">
+                    <summary class={theme::SUMMARY}>{locale.t("Error details")}</summary>
+                    <pre class="whitespace-pre-wrap">{diagnosis.detail.clone()}</pre>
+                </details>
+            </div>
+        </div>
+    }
 }
 
+/// The transcript of a proof which failed a check of the session, only shown once the user asks
+/// for it
+fn render_claimed(
+    locale: Locale,
+    claimed: &VerifiedOutput,
+    show: bool,
+    on_toggle: Callback<MouseEvent>,
+) -> Html {
+    let t = |message| locale.t(message);
+
+    html! {
+        <div class="p-4 flex flex-col justify-center items-center w-full">
+            <label class="flex gap-2 items-center px-4 py-2 border border-red-400 rounded bg-red-100 text-red-700">
+                <input type="checkbox" checked={show} onclick={on_toggle} />
+                {t("Show the transcript the Prover claims anyway (UNVERIFIED)")}
+            </label>
+            if show {
+                <div role="alert" class={classes!("mt-2", "bg-red-500", "text-white", "font-bold", "rounded", "px-4", "py-2", theme::PANEL)}>
+                    {locale.format("⚠️ UNVERIFIED: a check failed, nothing proves that this session with {server} took place or that the transcript is genuine", &[("server", &claimed.server_name)])}
+                </div>
+                <div class="contents" data-tour="sent">
+                    <RedactedBytesComponent direction={Direction::Sent} redacted_char={REDACTED_CHAR} bytes={claimed.sent.bytes.clone()} redacted_ranges={claimed.sent.redacted_ranges.clone()} />
+                </div>
+                <div class="contents" data-tour="received">
+                    <RedactedBytesComponent direction={Direction::Received} redacted_char={REDACTED_CHAR} bytes={claimed.recv.bytes.clone()} redacted_ranges={claimed.recv.redacted_ranges.clone()} />
+                </div>
+            }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct NotaryKeyProps {
+    pem: NotaryKey,
+}
+
+fn key_source(locale: Locale, source: &KeySource) -> String {
+    match source {
+        KeySource::Fixture => locale.t("the test fixture key, the default").to_string(),
+        KeySource::Configured => locale.t("the default key of this deployment").to_string(),
+        KeySource::Registry { name, url } => locale.format(
+            "{name} ({url}), picked from the Notary registry",
+            &[("name", name), ("url", url)],
+        ),
+        KeySource::Custom => locale.t("a key entered by you").to_string(),
+    }
+}
+
+/// The fingerprint of the Notary key, the Notary's name if it is a known one and where the key
+/// came from
 #[function_component]
-pub fn ViewFile(props: &Props) -> Html {
-    // Verify the session proof against the Notary's public key
-    fn verify_proof(session: &SessionProof, pem: p256::PublicKey) -> Result<(), String> {
-        session
-            .verify_with_default_cert_verifier(pem)
-            .map_err(|err| err.to_string())
-    }
-
-    fn parse_tls_proof(json_str: &str, pem: p256::PublicKey) -> Html {
-        let tls_proof: Result<TlsProof, serde_json::Error> = serde_json::from_str(json_str);
-
-        match tls_proof {
-            Err(e) => html! {
-                 <div>{format!("Parsing failed {}", e.to_string())}</div>
-            },
-            Ok(tls_proof) => {
-                let TlsProof {
-                    // The session proof establishes the identity of the server and the commitments
-                    // to the TLS transcript.
-                    session,
-                    // The substrings proof proves select portions of the transcript, while redacting
-                    // anything the Prover chose not to disclose.
-                    substrings,
-                } = tls_proof;
-
-                let proof_verification = verify_proof(&session, pem);
-
-                if proof_verification.is_err() {
-                    return html! {
-                        <>
-                            <div role="alert">
-                                <div class="bg-red-500 text-white font-bold rounded-t px-4 py-2">
-                                    {"Invalid Proof"}
-                                </div>
-                                <div class="border border-t-0 border-red-400 rounded-b bg-red-100 px-4 py-3 text-red-700">
-                                    { "❌ " }{proof_verification.unwrap_err().to_string()}
-                                </div>
-                            </div>
-                        </>
-                    };
-                }
+fn NotaryKeyInfo(NotaryKeyProps { pem }: &NotaryKeyProps) -> Html {
+    let registry = use_context::<Registry>().unwrap_or_else(Registry::bundled);
+    let source = use_context::<KeySource>().unwrap_or(KeySource::Custom);
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let t = |message| locale.t(message);
+    let fingerprint = pem.fingerprint();
+    let is_pse = matches!(registry.check_key(PSE_NOTARY_URL, *pem), KeyCheck::Match(_));
 
-                let proof_verification_feedback = "✅ Proof successfully verified ✅".to_string();
-
-                let SessionProof {
-                    // The session header that was signed by the Notary is a succinct commitment to the TLS transcript.
-                    header,
-                    // This is the server name, checked against the certificate chain shared in the TLS handshake.
-                    session_info,
-                    ..
-                } = session;
-
-                // The time at which the session was recorded
-                let time = chrono::DateTime::UNIX_EPOCH + Duration::from_secs(header.time());
-
-                // Verify the substrings proof against the session header.
-                let substring_verification_result = substrings.verify(&header);
-
-                if substring_verification_result.is_err() {
-                    return html! {
-                        <>
-                            <div role="alert">
-                                <div class="bg-red-500 text-white font-bold rounded-t px-4 py-2">
-                                    {"Invalid Proof"}
-                                </div>
-                                <div class="border border-t-0 border-red-400 rounded-b bg-red-100 px-4 py-3 text-red-700">
-                                    { "❌ " }{substring_verification_result.unwrap_err().to_string()}
-                                </div>
-                            </div>
-                        </>
-                    };
+    html! {
+        <>
+        if !is_pse {
+            <div role="alert" class="mb-2 border border-yellow-400 rounded bg-yellow-100 px-4 py-3 text-yellow-800">
+                {locale.format("⚠️ Not verified with the key of {pse}. Only trust this result if you trust the Notary whose key was used.", &[("pse", &"notary.pse.dev")])}
+            </div>
+        }
+        <div class={classes!("p-4", "rounded-md", "flex", "gap-2", "items-start", theme::CODE)}>
+            <div class="flex-1">
+                if let Some(notary) = registry.find(*pem) {
+                    <a class="underline font-bold" href={notary.url.clone()} target="_blank" rel="noopener noreferrer">{notary.name.clone()}</a>
+                } else {
+                    <span class="text-gray-400">{t("Not a known Notary")}</span>
                 }
+                <pre class="whitespace-pre-wrap break-all" title={t("SHA-256 of the DER encoded public key")}>{format!("{} {}", pem.scheme(), fingerprint)}</pre>
+                <p class="text-sm text-gray-400">{locale.format("Key source: {source}", &[("source", &key_source(locale, &source))])}</p>
+            </div>
+            <CopyButton value={fingerprint} what={t("the Notary key fingerprint")} />
+        </div>
+        </>
+    }
+}
 
-                // This returns the redacted transcripts
-                let (mut sent, mut recv) = substring_verification_result.unwrap();
+fn render_not_a_proof(locale: Locale, detected: Detected) -> Html {
+    html! {
+        <div role="alert">
+            <div class="bg-yellow-500 text-black font-bold rounded-t px-4 py-2">
+                {locale.t("Not a TLSNotary proof")}
+            </div>
+            <div class="border border-t-0 border-yellow-400 rounded-b bg-yellow-100 px-4 py-3 text-yellow-900">
+                <p>{locale.t("This doesn't look like a TLSNotary proof, here is what was detected:")}</p>
+                <p class="py-2">{detected.describe(locale)}</p>
+            </div>
+        </div>
+    }
+}
 
-                // Replace the bytes which the Prover chose not to disclose with 'X'
-                sent.set_redacted(b'X');
-                recv.set_redacted(b'X');
+/// A proof waiting for its verification, which is cancelled by removing the proof
+fn render_queued(locale: Locale, on_cancel: Option<Callback<()>>) -> Html {
+    html! {
+        <div role="status" class="flex gap-2 justify-center items-center p-4">
+            <span>{locale.t("⏳ Verifying...")}</span>
+            if let Some(on_cancel) = on_cancel {
+                <button class="px-2 text-sm hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" onclick={on_cancel.reform(|_: MouseEvent| ())}>{locale.t("Cancel")}</button>
+            }
+        </div>
+    }
+}
 
-                let redacted_ranges_sent: Vec<Range<usize>> =
-                    sent.redacted().clone().iter_ranges().collect();
-                let redacted_ranges_recv: Vec<Range<usize>> =
-                    recv.redacted().clone().iter_ranges().collect();
+fn time_warning(locale: Locale, warning: &TimeWarning) -> String {
+    match warning {
+        TimeWarning::InFuture => locale
+            .t("Notarization time is in the future, check your clock or the Notary's")
+            .to_string(),
+        TimeWarning::TooOld { max_age_days } => {
+            locale.format("Proof is older than {days} days", &[("days", max_age_days)])
+        }
+    }
+}
 
-                html! {
-                    <div class="p-4 flex flex-col justify-center items-center w-full">
-                        <div class="p-4 w-5/6">
-                            <b>{"Server domain:" }</b>
-                            <div class="bg-black text-white p-4 rounded-md">
-                                <pre>{session_info.server_name.as_str().to_string()}</pre>
-                            </div>
-                            <b>{"Notarization time:" }</b>
-                            <div class="bg-black text-white p-4 rounded-md">
-                                <pre>{time.to_string()}</pre>
-                            </div>
-                            <b>{"Proof:" }</b>
-                            <div class="bg-black text-white p-4 rounded-md">
-                                <pre>{proof_verification_feedback}</pre>
-                            </div>
-                        </div>
+fn render_time(
+    locale: Locale,
+    verified: &VerifiedOutput,
+    max_age_days: u32,
+    on_max_age: Callback<InputEvent>,
+) -> Html {
+    let now = chrono::Utc::now();
+    let warning = time_checks::check(verified.time, now, max_age_days);
 
-                        <RedactedBytesComponent direction={Direction::Sent} redacted_char={REDACTED_CHAR} bytes={sent.data().to_vec()} redacted_ranges={redacted_ranges_sent} />
+    html! {
+        <>
+            <div class={classes!("p-4", "rounded-md", theme::CODE)}>
+                <div class="flex gap-2 items-start">
+                    <pre class="flex-1">{verified.time.to_string()}</pre>
+                    <CopyButton value={verified.time.to_rfc3339()} what={locale.t("the notarization time")} />
+                </div>
+                <pre>{time_checks::local(verified.time)}{locale.t(" (local)")}</pre>
+                <pre class="text-gray-400">{time_checks::relative(verified.time, now)}</pre>
+            </div>
+            if let Some(warning) = warning {
+                <p class="text-yellow-600 dark:text-yellow-400">{"⚠️ "}{time_warning(locale, &warning)}</p>
+            }
+            <label class="flex gap-2 items-center py-2 text-sm text-gray-400">
+                {locale.t("Warn when older than")}
+                <input type="number" min="1" aria-label={locale.t("Warn when older than, in days")} class={classes!("p-1", "w-20", theme::INPUT)} value={max_age_days.to_string()} oninput={on_max_age} />
+                {locale.t("days")}
+            </label>
+        </>
+    }
+}
+
+fn render_outcomes(title: &str, outcomes: &[Outcome]) -> Html {
+    if outcomes.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <>
+            <b>{title.to_string()}</b>
+            <div class={classes!("p-4", "rounded-md", theme::CODE)}>
+                {for outcomes.iter().map(|outcome| html! {
+                    <pre class={if outcome.passed { "" } else { "text-red-500" }}>
+                        {if outcome.passed { "✅ " } else { "❌ " }}{outcome.description.clone()}
+                        if !outcome.passed {
+                            {format!(" ({})", outcome.detail)}
+                        }
+                    </pre>
+                })}
+            </div>
+        </>
+    }
+}
+
+fn render_privacy_warnings(locale: Locale, warnings: &[Warning]) -> Html {
+    if warnings.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <>
+            <b>{locale.t("Privacy warnings:")}</b>
+            <div class={classes!("p-4", "rounded-md", theme::CODE)}>
+                {for warnings.iter().map(|warning| html! {
+                    <pre class="whitespace-pre-wrap text-yellow-600 dark:text-yellow-400" title={format!("{} bytes {:?}", warning.direction, warning.range)}>
+                        {"⚠️ "}{warning.message.clone()}
+                    </pre>
+                })}
+            </div>
+        </>
+    }
+}
 
-                        <ContentIFrame bytes={recv.data().to_vec()} />
+fn render_verified(
+    locale: Locale,
+    proof: &Rc<ParsedProof>,
+    pem: NotaryKey,
+    verified: &Rc<VerifiedOutput>,
+    assertions: &Assertions,
+    max_age_days: u32,
+    on_max_age: Callback<InputEvent>,
+) -> Html {
+    let t = |message| locale.t(message);
+    let proof_verification_feedback = if verified.cert_chain_verified {
+        t("✅ Proof successfully verified ✅")
+    } else {
+        t("⚠️ Notary signature verified, certificate chain NOT checked ⚠️")
+    };
 
-                        <RedactedBytesComponent direction={Direction::Received} redacted_char={REDACTED_CHAR} bytes={recv.data().to_vec()} redacted_ranges={redacted_ranges_recv} />
+    let outcomes = assertions::evaluate(assertions, verified);
+    let server_name_failed = assertions.server_name_mismatch(&verified.server_name);
 
+    html! {
+        <div class="p-4 flex flex-col justify-center items-center w-full">
+            <div class={theme::PANEL} data-tour="summary">
+                <b>{t("Server domain:")}</b>
+                <div class={classes!("p-4", "rounded-md", "flex", "gap-2", "items-start", theme::CODE)}>
+                    <pre class={classes!("flex-1", server_name_failed.then_some("text-red-500"))}>{verified.server_name.clone()}</pre>
+                    <CopyButton value={verified.server_name.clone()} what={t("the server domain")} />
+                </div>
+                <b>{t("Notarization time:")}</b>
+                {render_time(locale, verified, max_age_days, on_max_age)}
+                <b>{t("Proof:")}</b>
+                <div class={classes!("p-4", "rounded-md", theme::CODE)}>
+                    if config::is_test_key(pem) {
+                        <div role="alert" class="mb-2 flex flex-wrap gap-2 items-center text-red-500">
+                            <TestKeyBadge />
+                            <span>{t("Verified with a test fixture key, this proof does not show anything about a real session")}</span>
+                        </div>
+                    }
+                    <pre>{proof_verification_feedback}</pre>
+                    <pre class="text-gray-400">{locale.format("Verified as a {format} proof", &[("format", &verified.format)])}</pre>
+                </div>
+                <StatusChips verified={verified.clone()} />
+                <b>{t("Notary key:")}</b>
+                <NotaryKeyInfo {pem} />
+                <b>{t("Proof SHA-256:")}</b>
+                <IntegrityStamp proof={proof.clone()} />
+                {render_outcomes(t("Assertions:"), &outcomes)}
+                if let Some(policy) = &assertions.policy {
+                    {render_outcomes(t("Policy compliance:"), &policy::evaluate(policy, verified))}
+                }
+                {render_privacy_warnings(locale, &privacy_lints::lint(verified))}
+                if !verified.cert_chain_verified {
+                    <div role="alert" class="mt-2 border border-yellow-400 rounded bg-yellow-100 px-4 py-3 text-yellow-800">
+                        {t("Reduced assurance: certificate verification is disabled in the advanced options, so nothing proves this session was with the real ")}
+                        <span class="font-mono">{verified.server_name.clone()}</span>
                     </div>
                 }
+            </div>
+
+            <div class="contents" data-tour="header">
+                <TechnicalDetails proof={proof.clone()} verified={verified.clone()} />
+            </div>
+
+            <ZkClaims proof={proof.clone()} />
+
+            // The badge stands for a real session with the server, which these can't show
+            if verified.cert_chain_verified && !config::is_test_key(pem) {
+                <BadgeExport verified={verified.clone()} {pem} />
+            }
+
+            if !verified.header_only {
+                <HarExport verified={verified.clone()} />
             }
+
+            <div class="contents" data-tour="certificate">
+                <CertChain proof={proof.clone()} time={verified.time} />
+            </div>
+
+            if verified.header_only {
+                <div role="note" class={classes!("p-4", "my-4", theme::CARD)} data-tour="redactions">
+                    <b>{t("No transcript content disclosed")}</b>
+                    <p>{locale.format("This proof only holds the session header signed by the Notary: the server, the time and the commitments to the {sent} bytes sent and {recv} bytes received. None of the transcript itself was disclosed.", &[("sent", &verified.sent.bytes.len()), ("recv", &verified.recv.bytes.len())])}</p>
+                </div>
+            } else {
+                <SiteSummary verified={verified.clone()} />
+
+                <HttpExchanges verified={verified.clone()} />
+
+                <MailView verified={verified.clone()} />
+
+                <LineView verified={verified.clone()} />
+
+                <div class="contents" data-tour="redactions">
+                    <CoverageBar proof={proof.clone()} verified={verified.clone()} />
+                    <RedactionSummary verified={verified.clone()} />
+                </div>
+
+                <CookieList verified={verified.clone()} />
+
+                <JwtList verified={verified.clone()} />
+
+                <div class="contents" data-tour="sent">
+                    <RedactedBytesComponent direction={Direction::Sent} redacted_char={REDACTED_CHAR} bytes={verified.sent.bytes.clone()} redacted_ranges={verified.sent.redacted_ranges.clone()} />
+                </div>
+
+                <ContentIFrame bytes={verified.recv.bytes.clone()} redacted_ranges={verified.recv.redacted_ranges.clone()} />
+
+                <div class="contents" data-tour="received">
+                    <RedactedBytesComponent direction={Direction::Received} redacted_char={REDACTED_CHAR} bytes={verified.recv.bytes.clone()} redacted_ranges={verified.recv.redacted_ranges.clone()} />
+                </div>
+            }
+
+        </div>
+    }
+}
+
+fn verify(proof: &ParsedProof, pem: NotaryKey, options: &VerifyOptions) -> Verification {
+    if proof.is_json() {
+        network_guard::watch(|| {
+            tracing::info_span!("proof", bytes = proof.size).in_scope(|| proof.verify(pem, options))
+        })
+    } else {
+        tracing::warn!(bytes = proof.size, detected = ?proof.detected, "not a proof");
+        proof.verify(pem, options)
+    }
+}
+
+/// Verify a proof, only redoing the (expensive) work when the proof, the Notary key or the
+/// verification options (provided as context) change. `None` while the verification is queued.
+///
+/// Verifications run one per turn of the event loop rather than while rendering, so the page
+/// stays responsive between the proofs of a large upload. A queued verification is cancelled
+/// when the component unmounts, e.g. because the proof was removed, or the inputs change.
+#[hook]
+pub fn use_verification(proof: &Rc<ParsedProof>, pem: NotaryKey) -> Option<Rc<Verification>> {
+    use_verification_of(Some(proof.clone()), pem)
+}
+
+/// [`use_verification`] of a proof, nothing is verified for `None`
+#[hook]
+fn use_verification_of(proof: Option<Rc<ParsedProof>>, pem: NotaryKey) -> Option<Rc<Verification>> {
+    let options = use_context::<VerifyOptions>().unwrap_or_default();
+    let verification = use_state(|| None);
+    {
+        let verification = verification.clone();
+        use_effect_with((proof, pem, options), move |(proof, pem, options)| {
+            let (proof, pem, options) = (proof.clone(), *pem, options.clone());
+            verification.set(None);
+            let queued = proof.map(|proof| {
+                Timeout::new(0, move || {
+                    verification.set(Some(Rc::new(verify(&proof, pem, &options))));
+                })
+            });
+            // Dropping the timeout before it fired cancels the verification
+            move || drop(queued)
+        });
+    }
+    (*verification).clone()
+}
+
+#[function_component]
+pub fn ViewFile(props: &Props) -> Html {
+    let proof = &props.proof;
+    let is_json = proof.is_json();
+    let own_verification = use_verification_of(
+        props.verification.is_none().then(|| proof.clone()),
+        props.pem,
+    );
+    let verification = props.verification.clone().or(own_verification.clone());
+    // Shared by the views, rather than copying the transcripts into each
+    let verified = use_memo(verification.clone(), |verification| {
+        let verification = verification.as_ref()?;
+        verification.result.as_ref().ok().cloned().map(Rc::new)
+    });
+    let max_age_days = use_state(time_checks::load_max_age_days);
+    let assertions = use_context::<Assertions>().unwrap_or_default();
+    let locale = use_context::<Locale>().unwrap_or_default();
+    let tab = use_state(|| settings::load_view(TAB_VIEW, Tab::Verified));
+    // Not persisted, an unverified transcript is only ever shown on request
+    let show_claimed = use_state(|| false);
+
+    let select_tab = |selected: Tab| {
+        let tab = tab.clone();
+        Callback::from(move |_: MouseEvent| {
+            settings::save_view(TAB_VIEW, selected);
+            tab.set(selected)
+        })
+    };
+    let tab_class = |selected: Tab| {
+        if *tab == selected {
+            "px-4 py-1 border-b-2 border-black dark:border-white font-bold"
+        } else {
+            "px-4 py-1 text-gray-400 hover:text-black dark:hover:text-white"
         }
+    };
+
+    let toggle_claimed = {
+        let show_claimed = show_claimed.clone();
+        Callback::from(move |_: MouseEvent| show_claimed.set(!*show_claimed))
+    };
+
+    let on_max_age = {
+        let max_age_days = max_age_days.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(days) = input.value().parse::<u32>() {
+                time_checks::save_max_age_days(days);
+                max_age_days.set(days);
+            }
+        })
+    };
+
+    {
+        let on_verified = props.on_verified.clone();
+        let name = props.name.clone();
+        let proof = proof.clone();
+        let pem = props.pem;
+        // Only the card's own verifications, a given one is reported by whoever verified it
+        use_effect_with(own_verification, move |verification| {
+            if let (true, Some(verification)) = (is_json, verification) {
+                let report = report::to_json(&proof, pem, &verification.result);
+                on_verified.emit((
+                    HistoryEntry::new(&name, &proof.file_digest, &verification.result),
+                    report,
+                ));
+            }
+        });
     }
 
-    let json_str = str::from_utf8(&props.data).unwrap();
+    let card = use_node_ref();
+    // Not persisted, the tour is started for one proof at a time
+    let touring = use_state(|| false);
+    let toggle_tour = {
+        let touring = touring.clone();
+        Callback::from(move |_: MouseEvent| touring.set(!*touring))
+    };
+    let close_tour = {
+        let touring = touring.clone();
+        Callback::from(move |_| touring.set(false))
+    };
+
     html! {
-            <div class="p-4 flex flex-col justify-center items-center bg-zinc-700 border border-white border-dashed rounded-2xl">
-                <p class="text-center">{ format!("{}", &props.name) }</p>
+            <div ref={card.clone()} class={classes!("p-4", "flex", "flex-col", "justify-center", "items-center", theme::CARD)}>
+                <p class="text-center">
+                    { format!("{}", &props.name) }
+                    if props.uploads > 1 {
+                        <span class="text-sm text-gray-500">{locale.format(" (uploaded {count}×)", &[("count", &props.uploads)])}</span>
+                    }
+                </p>
 
                 <div class="flex-1 flex flex-col justify-center p-4">
                     <div class="container mx-auto px-4">
-                    if props.file_type.contains("application/json") {
-                        {parse_tls_proof(json_str, props.pem)}
+                    if is_json {
+                        <div class="flex justify-center gap-2 pb-2" role="tablist">
+                            <button class={tab_class(Tab::Verified)} role="tab" aria-selected={(*tab == Tab::Verified).to_string()} onclick={select_tab(Tab::Verified)}>{locale.t("Verified proof")}</button>
+                            <button class={tab_class(Tab::Conversation)} role="tab" aria-selected={(*tab == Tab::Conversation).to_string()} onclick={select_tab(Tab::Conversation)}>{locale.t("Conversation")}</button>
+                            <button class={tab_class(Tab::Raw)} role="tab" aria-selected={(*tab == Tab::Raw).to_string()} onclick={select_tab(Tab::Raw)}>{locale.t("Raw proof")}</button>
+                        </div>
+                    }
+                    if is_json && *tab == Tab::Verified {
+                        <div class="flex justify-end gap-2 pb-2">
+                            <button class="px-2 text-sm hover:bg-black hover:text-white rounded border-black dark:border-gray-400 border" onclick={toggle_tour}>
+                                {locale.t("🎓 Guided tour")}
+                            </button>
+                            <PrintButton card={card.clone()} />
+                        </div>
+                    }
+                    if is_json && *tab == Tab::Raw {
+                        <RawProof proof={proof.clone()} />
+                    } else if let Some(verification) = verification.as_ref().filter(|_| is_json) {
+                        if *tab == Tab::Conversation {
+                            {match (&*verified, &verification.result) {
+                                (Some(verified), _) if verified.header_only => html! {
+                                    <p class="text-center">{locale.t("No transcript content disclosed")}</p>
+                                },
+                                (Some(verified), _) => html! { <Conversation verified={verified.clone()} /> },
+                                (None, Err(error)) => render_error(locale, error, proof),
+                                (None, Ok(_)) => html! {},
+                            }}
+                        } else {
+                            {match (&*verified, &verification.result) {
+                                (Some(verified), _) => render_verified(locale, proof, props.pem, verified, &assertions, *max_age_days, on_max_age),
+                                (None, Err(error)) => render_error(locale, error, proof),
+                                (None, Ok(_)) => html! {},
+                            }}
+                            if let Some(claimed) = &verification.claimed {
+                                {render_claimed(locale, claimed, *show_claimed, toggle_claimed)}
+                            }
+                            <div class="contents" data-tour="signature">
+                                <SignatureDetails proof={proof.clone()} pem={props.pem} open={matches!(verification.result, Err(VerifyError::Session(_)))} />
+                            </div>
+                            <div class="contents" data-tour="steps">
+                                <VerificationStepsPanel steps={verification.steps.clone()} open={verification.result.is_err()} />
+                            </div>
+                            <TamperingPlayground proof={proof.clone()} pem={props.pem} />
+                            <NetworkIndicator />
+                        }
+                    } else if is_json {
+                        {render_queued(locale, props.on_cancel.clone())}
+                    } else {
+                        {render_not_a_proof(locale, proof.detected)}
                     }
                     </div>
                 </div>
+                if is_json {
+                    <PrintFooter proof={proof.clone()} pem={props.pem} />
+                }
+                if let Some(verification) = verification.as_ref().filter(|_| is_json && *touring && *tab == Tab::Verified) {
+                    <GuidedTour steps={tour::steps(locale, verification, props.pem)} card={card.clone()} on_close={close_tour} />
+                }
             </div>
     }
 }
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::components::harness::{render, text};
+    use crate::fixtures;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    fn props(data: &str) -> Props {
+        Props {
+            name: "proof.json".to_string(),
+            file_type: "application/json".to_string(),
+            proof: Rc::new(ParsedProof::parse(data.as_bytes())),
+            uploads: 1,
+            pem: NotaryKey::parse(config::FIXTURE_PEM).unwrap(),
+            on_verified: Callback::noop(),
+            verification: None,
+            on_cancel: None,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn fixture_proof_is_verified() {
+        let root = render::<ViewFile>(props(fixtures::EXAMPLE_COM)).await;
+        let text = text(&root);
+        assert!(text.contains("✅ Proof successfully verified ✅"));
+        assert!(text.contains("example.com"));
+        assert!(text.contains("Verified with a test fixture key"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn tampered_proof_is_invalid() {
+        let root = render::<ViewFile>(props(&fixtures::tampered_signature())).await;
+        let alert = root.query_selector("[role=alert]").unwrap().unwrap();
+        assert!(text(&alert).contains("Invalid Proof"));
+        assert!(!text(&root).contains("Proof successfully verified"));
+    }
+}
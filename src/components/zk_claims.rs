@@ -0,0 +1,71 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::formats::zk::{self, Bundle};
+use crate::i18n::Locale;
+use crate::parsed_proof::ParsedProof;
+use crate::theme;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub proof: Rc<ParsedProof>,
+}
+
+fn render_claims(locale: Locale, bundle: &Bundle) -> Html {
+    let t = |message| locale.t(message);
+    html! {
+        <>
+            <table class="table-auto text-left text-sm">
+                <thead>
+                    <tr>
+                        <th class="pr-4">{t("Field")}</th>
+                        <th class="pr-4">{t("Predicate")}</th>
+                        <th>{t("Status")}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {for bundle.claims.iter().map(|claim| html! {
+                        <tr>
+                            <td class="pr-4 font-mono break-all">
+                                {claim.field.clone()}
+                                if let Some(direction) = &claim.direction {
+                                    <span class="text-gray-400">{format!(" ({})", direction)}</span>
+                                }
+                            </td>
+                            <td class="pr-4 font-mono">{claim.predicate.to_string()}</td>
+                            <td class="text-yellow-600 dark:text-yellow-400">{t("⚠️ not checked")}</td>
+                        </tr>
+                    })}
+                </tbody>
+            </table>
+            <p class="mt-2 text-sm">
+                {locale.format("The values stay undisclosed, and this verifier cannot check {system} proofs yet: nothing here proves the claims.", &[("system", &bundle.system)])}
+            </p>
+        </>
+    }
+}
+
+/// Zero-knowledge claims about undisclosed values of the transcript, bundled with the proof
+#[function_component]
+pub fn ZkClaims(Props { proof }: &Props) -> Html {
+    let bundle = use_memo(proof.clone(), |proof| zk::bundle(&proof.value));
+    let locale = use_context::<Locale>().unwrap_or_default();
+
+    let content = match bundle.as_ref() {
+        None => return html! {},
+        Some(Ok(bundle)) => render_claims(locale, bundle),
+        Some(Err(err)) => html! {
+            <p class="text-red-500">{locale.format("The zero-knowledge claims do not parse: {error}", &[("error", err)])}</p>
+        },
+    };
+
+    html! {
+        <details class={theme::PANEL} open={true}>
+            <summary class={theme::SUMMARY}><b>{locale.t("Zero-knowledge claims")}</b></summary>
+            <div class={classes!("p-4", "rounded-md", "overflow-x-auto", theme::CODE)}>
+                {content}
+            </div>
+        </details>
+    }
+}
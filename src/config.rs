@@ -0,0 +1,86 @@
+//! Build-time configuration, read from environment variables when the app is compiled, e.g.
+//! `PROOF_VIZ_DEFAULT_NOTARY_KEY="$(cat notary.pub)" trunk build --release`.
+
+use crate::notary_key::{KeySource, NotaryKey};
+
+/// The Notary key used until another one is picked, in any format the key input accepts
+const DEFAULT_NOTARY_KEY: Option<&str> = option_env!("PROOF_VIZ_DEFAULT_NOTARY_KEY");
+/// Where the signed Notary registry is published, and the maintainer key it is signed with
+const REGISTRY_URL: Option<&str> = option_env!("NOTARY_REGISTRY_URL");
+const REGISTRY_KEY: Option<&str> = option_env!("NOTARY_REGISTRY_KEY");
+/// Origins, besides the page's own, which may hand over proofs to the verifier and receive the
+/// results of an embedded one, comma separated
+const ALLOWED_ORIGINS: Option<&str> = option_env!("PROOF_VIZ_ALLOWED_ORIGINS");
+/// IDs of the browser extensions which may hand over proofs in handoff mode, comma separated
+const EXTENSION_IDS: Option<&str> = option_env!("PROOF_VIZ_EXTENSION_IDS");
+
+// from https://github.com/tlsnotary/notary-server/tree/main/src/fixture/notary/notary.key
+// converted with `openssl ec -in notary.key -pubout -outform PEM`
+pub const FIXTURE_PEM: &str = "-----BEGIN PUBLIC KEY-----
+MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEBv36FI4ZFszJa0DQFJ3wWCXvVLFr
+cRzMG5kaTeHGoSzDu6cFqx3uEWYpFGo6C0EOUgf+mEgbktLrXocv5yHzKg==
+-----END PUBLIC KEY-----";
+
+/// Keys whose private key is public, for testing. A proof verified with one proves nothing.
+const TEST_KEYS: &[&str] = &[FIXTURE_PEM];
+
+/// The configured default Notary key, the test fixture key if none is configured. The build
+/// script fails the build if the configured key is invalid, it never falls back to the fixture.
+pub fn default_notary_key() -> (NotaryKey, KeySource) {
+    match DEFAULT_NOTARY_KEY {
+        Some(key) => (
+            NotaryKey::parse(key).expect("the build script checked the configured key"),
+            KeySource::Configured,
+        ),
+        None => (
+            NotaryKey::parse(FIXTURE_PEM).expect("the fixture key is valid"),
+            KeySource::Fixture,
+        ),
+    }
+}
+
+pub fn is_test_key(key: NotaryKey) -> bool {
+    TEST_KEYS
+        .iter()
+        .any(|pem| NotaryKey::parse(pem).is_ok_and(|test_key| test_key == key))
+}
+
+/// The URL of the signed Notary registry and the maintainer key, if the build has both. A build
+/// with an invalid maintainer key does not refresh the registry.
+pub fn registry_source() -> Option<(&'static str, NotaryKey)> {
+    let key = NotaryKey::parse(REGISTRY_KEY?).ok()?;
+    Some((REGISTRY_URL?, key))
+}
+
+/// The configured origins which may post proofs and receive results
+pub fn allowed_origins() -> Vec<String> {
+    parse_list(ALLOWED_ORIGINS.unwrap_or_default())
+}
+
+/// The configured extension IDs, none unless the build names them
+pub fn extension_ids() -> Vec<String> {
+    parse_list(EXTENSION_IDS.unwrap_or_default())
+}
+
+// A wildcard is dropped: it would let any page or extension talk to the verifier
+fn parse_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|entry| entry.trim().trim_end_matches('/'))
+        .filter(|entry| !entry.is_empty() && *entry != "*")
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn origins() {
+        assert_eq!(
+            parse_list(" https://a.example/, *,https://b.example:8080,"),
+            ["https://a.example", "https://b.example:8080"]
+        );
+        assert!(parse_list("").is_empty());
+    }
+}
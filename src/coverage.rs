@@ -0,0 +1,101 @@
+//! How much of the committed transcript a proof opens. The session header records the full
+//! lengths of both directions; the substrings proof discloses some of those bytes and redacts
+//! others, keeping their length. Bytes the verified transcript does not account for at all, e.g.
+//! after its end, are uncovered: the proof says nothing about them but that they exist.
+
+use crate::verify::Transcript;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coverage {
+    /// The length recorded in the session header
+    pub committed: usize,
+    pub disclosed: usize,
+    /// Redacted, with their length and position known
+    pub redacted: usize,
+    /// Neither disclosed nor redacted
+    pub uncovered: usize,
+}
+
+impl Coverage {
+    /// The coverage of `transcript`, for a direction of `committed` bytes
+    pub fn new(committed: usize, transcript: &Transcript) -> Self {
+        let len = transcript.bytes.len().min(committed);
+        let redacted = transcript
+            .redactions_within(&(0..len))
+            .iter()
+            .map(|range| range.len())
+            .sum();
+        Coverage {
+            committed,
+            disclosed: len - redacted,
+            redacted,
+            uncovered: committed - len,
+        }
+    }
+
+    /// A share of the committed bytes in percent, 0 for an empty transcript
+    pub fn percent(&self, bytes: usize) -> f64 {
+        if self.committed == 0 {
+            return 0.0;
+        }
+        bytes as f64 * 100.0 / self.committed as f64
+    }
+}
+
+/// A byte count for people, e.g. `512 B`, `3.2 KB` or `18 KB`
+pub fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+    if bytes < 1000 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if size < 1000.0 {
+            break;
+        }
+        size /= 1000.0;
+        unit = next;
+    }
+    if size < 10.0 {
+        format!("{:.1} {}", size, unit)
+    } else {
+        format!("{:.0} {}", size, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coverage() {
+        let transcript = Transcript {
+            bytes: vec![b'X'; 80],
+            redacted_ranges: vec![10..20, 70..80],
+        };
+        let coverage = Coverage::new(100, &transcript);
+        assert_eq!(
+            coverage,
+            Coverage {
+                committed: 100,
+                disclosed: 60,
+                redacted: 20,
+                uncovered: 20,
+            }
+        );
+        assert_eq!(coverage.percent(coverage.disclosed), 60.0);
+        assert_eq!(
+            Coverage::new(0, &Transcript::undisclosed(0)).percent(0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(3200), "3.2 KB");
+        assert_eq!(format_size(18_000), "18 KB");
+        assert_eq!(format_size(2_500_000), "2.5 MB");
+    }
+}
@@ -0,0 +1,84 @@
+//! Links to a byte range of a transcript, as URL fragments like `#recv:1024-2048`.
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::components::redacted_bytes_component::Direction;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ByteLink {
+    pub direction: Direction,
+    pub range: Range<usize>,
+}
+
+impl ByteLink {
+    /// Parse a fragment, with or without the leading `#`
+    pub fn parse(fragment: &str) -> Option<Self> {
+        let (direction, range) = fragment.trim_start_matches('#').split_once(':')?;
+        let direction = match direction {
+            "sent" => Direction::Sent,
+            "recv" => Direction::Received,
+            _ => return None,
+        };
+        let (start, end) = range.split_once('-')?;
+        let range = start.parse().ok()?..end.parse().ok()?;
+        (!range.is_empty()).then_some(ByteLink { direction, range })
+    }
+
+    /// The link in the fragment of the current URL
+    pub fn current() -> Option<Self> {
+        let hash = web_sys::window()?.location().hash().ok()?;
+        Self::parse(&hash)
+    }
+
+    /// The current URL with this link as fragment
+    pub fn url(&self) -> Option<String> {
+        let location = web_sys::window()?.location();
+        let href = location.href().ok()?;
+        let base = href.split('#').next().unwrap_or_default();
+        Some(format!("{}#{}", base, self))
+    }
+}
+
+impl fmt::Display for ByteLink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::Sent => "sent",
+            Direction::Received => "recv",
+        };
+        write!(f, "{}:{}-{}", direction, self.range.start, self.range.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fragments() {
+        let link = |direction, range| Some(ByteLink { direction, range });
+        let cases = [
+            ("#recv:1024-2048", link(Direction::Received, 1024..2048)),
+            ("sent:0-5", link(Direction::Sent, 0..5)),
+            ("#sent:5-5", None),
+            ("#recv:2048-1024", None),
+            ("#both:0-5", None),
+            ("#sent:a-5", None),
+            ("#sent:0-", None),
+            ("#sent:-1-5", None),
+            ("#sent", None),
+        ];
+        for (fragment, expected) in cases {
+            assert_eq!(ByteLink::parse(fragment), expected, "{}", fragment);
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for fragment in ["recv:1024-2048", "sent:0-1"] {
+            let link = ByteLink::parse(fragment).unwrap();
+            assert_eq!(link.to_string(), fragment);
+            assert_eq!(ByteLink::parse(&format!("#{}", link)), Some(link));
+        }
+    }
+}
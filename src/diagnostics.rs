@@ -0,0 +1,291 @@
+//! A diagnostic log users can hand over when reporting a problem, instead of screenshots: the
+//! `tracing` events of this crate (files loaded, format detection, the outcome of each
+//! verification step, errors) and the time spent in its spans are kept in memory, for the
+//! diagnostics drawer to show and copy. Nothing leaves the browser unless the user copies it.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Write as _};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use tracing::field::{Field, Visit};
+use tracing::level_filters::LevelFilter;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+use web_time::{Duration, Instant};
+
+/// Only the latest events are kept
+const CAPACITY: usize = 500;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// Since the log was started
+    pub elapsed: Duration,
+    pub level: Level,
+    /// The module which logged the event
+    pub target: String,
+    /// The message, followed by the other fields as `name=value`
+    pub message: String,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{:>9.3}s] {:<5} {}: {}",
+            self.elapsed.as_secs_f64(),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+thread_local! {
+    static ENTRIES: RefCell<VecDeque<Entry>> = const { RefCell::new(VecDeque::new()) };
+}
+
+fn push(entry: Entry) {
+    ENTRIES.with_borrow_mut(|entries| {
+        if entries.len() == CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    });
+}
+
+/// The message and the other fields of an event or span
+#[derive(Default)]
+struct Fields(String);
+
+impl Fields {
+    fn push(&mut self, field: &Field, value: fmt::Arguments) {
+        if field.name() == "message" {
+            self.0.insert_str(0, &value.to_string());
+        } else {
+            let _ = write!(self.0, " {}={}", field.name(), value);
+        }
+    }
+}
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, format_args!("{}", value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push(field, format_args!("{:?}", value));
+    }
+}
+
+struct OpenSpan {
+    metadata: &'static Metadata<'static>,
+    fields: String,
+    opened: Instant,
+    /// Handles to the span, it is closed when the last is dropped
+    handles: usize,
+}
+
+/// Keeps the events of this crate, up to `DEBUG`, and logs how long each of its spans was open
+/// when the span closes
+pub struct Diagnostics {
+    started: Instant,
+    next_id: AtomicU64,
+    spans: Mutex<HashMap<u64, OpenSpan>>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Diagnostics {
+            started: Instant::now(),
+            next_id: AtomicU64::new(1),
+            spans: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn log(&self, metadata: &Metadata, message: String) {
+        let entry = Entry {
+            elapsed: self.started.elapsed(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message,
+        };
+        #[cfg(target_arch = "wasm32")]
+        match entry.level {
+            Level::ERROR => gloo::console::error!(entry.to_string()),
+            Level::WARN => gloo::console::warn!(entry.to_string()),
+            Level::INFO => gloo::console::info!(entry.to_string()),
+            _ => gloo::console::debug!(entry.to_string()),
+        }
+        push(entry);
+    }
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Subscriber for Diagnostics {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target().starts_with(env!("CARGO_CRATE_NAME")) && *metadata.level() <= Level::DEBUG
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(LevelFilter::DEBUG)
+    }
+
+    fn new_span(&self, span: &Attributes) -> Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.spans.lock().unwrap().insert(
+            id,
+            OpenSpan {
+                metadata: span.metadata(),
+                fields: fields.0,
+                opened: Instant::now(),
+                handles: 1,
+            },
+        );
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record) {
+        if let Some(open) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            let mut fields = Fields(std::mem::take(&mut open.fields));
+            values.record(&mut fields);
+            open.fields = fields.0;
+        }
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        self.log(event.metadata(), fields.0.trim_start().to_string());
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+
+    fn clone_span(&self, span: &Id) -> Id {
+        if let Some(open) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            open.handles += 1;
+        }
+        span.clone()
+    }
+
+    fn try_close(&self, span: Id) -> bool {
+        let closed = {
+            let mut spans = self.spans.lock().unwrap();
+            match spans.get_mut(&span.into_u64()) {
+                Some(open) if open.handles > 1 => {
+                    open.handles -= 1;
+                    None
+                }
+                Some(_) => spans.remove(&span.into_u64()),
+                None => None,
+            }
+        };
+        let Some(closed) = closed else {
+            return false;
+        };
+        let took = closed.opened.elapsed().as_secs_f64() * 1000.0;
+        let message = format!(
+            "{}{} took {:.1} ms",
+            closed.metadata.name(),
+            closed.fields,
+            took
+        );
+        self.log(closed.metadata, message);
+        true
+    }
+}
+
+/// Start the log. Call once, before anything logs.
+pub fn install() {
+    if tracing::subscriber::set_global_default(Diagnostics::new()).is_err() {
+        gloo::console::warn!("the diagnostic log was already started");
+    }
+}
+
+/// The latest events, oldest first
+pub fn entries() -> Vec<Entry> {
+    ENTRIES.with_borrow(|entries| entries.iter().cloned().collect())
+}
+
+pub fn clear() {
+    ENTRIES.with_borrow_mut(|entries| entries.clear());
+}
+
+/// The log as text for a problem report, headed by the app version and the browser's `user_agent`
+pub fn dump(entries: &[Entry], user_agent: &str) -> String {
+    let mut dump = format!(
+        "{} {}\n{}\n\n",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        user_agent
+    );
+    for entry in entries {
+        let _ = writeln!(dump, "{}", entry);
+    }
+    dump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_and_spans() {
+        tracing::subscriber::with_default(Diagnostics::new(), || {
+            let span = tracing::debug_span!("verify", format = "alpha6");
+            span.in_scope(|| tracing::warn!(step = ?Some(1), "failed: {}", "bad json"));
+            drop(span);
+            tracing::trace!("too verbose");
+        });
+        let entries = entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].level, Level::WARN);
+        assert_eq!(entries[0].target, "proof_viz::diagnostics::tests");
+        assert_eq!(entries[0].message, "failed: bad json step=Some(1)");
+        assert_eq!(entries[1].level, Level::DEBUG);
+        assert!(entries[1].message.starts_with("verify format=alpha6 took "));
+    }
+
+    #[test]
+    fn latest_events() {
+        tracing::subscriber::with_default(Diagnostics::new(), || {
+            for i in 0..CAPACITY + 10 {
+                tracing::info!(i);
+            }
+        });
+        let entries = entries();
+        assert_eq!(entries.len(), CAPACITY);
+        assert_eq!(entries[0].message, "i=10");
+        clear();
+        assert!(super::entries().is_empty());
+    }
+
+    #[test]
+    fn dump_for_report() {
+        let entry = Entry {
+            elapsed: Duration::from_millis(1500),
+            level: Level::INFO,
+            target: "proof_viz::verify".to_string(),
+            message: "detected proof format format=Some(Alpha6)".to_string(),
+        };
+        assert_eq!(
+            dump(&[entry], "Firefox"),
+            format!(
+                "proof_viz {}\nFirefox\n\n[    1.500s] INFO  proof_viz::verify: detected proof format format=Some(Alpha6)\n",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Embedding the verifier in other sites' iframes.
+//!
+//! `?embed=1` hides the navigation and upload chrome, `?proof_url=...` loads a proof without
+//! user interaction. The report of every verification is posted to the parent window, if its
+//! origin may receive it. Embedded verifications always check the certificate chain.
+//!
+//! Pages and extensions can also hand over proofs with
+//! `postMessage({ type: "proof_viz:proof", proof, name })`, they get the report back as
+//! `{ type: "proof_viz:result", result }`. Only the page's own origin and the origins
+//! configured at build time may do so (the query string is written by whoever shares the link,
+//! so it can not grant that), and with `?handoff=1` the configured browser extensions, see `handoff`.
+
+use gloo::events::EventListener;
+use gloo::net::http::Request;
+use gloo::utils::window;
+use serde_json::Value;
+use wasm_bindgen::JsCast;
+use web_sys::{MessageEvent, UrlSearchParams, Window};
+use yew::Callback;
+
+use crate::config;
+use crate::handoff;
+
+/// Type of the messages posted to the parent window
+pub const VERIFIED_MESSAGE_TYPE: &str = "proof_viz:verified";
+/// Type of the messages which hand over a proof
+pub const PROOF_MESSAGE_TYPE: &str = "proof_viz:proof";
+/// Type of the replies to those
+pub const RESULT_MESSAGE_TYPE: &str = "proof_viz:result";
+
+/// The embedding options in the page's query string
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EmbedParams {
+    pub embed: bool,
+    /// Proof to fetch and verify on load
+    pub proof_url: Option<String>,
+    /// Opened by a browser extension to hand over a proof
+    pub handoff: bool,
+}
+
+impl EmbedParams {
+    pub fn current() -> Self {
+        let Some(params) = window()
+            .location()
+            .search()
+            .ok()
+            .and_then(|search| UrlSearchParams::new_with_str(&search).ok())
+        else {
+            return Self::default();
+        };
+
+        EmbedParams {
+            embed: matches!(params.get("embed").as_deref(), Some("1" | "true")),
+            handoff: matches!(params.get("handoff").as_deref(), Some("1" | "true")),
+            proof_url: params.get("proof_url").filter(|url| !url.is_empty()),
+        }
+    }
+}
+
+/// The file name of a proof URL: its last path segment
+pub fn file_name(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    match path.rsplit('/').next() {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => "proof.json".to_string(),
+    }
+}
+
+/// Fetch the proof at `url`, emitting its bytes or why it could not be fetched
+pub fn fetch_proof(url: String, callback: Callback<Result<Vec<u8>, String>>) {
+    yew::platform::spawn_local(async move {
+        let result = match Request::get(&url).send().await {
+            Ok(response) if response.ok() => response.binary().await.map_err(|e| e.to_string()),
+            Ok(response) => Err(format!(
+                "HTTP {} {}",
+                response.status(),
+                response.status_text()
+            )),
+            Err(err) => Err(err.to_string()),
+        };
+        callback.emit(result);
+    });
+}
+
+/// The origin of `url`, e.g. `https://example.com:8080` of `https://example.com:8080/a?b`
+fn origin_of(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    let host = rest.split(['/', '?', '#']).next()?;
+    (!scheme.is_empty() && !host.is_empty()).then(|| format!("{}://{}", scheme, host))
+}
+
+/// The origin of the embedding page, if it is allowed to receive results. Browsers tell it in
+/// `location.ancestorOrigins`, or else in the referrer.
+fn parent_origin() -> Option<String> {
+    let window = window();
+    let ancestors = js_sys::Reflect::get(&window.location(), &"ancestorOrigins".into())
+        .ok()
+        .and_then(|origins| js_sys::Reflect::get(&origins, &0.into()).ok())
+        .and_then(|origin| origin.as_string());
+    let origin = ancestors.or_else(|| origin_of(&window.document()?.referrer()))?;
+    is_allowed(&origin, false).then_some(origin)
+}
+
+/// Tell the embedding page the report of a verification. It is posted to the parent's origin
+/// only, so a page which frames the verifier without being allowed to learns nothing.
+pub fn post_result(report: &Value) {
+    let Ok(Some(parent)) = window().parent() else {
+        return;
+    };
+    let Some(origin) = parent_origin() else {
+        return;
+    };
+    let message = serde_json::json!({ "type": VERIFIED_MESSAGE_TYPE, "result": report });
+    if let Ok(message) = js_sys::JSON::parse(&message.to_string()) {
+        // The browser drops the message if the parent is no longer at `origin`
+        let _ = parent.post_message(&message, &origin);
+    }
+}
+
+/// A proof handed over with `postMessage`
+pub struct PostedProof {
+    pub name: String,
+    pub data: Vec<u8>,
+    origin: String,
+    source: Option<Window>,
+}
+
+impl PostedProof {
+    /// Send the verification report back to the sender
+    pub fn reply(&self, report: &Value) {
+        let Some(source) = &self.source else {
+            return;
+        };
+        let message = serde_json::json!({ "type": RESULT_MESSAGE_TYPE, "result": report });
+        if let Ok(message) = js_sys::JSON::parse(&message.to_string()) {
+            let _ = source.post_message(&message, &self.origin);
+        }
+    }
+}
+
+fn is_allowed(origin: &str, handoff: bool) -> bool {
+    let own = window().location().origin().ok();
+    own.as_deref() == Some(origin)
+        || (handoff && handoff::is_extension_origin(origin, &config::extension_ids()))
+        || config::allowed_origins()
+            .iter()
+            .any(|allowed| allowed == origin)
+}
+
+// The name and proof of a `proof_viz:proof` message, the proof as a JSON string or object, or
+// why it cannot be taken
+fn posted_proof(event: &MessageEvent) -> Option<(String, Result<Vec<u8>, String>)> {
+    let message: Value = js_sys::JSON::stringify(&event.data())
+        .ok()?
+        .as_string()
+        .and_then(|json| serde_json::from_str(&json).ok())?;
+    if message.get("type")?.as_str()? != PROOF_MESSAGE_TYPE {
+        return None;
+    }
+    let name = message
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or("proof.json")
+        .to_string();
+    Some((name, handoff::decode(&message)))
+}
+
+/// Listen for proofs posted by allowed origins, until the listener is dropped
+pub fn listen(params: &EmbedParams, callback: Callback<PostedProof>) -> EventListener {
+    let handoff = params.handoff;
+    EventListener::new(&window(), "message", move |event| {
+        let event: &MessageEvent = event.unchecked_ref();
+        let origin = event.origin();
+        if !is_allowed(&origin, handoff) {
+            return;
+        }
+        let Some((name, data)) = posted_proof(event) else {
+            return;
+        };
+        let source: Option<Window> = event.source().and_then(|source| source.dyn_into().ok());
+        match data {
+            Ok(data) => callback.emit(PostedProof {
+                name,
+                data,
+                origin,
+                source,
+            }),
+            Err(error) => {
+                let message = js_sys::JSON::parse(&handoff::error_message(&error).to_string());
+                if let (Some(source), Ok(message)) = (source, message) {
+                    let _ = source.post_message(&message, &origin);
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn origins() {
+        assert_eq!(
+            origin_of("https://example.com:8080/page?a=b#c").as_deref(),
+            Some("https://example.com:8080")
+        );
+        assert_eq!(
+            origin_of("https://example.com").as_deref(),
+            Some("https://example.com")
+        );
+        assert_eq!(origin_of(""), None);
+        assert_eq!(origin_of("about:blank"), None);
+    }
+}
@@ -0,0 +1,183 @@
+//! A compact description of the endpoint of an HTTP exchange, in the style of an OpenAPI path:
+//! `GET /users/{id}/repos?page&per_page → 200 application/json`. Path segments which look like
+//! identifiers are generalized, so exchanges with the same endpoint read the same.
+
+use std::fmt;
+
+use crate::exchanges::Exchange;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Endpoint {
+    pub method: String,
+    /// The path with identifiers replaced by `{id}`, `{uuid}` or `{hash}`, without the query
+    pub template: String,
+    /// The names of the query parameters, in order
+    pub query: Vec<String>,
+    /// The media type of the request body, without parameters
+    pub request_type: Option<String>,
+    pub status: Option<String>,
+    /// The media type of the response body, without parameters
+    pub response_type: Option<String>,
+}
+
+fn is_uuid(segment: &str) -> bool {
+    segment.len() == 36
+        && segment.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// The placeholder for a path segment which looks like an identifier, `None` for other segments
+fn placeholder(segment: &str) -> Option<&'static str> {
+    let has_digit = segment.chars().any(|c| c.is_ascii_digit());
+    if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit()) {
+        Some("{id}")
+    } else if is_uuid(segment) {
+        Some("{uuid}")
+    } else if segment.len() >= 16 && has_digit && segment.chars().all(|c| c.is_ascii_hexdigit()) {
+        Some("{hash}")
+    } else if segment.len() >= 16
+        && has_digit
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        // e.g. base62 or base64url identifiers
+        Some("{id}")
+    } else {
+        None
+    }
+}
+
+/// The path with identifiers generalized, e.g. `/users/42/repos` → `/users/{id}/repos`
+pub fn template(path: &str) -> String {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    path.split('/')
+        .map(|segment| placeholder(segment).unwrap_or(segment))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn query_names(path: &str) -> Vec<String> {
+    let Some((_, query)) = path.split_once('?') else {
+        return Vec::new();
+    };
+    let query = query.split('#').next().unwrap_or_default();
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split('=').next().unwrap_or(pair).to_string())
+        .collect()
+}
+
+fn media_type(content_type: &Option<String>) -> Option<String> {
+    content_type
+        .as_deref()
+        .and_then(|content_type| content_type.split(';').next())
+        .map(|media_type| media_type.trim().to_ascii_lowercase())
+        .filter(|media_type| !media_type.is_empty())
+}
+
+impl Endpoint {
+    /// The endpoint of `exchange`, `None` without a request
+    pub fn new(exchange: &Exchange) -> Option<Self> {
+        let request = exchange.request.as_ref()?;
+        let response = exchange.response.as_ref();
+        Some(Endpoint {
+            method: request.method.clone(),
+            template: template(&request.path),
+            query: query_names(&request.path),
+            request_type: media_type(&request.content_type),
+            status: response.map(|response| response.code.clone()),
+            response_type: response.and_then(|response| media_type(&response.content_type)),
+        })
+    }
+
+    /// The method and path template with the query parameter names, e.g.
+    /// `GET /users/{id}?page&per_page`
+    pub fn operation(&self) -> String {
+        let mut operation = format!("{} {}", self.method, self.template);
+        if !self.query.is_empty() {
+            operation.push('?');
+            operation.push_str(&self.query.join("&"));
+        }
+        operation
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation())?;
+        if let Some(request_type) = &self.request_type {
+            write!(f, " ({})", request_type)?;
+        }
+        if let Some(status) = &self.status {
+            write!(f, " → {}", status)?;
+        }
+        if let Some(response_type) = &self.response_type {
+            write!(f, " {}", response_type)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::{RequestInfo, ResponseInfo};
+
+    #[test]
+    fn templates() {
+        assert_eq!(template("/users/42/repos"), "/users/{id}/repos");
+        assert_eq!(
+            template("/orders/3f2504e0-4f89-11d3-9a0c-0305e82c3301?x=1"),
+            "/orders/{uuid}"
+        );
+        assert_eq!(
+            template("/commits/9fceb02d0ae598e95dc970b74767f19372d61af8"),
+            "/commits/{hash}"
+        );
+        assert_eq!(template("/v1/ch_3MmlLrLkdIwHu7ix0snN0B15"), "/v1/{id}");
+        assert_eq!(template("/api/v2/users/me"), "/api/v2/users/me");
+        assert_eq!(template("/"), "/");
+    }
+
+    #[test]
+    fn endpoint() {
+        let exchange = Exchange {
+            request: Some(RequestInfo {
+                range: 0..0,
+                method: "POST".to_string(),
+                path: "/repos/7/issues?page=2&per_page".to_string(),
+                headers: Vec::new(),
+                content_type: Some("application/json; charset=utf-8".to_string()),
+                body: None,
+            }),
+            response: Some(ResponseInfo {
+                range: 0..0,
+                code: "201".to_string(),
+                reason: "Created".to_string(),
+                headers: Vec::new(),
+                content_type: Some("Application/JSON".to_string()),
+                body: None,
+            }),
+        };
+        let endpoint = Endpoint::new(&exchange).unwrap();
+        assert_eq!(
+            endpoint.operation(),
+            "POST /repos/{id}/issues?page&per_page"
+        );
+        assert_eq!(
+            endpoint.to_string(),
+            "POST /repos/{id}/issues?page&per_page (application/json) → 201 application/json"
+        );
+        assert_eq!(
+            Endpoint::new(&Exchange {
+                request: None,
+                response: exchange.response
+            }),
+            None
+        );
+    }
+}
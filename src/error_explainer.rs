@@ -0,0 +1,352 @@
+//! Turns verification errors into a diagnosis: the likely cause and what to try next.
+//!
+//! tlsn-core and serde_json only report errors as strings, so the causes are recognised from
+//! the tell-tale parts of those messages.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::certificate;
+use crate::formats::{self, ProofFormat};
+use crate::i18n::Locale;
+use crate::parsed_proof::ParsedProof;
+use crate::session_header;
+use crate::verify::VerifyError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Cause {
+    /// The file is not JSON or not shaped like a `TlsProof`
+    MalformedJson {
+        line: Option<usize>,
+        column: Option<usize>,
+        missing_field: Option<String>,
+    },
+    /// A substrings proof without the session proof it discloses parts of
+    SubstringsOnly,
+    /// A proof of a tlsn-core version this verifier does not support
+    UnsupportedVersion {
+        format: ProofFormat,
+    },
+    /// The session header signature does not verify against the Notary key
+    WrongNotaryKey,
+    /// A certificate of the chain was not valid at the time of the session
+    CertificateExpired {
+        not_after: Option<DateTime<Utc>>,
+    },
+    /// The certificate chain does not lead to a trusted root
+    UntrustedCertificate,
+    /// The certificate is not issued for the server name in the proof
+    ServerNameMismatch,
+    /// The disclosed transcript does not match the signed commitments
+    TamperedTranscript,
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnosis {
+    pub cause: Cause,
+    pub summary: String,
+    pub suggestions: Vec<String>,
+    /// The original error message
+    pub detail: String,
+}
+
+// `serde_json` errors end with "at line L column C"
+fn position(message: &str) -> (Option<usize>, Option<usize>) {
+    let Some((_, position)) = message.rsplit_once(" at line ") else {
+        return (None, None);
+    };
+    let mut numbers = position.split(" column ").map(|n| n.trim().parse().ok());
+    (numbers.next().flatten(), numbers.next().flatten())
+}
+
+// The name in "missing field `name`"
+fn missing_field(message: &str) -> Option<String> {
+    let (_, rest) = message.split_once("missing field `")?;
+    let (field, _) = rest.split_once('`')?;
+    Some(field.to_string())
+}
+
+/// Recognise the cause of a verification error from its message
+pub fn classify(error: &VerifyError) -> Cause {
+    match error {
+        VerifyError::Parse(message) => {
+            let (line, column) = position(message);
+            Cause::MalformedJson {
+                line,
+                column,
+                missing_field: missing_field(message),
+            }
+        }
+        VerifyError::Session(message) => {
+            let lower = message.to_lowercase();
+            if lower.contains("expired") || lower.contains("notvalidyet") {
+                Cause::CertificateExpired { not_after: None }
+            } else if lower.contains("unknownissuer") || lower.contains("untrusted") {
+                Cause::UntrustedCertificate
+            } else if lower.contains("notvalidforname") || lower.contains("server name") {
+                Cause::ServerNameMismatch
+            } else if lower.contains("signature") || lower.contains("notary key") {
+                Cause::WrongNotaryKey
+            } else {
+                Cause::Unknown
+            }
+        }
+        VerifyError::Substrings(_) => Cause::TamperedTranscript,
+    }
+}
+
+// When the leaf or an intermediate certificate expired, relative to the session time
+fn expiry(proof: &Value) -> Option<DateTime<Utc>> {
+    let time = session_header::session_header(proof)
+        .ok()?
+        .handshake_summary
+        .time;
+    let time = DateTime::from_timestamp(time as i64, 0)?;
+    certificate::cert_chain(proof)
+        .ok()?
+        .into_iter()
+        .find(|cert| !cert.is_valid_at(time))
+        .map(|cert| cert.not_after)
+}
+
+/// Diagnose a verification error of `proof`, in English
+pub fn explain(error: &VerifyError, proof: &ParsedProof) -> Diagnosis {
+    explain_in(Locale::En, error, proof)
+}
+
+/// Diagnose a verification error of `proof`, in the language of the UI
+pub fn explain_in(locale: Locale, error: &VerifyError, proof: &ParsedProof) -> Diagnosis {
+    let cause = match (classify(error), formats::detect(&proof.value)) {
+        (Cause::MalformedJson { .. }, Some(format)) if !format.is_supported() => {
+            Cause::UnsupportedVersion { format }
+        }
+        (Cause::MalformedJson { .. }, None) if formats::is_substrings_proof(&proof.value) => {
+            Cause::SubstringsOnly
+        }
+        (Cause::CertificateExpired { .. }, _) => Cause::CertificateExpired {
+            not_after: expiry(&proof.value),
+        },
+        (cause, _) => cause,
+    };
+
+    let t = |message| locale.t(message).to_string();
+    let (summary, suggestions) = match &cause {
+        Cause::MalformedJson {
+            line,
+            column,
+            missing_field,
+        } => {
+            let summary = match (line, column) {
+                (Some(line), Some(column)) => locale.format(
+                    "The file is not a valid TLSNotary proof (problem at line {line}, column {column})",
+                    &[("line", line), ("column", column)],
+                ),
+                _ => t("The file is not a valid TLSNotary proof"),
+            };
+            let mut suggestions = Vec::new();
+            if let Some(field) = missing_field {
+                suggestions.push(locale.format(
+                    "The proof has no `{field}` field, it may be truncated or made by another tlsn version",
+                    &[("field", field)],
+                ));
+            }
+            suggestions.push(t("Check that you selected the proof JSON and not another file"));
+            (summary, suggestions)
+        }
+        Cause::SubstringsOnly => (
+            t("This is a substrings proof without the session proof it belongs to"),
+            vec![t("Verify it against the session of another loaded proof, or paste the session proof, in the panel above the results")],
+        ),
+        Cause::UnsupportedVersion { format } => (
+            locale.format(
+                "This proof was generated with {format}; this verifier supports {supported}",
+                &[("format", format), ("supported", &formats::SUPPORTED_VERSION)],
+            ),
+            vec![locale.format(
+                "Generate the proof again with tlsn {supported}, or verify it with a verifier for {format}",
+                &[("supported", &formats::SUPPORTED_VERSION), ("format", format)],
+            )],
+        ),
+        Cause::WrongNotaryKey => (
+            t("The Notary signature does not match the Notary public key"),
+            vec![
+                t("Wrong Notary key? Select the key of the Notary that signed this proof"),
+                t("The Notary signature panel shows which known key, if any, made the signature"),
+            ],
+        ),
+        Cause::CertificateExpired { not_after } => (
+            match not_after {
+                Some(not_after) => locale.format(
+                    "The server certificate expired on {not_after} before the session",
+                    &[("not_after", not_after)],
+                ),
+                None => t("The server certificate was not valid at the time of the session"),
+            },
+            vec![
+                t("Only proofs of servers with a valid certificate can be fully verified"),
+                t("For testing, disable certificate verification in the advanced options"),
+            ],
+        ),
+        Cause::UntrustedCertificate => (
+            t("The server certificate is not issued by a trusted certificate authority"),
+            vec![
+                t("For a private CA, add its root certificate in the advanced options"),
+                t("For self-signed certificates, disable certificate verification in the advanced options"),
+            ],
+        ),
+        Cause::ServerNameMismatch => (
+            t("The server certificate is not valid for the server name in the proof"),
+            vec![t("The proof may claim another server than the one the Prover talked to")],
+        ),
+        Cause::TamperedTranscript => (
+            t("The disclosed transcript does not match what the Notary signed"),
+            vec![t("The proof was modified after notarization, do not trust its content")],
+        ),
+        Cause::Unknown => (t("The proof could not be verified"), Vec::new()),
+    };
+
+    Diagnosis {
+        cause,
+        summary,
+        suggestions,
+        detail: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json_str: &str) -> ParsedProof {
+        ParsedProof::parse(json_str.as_bytes())
+    }
+
+    #[test]
+    fn missing_field_with_position() {
+        let error = VerifyError::Parse("missing field `session` at line 3 column 1".to_string());
+        assert_eq!(
+            classify(&error),
+            Cause::MalformedJson {
+                line: Some(3),
+                column: Some(1),
+                missing_field: Some("session".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn syntax_error() {
+        let error = VerifyError::Parse("expected value at line 1 column 1".to_string());
+        let diagnosis = explain(&error, &parse("not json"));
+        assert_eq!(
+            diagnosis.cause,
+            Cause::MalformedJson {
+                line: Some(1),
+                column: Some(1),
+                missing_field: None,
+            }
+        );
+        assert!(diagnosis.summary.contains("line 1, column 1"));
+    }
+
+    #[test]
+    fn legacy_format() {
+        let error =
+            VerifyError::Parse("missing field `server_name` at line 1 column 60".to_string());
+        let json_str = r#"{"session": {"header": {}, "session_info": {}}, "substrings": {}}"#;
+        let diagnosis = explain(&error, &parse(json_str));
+        assert_eq!(
+            diagnosis.cause,
+            Cause::UnsupportedVersion {
+                format: ProofFormat::Legacy
+            }
+        );
+        assert_eq!(
+            diagnosis.summary,
+            "This proof was generated with tlsn v0.1.0-alpha.5 or earlier; this verifier supports v0.1.0-alpha.6"
+        );
+    }
+
+    #[test]
+    fn presentation_format() {
+        let error = VerifyError::Parse("missing field `session` at line 1 column 40".to_string());
+        let json_str = r#"{"attestation": {}, "identity": {}}"#;
+        assert_eq!(
+            explain(&error, &parse(json_str)).cause,
+            Cause::UnsupportedVersion {
+                format: ProofFormat::Presentation
+            }
+        );
+    }
+
+    #[test]
+    fn signature_mismatch() {
+        let error = VerifyError::Session("signature verification failed".to_string());
+        assert_eq!(classify(&error), Cause::WrongNotaryKey);
+        let diagnosis = explain(&error, &parse("{}"));
+        assert!(diagnosis.suggestions[0].starts_with("Wrong Notary key?"));
+    }
+
+    #[test]
+    fn ed25519_key() {
+        let error = VerifyError::Session(
+            "the session header is signed with P-256, it can not be verified with an Ed25519 Notary key"
+                .to_string(),
+        );
+        assert_eq!(classify(&error), Cause::WrongNotaryKey);
+    }
+
+    #[test]
+    fn certificate_errors() {
+        let session = |message: &str| classify(&VerifyError::Session(message.to_string()));
+        assert_eq!(
+            session("invalid server certificate: invalid peer certificate: CertExpired"),
+            Cause::CertificateExpired { not_after: None }
+        );
+        assert_eq!(
+            session("invalid server certificate: invalid peer certificate: UnknownIssuer"),
+            Cause::UntrustedCertificate
+        );
+        assert_eq!(
+            session("invalid server certificate: invalid peer certificate: CertNotValidForName"),
+            Cause::ServerNameMismatch
+        );
+    }
+
+    #[test]
+    fn expiry_date_of_valid_chain() {
+        let json_str = std::fs::read_to_string("test-input/proof.json").unwrap();
+        let error = VerifyError::Session("invalid peer certificate: CertExpired".to_string());
+        // The chain of the test proof was valid during the session, so no date is found
+        assert_eq!(
+            explain(&error, &parse(&json_str)).cause,
+            Cause::CertificateExpired { not_after: None }
+        );
+    }
+
+    #[test]
+    fn substrings_only() {
+        let error = VerifyError::Parse("missing field `session` at line 1 column 42".to_string());
+        let json_str = r#"{"openings": {}, "inclusion_proof": {}}"#;
+        assert_eq!(
+            explain(&error, &parse(json_str)).cause,
+            Cause::SubstringsOnly
+        );
+    }
+
+    #[test]
+    fn substrings() {
+        let error = VerifyError::Substrings("invalid opening".to_string());
+        assert_eq!(classify(&error), Cause::TamperedTranscript);
+        assert_eq!(explain(&error, &parse("")).detail, "invalid opening");
+    }
+
+    #[test]
+    fn unknown() {
+        let error = VerifyError::Session("something else".to_string());
+        let diagnosis = explain(&error, &parse(""));
+        assert_eq!(diagnosis.cause, Cause::Unknown);
+        assert!(diagnosis.suggestions.is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+//! DOM events announcing verification results in embed mode, so the hosting page can react (e.g.
+//! unlock content) without polling, with the report of `verify_proof_json` as `detail`:
+//! `proofviz:verified` only when the certificate chain was checked and the Notary key is not
+//! the public test key, `proofviz:reduced-assurance` for a proof which verified with less, and
+//! `proofviz:failed`.
+//!
+//! The events are dispatched on the window, and on the `<iframe>` element of a same-origin
+//! parent, where they bubble up to its document. Cross-origin parents get the `postMessage`
+//! of `embed::post_result` instead.
+
+use gloo::utils::window;
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+use web_sys::{CustomEvent, CustomEventInit, EventTarget};
+
+use crate::report;
+
+pub const VERIFIED_EVENT: &str = "proofviz:verified";
+/// Verified, but with the certificate chain check skipped or against the test key: anyone can
+/// make such a proof
+pub const REDUCED_ASSURANCE_EVENT: &str = "proofviz:reduced-assurance";
+pub const FAILED_EVENT: &str = "proofviz:failed";
+
+/// The type of the event announcing `report`
+pub fn event_type(report: &Value) -> &'static str {
+    if report::is_full_assurance(report) {
+        VERIFIED_EVENT
+    } else if report.get("verified") == Some(&Value::Bool(true)) {
+        REDUCED_ASSURANCE_EVENT
+    } else {
+        FAILED_EVENT
+    }
+}
+
+fn dispatch_on(target: &EventTarget, event_type: &str, detail: &JsValue) -> Result<(), JsValue> {
+    let init = CustomEventInit::new();
+    init.set_bubbles(true);
+    init.set_detail(detail);
+    let event = CustomEvent::new_with_event_init_dict(event_type, &init)?;
+    target.dispatch_event(&event)?;
+    Ok(())
+}
+
+/// Announce the verification `report`
+pub fn dispatch(report: &Value) {
+    let Ok(detail) = js_sys::JSON::parse(&report.to_string()) else {
+        return;
+    };
+    let event_type = event_type(report);
+    let window = window();
+    if let Err(err) = dispatch_on(&window, event_type, &detail) {
+        tracing::warn!(event_type, error = ?err, "could not dispatch");
+    }
+    // `null` unless the parent is of the same origin
+    if let Ok(Some(frame)) = window.frame_element() {
+        if let Err(err) = dispatch_on(&frame, event_type, &detail) {
+            tracing::warn!(event_type, error = ?err, "could not dispatch");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn event_types() {
+        let full = json!({ "verified": true, "cert_chain_verified": true, "test_key": false });
+        assert_eq!(event_type(&full), VERIFIED_EVENT);
+        let skipped_chain =
+            json!({ "verified": true, "cert_chain_verified": false, "test_key": false });
+        assert_eq!(event_type(&skipped_chain), REDUCED_ASSURANCE_EVENT);
+        let test_key = json!({ "verified": true, "cert_chain_verified": true, "test_key": true });
+        assert_eq!(event_type(&test_key), REDUCED_ASSURANCE_EVENT);
+        // Older reports without the fields are not taken as fully verified
+        assert_eq!(
+            event_type(&json!({ "verified": true })),
+            REDUCED_ASSURANCE_EVENT
+        );
+        assert_eq!(event_type(&json!({ "verified": false })), FAILED_EVENT);
+        assert_eq!(event_type(&json!({})), FAILED_EVENT);
+    }
+}
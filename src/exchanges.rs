@@ -0,0 +1,297 @@
+//! Splitting the transcript into HTTP exchanges: one connection may carry several pipelined or
+//! keep-alive requests, each answered by a response in the same order.
+
+use std::ops::Range;
+
+use spansy::http::{parse_request, parse_response, Header};
+use spansy::{ParseError, Spanned};
+
+use crate::components::redacted_bytes_component::Direction;
+use crate::verify::VerifiedOutput;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeaderInfo {
+    pub name: String,
+    /// Position of the value in the transcript
+    pub value: Range<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestInfo {
+    /// Position in the sent transcript
+    pub range: Range<usize>,
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<HeaderInfo>,
+    pub content_type: Option<String>,
+    pub body: Option<Range<usize>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponseInfo {
+    /// Position in the received transcript
+    pub range: Range<usize>,
+    pub code: String,
+    pub reason: String,
+    pub headers: Vec<HeaderInfo>,
+    pub content_type: Option<String>,
+    pub body: Option<Range<usize>>,
+}
+
+/// A request and its response, either is missing if the other direction has more messages
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exchange {
+    pub request: Option<RequestInfo>,
+    pub response: Option<ResponseInfo>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exchanges {
+    pub exchanges: Vec<Exchange>,
+    /// Where parsing of the sent transcript stopped, e.g. on a redacted request line
+    pub sent_parsed: usize,
+    /// Where parsing of the received transcript stopped
+    pub recv_parsed: usize,
+}
+
+/// Parse messages back to back until the bytes run out or do not parse, returning each message
+/// with its offset and where parsing stopped
+fn parse_all<T: Spanned>(
+    bytes: &[u8],
+    parse: impl Fn(&[u8]) -> Result<T, ParseError>,
+) -> (Vec<(usize, T)>, usize) {
+    let mut messages = Vec::new();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        match parse(&bytes[offset..]) {
+            Ok(message) if !message.span().is_empty() => {
+                let len = message.span().len();
+                messages.push((offset, message));
+                offset += len;
+            }
+            _ => break,
+        }
+    }
+    (messages, offset)
+}
+
+fn content_type(headers: &[Header]) -> Option<String> {
+    headers
+        .iter()
+        .find(|h| h.name.as_str().eq_ignore_ascii_case("content-type"))
+        .map(|h| String::from_utf8_lossy(h.value.as_bytes()).to_string())
+}
+
+/// Position of a parsed item in the transcript, given the offset of its message
+fn item_range<T: ?Sized>(item: &impl Spanned<T>, offset: usize) -> Option<Range<usize>> {
+    let indices = item.span().indices();
+    Some(indices.min()? + offset..indices.end()? + offset)
+}
+
+fn headers(headers: &[Header], offset: usize) -> Vec<HeaderInfo> {
+    headers
+        .iter()
+        .map(|header| HeaderInfo {
+            name: header.name.as_str().to_string(),
+            value: item_range(&header.value, offset).unwrap_or(offset..offset),
+        })
+        .collect()
+}
+
+/// The syntactic parts of an HTTP message
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HttpPart {
+    /// The request or status line
+    StartLine,
+    HeaderName,
+    HeaderValue,
+    Body,
+}
+
+fn push_parts(
+    parts: &mut Vec<(Range<usize>, HttpPart)>,
+    offset: usize,
+    start_line: Option<Range<usize>>,
+    headers: &[Header],
+    body: Option<Range<usize>>,
+) {
+    parts.extend(start_line.map(|range| (range, HttpPart::StartLine)));
+    for header in headers {
+        parts.extend(item_range(&header.name, offset).map(|range| (range, HttpPart::HeaderName)));
+        parts.extend(item_range(&header.value, offset).map(|range| (range, HttpPart::HeaderValue)));
+    }
+    parts.extend(body.map(|range| (range, HttpPart::Body)));
+}
+
+/// The positions of the start lines, header names and values, and bodies of the HTTP messages in
+/// one direction of the transcript, ordered by position
+pub fn message_parts(bytes: &[u8], direction: Direction) -> Vec<(Range<usize>, HttpPart)> {
+    let mut parts = Vec::new();
+    match direction {
+        Direction::Sent => {
+            for (offset, request) in parse_all(bytes, parse_request).0 {
+                let start_line = item_range(&request.request, offset);
+                let body = request.body.and_then(|body| item_range(&body, offset));
+                push_parts(&mut parts, offset, start_line, &request.headers, body);
+            }
+        }
+        Direction::Received => {
+            for (offset, response) in parse_all(bytes, parse_response).0 {
+                let start_line = item_range(&response.status, offset);
+                let body = response.body.and_then(|body| item_range(&body, offset));
+                push_parts(&mut parts, offset, start_line, &response.headers, body);
+            }
+        }
+    }
+    parts.sort_by_key(|(range, _)| range.start);
+    parts
+}
+
+pub fn split(verified: &VerifiedOutput) -> Exchanges {
+    let (requests, sent_parsed) = parse_all(&verified.sent.bytes, parse_request);
+    let (responses, recv_parsed) = parse_all(&verified.recv.bytes, parse_response);
+
+    let mut requests = requests.into_iter().map(|(offset, request)| RequestInfo {
+        range: offset..offset + request.span().len(),
+        method: request.request.method.as_str().to_string(),
+        path: request.request.path.as_str().to_string(),
+        headers: headers(&request.headers, offset),
+        content_type: content_type(&request.headers),
+        body: request.body.and_then(|body| item_range(&body, offset)),
+    });
+    let mut responses = responses
+        .into_iter()
+        .map(|(offset, response)| ResponseInfo {
+            range: offset..offset + response.span().len(),
+            code: response.status.code.as_str().to_string(),
+            reason: response.status.reason.as_str().to_string(),
+            headers: headers(&response.headers, offset),
+            content_type: content_type(&response.headers),
+            body: response.body.and_then(|body| item_range(&body, offset)),
+        });
+
+    let mut exchanges = Vec::new();
+    loop {
+        match (requests.next(), responses.next()) {
+            (None, None) => break,
+            (request, response) => exchanges.push(Exchange { request, response }),
+        }
+    }
+
+    Exchanges {
+        exchanges,
+        sent_parsed,
+        recv_parsed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify::find;
+
+    const TWO_REQUESTS: &str =
+        "GET /a HTTP/1.1\r\nHost: example.com\r\n\r\nGET /b HTTP/1.1\r\nHost: example.com\r\n\r\n";
+    const TWO_RESPONSES: &str =
+        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nhiHTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n";
+
+    // Where the second message starts
+    fn second(text: &str, start: &str) -> usize {
+        text[1..].find(start).unwrap() + 1
+    }
+
+    #[test]
+    fn pipelined_exchanges() {
+        let exchanges = split(&VerifiedOutput::for_test(TWO_REQUESTS, TWO_RESPONSES));
+        assert_eq!(exchanges.sent_parsed, TWO_REQUESTS.len());
+        assert_eq!(exchanges.recv_parsed, TWO_RESPONSES.len());
+        assert_eq!(exchanges.exchanges.len(), 2);
+
+        let request_end = second(TWO_REQUESTS, "GET");
+        let response_end = second(TWO_RESPONSES, "HTTP");
+        let first = &exchanges.exchanges[0];
+        let request = first.request.as_ref().unwrap();
+        assert_eq!(
+            (request.method.as_str(), request.path.as_str()),
+            ("GET", "/a")
+        );
+        assert_eq!(request.range, 0..request_end);
+        assert_eq!(request.headers[0].name, "Host");
+        let response = first.response.as_ref().unwrap();
+        assert_eq!(
+            (response.code.as_str(), response.reason.as_str()),
+            ("200", "OK")
+        );
+        assert_eq!(response.range, 0..response_end);
+        assert_eq!(response.body, Some(response_end - 2..response_end));
+
+        let last = &exchanges.exchanges[1];
+        let request = last.request.as_ref().unwrap();
+        assert_eq!(request.path, "/b");
+        assert_eq!(request.range, request_end..TWO_REQUESTS.len());
+        let response = last.response.as_ref().unwrap();
+        assert_eq!(response.code, "404");
+        assert_eq!(response.range, response_end..TWO_RESPONSES.len());
+        assert_eq!(response.body, None);
+    }
+
+    #[test]
+    fn more_requests_than_responses() {
+        let one_response = &TWO_RESPONSES[..second(TWO_RESPONSES, "HTTP")];
+        let exchanges = split(&VerifiedOutput::for_test(TWO_REQUESTS, one_response));
+        assert_eq!(exchanges.sent_parsed, TWO_REQUESTS.len());
+        assert_eq!(exchanges.recv_parsed, one_response.len());
+        assert_eq!(exchanges.exchanges.len(), 2);
+        assert!(exchanges.exchanges[0].response.is_some());
+        assert_eq!(exchanges.exchanges[1].request.as_ref().unwrap().path, "/b");
+        assert_eq!(exchanges.exchanges[1].response, None);
+    }
+
+    #[test]
+    fn redacted_request_line() {
+        let request_end = second(TWO_REQUESTS, "GET");
+        let redacted = request_end..request_end + "GET /b HTTP/1.1".len();
+        let mut sent = TWO_REQUESTS.as_bytes().to_vec();
+        sent[redacted.clone()].fill(b'X');
+        let mut verified = VerifiedOutput::for_test(sent, TWO_RESPONSES);
+        verified.sent.redacted_ranges.push(redacted);
+
+        // Parsing stops at the redacted request, the responses are still paired up
+        let exchanges = split(&verified);
+        assert_eq!(exchanges.sent_parsed, request_end);
+        assert_eq!(exchanges.recv_parsed, TWO_RESPONSES.len());
+        assert_eq!(exchanges.exchanges.len(), 2);
+        assert_eq!(exchanges.exchanges[0].request.as_ref().unwrap().path, "/a");
+        assert_eq!(exchanges.exchanges[1].request, None);
+        assert_eq!(
+            exchanges.exchanges[1].response.as_ref().unwrap().code,
+            "404"
+        );
+
+        let (requests, parsed) = parse_all(&verified.sent.bytes, parse_request);
+        assert_eq!((requests.len(), parsed), (1, request_end));
+    }
+
+    #[test]
+    fn parts_of_messages() {
+        let kinds = |parts: Vec<(Range<usize>, HttpPart)>| -> Vec<HttpPart> {
+            parts.into_iter().map(|(_, part)| part).collect()
+        };
+        let message = [
+            HttpPart::StartLine,
+            HttpPart::HeaderName,
+            HttpPart::HeaderValue,
+        ];
+        let parts = message_parts(TWO_REQUESTS.as_bytes(), Direction::Sent);
+        assert_eq!(parts[1].0, find(TWO_REQUESTS, "Host"));
+        assert_eq!(kinds(parts), [message, message].concat());
+
+        let parts = message_parts(TWO_RESPONSES.as_bytes(), Direction::Received);
+        let body = second(TWO_RESPONSES, "HTTP") - 2;
+        assert_eq!(parts[3], (body..body + 2, HttpPart::Body));
+        assert_eq!(
+            kinds(parts),
+            [&message[..], &[HttpPart::Body], &message[..]].concat()
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! A small SVG badge of a verified proof, e.g.
+//! "TLSNotary verified | api.example.com · 2024-05-01 · notary.pse.dev", to embed next to a link
+//! to the proof. It is drawn like the usual shields.io badges, without any external resources.
+
+use crate::notary_key::NotaryKey;
+use crate::notary_registry::Registry;
+use crate::verify::VerifiedOutput;
+
+const LABEL: &str = "TLSNotary verified";
+const HEIGHT: usize = 20;
+// Approximate advance of a character of 11px Verdana, the badge needs no font metrics to be
+// readable, only enough room
+const CHAR_WIDTH: usize = 7;
+const PADDING: usize = 6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Badge {
+    pub server_name: String,
+    /// The day of the notarization, `YYYY-MM-DD`
+    pub date: String,
+    /// Who notarized, the host of a known Notary or the start of its key fingerprint
+    pub notary: String,
+}
+
+impl Badge {
+    pub fn new(verified: &VerifiedOutput, pem: NotaryKey, registry: &Registry) -> Self {
+        Badge {
+            server_name: verified.server_name.clone(),
+            date: verified.time.format("%Y-%m-%d").to_string(),
+            notary: notary_name(pem, registry),
+        }
+    }
+
+    /// The text right of the label
+    pub fn message(&self) -> String {
+        format!("{} · {} · {}", self.server_name, self.date, self.notary)
+    }
+
+    /// A file name for the badge, e.g. `tlsnotary-api.example.com-2024-05-01.svg`
+    pub fn file_name(&self) -> String {
+        format!("tlsnotary-{}-{}.svg", self.server_name, self.date)
+    }
+
+    pub fn to_svg(&self) -> String {
+        let message = escape(&self.message());
+        let title = escape(&format!("{}: {}", LABEL, self.message()));
+        let label_width = text_width(LABEL);
+        let message_width = text_width(&self.message());
+        let width = label_width + message_width;
+        let (label_x, message_x) = (label_width / 2, label_width + message_width / 2);
+        format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{HEIGHT}" role="img" aria-label="{title}"><title>{title}</title><linearGradient id="s" x2="0" y2="100%"><stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient><clipPath id="r"><rect width="{width}" height="{HEIGHT}" rx="3" fill="#fff"/></clipPath><g clip-path="url(#r)"><rect width="{label_width}" height="{HEIGHT}" fill="#555"/><rect x="{label_width}" width="{message_width}" height="{HEIGHT}" fill="#4c1"/><rect width="{width}" height="{HEIGHT}" fill="url(#s)"/></g><g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11"><text x="{label_x}" y="14">{LABEL}</text><text x="{message_x}" y="14">{message}</text></g></svg>"##
+        )
+    }
+}
+
+/// The host of the Notary's registry entry, or the first bytes of the key fingerprint
+fn notary_name(pem: NotaryKey, registry: &Registry) -> String {
+    match registry.find(pem) {
+        Some(entry) => {
+            let url = entry.url.trim_end_matches('/');
+            let host = url.split_once("://").map_or(url, |(_, rest)| rest);
+            host.split('/').next().unwrap_or(host).to_string()
+        }
+        None => format!("key {}…", &pem.fingerprint()[..16]),
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    text.chars().count() * CHAR_WIDTH + 2 * PADDING
+}
+
+/// Escape text for XML content and attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn badge(server_name: &str) -> Badge {
+        Badge {
+            server_name: server_name.to_string(),
+            date: "2024-05-01".to_string(),
+            notary: "notary.pse.dev".to_string(),
+        }
+    }
+
+    #[test]
+    fn badge_shows_server_date_and_notary() {
+        let svg = badge("api.example.com").to_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains(">api.example.com · 2024-05-01 · notary.pse.dev</text>"));
+        assert!(svg.contains(">TLSNotary verified</text>"));
+    }
+
+    #[test]
+    fn server_name_is_escaped() {
+        let svg = badge("<script>\"&").to_svg();
+        assert!(!svg.contains("<script>"));
+        assert!(svg.contains("&lt;script&gt;&quot;&amp; · 2024-05-01"));
+    }
+}
@@ -0,0 +1,438 @@
+//! The notarized HTTP exchanges as an HTTP Archive (HAR 1.2), to import into browser devtools
+//! and other tools which read HAR files.
+//!
+//! Redacted bytes are replaced by [`REDACTED`], one per byte, so lengths stay as notarized;
+//! headers and bodies with redacted bytes carry a comment saying so. Timings are not part of a
+//! proof and are all zero.
+
+use std::ops::Range;
+
+use serde::Serialize;
+
+use crate::components::redacted_bytes_component::segments;
+use crate::exchanges::{Exchange, Exchanges, HeaderInfo, RequestInfo, ResponseInfo};
+use crate::verify::{Transcript, VerifiedOutput};
+
+/// Stands in for each redacted byte
+pub const REDACTED: char = '█';
+const REDACTED_COMMENT: &str = "redacted bytes are replaced by █";
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Har {
+    pub log: Log,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Log {
+    pub version: String,
+    pub creator: Creator,
+    pub entries: Vec<Entry>,
+    pub comment: String,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Creator {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    pub started_date_time: String,
+    pub time: u32,
+    pub request: Request,
+    pub response: Response,
+    pub cache: Cache,
+    pub timings: Timings,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub http_version: String,
+    pub cookies: Vec<NameValue>,
+    pub headers: Vec<NameValue>,
+    pub query_string: Vec<NameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<PostData>,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub status: u16,
+    pub status_text: String,
+    pub http_version: String,
+    pub cookies: Vec<NameValue>,
+    pub headers: Vec<NameValue>,
+    pub content: Content,
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: String,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct NameValue {
+    pub name: String,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PostData {
+    pub mime_type: String,
+    pub params: Vec<NameValue>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Content {
+    pub size: i64,
+    pub mime_type: String,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct Cache {}
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct Timings {
+    pub send: u32,
+    pub wait: u32,
+    pub receive: u32,
+}
+
+/// The bytes of `range` as text, with [`REDACTED`] for redacted bytes, and whether any are
+fn text(transcript: &Transcript, range: &Range<usize>) -> (String, bool) {
+    let bytes = &transcript.bytes[range.clone()];
+    let redacted = transcript.redactions_within(range);
+    let text = segments(bytes.len(), &redacted)
+        .into_iter()
+        .map(|(start, end, is_redacted)| {
+            if is_redacted {
+                REDACTED.to_string().repeat(end - start)
+            } else {
+                String::from_utf8_lossy(&bytes[start..end]).to_string()
+            }
+        })
+        .collect();
+    (text, !redacted.is_empty())
+}
+
+fn comment(redacted: bool) -> Option<String> {
+    redacted.then(|| REDACTED_COMMENT.to_string())
+}
+
+fn headers(transcript: &Transcript, headers: &[HeaderInfo]) -> Vec<NameValue> {
+    headers
+        .iter()
+        .map(|header| {
+            let (value, redacted) = text(transcript, &header.value);
+            NameValue {
+                name: header.name.clone(),
+                value,
+                comment: comment(redacted),
+            }
+        })
+        .collect()
+}
+
+/// The HTTP version of a start line: the last word of a request line, the first of a status line
+fn http_version(transcript: &Transcript, range: &Range<usize>, version_first: bool) -> String {
+    let (message, _) = text(transcript, range);
+    let line = message.lines().next().unwrap_or_default();
+    let mut words = line.split_whitespace();
+    let version = if version_first {
+        words.next()
+    } else {
+        words.last()
+    };
+    version
+        .filter(|version| version.starts_with("HTTP/"))
+        .unwrap_or("HTTP/1.1")
+        .to_string()
+}
+
+/// Sizes of the head (start line and headers) and the body of a message
+fn sizes(range: &Range<usize>, body: &Option<Range<usize>>) -> (i64, i64) {
+    match body {
+        Some(body) => ((body.start - range.start) as i64, body.len() as i64),
+        None => (range.len() as i64, 0),
+    }
+}
+
+fn query_string(path: &str) -> Vec<NameValue> {
+    let Some((_, query)) = path.split_once('?') else {
+        return Vec::new();
+    };
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            NameValue {
+                name: name.to_string(),
+                value: value.to_string(),
+                comment: None,
+            }
+        })
+        .collect()
+}
+
+fn request(verified: &VerifiedOutput, request: Option<&RequestInfo>) -> Request {
+    let Some(request) = request else {
+        return Request {
+            method: String::new(),
+            url: format!("https://{}/", verified.server_name),
+            http_version: "HTTP/1.1".to_string(),
+            cookies: Vec::new(),
+            headers: Vec::new(),
+            query_string: Vec::new(),
+            post_data: None,
+            headers_size: -1,
+            body_size: -1,
+        };
+    };
+    let sent = &verified.sent;
+    let (headers_size, body_size) = sizes(&request.range, &request.body);
+    let post_data = request
+        .body
+        .as_ref()
+        .filter(|body| !body.is_empty())
+        .map(|body| {
+            let (text, redacted) = text(sent, body);
+            PostData {
+                mime_type: request.content_type.clone().unwrap_or_default(),
+                params: Vec::new(),
+                text,
+                comment: comment(redacted),
+            }
+        });
+    Request {
+        method: request.method.clone(),
+        url: format!("https://{}{}", verified.server_name, request.path),
+        http_version: http_version(sent, &request.range, false),
+        cookies: Vec::new(),
+        headers: headers(sent, &request.headers),
+        query_string: query_string(&request.path),
+        post_data,
+        headers_size,
+        body_size,
+    }
+}
+
+fn response(verified: &VerifiedOutput, response: Option<&ResponseInfo>) -> Response {
+    let Some(response) = response else {
+        // A request without response, HAR marks these with status 0
+        return Response {
+            status: 0,
+            status_text: String::new(),
+            http_version: "HTTP/1.1".to_string(),
+            cookies: Vec::new(),
+            headers: Vec::new(),
+            content: Content {
+                size: 0,
+                mime_type: String::new(),
+                text: String::new(),
+                comment: None,
+            },
+            redirect_url: String::new(),
+            headers_size: -1,
+            body_size: -1,
+        };
+    };
+    let recv = &verified.recv;
+    let (headers_size, body_size) = sizes(&response.range, &response.body);
+    let (body, redacted) = match &response.body {
+        Some(body) => text(recv, body),
+        None => (String::new(), false),
+    };
+    let headers = headers(recv, &response.headers);
+    let redirect_url = headers
+        .iter()
+        .find(|header| header.name.eq_ignore_ascii_case("location"))
+        .map(|header| header.value.clone())
+        .unwrap_or_default();
+    Response {
+        status: response.code.parse().unwrap_or_default(),
+        status_text: response.reason.clone(),
+        http_version: http_version(recv, &response.range, true),
+        cookies: Vec::new(),
+        headers,
+        content: Content {
+            size: body_size,
+            mime_type: response.content_type.clone().unwrap_or_default(),
+            text: body,
+            comment: comment(redacted),
+        },
+        redirect_url,
+        headers_size,
+        body_size,
+    }
+}
+
+fn entry(verified: &VerifiedOutput, exchange: &Exchange) -> Entry {
+    Entry {
+        started_date_time: verified.time.to_rfc3339(),
+        time: 0,
+        request: request(verified, exchange.request.as_ref()),
+        response: response(verified, exchange.response.as_ref()),
+        cache: Cache::default(),
+        timings: Timings::default(),
+    }
+}
+
+impl Har {
+    /// The archive of the `exchanges` of `verified`, as split by [`crate::exchanges::split`]
+    pub fn new(verified: &VerifiedOutput, exchanges: &Exchanges) -> Self {
+        Har {
+            log: Log {
+                version: "1.2".to_string(),
+                creator: Creator {
+                    name: "TLSNotary proof visualizer".to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                entries: exchanges
+                    .exchanges
+                    .iter()
+                    .map(|exchange| entry(verified, exchange))
+                    .collect(),
+                comment: format!(
+                    "Notarized session with {} at {}; {}",
+                    verified.server_name,
+                    verified.time.to_rfc3339(),
+                    REDACTED_COMMENT
+                ),
+            },
+        }
+    }
+
+    /// A file name for the archive, e.g. `tlsnotary-api.example.com-2024-05-01.har`
+    pub fn file_name(verified: &VerifiedOutput) -> String {
+        format!(
+            "tlsnotary-{}-{}.har",
+            verified.server_name,
+            verified.time.format("%Y-%m-%d")
+        )
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a HAR serializes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify::find;
+    use serde_json::Value;
+
+    const SENT: &str = "POST /api?a=1&b=2 HTTP/1.1\r\nHost: api.example.com\r\nAuthorization: Bearer secret\r\nContent-Type: application/json\r\n\r\n{\"x\":1}";
+    const RECV: &str =
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{\"token\":\"abc\"}";
+
+    fn header(text: &str, name: &str, value: &str) -> HeaderInfo {
+        HeaderInfo {
+            name: name.to_string(),
+            value: find(text, value),
+        }
+    }
+
+    fn verified() -> VerifiedOutput {
+        let mut verified = VerifiedOutput::for_test(SENT, RECV);
+        verified.server_name = "api.example.com".to_string();
+        verified.time = chrono::DateTime::from_timestamp(1714521600, 0).unwrap();
+        verified.sent.redacted_ranges = vec![find(SENT, "secret"), find(SENT, "1}")];
+        verified
+    }
+
+    fn exchanges() -> Exchanges {
+        let request = RequestInfo {
+            range: 0..SENT.len(),
+            method: "POST".to_string(),
+            path: "/api?a=1&b=2".to_string(),
+            headers: vec![
+                header(SENT, "Host", "api.example.com"),
+                header(SENT, "Authorization", "Bearer secret"),
+            ],
+            content_type: Some("application/json".to_string()),
+            body: Some(find(SENT, "{\"x\":1}")),
+        };
+        let response = ResponseInfo {
+            range: 0..RECV.len(),
+            code: "200".to_string(),
+            reason: "OK".to_string(),
+            headers: vec![header(RECV, "Content-Type", "application/json")],
+            content_type: Some("application/json".to_string()),
+            body: Some(find(RECV, "{\"token\":\"abc\"}")),
+        };
+        Exchanges {
+            exchanges: vec![
+                Exchange {
+                    request: Some(request),
+                    response: Some(response),
+                },
+                Exchange {
+                    request: None,
+                    response: None,
+                },
+            ],
+            sent_parsed: SENT.len(),
+            recv_parsed: RECV.len(),
+        }
+    }
+
+    #[test]
+    fn exchange_to_har() {
+        let har: Value =
+            serde_json::from_str(&Har::new(&verified(), &exchanges()).to_json()).unwrap();
+        let log = &har["log"];
+        assert_eq!(log["version"], "1.2");
+        let entry = &log["entries"][0];
+        assert_eq!(entry["startedDateTime"], "2024-05-01T00:00:00+00:00");
+
+        let request = &entry["request"];
+        assert_eq!(request["method"], "POST");
+        assert_eq!(request["url"], "https://api.example.com/api?a=1&b=2");
+        assert_eq!(request["httpVersion"], "HTTP/1.1");
+        assert_eq!(request["queryString"][1]["name"], "b");
+        assert_eq!(request["headers"][0]["value"], "api.example.com");
+        assert!(request["headers"][0].get("comment").is_none());
+        assert_eq!(request["headers"][1]["value"], "Bearer ██████");
+        assert_eq!(request["headers"][1]["comment"], REDACTED_COMMENT);
+        assert_eq!(request["postData"]["text"], "{\"x\":██");
+        assert_eq!(request["bodySize"], 7);
+
+        let response = &entry["response"];
+        assert_eq!(response["status"], 200);
+        assert_eq!(response["content"]["mimeType"], "application/json");
+        assert_eq!(response["content"]["text"], "{\"token\":\"abc\"}");
+        assert!(response["content"].get("comment").is_none());
+        assert_eq!(response["redirectURL"], "");
+    }
+
+    #[test]
+    fn missing_messages() {
+        let har = Har::new(&verified(), &exchanges());
+        let entry = &har.log.entries[1];
+        assert_eq!(entry.request.url, "https://api.example.com/");
+        assert_eq!(entry.response.status, 0);
+        assert_eq!(
+            Har::file_name(&verified()),
+            "tlsnotary-api.example.com-2024-05-01.har"
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! Files made from a verified proof for use elsewhere, e.g. a badge to embed in a web page.
+
+use gloo::file::{Blob, ObjectUrl};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+pub mod badge;
+pub mod har;
+
+/// Let the browser save `content` as a file called `name`
+pub fn download(name: &str, mime_type: &str, content: &str) {
+    let url = ObjectUrl::from(Blob::new_with_options(content, Some(mime_type)));
+    let Ok(link) = gloo::utils::document().create_element("a") else {
+        return;
+    };
+    let _ = link.set_attribute("href", &url);
+    let _ = link.set_attribute("download", name);
+    if let Ok(link) = link.dyn_into::<HtmlElement>() {
+        link.click();
+    }
+}
@@ -0,0 +1,102 @@
+//! Proofs bundled with the app, for the example gallery and the self-test. All are signed with
+//! the TLSNotary test fixture key (`config::FIXTURE_PEM`).
+
+use serde_json::Value;
+
+/// A request to example.com with parts of the response redacted
+pub const EXAMPLE_COM: &str = include_str!("../test-input/proof.json");
+/// A proof claiming a server name which its certificate does not cover
+pub const INVALID_SERVER_NAME: &str = include_str!("../test-input/proof_invalid_server_name.json");
+
+pub const SIGNATURE_POINTER: &str = "/session/signature/P256";
+/// The first disclosed byte of the request, the `G` of `GET`
+pub const TRANSCRIPT_BYTE_POINTER: &str = "/substrings/openings/0/1/Blake3/data/0";
+
+/// `proof` with the value at `pointer` (a JSON pointer) replaced by `change`
+fn tamper(proof: &str, pointer: &str, change: impl FnOnce(&Value) -> Value) -> String {
+    let mut json: Value = serde_json::from_str(proof).expect("fixture proofs are valid JSON");
+    let value = json
+        .pointer_mut(pointer)
+        .expect("fixture proofs have the tampered value");
+    *value = change(value);
+    json.to_string()
+}
+
+/// `EXAMPLE_COM` with the lowest bit of the last digit of the hex encoded signature flipped
+pub fn tampered_signature() -> String {
+    tamper(EXAMPLE_COM, SIGNATURE_POINTER, |signature| {
+        let mut signature = signature.as_str().unwrap_or_default().to_string();
+        let last = signature.pop().and_then(|c| c.to_digit(16)).unwrap_or(0);
+        signature.push(char::from_digit(last ^ 1, 16).unwrap_or('0'));
+        Value::String(signature.to_ascii_uppercase())
+    })
+}
+
+/// `EXAMPLE_COM` with the disclosed `GET` of the request turned into `PET`
+pub fn tampered_transcript() -> String {
+    tamper(EXAMPLE_COM, TRANSCRIPT_BYTE_POINTER, |byte| {
+        Value::from(byte.as_u64().unwrap_or(0) ^ 0x17)
+    })
+}
+
+/// A proof of the gallery, to try the app without a proof of one's own
+#[derive(Clone, Copy, Debug)]
+pub struct Example {
+    /// The file name it is loaded as
+    pub file_name: &'static str,
+    /// Untranslated
+    pub title: &'static str,
+    /// Untranslated
+    pub description: &'static str,
+    pub proof: fn() -> String,
+}
+
+pub const EXAMPLES: [Example; 3] = [
+    Example {
+        file_name: "example.com.json",
+        title: "Web page",
+        description: "A request to example.com, with parts of the response redacted",
+        proof: || EXAMPLE_COM.to_string(),
+    },
+    Example {
+        file_name: "tampered transcript.json",
+        title: "Tampered transcript",
+        description: "The same proof with one disclosed byte changed, verification fails",
+        proof: tampered_transcript,
+    },
+    Example {
+        file_name: "wrong server name.json",
+        title: "Wrong server name",
+        description: "A proof for a server its certificate is not issued for",
+        proof: || INVALID_SERVER_NAME.to_string(),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tampering_changes_one_value() {
+        let valid: Value = serde_json::from_str(EXAMPLE_COM).unwrap();
+        let signature: Value = serde_json::from_str(&tampered_signature()).unwrap();
+        assert_ne!(
+            signature.pointer(SIGNATURE_POINTER),
+            valid.pointer(SIGNATURE_POINTER)
+        );
+        assert_eq!(
+            signature.pointer(TRANSCRIPT_BYTE_POINTER),
+            valid.pointer(TRANSCRIPT_BYTE_POINTER)
+        );
+
+        let transcript: Value = serde_json::from_str(&tampered_transcript()).unwrap();
+        assert_eq!(
+            transcript.pointer(TRANSCRIPT_BYTE_POINTER),
+            Some(&Value::from(b'P'))
+        );
+        assert_eq!(
+            transcript.pointer(SIGNATURE_POINTER),
+            valid.pointer(SIGNATURE_POINTER)
+        );
+    }
+}
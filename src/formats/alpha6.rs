@@ -0,0 +1,235 @@
+//! Verification of tlsn-core v0.1.0-alpha.6 `TlsProof`s.
+
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+use serde_json::Value;
+use web_time::Duration;
+
+use tls_core::anchors::{OwnedTrustAnchor, RootCertStore};
+use tls_core::dns::ServerName;
+use tls_core::key::Certificate;
+use tls_core::verify::{ServerCertVerified, ServerCertVerifier, WebPkiVerifier};
+use tlsn_core::proof::{SessionProof, SubstringsProof, TlsProof};
+use tlsn_core::RedactedTranscript;
+
+use super::{Format, ProofFormat};
+use crate::certificate;
+use crate::notary_key::NotaryKey;
+use crate::verify::{
+    timed, Step, StepOutcome, Timings, Transcript, Verification, VerificationSteps, VerifiedOutput,
+    VerifyError, VerifyOptions, REDACTED_BYTE,
+};
+
+pub struct Alpha6;
+
+impl Format for Alpha6 {
+    fn format(&self) -> ProofFormat {
+        ProofFormat::Alpha6
+    }
+
+    fn verify(&self, proof: &Value, pem: NotaryKey, options: &VerifyOptions) -> Verification {
+        verify_proof(proof, pem, options)
+    }
+}
+
+// The webpki roots (what `verify_with_default_cert_verifier` uses) plus the user's extra roots
+fn cert_verifier(options: &VerifyOptions) -> WebPkiVerifier {
+    let mut root_store = RootCertStore::empty();
+    root_store.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|ta| {
+        OwnedTrustAnchor::from_subject_spki_name_constraints(
+            ta.subject.as_ref(),
+            ta.subject_public_key_info.as_ref(),
+            ta.name_constraints.as_ref().map(|nc| nc.as_ref()),
+        )
+    }));
+    // the roots were checked when they were added, unparsable ones can only be skipped here
+    root_store.add_parsable_certificates(&options.extra_roots);
+
+    WebPkiVerifier::new(root_store, None)
+}
+
+/// Accepts every certificate chain, the server key exchange signature is still checked
+struct NoCertVerifier;
+
+impl ServerCertVerifier for NoCertVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: web_time::SystemTime,
+    ) -> Result<ServerCertVerified, tls_core::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+// Verify the session proof against the Notary's public key, using the key's signature scheme
+fn verify_session(
+    session: &SessionProof,
+    pem: NotaryKey,
+    cert_verifier: &impl ServerCertVerifier,
+) -> Result<(), String> {
+    match pem {
+        NotaryKey::P256(pem) => session
+            .verify(pem, cert_verifier)
+            .map_err(|err| err.to_string()),
+        // tlsn-core v0.1.0-alpha.6 Notaries only sign session headers with P-256
+        NotaryKey::Ed25519(_) => Err(format!(
+            "the session header is signed with P-256, it can not be verified with an {} Notary key",
+            pem
+        )),
+    }
+}
+
+// The leaf certificate has to name the server, as webpki checks it: by a DNS name or IP address
+// subject alternative name, with a wildcard only standing for the leftmost label
+fn check_server_name(proof: &Value, server_name: &str) -> Result<(), String> {
+    let chain = certificate::cert_chain(proof)?;
+    let leaf = chain
+        .first()
+        .ok_or_else(|| "the certificate chain is empty".to_string())?;
+    if leaf.is_valid_for(server_name) {
+        Ok(())
+    } else {
+        Err(format!(
+            "the certificate is issued for {}, not {}",
+            leaf.sans.join(", "),
+            server_name
+        ))
+    }
+}
+
+/// A proof of only the session, which discloses nothing of the transcript
+#[derive(Deserialize)]
+struct SessionOnlyProof {
+    session: SessionProof,
+    substrings: Option<IgnoredAny>,
+}
+
+// A `TlsProof`, or a session proof without substrings
+fn parse(proof: &Value) -> Result<(SessionProof, Option<SubstringsProof>), String> {
+    match TlsProof::deserialize(proof) {
+        Ok(TlsProof {
+            session,
+            substrings,
+        }) => Ok((session, Some(substrings))),
+        Err(err) => match SessionOnlyProof::deserialize(proof) {
+            Ok(SessionOnlyProof {
+                session,
+                substrings: None,
+            }) => Ok((session, None)),
+            // Report why the full proof does not parse
+            _ => Err(err.to_string()),
+        },
+    }
+}
+
+// Parse and verify a `TlsProof` parsed from JSON. The checks go on after one of the session
+// fails, so every failing check is reported and the transcript the Prover claims can still be
+// inspected.
+fn verify_proof(proof: &Value, pem: NotaryKey, options: &VerifyOptions) -> Verification {
+    let mut steps = VerificationSteps::default();
+    // The session proof establishes the identity of the server and the commitments to the TLS
+    // transcript. The substrings proof proves select portions of the transcript, while
+    // redacting anything the Prover chose not to disclose.
+    let (session, substrings) = match steps.check(Step::Parse, parse(proof)) {
+        Ok(tls_proof) => tls_proof,
+        Err(err) => return Verification::failed(VerifyError::Parse(err), steps),
+    };
+
+    let mut timings = Timings::default();
+    // First without the certificate, so a bad signature is told apart from a bad certificate
+    let (signature, took) = timed(|| verify_session(&session, pem, &NoCertVerifier));
+    timings.signature = Some(took);
+    let signature = steps.check(Step::NotarySignature, signature);
+    let certificate = if options.skip_cert_verification {
+        steps.record(Step::ServerName, StepOutcome::Skipped);
+        steps.record(Step::CertChain, StepOutcome::Skipped);
+        Ok(())
+    } else {
+        let (certificate, took) = timed(|| {
+            let server_name = session.session_info.server_name.as_str();
+            let server_name = steps.check(Step::ServerName, check_server_name(proof, server_name));
+            // tlsn-core checks the signature before the certificate chain, which is left
+            // unchecked when the signature is bad
+            let chain = match signature {
+                Ok(()) => steps.check(
+                    Step::CertChain,
+                    verify_session(&session, pem, &cert_verifier(options)),
+                ),
+                Err(_) => Ok(()),
+            };
+            server_name.and(chain)
+        });
+        timings.certificate = Some(took);
+        certificate
+    };
+    let session_result = signature.and(certificate);
+
+    let SessionProof {
+        // The session header that was signed by the Notary is a succinct commitment to the TLS transcript.
+        header,
+        // This is the server name, checked against the certificate chain shared in the TLS handshake.
+        session_info,
+        ..
+    } = session;
+
+    // The time at which the session was recorded
+    let time = chrono::DateTime::UNIX_EPOCH + Duration::from_secs(header.time());
+
+    let header_only = substrings.is_none();
+    let transcripts = match substrings {
+        // Verify the substrings proof against the session header.
+        // This returns the redacted transcripts
+        Some(substrings) => {
+            let (opened, took) = timed(|| substrings.verify(&header));
+            timings.substrings = Some(took);
+            steps
+                .check(Step::Substrings, opened.map_err(|err| err.to_string()))
+                .map(|(mut sent, mut recv)| {
+                    // Replace the bytes which the Prover chose not to disclose with 'X'
+                    sent.set_redacted(REDACTED_BYTE);
+                    recv.set_redacted(REDACTED_BYTE);
+                    let transcript = |redacted: RedactedTranscript| Transcript {
+                        bytes: redacted.data().to_vec(),
+                        redacted_ranges: redacted.redacted().clone().iter_ranges().collect(),
+                    };
+                    (transcript(sent), transcript(recv))
+                })
+        }
+        // Nothing of the transcript is disclosed, only its length is known from the header
+        None => {
+            steps.record(Step::Substrings, StepOutcome::NotApplicable);
+            Ok((
+                Transcript::undisclosed(header.sent_len()),
+                Transcript::undisclosed(header.recv_len()),
+            ))
+        }
+    };
+    let output = transcripts.map(|(sent, recv)| VerifiedOutput {
+        server_name: session_info.server_name.as_str().to_string(),
+        time,
+        sent,
+        recv,
+        cert_chain_verified: !options.skip_cert_verification && session_result.is_ok(),
+        format: ProofFormat::Alpha6,
+        header_only,
+        timings,
+    });
+
+    match (session_result, output) {
+        (Ok(()), Ok(verified)) => Verification {
+            result: Ok(verified),
+            steps,
+            claimed: None,
+        },
+        (Err(err), output) => Verification {
+            result: Err(VerifyError::Session(err)),
+            steps,
+            claimed: output.ok(),
+        },
+        (Ok(()), Err(err)) => Verification::failed(VerifyError::Substrings(err), steps),
+    }
+}
@@ -0,0 +1,148 @@
+//! Proof formats of the different tlsn-core versions.
+//!
+//! Each verifiable version implements [`Format`]. Only `TlsProof`s of tlsn-core
+//! v0.1.0-alpha.6, the tlsn-core this crate depends on, can be verified. Other versions are
+//! recognised from the tell-tale keys of their serialization so users know what went wrong.
+//!
+//! Zero-knowledge claims bundled with a proof are read by [`zk`].
+
+mod alpha6;
+pub mod zk;
+
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::notary_key::NotaryKey;
+use crate::verify::{Verification, VerifyOptions};
+
+/// The tlsn-core version whose proofs this verifier supports
+pub const SUPPORTED_VERSION: &str = "v0.1.0-alpha.6";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProofFormat {
+    /// `TlsProof` with the server name at the session level, which this verifier supports
+    Alpha6,
+    /// `TlsProof` with a nested `session_info`, from before v0.1.0-alpha.6
+    Legacy,
+    /// `Presentation` of an attestation, from v0.1.0-alpha.7 on
+    Presentation,
+}
+
+impl ProofFormat {
+    pub fn is_supported(&self) -> bool {
+        *self == ProofFormat::Alpha6
+    }
+}
+
+impl fmt::Display for ProofFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProofFormat::Alpha6 => write!(f, "tlsn {}", SUPPORTED_VERSION),
+            ProofFormat::Legacy => write!(f, "tlsn v0.1.0-alpha.5 or earlier"),
+            ProofFormat::Presentation => write!(f, "tlsn v0.1.0-alpha.7 or later"),
+        }
+    }
+}
+
+/// Verifier for the proofs of one tlsn-core version
+pub trait Format {
+    fn format(&self) -> ProofFormat;
+
+    /// Verify a proof parsed from JSON
+    fn verify(&self, proof: &Value, pem: NotaryKey, options: &VerifyOptions) -> Verification;
+}
+
+/// The verifiers of every supported version, newest first
+pub fn verifiers() -> Vec<Box<dyn Format>> {
+    vec![Box::new(alpha6::Alpha6)]
+}
+
+/// Recognise the format of a proof, `None` if it is not a proof of any known version
+pub fn detect(proof: &Value) -> Option<ProofFormat> {
+    if ["attestation", "presentation", "identity"]
+        .iter()
+        .any(|key| proof.get(key).is_some())
+    {
+        return Some(ProofFormat::Presentation);
+    }
+
+    let session = proof.get("session")?.as_object()?;
+    if session.contains_key("session_info") {
+        Some(ProofFormat::Legacy)
+    } else if session.contains_key("server_name") {
+        Some(ProofFormat::Alpha6)
+    } else {
+        None
+    }
+}
+
+/// A `SessionProof` serialized on its own, without substrings, wrapped like the session of a
+/// `TlsProof` so it is read like one. Anything else is returned as it is.
+pub fn wrap_session_value(proof: Value) -> Value {
+    let is_session = ["header", "server_name"]
+        .iter()
+        .all(|key| proof.get(key).is_some());
+    if is_session && proof.get("session").is_none() {
+        serde_json::json!({ "session": proof })
+    } else {
+        proof
+    }
+}
+
+/// Is `proof` a `SubstringsProof` on its own, without the session it discloses parts of?
+pub fn is_substrings_proof(proof: &Value) -> bool {
+    ["openings", "inclusion_proof"]
+        .iter()
+        .all(|key| proof.get(key).is_some())
+}
+
+/// A `TlsProof` of the substrings proof `substrings` and the session of `session`, which is a
+/// (wrapped) session proof on its own or the full proof it was taken from
+pub fn combine(session: &Value, substrings: &Value) -> Result<Value, String> {
+    if !is_substrings_proof(substrings) {
+        return Err("not a substrings proof".to_string());
+    }
+    let session = session
+        .get("session")
+        .filter(|session| session.get("header").is_some())
+        .ok_or("no session header found")?;
+    Ok(serde_json::json!({ "session": session, "substrings": substrings }))
+}
+
+/// Advice for proofs of versions this verifier does not support
+pub fn migration_hint(format: ProofFormat) -> Option<String> {
+    if format.is_supported() {
+        return None;
+    }
+    Some(format!(
+        "This proof was generated with {}; this verifier supports {}",
+        format, SUPPORTED_VERSION
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combine_session_and_substrings() {
+        let session = wrap_session_value(serde_json::json!({
+            "header": {"sent_len": 10},
+            "server_name": {"Dns": "example.com"},
+        }));
+        let substrings = serde_json::json!({ "openings": {}, "inclusion_proof": {} });
+        assert!(is_substrings_proof(&substrings));
+        assert!(!is_substrings_proof(&session));
+
+        let proof = combine(&session, &substrings).unwrap();
+        assert_eq!(proof["session"]["header"]["sent_len"], 10);
+        assert_eq!(proof["substrings"], substrings);
+        // The session of a full proof is used as well
+        let full = serde_json::json!({ "session": session["session"], "substrings": {} });
+        assert_eq!(combine(&full, &substrings), combine(&session, &substrings));
+
+        assert!(combine(&serde_json::json!({ "session": {} }), &substrings).is_err());
+        assert!(combine(&session, &session).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+//! Zero-knowledge claims about committed, undisclosed values of the transcript, e.g. "the
+//! balance is at least 1000" proven with authdecode-style commitments without revealing the
+//! balance.
+//!
+//! tlsn-core v0.1.0-alpha.6 has no such proofs, so the bundle format is provisional: a proof
+//! with a `zk` object next to its `session`, naming the proof system and its claims:
+//!
+//! ```json
+//! "zk": {
+//!     "system": "authdecode",
+//!     "claims": [{
+//!         "field": "$.balance",
+//!         "direction": "recv",
+//!         "predicate": { "op": ">=", "value": 1000 },
+//!         "commitment": "…",
+//!         "proof": "…"
+//!     }]
+//! }
+//! ```
+//!
+//! The session is verified as usual. No proof system is implemented yet, so the claims are
+//! shown, but reported as not checked.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+pub enum Op {
+    #[serde(rename = "==")]
+    Eq,
+    #[serde(rename = "!=")]
+    Ne,
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Le,
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Ge,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let op = match self {
+            Op::Eq => "=",
+            Op::Ne => "≠",
+            Op::Lt => "<",
+            Op::Le => "≤",
+            Op::Gt => ">",
+            Op::Ge => "≥",
+        };
+        write!(f, "{}", op)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Predicate {
+    pub op: Op,
+    pub value: Value,
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.value {
+            Value::String(value) => write!(f, "{} {}", self.op, value),
+            value => write!(f, "{} {}", self.op, value),
+        }
+    }
+}
+
+/// A claim that the committed value of `field` satisfies `predicate`
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Claim {
+    /// What the value is, e.g. a JSON path into the response body
+    pub field: String,
+    /// "sent" or "recv"
+    #[serde(default)]
+    pub direction: Option<String>,
+    pub predicate: Predicate,
+    /// The commitment to the value, in the proof system's encoding
+    #[serde(default)]
+    pub commitment: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Bundle {
+    /// The proof system of the claims, e.g. "authdecode"
+    pub system: String,
+    pub claims: Vec<Claim>,
+}
+
+/// The zero-knowledge claims of a proof, `None` if it has none. Claims which do not parse
+/// are reported as an error, rather than dropped silently.
+pub fn bundle(proof: &Value) -> Option<Result<Bundle, String>> {
+    let zk = proof.get("zk")?;
+    Some(Bundle::deserialize(zk).map_err(|err| err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn claims() {
+        let proof = |json_str: &str| serde_json::from_str::<Value>(json_str).unwrap();
+        let json_str = r#"{"session": {}, "zk": {"system": "authdecode", "claims": [
+            {"field": "$.balance", "direction": "recv", "predicate": {"op": ">=", "value": 1000}},
+            {"field": "$.country", "predicate": {"op": "!=", "value": "XX"}, "commitment": "ab"}
+        ]}}"#;
+        let zk = bundle(&proof(json_str)).unwrap().unwrap();
+        assert_eq!(zk.system, "authdecode");
+        let predicates: Vec<String> = zk
+            .claims
+            .iter()
+            .map(|claim| format!("{} {}", claim.field, claim.predicate))
+            .collect();
+        assert_eq!(predicates, ["$.balance ≥ 1000", "$.country ≠ XX"]);
+
+        assert_eq!(bundle(&proof(r#"{"session": {}}"#)), None);
+        let unknown_op = r#"{"zk": {"system": "x", "claims": [{"field": "a", "predicate": {"op": "~", "value": 1}}]}}"#;
+        assert!(bundle(&proof(unknown_op)).unwrap().is_err());
+    }
+}
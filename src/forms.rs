@@ -0,0 +1,227 @@
+//! Decoding of HTML form submissions: `application/x-www-form-urlencoded` and
+//! `multipart/form-data` bodies.
+
+use std::ops::Range;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormField {
+    pub name: String,
+    pub value: String,
+    /// Position of the encoded value in the body
+    pub value_range: Range<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Part {
+    pub name: Option<String>,
+    pub filename: Option<String>,
+    pub content_type: Option<String>,
+    /// Position of the part headers in the body
+    pub headers: Range<usize>,
+    /// Position of the part content in the body
+    pub content: Range<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Form {
+    UrlEncoded(Vec<FormField>),
+    Multipart(Vec<Part>),
+}
+
+/// Decode `+` and `%XX` escapes, invalid escapes are kept as is
+fn percent_decode(bytes: &[u8]) -> String {
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'+', _) => decoded.push(b' '),
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 2;
+            }
+            (byte, _) => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+fn decode_urlencoded(body: &[u8]) -> Vec<FormField> {
+    let mut fields = Vec::new();
+    let mut start = 0;
+    for end in (0..=body.len()).filter(|&i| i == body.len() || body[i] == b'&') {
+        let pair = &body[start..end];
+        if !pair.is_empty() {
+            let (name, value_start) = match pair.iter().position(|&b| b == b'=') {
+                Some(eq) => (&pair[..eq], start + eq + 1),
+                None => (pair, end),
+            };
+            fields.push(FormField {
+                name: percent_decode(name),
+                value: percent_decode(&body[value_start..end]),
+                value_range: value_start..end,
+            });
+        }
+        start = end + 1;
+    }
+    fields
+}
+
+/// The value of a `key=value` parameter of a header like `Content-Type` or `Content-Disposition`
+pub fn header_param(header: &str, key: &str) -> Option<String> {
+    header.split(';').skip(1).find_map(|param| {
+        let (k, v) = param.split_once('=')?;
+        k.trim()
+            .eq_ignore_ascii_case(key)
+            .then(|| v.trim().trim_matches('"').to_string())
+    })
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|pos| from + pos)
+}
+
+/// The parts of a `multipart/*` body, as in form submissions and MIME email
+pub fn decode_multipart(body: &[u8], boundary: &str) -> Vec<Part> {
+    let delimiter = format!("--{}", boundary).into_bytes();
+    let mut parts = Vec::new();
+
+    let mut pos = match find(body, &delimiter, 0) {
+        Some(pos) => pos,
+        None => return parts,
+    };
+    loop {
+        let start = pos + delimiter.len();
+        // the close delimiter ends with `--`
+        if body[start..].starts_with(b"--") {
+            break;
+        }
+        let start = match find(body, b"\r\n", start) {
+            Some(line_end) => line_end + 2,
+            None => break,
+        };
+        let next = match find(body, &delimiter, start) {
+            Some(next) => next,
+            None => break,
+        };
+        // the CRLF before the delimiter belongs to the delimiter
+        let end = if body[..next].ends_with(b"\r\n") {
+            next - 2
+        } else {
+            next
+        };
+
+        let header_end = find(&body[..end], b"\r\n\r\n", start).map_or(end, |pos| pos + 4);
+        let headers = String::from_utf8_lossy(&body[start..header_end]);
+        let header = |name: &str| {
+            headers.lines().find_map(|line| {
+                let (k, v) = line.split_once(':')?;
+                k.trim()
+                    .eq_ignore_ascii_case(name)
+                    .then(|| v.trim().to_string())
+            })
+        };
+        let disposition = header("content-disposition").unwrap_or_default();
+        parts.push(Part {
+            name: header_param(&disposition, "name"),
+            filename: header_param(&disposition, "filename"),
+            content_type: header("content-type"),
+            headers: start..header_end,
+            content: header_end..end,
+        });
+        pos = next;
+    }
+    parts
+}
+
+/// Decode a form body according to its `Content-Type`, `None` if it is not a form
+pub fn decode(content_type: &str, body: &[u8]) -> Option<Form> {
+    let mime = content_type.split(';').next()?.trim().to_ascii_lowercase();
+    match mime.as_str() {
+        "application/x-www-form-urlencoded" => Some(Form::UrlEncoded(decode_urlencoded(body))),
+        "multipart/form-data" => {
+            let boundary = header_param(content_type, "boundary")?;
+            Some(Form::Multipart(decode_multipart(body, &boundary)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTIPART: &[u8] = b"--XyZ\r\nContent-Disposition: form-data; name=\"user\"\r\n\r\nalice\r\n--XyZ\r\nContent-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\nContent-Type: text/plain\r\n\r\nhello\r\n--XyZ--\r\n";
+
+    fn urlencoded(body: &[u8]) -> Vec<(String, String, Range<usize>)> {
+        let Some(Form::UrlEncoded(fields)) =
+            decode("application/x-www-form-urlencoded; charset=UTF-8", body)
+        else {
+            panic!("the body is urlencoded")
+        };
+        fields
+            .into_iter()
+            .map(|field| (field.name, field.value, field.value_range))
+            .collect()
+    }
+
+    fn field(name: &str, value: &str, range: Range<usize>) -> (String, String, Range<usize>) {
+        (name.to_string(), value.to_string(), range)
+    }
+
+    #[test]
+    fn urlencoded_fields() {
+        assert_eq!(
+            urlencoded(b"name=J%C3%BCrgen+M&empty=&flag&&bad=%zz%4"),
+            [
+                field("name", "Jürgen M", 5..18),
+                field("empty", "", 25..25),
+                field("flag", "", 30..30),
+                field("bad", "%zz%4", 36..41),
+            ]
+        );
+        assert_eq!(urlencoded(b"a%20b=%3D%26"), [field("a b", "=&", 6..12)]);
+        assert!(urlencoded(b"").is_empty());
+    }
+
+    #[test]
+    fn multipart_parts() {
+        let content_type = "multipart/form-data; boundary=\"XyZ\"";
+        let Some(Form::Multipart(parts)) = decode(content_type, MULTIPART) else {
+            panic!("the body is multipart")
+        };
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].name.as_deref(), Some("user"));
+        assert_eq!(&MULTIPART[parts[0].content.clone()], b"alice");
+        assert_eq!(parts[1].name.as_deref(), Some("file"));
+        assert_eq!(parts[1].filename.as_deref(), Some("a.txt"));
+        assert_eq!(parts[1].content_type.as_deref(), Some("text/plain"));
+        assert_eq!(&MULTIPART[parts[1].content.clone()], b"hello");
+        assert!(MULTIPART[parts[1].headers.clone()].starts_with(b"Content-Disposition"));
+
+        assert_eq!(decode("multipart/form-data", MULTIPART), None);
+        assert_eq!(decode("text/plain", MULTIPART), None);
+    }
+
+    #[test]
+    fn truncated_multipart() {
+        // the part without a delimiter after it is incomplete and left out
+        let truncated = &MULTIPART[..MULTIPART.len() - 12];
+        let parts = decode_multipart(truncated, "XyZ");
+        assert_eq!(parts.len(), 1);
+        assert_eq!(&truncated[parts[0].content.clone()], b"alice");
+
+        for end in [0, 5, 20] {
+            assert!(decode_multipart(&MULTIPART[..end], "XyZ").is_empty());
+        }
+        assert!(decode_multipart(MULTIPART, "other").is_empty());
+    }
+}
@@ -0,0 +1,188 @@
+//! The handoff of proofs from browser extensions, e.g. a "verify" button in the TLSNotary
+//! extension.
+//!
+//! The extension opens `?handoff=1`, the page then announces itself to its opener (or, for a tab
+//! opened with `chrome.tabs.create`, to the extension's content script in the page itself) with
+//! `{ type: "proof_viz:ready", version, formats, encodings }`. The extension answers with the
+//! `proof_viz:proof` message of `embed`, optionally with the `version` of the protocol it speaks,
+//! the `format` of the proof and its `encoding`. Proofs it cannot take are answered with
+//! `{ type: "proof_viz:error", error }`.
+//!
+//! Small proofs can also be handed over in the URL, as `#proof=` and the base64url encoded JSON.
+
+use base64::{engine::general_purpose, Engine as _};
+use gloo::utils::window;
+use serde_json::{json, Value};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Window;
+
+use crate::formats::SUPPORTED_VERSION;
+
+pub const PROTOCOL_VERSION: u64 = 1;
+/// Type of the message announcing the page is ready to receive a proof
+pub const READY_MESSAGE_TYPE: &str = "proof_viz:ready";
+/// Type of the reply to a proof which could not be taken
+pub const ERROR_MESSAGE_TYPE: &str = "proof_viz:error";
+const ENCODINGS: [&str; 2] = ["json", "base64"];
+const FRAGMENT_PREFIX: &str = "#proof=";
+
+/// Is this the origin of one of the given browser extensions, which may hand over proofs in
+/// handoff mode?
+pub fn is_extension_origin(origin: &str, extension_ids: &[String]) -> bool {
+    [
+        "chrome-extension://",
+        "moz-extension://",
+        "safari-web-extension://",
+    ]
+    .iter()
+    .filter_map(|scheme| origin.strip_prefix(scheme))
+    .any(|id| extension_ids.iter().any(|allowed| allowed == id))
+}
+
+pub fn ready_message() -> Value {
+    json!({
+        "type": READY_MESSAGE_TYPE,
+        "version": PROTOCOL_VERSION,
+        "formats": [SUPPORTED_VERSION],
+        "encodings": ENCODINGS,
+    })
+}
+
+pub fn error_message(error: &str) -> Value {
+    json!({ "type": ERROR_MESSAGE_TYPE, "error": error })
+}
+
+/// Tell the opener, or a content script, that the page is ready for a proof
+pub fn announce_ready() {
+    let Ok(message) = js_sys::JSON::parse(&ready_message().to_string()) else {
+        return;
+    };
+    let window = window();
+    // The announcement holds no secrets, any opener may receive it
+    if let Ok(opener) = window.opener() {
+        if !opener.is_null() && !opener.is_undefined() {
+            let opener: Window = opener.unchecked_into();
+            let _ = opener.post_message(&message, "*");
+        }
+    }
+    if let Ok(origin) = window.location().origin() {
+        let _ = window.post_message(&message, &origin);
+    }
+}
+
+/// The proof of a `proof_viz:proof` message, after negotiating the protocol version, format
+/// and encoding
+pub fn decode(message: &Value) -> Result<Vec<u8>, String> {
+    if let Some(version) = message.get("version") {
+        match version.as_u64() {
+            Some(version) if version <= PROTOCOL_VERSION => {}
+            _ => {
+                return Err(format!(
+                    "unsupported protocol version {}, this verifier speaks version {}",
+                    version, PROTOCOL_VERSION
+                ))
+            }
+        }
+    }
+    if let Some(format) = message.get("format") {
+        if format.as_str() != Some(SUPPORTED_VERSION) {
+            return Err(format!(
+                "unsupported proof format {}, this verifier supports {}",
+                format, SUPPORTED_VERSION
+            ));
+        }
+    }
+    let proof = message.get("proof").ok_or("the message has no proof")?;
+    match message.get("encoding").and_then(Value::as_str) {
+        None | Some("json") => Ok(match proof {
+            Value::String(proof) => proof.as_bytes().to_vec(),
+            proof => proof.to_string().into_bytes(),
+        }),
+        Some("base64") => {
+            let proof = proof
+                .as_str()
+                .ok_or("a base64 encoded proof has to be a string")?;
+            general_purpose::STANDARD
+                .decode(proof.trim())
+                .map_err(|err| format!("invalid base64: {}", err))
+        }
+        Some(encoding) => Err(format!(
+            "unsupported encoding {}, use one of {}",
+            encoding,
+            ENCODINGS.join(", ")
+        )),
+    }
+}
+
+/// The proof in a `#proof=` fragment
+pub fn fragment_proof(fragment: &str) -> Option<Vec<u8>> {
+    let encoded = fragment.strip_prefix(FRAGMENT_PREFIX)?;
+    general_purpose::URL_SAFE_NO_PAD
+        .decode(encoded.trim_end_matches('='))
+        .ok()
+}
+
+/// The proof in the fragment of the current URL, which is removed from the address bar so
+/// reloading or sharing the page does not carry the proof along
+pub fn take_fragment_proof() -> Option<Vec<u8>> {
+    let window = window();
+    let proof = fragment_proof(&window.location().hash().ok()?)?;
+    let location = window.location();
+    let url = format!(
+        "{}{}",
+        location.pathname().unwrap_or_default(),
+        location.search().unwrap_or_default()
+    );
+    if let Ok(history) = window.history() {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url));
+    }
+    Some(proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiation() {
+        let proof = json!({ "session": {} });
+        let message = |extra: Value| {
+            let mut message = json!({ "type": "proof_viz:proof", "proof": proof });
+            message
+                .as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            message
+        };
+        assert_eq!(
+            decode(&message(json!({}))).unwrap(),
+            proof.to_string().as_bytes()
+        );
+        let supported = json!({ "version": 1, "format": SUPPORTED_VERSION, "encoding": "json" });
+        assert!(decode(&message(supported)).is_ok());
+        assert!(decode(&message(json!({ "version": 2 }))).is_err());
+        assert!(decode(&message(json!({ "format": "v0.1.0-alpha.7" })))
+            .unwrap_err()
+            .contains(SUPPORTED_VERSION));
+        assert!(decode(&message(json!({ "encoding": "cbor" }))).is_err());
+
+        let encoded = general_purpose::STANDARD.encode(b"{}");
+        let base64 = json!({ "type": "proof_viz:proof", "proof": encoded, "encoding": "base64" });
+        assert_eq!(decode(&base64).unwrap(), b"{}");
+    }
+
+    #[test]
+    fn fragments() {
+        let encoded = general_purpose::URL_SAFE_NO_PAD.encode(b"{\"a\":1}");
+        assert_eq!(
+            fragment_proof(&format!("#proof={}", encoded)).unwrap(),
+            b"{\"a\":1}"
+        );
+        assert_eq!(fragment_proof("#recv:0-10"), None);
+        let ids = ["abcdef".to_string()];
+        assert!(is_extension_origin("chrome-extension://abcdef", &ids));
+        assert!(!is_extension_origin("chrome-extension://ghijkl", &ids));
+        assert!(!is_extension_origin("chrome-extension://abcdef", &[]));
+        assert!(!is_extension_origin("https://abcdef", &ids));
+    }
+}
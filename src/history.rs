@@ -0,0 +1,86 @@
+//! A persisted log of the proofs verified in this browser.
+//!
+//! Only a digest of each verification is stored, never the proof itself.
+
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+use crate::verify::{VerifiedOutput, VerifyError};
+
+const STORAGE_KEY: &str = "proof_viz.history";
+// Keep localStorage usage bounded, the oldest entries are dropped first
+const MAX_ENTRIES: usize = 200;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub file_name: String,
+    /// Hex encoded sha256 of the proof file
+    pub proof_hash: String,
+    pub server_name: Option<String>,
+    /// Notarization time
+    pub time: Option<String>,
+    /// When the proof was verified, RFC 3339
+    pub verified_at: String,
+    /// `None` if the proof verified successfully
+    pub error: Option<String>,
+}
+
+impl HistoryEntry {
+    /// The entry of a verification of the proof whose file has the SHA-256 `proof_hash`
+    pub fn new(
+        file_name: &str,
+        proof_hash: &str,
+        result: &Result<VerifiedOutput, VerifyError>,
+    ) -> Self {
+        let (server_name, time, error) = match result {
+            Ok(verified) => (
+                Some(verified.server_name.clone()),
+                Some(verified.time.to_string()),
+                None,
+            ),
+            Err(error) => (None, None, Some(error.to_string())),
+        };
+        HistoryEntry {
+            file_name: file_name.to_string(),
+            proof_hash: proof_hash.to_string(),
+            server_name,
+            time,
+            verified_at: chrono::Utc::now().to_rfc3339(),
+            error,
+        }
+    }
+
+    /// Was the same proof verified before with a different outcome?
+    pub fn conflicts_with(&self, entries: &[HistoryEntry]) -> bool {
+        entries
+            .iter()
+            .any(|other| other.proof_hash == self.proof_hash && other.error != self.error)
+    }
+}
+
+pub fn load() -> Vec<HistoryEntry> {
+    LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+}
+
+pub fn save(entries: &[HistoryEntry]) {
+    // A full or disabled localStorage only means the history is not kept
+    let _ = LocalStorage::set(STORAGE_KEY, entries);
+}
+
+pub fn clear() {
+    LocalStorage::delete(STORAGE_KEY);
+}
+
+/// Add an entry, a proof that is verified again with the same outcome only updates its time
+pub fn record(entries: &mut Vec<HistoryEntry>, entry: HistoryEntry) {
+    let same = entries.iter_mut().rev().find(|e| {
+        e.proof_hash == entry.proof_hash && e.file_name == entry.file_name && e.error == entry.error
+    });
+    match same {
+        Some(same) => same.verified_at = entry.verified_at,
+        None => entries.push(entry),
+    }
+    if entries.len() > MAX_ENTRIES {
+        entries.drain(..entries.len() - MAX_ENTRIES);
+    }
+}
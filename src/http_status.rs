@@ -0,0 +1,80 @@
+//! The HTTP status of the notarized responses. A proof only shows that a response was received,
+//! and a verified proof of a 404 or 500 response does not show that the request succeeded.
+
+use crate::exchanges;
+use crate::verify::VerifiedOutput;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusClass {
+    /// 1xx
+    Informational,
+    /// 2xx
+    Success,
+    /// 3xx
+    Redirect,
+    /// 4xx
+    ClientError,
+    /// 5xx
+    ServerError,
+    /// Not a three digit code, e.g. redacted
+    Unknown,
+}
+
+impl StatusClass {
+    pub fn of(code: &str) -> Self {
+        if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_digit()) {
+            return StatusClass::Unknown;
+        }
+        match code.as_bytes()[0] {
+            b'1' => StatusClass::Informational,
+            b'2' => StatusClass::Success,
+            b'3' => StatusClass::Redirect,
+            b'4' => StatusClass::ClientError,
+            b'5' => StatusClass::ServerError,
+            _ => StatusClass::Unknown,
+        }
+    }
+
+    pub fn is_error(self) -> bool {
+        matches!(self, StatusClass::ClientError | StatusClass::ServerError)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Status {
+    pub code: String,
+    pub reason: String,
+    pub class: StatusClass,
+}
+
+/// The statuses of the responses of `verified`, in order
+pub fn statuses(verified: &VerifiedOutput) -> Vec<Status> {
+    exchanges::split(verified)
+        .exchanges
+        .into_iter()
+        .filter_map(|exchange| exchange.response)
+        .map(|response| Status {
+            class: StatusClass::of(&response.code),
+            code: response.code,
+            reason: response.reason,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classes() {
+        assert_eq!(StatusClass::of("200"), StatusClass::Success);
+        assert_eq!(StatusClass::of("301"), StatusClass::Redirect);
+        assert_eq!(StatusClass::of("404"), StatusClass::ClientError);
+        assert_eq!(StatusClass::of("503"), StatusClass::ServerError);
+        assert_eq!(StatusClass::of("101"), StatusClass::Informational);
+        assert_eq!(StatusClass::of("XXX"), StatusClass::Unknown);
+        assert_eq!(StatusClass::of("2000"), StatusClass::Unknown);
+        assert!(StatusClass::of("429").is_error());
+        assert!(!StatusClass::of("304").is_error());
+    }
+}
@@ -0,0 +1,541 @@
+//! German translations, keyed by the English message.
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    (" (local)", " (lokal)"),
+    (" (select two proofs to compare them)", " (zwei Beweise zum Vergleichen auswählen)"),
+    (" ({count} rules)", " ({count} Regeln)"),
+    (" file: {name}", " Datei: {name}"),
+    (" of proofs) here", " mit Beweisen) hier ablegen"),
+    (" or click to select, or paste it", " oder zum Auswählen klicken, oder einfügen"),
+    (" proofs: it verifies the Notary's signature on the TLS session, the server certificate and the disclosed parts of the transcript, and shows what was sent and received.", "-Beweise: Sie prüft die Signatur des Notars über die TLS-Sitzung, das Serverzertifikat und die offengelegten Teile des Transkripts und zeigt, was gesendet und empfangen wurde."),
+    (" webpki roots + {count} custom", " webpki-Wurzeln + {count} eigene"),
+    ("(no request)", "(keine Anfrage)"),
+    ("(no response)", "(keine Antwort)"),
+    ("(unnamed part)", "(unbenannter Teil)"),
+    ("({count} certificates)", "({count} Zertifikate)"),
+    ("({count} lines differ)", "({count} Zeilen unterscheiden sich)"),
+    ("({size}B, redacted:{redacted}B)", "({size}B, geschwärzt:{redacted}B)"),
+    (", {count} B redacted", ", {count} B geschwärzt"),
+    (". A valid proof only shows that the Notary attested to the session, check that you trust the Notary key in use.", " angezeigt. Ein gültiger Beweis zeigt nur, dass der Notar die Sitzung bestätigt hat, prüfen Sie, ob Sie dem verwendeten Notarschlüssel vertrauen."),
+    ("A proof then no longer shows which server it came from. Use this only to test proofs of servers with self-signed or expired certificates.", "Ein Beweis zeigt dann nicht mehr, von welchem Server er stammt. Nur zum Testen von Beweisen für Server mit selbstsignierten oder abgelaufenen Zertifikaten verwenden."),
+    ("Add", "Hinzufügen"),
+    ("Add rule", "Regel hinzufügen"),
+    ("Advanced options", "Erweiterte Optionen"),
+    ("Algorithm: ", "Algorithmus: "),
+    ("Assertions", "Zusicherungen"),
+    ("Assertions:", "Zusicherungen:"),
+    ("Attributes", "Attribute"),
+    ("Body is not valid JSON: {error}", "Der Inhalt ist kein gültiges JSON: {error}"),
+    ("Certificate chain: ", "Zertifikatskette: "),
+    ("Certificates of private or internal CAs, to verify proofs of servers the webpki roots do not cover", "Zertifikate privater oder interner CAs, um Beweise für Server zu prüfen, die die webpki-Wurzeln nicht abdecken"),
+    ("Change Notary Public Key:", "Öffentlichen Notarschlüssel ändern:"),
+    ("Check TLSNotary proofs", "TLSNotary-Beweise prüfen"),
+    ("Check that you selected the proof JSON and not another file", "Prüfen Sie, ob Sie die Beweis-JSON und keine andere Datei ausgewählt haben"),
+    ("Clear", "Entfernen"),
+    ("Clear history", "Verlauf löschen"),
+    ("Close", "Schließen"),
+    ("Compare", "Vergleichen"),
+    ("Compare ", "Vergleich "),
+    ("Configured keys:", "Konfigurierte Schlüssel:"),
+    ("Copy hex", "Hex kopieren"),
+    ("Copy raw", "Roh kopieren"),
+    ("Could not read the certificates: {error}", "Die Zertifikate konnten nicht gelesen werden: {error}"),
+    ("Drop your \"", "Legen Sie Ihre Datei \""),
+    ("Encoder seed", "Encoder-Seed"),
+    ("Error details", "Fehlerdetails"),
+    ("Everything happens locally in your browser, proofs are never uploaded. Only a digest of each verification is kept, in your browser's local storage, for the history page.", "Alles geschieht lokal in Ihrem Browser, Beweise werden nie hochgeladen. Nur eine Zusammenfassung jeder Prüfung wird für den Verlauf im lokalen Speicher Ihres Browsers aufbewahrt."),
+    ("Expected server name:", "Erwarteter Servername:"),
+    ("Failed to fetch the proof: {error}", "Der Beweis konnte nicht geladen werden: {error}"),
+    ("Failed to unpack \"{file}\": {error}", "\"{file}\" konnte nicht entpackt werden: {error}"),
+    ("File", "Datei"),
+    ("For a private CA, add its root certificate in the advanced options", "Fügen Sie für eine private CA deren Wurzelzertifikat in den erweiterten Optionen hinzu"),
+    ("For self-signed certificates, disable certificate verification in the advanced options", "Deaktivieren Sie für selbstsignierte Zertifikate die Zertifikatsprüfung in den erweiterten Optionen"),
+    ("For testing, disable certificate verification in the advanced options", "Deaktivieren Sie zum Testen die Zertifikatsprüfung in den erweiterten Optionen"),
+    ("Form fields:", "Formularfelder:"),
+    ("Generate the proof again with tlsn {supported}, or verify it with a verifier for {format}", "Erzeugen Sie den Beweis erneut mit tlsn {supported} oder prüfen Sie ihn mit einem Prüfer für {format}"),
+    ("GraphQL query:", "GraphQL-Abfrage:"),
+    ("HTTP exchanges ({count}):", "HTTP-Austausche ({count}):"),
+    ("Handshake commitment", "Handshake-Commitment"),
+    ("Handshake time", "Handshake-Zeit"),
+    ("History ({count} verifications)", "Verlauf ({count} Prüfungen)"),
+    ("Inline", "Untereinander"),
+    ("Intermediate / root certificate", "Zwischen- / Wurzelzertifikat"),
+    ("Invalid Proof", "Ungültiger Beweis"),
+    ("Invalid policy: {error}", "Ungültige Richtlinie: {error}"),
+    ("Issuer", "Aussteller"),
+    ("Key", "Schlüssel"),
+    ("Keys", "Schlüssel"),
+    ("Keys recovered from the signature:", "Aus der Signatur wiederhergestellte Schlüssel:"),
+    ("Language", "Sprache"),
+    ("Looks like a session credential and is fully disclosed", "Sieht nach Sitzungszugangsdaten aus und ist vollständig offengelegt"),
+    ("Menu", "Menü"),
+    ("Merkle root", "Merkle-Wurzel"),
+    ("Multipart form ({count} parts):", "Multipart-Formular ({count} Teile):"),
+    ("No body", "Kein Inhalt"),
+    ("No match", "Kein Treffer"),
+    ("No proof with hash {hash} was verified in this browser, load the proof to verify it", "In diesem Browser wurde kein Beweis mit dem Hash {hash} geprüft, laden Sie den Beweis, um ihn zu prüfen"),
+    ("No proofs loaded yet, ", "Noch keine Beweise geladen, "),
+    ("No proofs verified in this browser yet", "In diesem Browser wurden noch keine Beweise geprüft"),
+    ("No response", "Keine Antwort"),
+    ("No variables", "Keine Variablen"),
+    ("Not a valid JWT: {error}", "Kein gültiges JWT: {error}"),
+    ("Not a valid certificate: {error}", "Kein gültiges Zertifikat: {error}"),
+    ("Not valid JSON: {error}", "Kein gültiges JSON: {error}"),
+    ("Notarization time", "Notarisierungszeit"),
+    ("Notarization time is in the future, check your clock or the Notary's", "Die Notarisierungszeit liegt in der Zukunft, prüfen Sie Ihre Uhr oder die des Notars"),
+    ("Notarization time:", "Notarisierungszeit:"),
+    ("Notary signature", "Notarsignatur"),
+    ("One of these made the signature, if none is configured the proof was signed by another Notary", "Einer davon hat signiert, ist keiner konfiguriert, wurde der Beweis von einem anderen Notar signiert"),
+    ("Only proofs of servers with a valid certificate can be fully verified", "Nur Beweise von Servern mit gültigem Zertifikat können vollständig geprüft werden"),
+    ("PEM, or a hex/base64 encoded DER public key, SEC1 point or raw Ed25519 key", "PEM oder ein hex/base64-kodierter DER-Schlüssel, SEC1-Punkt oder roher Ed25519-Schlüssel"),
+    ("Page not found, ", "Seite nicht gefunden, "),
+    ("Parsing failed", "Lesen fehlgeschlagen"),
+    ("Policy compliance:", "Einhaltung der Richtlinie:"),
+    ("Policy: ", "Richtlinie: "),
+    ("Privacy warnings:", "Datenschutzwarnungen:"),
+    ("Processing...", "Wird verarbeitet..."),
+    ("Proof hash", "Beweis-Hash"),
+    ("Proof is older than {days} days", "Der Beweis ist älter als {days} Tage"),
+    ("Proof:", "Beweis:"),
+    ("Proofs are verified with the {scheme} Notary key configured under ", "Beweise werden mit dem {scheme}-Notarschlüssel geprüft, konfiguriert unter "),
+    ("Query:", "Abfrage:"),
+    ("Raw preview (unsanitized, scripts and forms stay blocked by the sandbox)", "Rohvorschau (unbereinigt, Skripte und Formulare bleiben durch die Sandbox blockiert)"),
+    ("Raw proof", "Roher Beweis"),
+    ("Received HTML content:", "Empfangener HTML-Inhalt:"),
+    ("Received JSON content:", "Empfangener JSON-Inhalt:"),
+    ("Received PDF:", "Empfangenes PDF:"),
+    ("Received bytes", "Empfangene Bytes"),
+    ("Received image:", "Empfangenes Bild:"),
+    ("Redacted bytes are shown as ", "Geschwärzte Bytes werden als "),
+    ("Reduced assurance: certificate verification is disabled in the advanced options, so nothing proves this session was with the real ", "Eingeschränkte Sicherheit: Die Zertifikatsprüfung ist in den erweiterten Optionen deaktiviert, daher beweist nichts, dass diese Sitzung mit dem echten Server stattfand: "),
+    ("Reduced assurance: only the Notary signature and the handshake commitment are checked. ", "Eingeschränkte Sicherheit: Nur die Notarsignatur und das Handshake-Commitment werden geprüft. "),
+    ("Remove", "Entfernen"),
+    ("Request body:", "Anfrageinhalt:"),
+    ("Response body:", "Antwortinhalt:"),
+    ("Response data:", "Antwortdaten:"),
+    ("Result", "Ergebnis"),
+    ("Search {direction} bytes", "{direction} Bytes durchsuchen"),
+    ("Select to compare", "Zum Vergleichen auswählen"),
+    ("Sent bytes", "Gesendete Bytes"),
+    ("Serial", "Seriennummer"),
+    ("Server certificate", "Serverzertifikat"),
+    ("Server domain", "Serverdomain"),
+    ("Server domain:", "Serverdomain:"),
+    ("Server ephemeral key", "Ephemerer Serverschlüssel"),
+    ("Server key group", "Gruppe des Serverschlüssels"),
+    ("Side by side", "Nebeneinander"),
+    ("Signature: ", "Signatur: "),
+    ("Signature: {count} bytes", "Signatur: {count} Bytes"),
+    ("Skip certificate chain verification", "Prüfung der Zertifikatskette überspringen"),
+    ("Stop scanning", "Scannen beenden"),
+    ("Subject", "Inhaber"),
+    ("Technical details", "Technische Details"),
+    ("The Notary signature does not match the Notary public key", "Die Notarsignatur passt nicht zum öffentlichen Notarschlüssel"),
+    ("The Notary signature panel shows which known key, if any, made the signature", "Der Bereich Notarsignatur zeigt, welcher bekannte Schlüssel signiert hat, falls einer"),
+    ("The QR code is not a proof URL or hash: {text}", "Der QR-Code ist keine Beweis-URL und kein Hash: {text}"),
+    ("The disclosed transcript does not match what the Notary signed", "Das offengelegte Transkript passt nicht zu dem, was der Notar signiert hat"),
+    ("The file is not a valid TLSNotary proof", "Die Datei ist kein gültiger TLSNotary-Beweis"),
+    ("The file is not a valid TLSNotary proof (problem at line {line}, column {column})", "Die Datei ist kein gültiger TLSNotary-Beweis (Problem in Zeile {line}, Spalte {column})"),
+    ("The proof could not be verified", "Der Beweis konnte nicht geprüft werden"),
+    ("The proof has no `{field}` field, it may be truncated or made by another tlsn version", "Der Beweis hat kein Feld `{field}`, er ist vielleicht abgeschnitten oder mit einer anderen tlsn-Version erstellt"),
+    ("The proof may claim another server than the one the Prover talked to", "Der Beweis gibt vielleicht einen anderen Server an als den, mit dem der Prover kommuniziert hat"),
+    ("The proof was modified after notarization, do not trust its content", "Der Beweis wurde nach der Notarisierung verändert, vertrauen Sie seinem Inhalt nicht"),
+    ("The query is redacted", "Die Abfrage ist geschwärzt"),
+    ("The server certificate expired on {not_after} before the session", "Das Serverzertifikat ist am {not_after} vor der Sitzung abgelaufen"),
+    ("The server certificate is not issued by a trusted certificate authority", "Das Serverzertifikat wurde nicht von einer vertrauenswürdigen Zertifizierungsstelle ausgestellt"),
+    ("The server certificate is not valid for the server name in the proof", "Das Serverzertifikat ist für den Servernamen im Beweis nicht gültig"),
+    ("The server certificate was not valid at the time of the session", "Das Serverzertifikat war zum Zeitpunkt der Sitzung nicht gültig"),
+    ("This page checks ", "Diese Seite prüft "),
+    ("This proof was generated with {format}; this verifier supports {supported}", "Dieser Beweis wurde mit {format} erzeugt; dieser Prüfer unterstützt {supported}"),
+    ("This proof was verified before with a different result", "Dieser Beweis wurde schon einmal mit anderem Ergebnis geprüft"),
+    ("Toggle dark/light theme", "Zwischen dunklem und hellem Design wechseln"),
+    ("Trusted root certificates:", "Vertrauenswürdige Wurzelzertifikate:"),
+    ("Valid until", "Gültig bis"),
+    ("Validity", "Gültigkeit"),
+    ("Value", "Wert"),
+    ("Variables:", "Variablen:"),
+    ("Verified as a {format} proof", "Als {format}-Beweis geprüft"),
+    ("Verified at", "Geprüft am"),
+    ("Verified proof", "Geprüfter Beweis"),
+    ("Warn when older than", "Warnen, wenn älter als"),
+    ("Wrong Notary key? Select the key of the Notary that signed this proof", "Falscher Notarschlüssel? Wählen Sie den Schlüssel des Notars, der diesen Beweis signiert hat"),
+    ("Your browser can not display PDF files.", "Ihr Browser kann keine PDF-Dateien anzeigen."),
+    ("Your file will not be uploaded", "Ihre Datei wird nicht hochgeladen"),
+    ("\" file (or a ", "\" (oder ein "),
+    ("\"{file}\" failed verification on {date}: {error}", "Die Prüfung von \"{file}\" ist am {date} fehlgeschlagen: {error}"),
+    ("\"{file}\" was verified on {date}: {server}", "\"{file}\" wurde am {date} geprüft: {server}"),
+    ("bytes {start}..{end}", "Bytes {start}..{end}"),
+    ("bytes {start}..{end} ({count} bytes {label})", "Bytes {start}..{end} ({count} Bytes {label})"),
+    ("checked locally in the browser", "lokal im Browser geprüft"),
+    ("days", "Tage"),
+    ("differs", "unterschiedlich"),
+    ("disclosed", "offengelegt"),
+    ("drop or paste a JSON policy file", "JSON-Richtliniendatei ablegen oder einfügen"),
+    ("no matches", "keine Treffer"),
+    ("not valid UTF-8 from byte {offset}", "kein gültiges UTF-8 ab Byte {offset}"),
+    ("of a proof URL or a proof hash", "einer Beweis-URL oder eines Beweis-Hashes"),
+    ("only in this proof", "nur in diesem Beweis"),
+    ("partially redacted", "teilweise geschwärzt"),
+    ("redacted", "geschwärzt"),
+    ("redacted here only", "nur hier geschwärzt"),
+    ("redacted in the other proof only", "nur im anderen Beweis geschwärzt"),
+    ("redacted, {count} bytes", "geschwärzt, {count} Bytes"),
+    ("upload a proof", "Beweis hochladen"),
+    ("valid UTF-8", "gültiges UTF-8"),
+    ("{count} bytes", "{count} Bytes"),
+    ("{count} proofs loaded", "{count} Beweise geladen"),
+    ("{count} trailing bytes could not be parsed as HTTP", "{count} Bytes am Ende konnten nicht als HTTP gelesen werden"),
+    ("{direction} bytes {start}..{end}", "{direction} Bytes {start}..{end}"),
+    ("⚠️ Notary signature verified, certificate chain NOT checked ⚠️", "⚠️ Notarsignatur geprüft, Zertifikatskette NICHT geprüft ⚠️"),
+    ("⚠️ The token signatures are NOT verified, the Notary only attests that these bytes were exchanged with the server", "⚠️ Die Token-Signaturen werden NICHT geprüft, der Notar bestätigt nur, dass diese Bytes mit dem Server ausgetauscht wurden"),
+    ("⚠️ Verified without certificate check", "⚠️ Ohne Zertifikatsprüfung geprüft"),
+    ("⚠️ result changed", "⚠️ Ergebnis geändert"),
+    ("✅ Proof successfully verified ✅", "✅ Beweis erfolgreich geprüft ✅"),
+    ("✅ Verified", "✅ Geprüft"),
+    ("✅ valid at notarization time", "✅ gültig zur Notarisierungszeit"),
+    ("❌ Assertions failed", "❌ Zusicherungen nicht erfüllt"),
+    ("❌ Failed", "❌ Fehlgeschlagen"),
+    ("❌ Invalid proof", "❌ Ungültiger Beweis"),
+    ("❌ Parsing failed", "❌ Lesen fehlgeschlagen"),
+    ("❌ not valid at notarization time", "❌ nicht gültig zur Notarisierungszeit"),
+    ("📷 Scan a QR code", "📷 QR-Code scannen"),
+    ("🔗 Copy link", "🔗 Link kopieren"),
+    ("🕵️ Your proof is ", "🕵️ Ihr Beweis wird "),
+    ("Verify", "Prüfen"),
+    ("History", "Verlauf"),
+    ("About", "Info"),
+    ("About TLSNotary", "Über TLSNotary"),
+    ("Source", "Quellcode"),
+    ("in use", "in Verwendung"),
+    ("sent", "gesendet"),
+    ("received", "empfangen"),
+    ("off", "aus"),
+    ("decimal", "dezimal"),
+    ("Sent bytes contain", "Gesendete Bytes enthalten"),
+    ("Received bytes contain", "Empfangene Bytes enthalten"),
+    ("Received bytes do not contain", "Empfangene Bytes enthalten nicht"),
+    ("Received body JSON field equals", "JSON-Feld des empfangenen Inhalts ist gleich"),
+    ("issuer", "Aussteller"),
+    ("subject, usually the user id", "Subjekt, meist die Benutzer-ID"),
+    ("audience", "Zielgruppe"),
+    ("expires at", "läuft ab am"),
+    ("not valid before", "nicht gültig vor"),
+    ("issued at", "ausgestellt am"),
+    ("token id", "Token-ID"),
+    ("email address", "E-Mail-Adresse"),
+    ("full name", "vollständiger Name"),
+    ("granted permissions", "gewährte Berechtigungen"),
+    (" … {count} items ", " … {count} Einträge "),
+    ("Camera preview", "Kameravorschau"),
+    ("Collapse", "Einklappen"),
+    ("Compare {file}", "{file} vergleichen"),
+    ("Expand", "Ausklappen"),
+    ("Expected text", "Erwarteter Text"),
+    ("JSON path", "JSON-Pfad"),
+    ("JSON policy", "JSON-Richtlinie"),
+    ("JSON policy file", "JSON-Richtliniendatei"),
+    ("Main", "Hauptnavigation"),
+    ("Next match", "Nächster Treffer"),
+    ("Notary public key", "Öffentlicher Notarschlüssel"),
+    ("Previous match", "Vorheriger Treffer"),
+    ("Received HTML content", "Empfangener HTML-Inhalt"),
+    ("Root certificate file", "Wurzelzertifikatsdatei"),
+    ("Root certificate in PEM format", "Wurzelzertifikat im PEM-Format"),
+    ("Rule", "Regel"),
+    ("Select proof files, or drop them here", "Beweisdateien auswählen oder hier ablegen"),
+    ("Selected bytes", "Ausgewählte Bytes"),
+    ("Warn when older than, in days", "Warnen, wenn älter als (in Tagen)"),
+    ("{count} bytes redacted", "{count} Bytes geschwärzt"),
+    ("{file}: verification failed, {error}", "{file}: Prüfung fehlgeschlagen, {error}"),
+    ("{file}: verified, {server}", "{file}: geprüft, {server}"),
+    ("Copy base64", "Base64 kopieren"),
+    ("Copy text", "Text kopieren"),
+    ("Copy {what}", "{what} kopieren"),
+    ("Notary key:", "Notarschlüssel:"),
+    ("SHA-256 of the DER encoded public key", "SHA-256 des DER-kodierten öffentlichen Schlüssels"),
+    ("the Notary key fingerprint", "Fingerabdruck des Notarschlüssels"),
+    ("the notarization time", "Notarisierungszeit"),
+    ("the raw bytes as base64", "Rohbytes als Base64"),
+    ("the raw bytes as hex", "Rohbytes als Hex"),
+    ("the selected bytes as hex", "ausgewählte Bytes als Hex"),
+    ("the selected text", "ausgewählten Text"),
+    ("the server domain", "Serverdomain"),
+    ("the visible text", "sichtbaren Text"),
+    ("⚠️ This transcript is {size} bytes large, rendering it may make the page unresponsive.", "⚠️ Dieses Transkript ist {size} Bytes groß, seine Darstellung kann die Seite zum Stocken bringen."),
+    ("Render anyway", "Trotzdem anzeigen"),
+    ("Show more ({remaining} bytes left)", "Mehr anzeigen (noch {remaining} Bytes)"),
+    ("❔ Not a proof", "❔ Kein Beweis"),
+    ("Not a TLSNotary proof", "Kein TLSNotary-Beweis"),
+    ("This doesn't look like a TLSNotary proof, here is what was detected:", "Das sieht nicht nach einem TLSNotary-Beweis aus, erkannt wurde Folgendes:"),
+    ("The file is JSON.", "Die Datei ist JSON."),
+    ("The file is empty.", "Die Datei ist leer."),
+    ("The file has {size} bytes, more than the {max} bytes a proof may have.", "Die Datei hat {size} Bytes, mehr als die {max} Bytes, die ein Beweis haben darf."),
+    ("The file is a {format}.", "Die Datei ist ein {format}."),
+    ("The file is UTF-16 encoded text, proofs are UTF-8 encoded JSON.", "Die Datei ist UTF-16-kodierter Text, Beweise sind UTF-8-kodiertes JSON."),
+    ("The file is binary data, the byte at offset {offset} is not valid UTF-8.", "Die Datei enthält Binärdaten, das Byte an Offset {offset} ist kein gültiges UTF-8."),
+    ("The file is a PEM key or certificate. A Notary key can be set in the Notary key field instead.", "Die Datei ist ein PEM-Schlüssel oder -Zertifikat. Ein Notarschlüssel kann stattdessen im Feld für den Notarschlüssel gesetzt werden."),
+    ("The file is text, but not JSON.", "Die Datei ist Text, aber kein JSON."),
+    ("PNG image", "PNG-Bild"),
+    ("JPEG image", "JPEG-Bild"),
+    ("GIF image", "GIF-Bild"),
+    ("PDF document", "PDF-Dokument"),
+    ("ZIP archive", "ZIP-Archiv"),
+    ("gzip archive", "gzip-Archiv"),
+    ("Unix executable (ELF)", "Unix-Programm (ELF)"),
+    ("WebAssembly module", "WebAssembly-Modul"),
+    (" (uploaded {count}×)", " ({count}× hochgeladen)"),
+    ("Proof SHA-256:", "Beweis-SHA-256:"),
+    ("Canonical JSON", "Kanonisches JSON"),
+    ("SHA-256 of the proof file as uploaded", "SHA-256 der Beweisdatei wie hochgeladen"),
+    ("SHA-256 of the proof JSON without whitespace and with sorted keys, which survives reformatting", "SHA-256 des Beweis-JSON ohne Leerraum und mit sortierten Schlüsseln, bleibt bei Umformatierung gleich"),
+    ("the SHA-256 of the file", "SHA-256 der Datei"),
+    ("the SHA-256 of the canonical JSON", "SHA-256 des kanonischen JSON"),
+    ("Known Notaries:", "Bekannte Notare:"),
+    ("Custom key", "Eigener Schlüssel"),
+    ("Not a known Notary", "Kein bekannter Notar"),
+    ("⚠️ Key mismatch: possible MITM or rotated key", "⚠️ Schlüssel stimmt nicht überein: möglicher MITM-Angriff oder gewechselter Schlüssel"),
+    ("This key is not the one the registry lists for {name} ({url}). Someone may be intercepting the connection to the Notary, or the Notary rotated its key. Check with the Notary's operator before trusting proofs verified with it.", "Dieser Schlüssel ist nicht der, den das Verzeichnis für {name} ({url}) führt. Jemand könnte die Verbindung zum Notar abfangen, oder der Notar hat seinen Schlüssel gewechselt. Klären Sie das mit dem Betreiber des Notars, bevor Sie damit geprüften Beweisen vertrauen."),
+    ("Expected fingerprint: {fingerprint}", "Erwarteter Fingerabdruck: {fingerprint}"),
+    ("Key in use: {fingerprint}", "Verwendeter Schlüssel: {fingerprint}"),
+    ("Verified with a test fixture key: its private key is public, so anyone can make such proofs", "Mit einem Test-Schlüssel geprüft: sein privater Schlüssel ist öffentlich, solche Beweise kann also jeder erstellen"),
+    ("TEST KEY", "TEST-SCHLÜSSEL"),
+    ("Verified with a test fixture key, this proof does not show anything about a real session", "Mit einem Test-Schlüssel geprüft, dieser Beweis sagt nichts über eine echte Sitzung aus"),
+    ("This is the TLSNotary test fixture key, only use it for proofs of a local development Notary", "Dies ist der TLSNotary-Test-Schlüssel, verwenden Sie ihn nur für Beweise eines lokalen Entwicklungs-Notars"),
+    ("the test fixture key, the default", "der Test-Schlüssel, die Voreinstellung"),
+    ("the default key of this deployment", "der voreingestellte Schlüssel dieser Installation"),
+    ("{name} ({url}), picked from the Notary registry", "{name} ({url}), aus dem Notar-Verzeichnis gewählt"),
+    ("a key entered by you", "ein von Ihnen eingegebener Schlüssel"),
+    ("⚠️ Not verified with the key of {pse}. Only trust this result if you trust the Notary whose key was used.", "⚠️ Nicht mit dem Schlüssel von {pse} geprüft. Vertrauen Sie diesem Ergebnis nur, wenn Sie dem Notar des verwendeten Schlüssels vertrauen."),
+    ("Key source: {source}", "Herkunft des Schlüssels: {source}"),
+    ("What was checked?", "Was wurde geprüft?"),
+    ("The file is a TLSNotary proof in a supported format", "Die Datei ist ein TLSNotary-Beweis in einem unterstützten Format"),
+    ("The Notary's signature over the session header verifies with the Notary key", "Die Signatur des Notars über den Sitzungs-Header ist mit dem Notarschlüssel gültig"),
+    ("The server certificate is issued for the server name in the proof", "Das Serverzertifikat ist für den Servernamen im Beweis ausgestellt"),
+    ("The server certificate chain leads to a trusted root (the webpki roots and your extra roots)", "Die Zertifikatskette des Servers führt zu einer vertrauenswürdigen Wurzel (den webpki-Wurzeln und Ihren zusätzlichen Wurzeln)"),
+    ("The Merkle openings of the disclosed transcript match the commitments in the session header", "Die Merkle-Öffnungen des offengelegten Transkripts passen zu den Commitments im Sitzungs-Header"),
+    ("Skipped: certificate verification is disabled in the advanced options", "Übersprungen: Die Zertifikatsprüfung ist in den erweiterten Optionen deaktiviert"),
+    ("Not checked, an earlier step failed", "Nicht geprüft, ein vorheriger Schritt ist fehlgeschlagen"),
+    ("Show the transcript the Prover claims anyway (UNVERIFIED)", "Das vom Prover behauptete Transkript trotzdem anzeigen (UNGEPRÜFT)"),
+    ("⚠️ UNVERIFIED: a check failed, nothing proves that this session with {server} took place or that the transcript is genuine", "⚠️ UNGEPRÜFT: Eine Prüfung ist fehlgeschlagen, nichts belegt, dass diese Sitzung mit {server} stattgefunden hat oder dass das Transkript echt ist"),
+    ("Request {number}", "Anfrage {number}"),
+    ("Response {number}", "Antwort {number}"),
+    ("Sent data which is not HTTP", "Gesendete Daten, die kein HTTP sind"),
+    ("Received data which is not HTTP", "Empfangene Daten, die kein HTTP sind"),
+    ("header {name}", "Header {name}"),
+    ("body field {path}", "Inhaltsfeld {path}"),
+    ("body", "Inhalt"),
+    ("request line or header names", "Anfragezeile oder Header-Namen"),
+    ("status line or header names", "Statuszeile oder Header-Namen"),
+    ("unparsed bytes", "nicht ausgewertete Bytes"),
+    ("What was redacted? ({count})", "Was wurde geschwärzt? ({count})"),
+    ("characters", "Zeichen"),
+    ("blocks", "Blöcke"),
+    ("collapsed", "eingeklappt"),
+    ("blurred", "verschwommen"),
+    ("Redactions:", "Schwärzungen:"),
+    ("Click to collapse", "Zum Einklappen klicken"),
+    ("Click to expand", "Zum Ausklappen klicken"),
+    ("«{count} bytes redacted»", "«{count} Bytes geschwärzt»"),
+    ("Wrap lines", "Zeilen umbrechen"),
+    ("Show whitespace", "Leerraum anzeigen"),
+    ("Tab width: {width}", "Tabulatorbreite: {width}"),
+    ("Offsets are only shown without wrapping", "Offsets werden nur ohne Zeilenumbruch angezeigt"),
+    ("🖨 Print report", "🖨 Bericht drucken"),
+    ("Proof SHA-256: {digest}", "Beweis-SHA-256: {digest}"),
+    ("Notary key: {scheme} {fingerprint}", "Notarschlüssel: {scheme} {fingerprint}"),
+    ("A badge to put next to a link to this proof, e.g. in a blog post", "Ein Badge, das neben einem Link zu diesem Beweis stehen kann, z. B. in einem Blogbeitrag"),
+    ("Download SVG", "SVG herunterladen"),
+    ("the SVG markup of the badge", "das SVG-Markup des Badges"),
+    ("Copy SVG", "SVG kopieren"),
+    ("Install the app, it works offline", "App installieren, sie funktioniert offline"),
+    ("Install", "Installieren"),
+    ("(during verification)", "(während der Prüfung)"),
+    ("(blocked)", "(blockiert)"),
+    ("🔒 No network requests were made during verification", "🔒 Während der Prüfung wurden keine Netzwerkanfragen gestellt"),
+    ("⚠️ {count} network requests were made during verification", "⚠️ Während der Prüfung wurden {count} Netzwerkanfragen gestellt"),
+    ("Every fetch and XMLHttpRequest of this page is recorded. Proofs are verified in your browser, they are never uploaded.", "Jeder fetch und jede XMLHttpRequest dieser Seite wird aufgezeichnet. Beweise werden in Ihrem Browser geprüft und nie hochgeladen."),
+    ("This page has not made any requests.", "Diese Seite hat keine Anfragen gestellt."),
+    ("Requests of this page ({count}):", "Anfragen dieser Seite ({count}):"),
+    ("Block all network requests", "Alle Netzwerkanfragen blockieren"),
+    ("Loading proofs by URL and refreshing the Notary registry then fail.", "Das Laden von Beweisen per URL und das Aktualisieren des Notar-Verzeichnisses schlagen dann fehl."),
+    ("Valid proof", "Gültiger Beweis"),
+    ("Tampered Notary signature", "Manipulierte Notarsignatur"),
+    ("Tampered transcript", "Manipuliertes Transkript"),
+    ("Server name not in the certificate", "Servername nicht im Zertifikat"),
+    ("Valid proof with another Notary's key", "Gültiger Beweis mit dem Schlüssel eines anderen Notars"),
+    ("✅ Valid", "✅ Gültig"),
+    ("❌ Fails: {step}", "❌ Scheitert: {step}"),
+    ("Self-test", "Selbsttest"),
+    ("Verifies proofs bundled with this page, one valid and others tampered with, to confirm that this deployment accepts valid proofs and rejects tampered ones. The verification options you set are not used.", "Prüft mit dieser Seite ausgelieferte Beweise, einen gültigen und mehrere manipulierte, um zu bestätigen, dass diese Installation gültige Beweise annimmt und manipulierte ablehnt. Ihre Prüfoptionen werden dabei nicht verwendet."),
+    ("Running…", "Läuft…"),
+    ("Run self-test", "Selbsttest starten"),
+    ("All {count} proofs are verified as expected", "Alle {count} Beweise werden wie erwartet geprüft"),
+    ("{failed} of {count} proofs are NOT verified as expected, do not trust this deployment", "{failed} von {count} Beweisen werden NICHT wie erwartet geprüft, vertrauen Sie dieser Installation nicht"),
+    ("Proof", "Beweis"),
+    ("Expected", "Erwartet"),
+    ("Actual", "Tatsächlich"),
+    ("To confirm that this deployment verifies correctly, ", "Um zu bestätigen, dass diese Installation richtig prüft, "),
+    ("run the self-test", "starten Sie den Selbsttest"),
+    ("Web page", "Webseite"),
+    ("A request to example.com, with parts of the response redacted", "Eine Anfrage an example.com, Teile der Antwort sind geschwärzt"),
+    ("The same proof with one disclosed byte changed, verification fails", "Derselbe Beweis mit einem geänderten offengelegten Byte, die Prüfung schlägt fehl"),
+    ("Wrong server name", "Falscher Servername"),
+    ("A proof for a server its certificate is not issued for", "Ein Beweis für einen Server, für den sein Zertifikat nicht ausgestellt ist"),
+    ("No proof at hand? Try an example", "Kein Beweis zur Hand? Probieren Sie ein Beispiel"),
+    ("The examples are signed with the TLSNotary test fixture key, select it under ", "Die Beispiele sind mit dem TLSNotary-Test-Schlüssel signiert, wählen Sie ihn unter "),
+    (" to verify them", " aus, um sie zu prüfen"),
+    ("🎓 Guided tour", "🎓 Geführte Tour"),
+    ("Guided tour", "Geführte Tour"),
+    ("Step {number} of {count}", "Schritt {number} von {count}"),
+    ("← Back", "← Zurück"),
+    ("Done", "Fertig"),
+    ("Next →", "Weiter →"),
+    ("– {check}: skipped", "– {check}: übersprungen"),
+    ("– {check}: not checked", "– {check}: nicht geprüft"),
+    ("Each disclosed range of bytes comes with a Merkle opening: the bytes, encoded, hash to a leaf of the Merkle tree whose root the Notary signed. So the bytes are exactly the ones exchanged with the server, the redacted ones stay hidden.", "Jeder offengelegte Bytebereich hat eine Merkle-Öffnung: Die kodierten Bytes ergeben gehasht ein Blatt des Merkle-Baums, dessen Wurzel der Notar signiert hat. Die Bytes sind also genau die mit dem Server ausgetauschten, die geschwärzten bleiben verborgen."),
+    ("{disclosed} of {total} bytes are disclosed.", "{disclosed} von {total} Bytes sind offengelegt."),
+    ("The Prover connected to {server} on {time}, with a Notary taking part in the TLS session without seeing its content.", "Der Prover hat sich am {time} mit {server} verbunden, wobei ein Notar an der TLS-Sitzung teilnahm, ohne ihren Inhalt zu sehen."),
+    ("The Prover connected to a server, with a Notary taking part in the TLS session without seeing its content.", "Der Prover hat sich mit einem Server verbunden, wobei ein Notar an der TLS-Sitzung teilnahm, ohne ihren Inhalt zu sehen."),
+    ("The proof", "Der Beweis"),
+    ("The proof has two parts: the session proof, which the Notary signed, and the substrings proof, which discloses parts of the transcript.", "Der Beweis hat zwei Teile: den Sitzungsbeweis, den der Notar signiert hat, und den Teilstring-Beweis, der Teile des Transkripts offenlegt."),
+    ("The session header", "Der Sitzungs-Header"),
+    ("This is what the Notary signed: the lengths of the transcript, the root of a Merkle tree over the encoded transcript, the time and a commitment to the TLS handshake. Everything else is checked against it.", "Das hat der Notar signiert: die Längen des Transkripts, die Wurzel eines Merkle-Baums über das kodierte Transkript, die Zeit und eine Verpflichtung auf den TLS-Handshake. Alles andere wird damit abgeglichen."),
+    ("The Notary's signature", "Die Signatur des Notars"),
+    ("The signature over the header was checked with the {scheme} Notary key {fingerprint}. It only means something if that key belongs to a Notary you trust.", "Die Signatur über den Header wurde mit dem {scheme}-Notarschlüssel {fingerprint} geprüft. Sie bedeutet nur etwas, wenn dieser Schlüssel einem Notar gehört, dem Sie vertrauen."),
+    ("The server's certificate", "Das Zertifikat des Servers"),
+    ("The handshake commitment opens to the certificate chain the server sent. It has to be issued for the server name and lead to a trusted root, like in a browser.", "Die Handshake-Verpflichtung öffnet sich zur Zertifikatskette, die der Server gesendet hat. Sie muss wie im Browser für den Servernamen ausgestellt sein und zu einer vertrauenswürdigen Wurzel führen."),
+    ("The bytes sent", "Die gesendeten Bytes"),
+    ("The bytes received", "Die empfangenen Bytes"),
+    ("What was redacted", "Was geschwärzt wurde"),
+    ("Redacted bytes are committed to in the Merkle tree but not disclosed: neither the Notary nor the proof reveals them. This lists which headers and fields they belong to.", "Geschwärzte Bytes sind im Merkle-Baum festgelegt, aber nicht offengelegt: Weder der Notar noch der Beweis gibt sie preis. Hier steht, zu welchen Headern und Feldern sie gehören."),
+    ("What was checked", "Was geprüft wurde"),
+    ("All checks at a glance. The proof is only valid if every one of them passed.", "Alle Prüfungen auf einen Blick. Der Beweis ist nur gültig, wenn jede bestanden wurde."),
+    ("Disclosed transcript", "Offengelegtes Transkript"),
+    ("{target} at {position}: {before} → {after}", "{target} an Position {position}: {before} → {after}"),
+    ("Tampering playground", "Manipulations-Spielwiese"),
+    ("Flip a bit of this proof and see which check catches it. Only a copy in your browser is changed.", "Kippen Sie ein Bit dieses Beweises und sehen Sie, welche Prüfung es bemerkt. Nur eine Kopie in Ihrem Browser wird verändert."),
+    ("What to change", "Was geändert wird"),
+    ("Flip", "Kippen"),
+    ("This proof has no such value", "Dieser Beweis hat keinen solchen Wert"),
+    ("Reset", "Zurücksetzen"),
+    ("The tampered proof still verifies", "Der manipulierte Beweis ist trotzdem gültig"),
+    ("Caught: {check}", "Bemerkt von: {check}"),
+    ("Unpin", "Lösen"),
+    ("Pin to the top", "Oben anheften"),
+    ("Drag to reorder, or move with Alt+↑ and Alt+↓", "Zum Umsortieren ziehen oder mit Alt+↑ und Alt+↓ verschieben"),
+    ("Pin", "Anheften"),
+    ("All proofs", "Alle Beweise"),
+    ("Only verified", "Nur geprüfte"),
+    ("Only failed", "Nur fehlgeschlagene"),
+    ("Custom order", "Eigene Reihenfolge"),
+    ("Notarization time, newest first", "Notarisierungszeit, neueste zuerst"),
+    ("Upload order, newest first", "Hochladereihenfolge, neueste zuerst"),
+    ("Size, largest first", "Größe, größte zuerst"),
+    ("Filter and sort proofs", "Beweise filtern und sortieren"),
+    ("Show", "Anzeigen"),
+    ("All servers", "Alle Server"),
+    ("Sort by", "Sortieren nach"),
+    ("{shown} of {total} proofs shown", "{shown} von {total} Beweisen angezeigt"),
+    ("Clear filters", "Filter zurücksetzen"),
+    ("No proofs match the filters", "Keine Beweise entsprechen den Filtern"),
+    ("longest gap: {duration}", "längste Lücke: {duration}"),
+    (", uploaded out of sequence", ", außer der Reihe hochgeladen"),
+    ("Timeline", "Zeitleiste"),
+    (" (notarization times of the verified proofs, by server)", " (Notarisierungszeiten der geprüften Beweise, nach Server)"),
+    ("Notarization times of {count} proofs, from {start} to {end}", "Notarisierungszeiten von {count} Beweisen, von {start} bis {end}"),
+    ("Circled proofs were uploaded before a proof of the same server notarized earlier, they may be out of sequence.", "Umkreiste Beweise wurden vor einem früher notarisierten Beweis desselben Servers hochgeladen, sie sind womöglich außer der Reihe."),
+    ("Group by server", "Nach Server gruppieren"),
+    ("No server", "Kein Server"),
+    ("{count} proofs: {verified} verified, {failed} failed", "{count} Beweise: {verified} geprüft, {failed} fehlgeschlagen"),
+    (", {pending} pending", ", {pending} ausstehend"),
+    ("Nothing to check, the proof discloses no part of the transcript", "Nichts zu prüfen, der Beweis legt keinen Teil des Transkripts offen"),
+    ("– {check}: nothing to check", "– {check}: nichts zu prüfen"),
+    ("No transcript content disclosed", "Kein Inhalt des Transkripts offengelegt"),
+    ("This proof only holds the session header signed by the Notary: the server, the time and the commitments to the {sent} bytes sent and {recv} bytes received. None of the transcript itself was disclosed.", "Dieser Beweis enthält nur den vom Notar signierten Sitzungs-Header: den Server, die Zeit und die Commitments zu den {sent} gesendeten und {recv} empfangenen Bytes. Nichts vom Transkript selbst wurde offengelegt."),
+    ("This is a substrings proof without the session proof it belongs to", "Dies ist ein Teilstring-Beweis ohne den zugehörigen Sitzungsbeweis"),
+    ("Verify it against the session of another loaded proof, or paste the session proof, in the panel above the results", "Prüfen Sie ihn im Bereich über den Ergebnissen mit der Sitzung eines anderen geladenen Beweises oder fügen Sie den Sitzungsbeweis ein"),
+    ("pasted session", "eingefügte Sitzung"),
+    ("Not a session proof: {error}", "Kein Sitzungsbeweis: {error}"),
+    ("\"{file}\" is a substrings proof, it can only be verified with the session header it was made for.", "„{file}“ ist ein Teilstring-Beweis, er kann nur mit dem Sitzungs-Header geprüft werden, für den er erstellt wurde."),
+    ("Session:", "Sitzung:"),
+    ("Paste a session proof", "Sitzungsbeweis einfügen"),
+    ("Session proof JSON", "Sitzungsbeweis-JSON"),
+    ("Verify with this session", "Mit dieser Sitzung prüfen"),
+    ("Substrings proofs without a session", "Teilstring-Beweise ohne Sitzung"),
+    ("Field", "Feld"),
+    ("Predicate", "Prädikat"),
+    ("⚠️ not checked", "⚠️ nicht geprüft"),
+    ("The values stay undisclosed, and this verifier cannot check {system} proofs yet: nothing here proves the claims.", "Die Werte bleiben verborgen, und dieser Prüfer kann {system}-Beweise noch nicht prüfen: Nichts hier beweist die Behauptungen."),
+    ("The zero-knowledge claims do not parse: {error}", "Die Zero-Knowledge-Behauptungen sind nicht lesbar: {error}"),
+    ("Zero-knowledge claims", "Zero-Knowledge-Behauptungen"),
+    ("[redacted]", "[geschwärzt]"),
+    ("{site} summary:", "Zusammenfassung für {site}:"),
+    ("Direct messages", "Direktnachrichten"),
+    ("Account: ", "Konto: "),
+    ("✓ verified", "✓ bestätigt"),
+    ("primary", "primär"),
+    ("Account id: ", "Konto-ID: "),
+    ("Member since: ", "Mitglied seit: "),
+    ("Public email: ", "Öffentliche E-Mail: "),
+    ("Email addresses:", "E-Mail-Adressen:"),
+    ("(no subject)", "(kein Betreff)"),
+    ("Part {type}:", "Teil {type}:"),
+    ("Partially redacted, shown encoded", "Teilweise geschwärzt, kodiert angezeigt"),
+    ("{protocol} session ({count} commands):", "{protocol}-Sitzung ({count} Befehle):"),
+    ("Messages ({count}):", "Nachrichten ({count}):"),
+    ("Lines ({count}), not HTTP:", "Zeilen ({count}), kein HTTP:"),
+    ("The order of sent and received lines is estimated from their positions; the proof does not record when they were sent.", "Die Reihenfolge gesendeter und empfangener Zeilen ist aus ihren Positionen geschätzt; der Beweis hält nicht fest, wann sie gesendet wurden."),
+    ("Conversation", "Gespräch"),
+    ("Sent bytes which are not HTTP:", "Gesendete Bytes, die kein HTTP sind:"),
+    ("Received bytes which are not HTTP:", "Empfangene Bytes, die kein HTTP sind:"),
+    ("HAR export", "HAR-Export"),
+    ("The HTTP exchanges as an HTTP Archive, to import into browser devtools. Redacted bytes are replaced by █.", "Die HTTP-Austausche als HTTP-Archiv, zum Import in die Entwicklertools des Browsers. Geschwärzte Bytes sind durch █ ersetzt."),
+    ("Download HAR", "HAR herunterladen"),
+    ("the HAR file", "die HAR-Datei"),
+    ("Copy HAR", "HAR kopieren"),
+    ("HTTP status:", "HTTP-Status:"),
+    ("The server answered with an error status. The proof shows that this response was received, not that the request succeeded.", "Der Server antwortete mit einem Fehlerstatus. Der Beweis zeigt, dass diese Antwort empfangen wurde, nicht dass die Anfrage erfolgreich war."),
+    ("Request #{number}", "Anfrage #{number}"),
+    ("Response #{number}", "Antwort #{number}"),
+    ("{message}: Content-Length is {declared} bytes, but the body has {actual}", "{message}: Content-Length gibt {declared} Bytes an, der Inhalt hat aber {actual}"),
+    ("{message}: the Content-Length value is redacted, the body length is not checked", "{message}: Der Content-Length-Wert ist geschwärzt, die Inhaltslänge wird nicht geprüft"),
+    ("{message}: the body is redacted up to the end of the transcript, the proof may only open a part of it", "{message}: Der Inhalt ist bis zum Ende des Transkripts geschwärzt, der Beweis öffnet eventuell nur einen Teil davon"),
+    ("{count} bytes after the last request do not parse, the transcript may end in the middle of a request", "{count} Bytes nach der letzten Anfrage lassen sich nicht parsen, das Transkript endet eventuell mitten in einer Anfrage"),
+    ("{count} bytes after the last response do not parse, the transcript may end in the middle of a response", "{count} Bytes nach der letzten Antwort lassen sich nicht parsen, das Transkript endet eventuell mitten in einer Antwort"),
+    ("Body lengths:", "Inhaltslängen:"),
+    ("✅ The bodies match their Content-Length headers", "✅ Die Inhalte stimmen mit ihren Content-Length-Headern überein"),
+    ("Sent:", "Gesendet:"),
+    ("Received:", "Empfangen:"),
+    ("not covered", "nicht abgedeckt"),
+    ("Coverage:", "Abdeckung:"),
+    ("{disclosed} disclosed, {redacted} redacted, {uncovered} not covered", "{disclosed} offengelegt, {redacted} geschwärzt, {uncovered} nicht abgedeckt"),
+    ("disclosed {disclosed} of {committed} committed", "{disclosed} von {committed} festgelegten offengelegt"),
+    ("⚠️ {uncovered} are neither disclosed nor redacted: the proof says nothing about them but that they exist", "⚠️ {uncovered} sind weder offengelegt noch geschwärzt: Der Beweis sagt über sie nichts aus, außer dass es sie gibt"),
+    ("Diagnostics, to attach to a problem report", "Diagnose, zum Anhängen an eine Fehlermeldung"),
+    ("🩺 Diagnostics", "🩺 Diagnose"),
+    ("Diagnostics", "Diagnose"),
+    ("the diagnostic log", "das Diagnoseprotokoll"),
+    ("Copy", "Kopieren"),
+    ("What this page did: files loaded, formats detected, verification steps and their timings, and errors. Copy it into your problem report; it contains file names but no proof contents.", "Was diese Seite getan hat: geladene Dateien, erkannte Formate, Prüfschritte mit ihrer Dauer und Fehler. Kopieren Sie es in Ihre Fehlermeldung; es enthält Dateinamen, aber keine Inhalte der Beweise."),
+    ("Nothing logged yet.", "Noch nichts protokolliert."),
+    ("not checked", "nicht geprüft"),
+    ("Verification time:", "Dauer der Prüfung:"),
+    ("Total", "Gesamt"),
+    ("Stop reading {file}", "{file} nicht weiter lesen"),
+    ("Cancel", "Abbrechen"),
+    ("Skipped \"{file}\" of \"{archive}\": {error}", "\"{file}\" aus \"{archive}\" übersprungen: {error}"),
+    ("Dismiss", "Schließen"),
+    ("Reading the file failed: {error}", "Die Datei konnte nicht gelesen werden: {error}"),
+    ("⏳ Verifying...", "⏳ Wird geprüft..."),
+    ("⏳ Verifying", "⏳ Wird geprüft"),
+    ("Bytes {direction}: ", "Bytes {direction}: "),
+    ("Offsets: {gutter}", "Offsets: {gutter}"),
+    ("Header:", "Header:"),
+    ("Claims:", "Claims:"),
+    ("Cookies ({count}):", "Cookies ({count}):"),
+    ("JSON Web Tokens ({count}):", "JSON Web Tokens ({count}):"),
+    ("Badge", "Badge"),
+    ("redacted {range} ({count} bytes)", "geschwärzt {range} ({count} Bytes)"),
+    ("bytes {range}", "Bytes {range}"),
+    ("Your browser does not support iframes.", "Ihr Browser unterstützt keine iframes."),
+];
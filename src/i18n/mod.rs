@@ -0,0 +1,89 @@
+//! Translations of the user interface.
+//!
+//! Messages are looked up by their English text, gettext style, so untranslated messages
+//! simply show in English. Values are filled into `{name}` placeholders after translation.
+
+mod de;
+
+use gloo::storage::{LocalStorage, Storage};
+
+const STORAGE_KEY: &str = "proof_viz.locale";
+
+/// Language of the user interface, provided to all components as a yew context
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::De];
+
+    /// BCP 47 language tag
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+        }
+    }
+
+    /// Name of the language in that language, for the switcher
+    pub fn name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::De => "Deutsch",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|locale| code.eq_ignore_ascii_case(locale.code()))
+    }
+
+    /// The persisted choice, else the browser's preferred language if it is supported
+    pub fn load() -> Self {
+        let stored = LocalStorage::get::<String>(STORAGE_KEY).ok();
+        let browser = gloo::utils::window().navigator().language();
+        stored
+            .or(browser)
+            .and_then(|code| Self::from_code(code.split('-').next().unwrap_or(&code)))
+            .unwrap_or_default()
+    }
+
+    pub fn save(self) {
+        // Not being able to persist the language only means it is asked again
+        let _ = LocalStorage::set(STORAGE_KEY, self.code());
+    }
+
+    /// Screen readers and hyphenation follow the `lang` attribute of the root element
+    pub fn apply(self) {
+        let _ = gloo::utils::document_element().set_attribute("lang", self.code());
+    }
+
+    /// The translation of `message`, or `message` itself if there is none
+    pub fn t(self, message: &'static str) -> &'static str {
+        let messages = match self {
+            Locale::En => return message,
+            Locale::De => de::MESSAGES,
+        };
+        messages
+            .iter()
+            .find(|(english, _)| *english == message)
+            .map_or(message, |(_, translated)| translated)
+    }
+
+    /// Translate `message` and fill in its `{name}` placeholders
+    pub fn format(
+        self,
+        message: &'static str,
+        values: &[(&str, &dyn std::fmt::Display)],
+    ) -> String {
+        values
+            .iter()
+            .fold(self.t(message).to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{}}}", name), &value.to_string())
+            })
+    }
+}
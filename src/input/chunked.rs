@@ -0,0 +1,91 @@
+use gloo::file::callbacks::{read_as_bytes, FileReader};
+use gloo::file::{Blob, File, FileReadError};
+
+/// Bytes read at once, files larger than this show their progress
+const CHUNK: u64 = 1024 * 1024;
+
+/// An uploaded file which is read chunk by chunk
+pub struct Upload {
+    file: File,
+    data: Vec<u8>,
+    /// The read of the current chunk, cancelled when dropped
+    reader: Option<FileReader>,
+    /// Why the file could not be read
+    error: Option<String>,
+}
+
+impl Upload {
+    pub fn new(file: File) -> Self {
+        Self {
+            file,
+            data: Vec::new(),
+            reader: None,
+            error: None,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.file.name()
+    }
+
+    pub fn file_type(&self) -> String {
+        self.file.raw_mime_type()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.file.size()
+    }
+
+    /// Read the next chunk, which is handed to `on_chunk` to be added with `push`
+    pub fn read_next(&mut self, on_chunk: impl FnOnce(Result<Vec<u8>, FileReadError>) + 'static) {
+        // Only files which passed the size check get here, it is safe to allocate all at once
+        if self.data.capacity() == 0 {
+            self.data.reserve_exact(self.file.size() as usize);
+        }
+        let start = self.data.len() as u64;
+        let end = (start + CHUNK).min(self.file.size());
+        let blob: &Blob = &self.file;
+        self.reader = Some(read_as_bytes(&blob.slice(start, end), on_chunk));
+    }
+
+    pub fn push(&mut self, chunk: Vec<u8>) {
+        self.data.extend(chunk);
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.data.len() as u64 >= self.file.size()
+    }
+
+    /// Stop reading, the file is kept to show the error on its card
+    pub fn fail(&mut self, error: String) {
+        self.reader = None;
+        self.data = Vec::new();
+        self.error = Some(error);
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Still being read, neither failed nor complete
+    pub fn is_pending(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Only files taking more than one chunk show their progress
+    pub fn is_big(&self) -> bool {
+        self.file.size() > CHUNK
+    }
+
+    /// How much of the file is read, in percent
+    pub fn percent(&self) -> u64 {
+        match self.file.size() {
+            0 => 100,
+            size => self.data.len() as u64 * 100 / size,
+        }
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
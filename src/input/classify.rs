@@ -0,0 +1,168 @@
+use crate::i18n::Locale;
+
+/// Proofs larger than this are not verified, to keep the page responsive
+pub const MAX_SIZE: usize = 64 * 1024 * 1024;
+
+/// Binary formats recognized by their first bytes
+const MAGIC: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "PNG image"),
+    (b"\xff\xd8\xff", "JPEG image"),
+    (b"GIF8", "GIF image"),
+    (b"%PDF-", "PDF document"),
+    (b"PK\x03\x04", "ZIP archive"),
+    (b"\x1f\x8b", "gzip archive"),
+    (b"\x7fELF", "Unix executable (ELF)"),
+    (b"\0asm", "WebAssembly module"),
+];
+
+const UTF8_BOM: &str = "\u{feff}";
+
+/// What an uploaded file looks like, judged by its content rather than its name or type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Detected {
+    /// JSON text, worth verifying as a proof
+    Json,
+    /// No content besides whitespace
+    Empty,
+    /// Larger than `MAX_SIZE`, with the size in bytes
+    TooLarge(usize),
+    /// A binary format recognized by its magic bytes
+    Binary(&'static str),
+    /// Text with a UTF-16 byte order mark
+    Utf16,
+    /// Not UTF-8, with the offset of the first invalid byte
+    NotUtf8(usize),
+    /// A PEM encoded key or certificate
+    Pem,
+    /// UTF-8 text which is not JSON
+    Text,
+}
+
+impl Detected {
+    pub fn is_json(self) -> bool {
+        self == Detected::Json
+    }
+
+    /// What was detected and what to do about it, for the error card
+    pub fn describe(self, locale: Locale) -> String {
+        match self {
+            Detected::Json => locale.t("The file is JSON.").to_string(),
+            Detected::Empty => locale.t("The file is empty.").to_string(),
+            Detected::TooLarge(size) => locale.format(
+                "The file has {size} bytes, more than the {max} bytes a proof may have.",
+                &[("size", &size), ("max", &MAX_SIZE)],
+            ),
+            Detected::Binary(format) => locale.format(
+                "The file is a {format}.",
+                &[("format", &locale.t(format))],
+            ),
+            Detected::Utf16 => locale
+                .t("The file is UTF-16 encoded text, proofs are UTF-8 encoded JSON.")
+                .to_string(),
+            Detected::NotUtf8(offset) => locale.format(
+                "The file is binary data, the byte at offset {offset} is not valid UTF-8.",
+                &[("offset", &offset)],
+            ),
+            Detected::Pem => locale
+                .t("The file is a PEM key or certificate. A Notary key can be set in the Notary key field instead.")
+                .to_string(),
+            Detected::Text => locale.t("The file is text, but not JSON.").to_string(),
+        }
+    }
+}
+
+/// Classify an uploaded file by its content
+pub fn classify(data: &[u8]) -> Detected {
+    if data.len() > MAX_SIZE {
+        return Detected::TooLarge(data.len());
+    }
+    if let Some((_, format)) = MAGIC.iter().find(|(magic, _)| data.starts_with(magic)) {
+        return Detected::Binary(format);
+    }
+    if data.starts_with(b"\xff\xfe") || data.starts_with(b"\xfe\xff") {
+        return Detected::Utf16;
+    }
+
+    let text = match std::str::from_utf8(data) {
+        Ok(text) => text.trim_start_matches(UTF8_BOM).trim(),
+        Err(err) => return Detected::NotUtf8(err.valid_up_to()),
+    };
+    if text.is_empty() {
+        Detected::Empty
+    } else if text.starts_with('{') || text.starts_with('[') {
+        // malformed JSON is still verified, so the parse error can be explained
+        Detected::Json
+    } else if text.starts_with("-----BEGIN ") {
+        Detected::Pem
+    } else {
+        Detected::Text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json() {
+        assert_eq!(classify(br#"{"session": {}}"#), Detected::Json);
+        assert_eq!(classify(b"\n  [1, 2]\n"), Detected::Json);
+        assert_eq!(classify("\u{feff}{}".as_bytes()), Detected::Json);
+        // truncated, but still verified so the parse error gets explained
+        assert_eq!(classify(br#"{"session": "#), Detected::Json);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(classify(b""), Detected::Empty);
+        assert_eq!(classify(b" \r\n\t"), Detected::Empty);
+        assert_eq!(classify(UTF8_BOM.as_bytes()), Detected::Empty);
+    }
+
+    #[test]
+    fn binary_formats() {
+        assert_eq!(
+            classify(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"),
+            Detected::Binary("PNG image")
+        );
+        assert_eq!(
+            classify(b"\xff\xd8\xff\xe0"),
+            Detected::Binary("JPEG image")
+        );
+        assert_eq!(classify(b"%PDF-1.7\n"), Detected::Binary("PDF document"));
+        assert_eq!(
+            classify(b"PK\x03\x04\x14\0"),
+            Detected::Binary("ZIP archive")
+        );
+        assert_eq!(
+            classify(b"\x1f\x8b\x08\0"),
+            Detected::Binary("gzip archive")
+        );
+    }
+
+    #[test]
+    fn utf16() {
+        assert_eq!(classify(b"\xff\xfe{\0}\0"), Detected::Utf16);
+        assert_eq!(classify(b"\xfe\xff\0{\0}"), Detected::Utf16);
+    }
+
+    #[test]
+    fn not_utf8() {
+        assert_eq!(classify(b"{\"a\": \"\xc3\x28\"}"), Detected::NotUtf8(7));
+        assert_eq!(classify(b"\x80\x81\x82"), Detected::NotUtf8(0));
+    }
+
+    #[test]
+    fn text() {
+        let pem = b"-----BEGIN PUBLIC KEY-----\nMFkw\n-----END PUBLIC KEY-----\n";
+        assert_eq!(classify(pem), Detected::Pem);
+        assert_eq!(classify(b"hello world"), Detected::Text);
+        assert_eq!(classify(b"null"), Detected::Text);
+    }
+
+    #[test]
+    fn too_large() {
+        let data = vec![b' '; MAX_SIZE + 1];
+        assert_eq!(classify(&data), Detected::TooLarge(MAX_SIZE + 1));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod chunked;
+pub mod classify;
+pub mod zip;
@@ -0,0 +1,161 @@
+use std::io::{Cursor, Read};
+
+use crate::app::FileDetails;
+use crate::input::classify::MAX_SIZE;
+
+/// Does this uploaded file look like a ZIP archive?
+pub fn is_zip(file_name: &str, file_type: &str) -> bool {
+    file_type == "application/zip"
+        || file_type == "application/x-zip-compressed"
+        || file_name.to_lowercase().ends_with(".zip")
+}
+
+/// The files of an archive, and the entries which could not be unpacked
+pub struct Unpacked {
+    pub files: Vec<FileDetails>,
+    /// Entry name and why it was skipped
+    pub skipped: Vec<(String, String)>,
+}
+
+/// Unpack all files of a ZIP archive, so they can be verified like individually uploaded files.
+/// Entries which can not be read, e.g. encrypted or corrupt ones, are skipped.
+pub fn unpack(archive_name: &str, data: &[u8]) -> Result<Unpacked, String> {
+    unpack_within(archive_name, data, MAX_SIZE)
+}
+
+/// [`unpack`], reading at most `limit` bytes per entry and in total. The sizes in the archive
+/// are not trusted: they are what a ZIP bomb lies about.
+fn unpack_within(archive_name: &str, data: &[u8], limit: usize) -> Result<Unpacked, String> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(data)).map_err(|e| e.to_string())?;
+
+    let mut unpacked = Unpacked {
+        files: Vec::new(),
+        skipped: Vec::new(),
+    };
+    let mut remaining = limit;
+    for i in 0..archive.len() {
+        // Read without decrypting or decompressing, so skipped entries are named
+        let name = match archive.by_index_raw(i) {
+            Ok(entry) => entry.name().to_string(),
+            Err(err) => {
+                unpacked
+                    .skipped
+                    .push((format!("#{}", i + 1), err.to_string()));
+                continue;
+            }
+        };
+        let mut entry = match archive.by_index(i) {
+            Ok(entry) => entry,
+            Err(err) => {
+                unpacked.skipped.push((name, err.to_string()));
+                continue;
+            }
+        };
+
+        // Skip directories and the resource forks macOS adds to archives
+        if entry.is_dir() || name.starts_with("__MACOSX/") {
+            continue;
+        }
+
+        // One byte more than allowed tells a file of exactly the limit from a larger one
+        let mut data = Vec::new();
+        if let Err(err) = (&mut entry)
+            .take(remaining as u64 + 1)
+            .read_to_end(&mut data)
+        {
+            unpacked.skipped.push((name, err.to_string()));
+            continue;
+        }
+        if data.len() > remaining {
+            let reason = if remaining < limit {
+                "the unpacked files together are too large"
+            } else {
+                "the unpacked file is too large"
+            };
+            unpacked.skipped.push((name, reason.to_string()));
+            continue;
+        }
+        remaining -= data.len();
+
+        let file_type = if name.to_lowercase().ends_with(".json") {
+            "application/json"
+        } else {
+            ""
+        };
+
+        unpacked.files.push(FileDetails::new(
+            format!("{}/{}", archive_name, name),
+            file_type.to_string(),
+            data,
+        ));
+    }
+
+    Ok(unpacked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::FileOptions;
+    use zip::CompressionMethod;
+
+    fn archive(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, data) in entries {
+            let options = FileOptions::default().compression_method(CompressionMethod::Stored);
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(data).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn names(unpacked: &Unpacked) -> Vec<&str> {
+        unpacked
+            .files
+            .iter()
+            .map(|file| file.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn oversized_entries() {
+        let data = archive(&[
+            ("a.json", b"{}"),
+            ("big.json", &[b' '; 20]),
+            ("c.json", b"[]"),
+        ]);
+        let unpacked = unpack_within("proofs.zip", &data, 10).unwrap();
+        assert_eq!(names(&unpacked), ["proofs.zip/a.json", "proofs.zip/c.json"]);
+        assert_eq!(unpacked.skipped[0].0, "big.json");
+
+        // Each fits, but not all of them together
+        let unpacked = unpack_within("proofs.zip", &data, 22).unwrap();
+        assert_eq!(
+            names(&unpacked),
+            ["proofs.zip/a.json", "proofs.zip/big.json"]
+        );
+        assert_eq!(
+            unpacked.skipped,
+            [(
+                "c.json".to_string(),
+                "the unpacked files together are too large".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn corrupt_entry() {
+        let mut data = archive(&[("a.json", b"{\"corrupt\": 1}"), ("b.json", b"{}")]);
+        // Change a byte of the stored content, its checksum no longer matches
+        let at = data
+            .windows(7)
+            .position(|window| window == b"corrupt")
+            .unwrap();
+        data[at] = b'C';
+        let unpacked = unpack("proofs.zip", &data).unwrap();
+        assert_eq!(names(&unpacked), ["proofs.zip/b.json"]);
+        assert_eq!(unpacked.skipped.len(), 1);
+        assert_eq!(unpacked.skipped[0].0, "a.json");
+    }
+}
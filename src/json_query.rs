@@ -0,0 +1,236 @@
+//! A small subset of JSONPath, to extract values from JSON response bodies.
+//!
+//! Supported are `$` (root), `.key`, `['key']`, `[index]` (negative indices count from the end),
+//! `*` / `[*]` (all children) and `..` (all descendants). jq style expressions without the
+//! leading `$`, like `.data.balance`, are accepted as well.
+
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(i64),
+    Wildcard,
+    Descendants,
+}
+
+fn parse_bracket(content: &str) -> Result<Segment, String> {
+    let content = content.trim();
+    if content == "*" {
+        return Ok(Segment::Wildcard);
+    }
+    for quote in ['\'', '"'] {
+        if let Some(key) = content
+            .strip_prefix(quote)
+            .and_then(|c| c.strip_suffix(quote))
+        {
+            return Ok(Segment::Key(key.to_string()));
+        }
+    }
+    content
+        .parse::<i64>()
+        .map(Segment::Index)
+        .map_err(|_| format!("invalid selector [{}]", content))
+}
+
+fn parse(expression: &str) -> Result<Vec<Segment>, String> {
+    let expression = expression.trim();
+    let rest = match expression.strip_prefix('$') {
+        Some(rest) => rest,
+        // jq style `.key` or a bare `key`
+        None if expression.starts_with(['.', '[']) => expression,
+        None => return parse(&format!(".{}", expression)),
+    };
+
+    let chars: Vec<char> = rest.chars().collect();
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '.' => {
+                i += 1;
+                if chars.get(i) == Some(&'.') {
+                    segments.push(Segment::Descendants);
+                    i += 1;
+                }
+                match chars.get(i) {
+                    Some('*') => {
+                        segments.push(Segment::Wildcard);
+                        i += 1;
+                    }
+                    // `..[0]`, the bracket is handled in the next iteration
+                    Some('[') => {}
+                    _ => {
+                        let start = i;
+                        while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                            i += 1;
+                        }
+                        let key: String = chars[start..i].iter().collect();
+                        // a lone `.` is jq's identity
+                        if key.is_empty() && !(chars.len() == 1 && segments.is_empty()) {
+                            return Err(format!("expected a key at position {}", start));
+                        }
+                        if !key.is_empty() {
+                            segments.push(Segment::Key(key));
+                        }
+                    }
+                }
+            }
+            '[' => {
+                let start = i + 1;
+                let mut quote = None;
+                i = start;
+                while i < chars.len() && (quote.is_some() || chars[i] != ']') {
+                    match (quote, chars[i]) {
+                        (None, c @ ('\'' | '"')) => quote = Some(c),
+                        (Some(q), c) if q == c => quote = None,
+                        _ => {}
+                    }
+                    i += 1;
+                }
+                if i == chars.len() {
+                    return Err("missing ]".to_string());
+                }
+                let content: String = chars[start..i].iter().collect();
+                segments.push(parse_bracket(&content)?);
+                i += 1;
+            }
+            c => return Err(format!("unexpected '{}' at position {}", c, i)),
+        }
+    }
+
+    Ok(segments)
+}
+
+fn children(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(array) => array.iter().collect(),
+        Value::Object(object) => object.values().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn descendants<'a>(value: &'a Value, acc: &mut Vec<&'a Value>) {
+    acc.push(value);
+    for child in children(value) {
+        descendants(child, acc);
+    }
+}
+
+/// Evaluate a JSONPath expression, returning all matching values
+pub fn query<'a>(value: &'a Value, expression: &str) -> Result<Vec<&'a Value>, String> {
+    let segments = parse(expression)?;
+
+    let mut current = vec![value];
+    for segment in &segments {
+        current = match segment {
+            Segment::Key(key) => current.into_iter().filter_map(|v| v.get(key)).collect(),
+            Segment::Index(index) => current
+                .into_iter()
+                .filter_map(|v| {
+                    let array = v.as_array()?;
+                    let index = if *index < 0 {
+                        array.len().checked_sub(index.unsigned_abs() as usize)?
+                    } else {
+                        *index as usize
+                    };
+                    array.get(index)
+                })
+                .collect(),
+            Segment::Wildcard => current.into_iter().flat_map(children).collect(),
+            Segment::Descendants => {
+                let mut acc = Vec::new();
+                for v in current {
+                    descendants(v, &mut acc);
+                }
+                acc
+            }
+        };
+    }
+
+    Ok(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn body() -> Value {
+        json!({
+            "data": {
+                "balance": 100,
+                "a.b": "dotted",
+                "]": "bracket",
+                "items": [{"id": 1}, {"id": 2}, {"id": 3}]
+            }
+        })
+    }
+
+    fn values(expression: &str) -> Vec<Value> {
+        let body = body();
+        query(&body, expression)
+            .unwrap()
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn nested_keys() {
+        for expression in [
+            "$.data.balance",
+            ".data.balance",
+            "data.balance",
+            "$['data'].balance",
+        ] {
+            assert_eq!(values(expression), [json!(100)], "{expression}");
+        }
+        assert_eq!(values(r#"$.data["a.b"]"#), [json!("dotted")]);
+        assert_eq!(values(r#"$.data["]"]"#), [json!("bracket")]);
+        assert_eq!(values("$"), [body()]);
+        assert_eq!(values("."), [body()]);
+    }
+
+    #[test]
+    fn array_indices() {
+        assert_eq!(values("$.data.items[0].id"), [json!(1)]);
+        assert_eq!(values("$.data.items[-1].id"), [json!(3)]);
+        assert_eq!(values("$.data.items[*].id"), [json!(1), json!(2), json!(3)]);
+        assert_eq!(values("$.data.items.*.id"), [json!(1), json!(2), json!(3)]);
+        assert_eq!(values("$..id"), [json!(1), json!(2), json!(3)]);
+        assert_eq!(values("$..items[1].id"), [json!(2)]);
+    }
+
+    #[test]
+    fn missing_paths() {
+        for expression in [
+            "$.data.missing",
+            "$.data.balance.deeper",
+            "$.data.items[3]",
+            "$.data.items[-4]",
+            "$.data[0]",
+            "$.data.items.id",
+        ] {
+            assert!(values(expression).is_empty(), "{expression}");
+        }
+    }
+
+    #[test]
+    fn malformed_queries() {
+        let body = body();
+        for (expression, error) in [
+            ("$.data.items[0", "missing ]"),
+            ("$.data['balance'", "missing ]"),
+            ("$.data[first]", "invalid selector [first]"),
+            ("$.data..", "expected a key at position 7"),
+            ("$x", "unexpected 'x' at position 0"),
+        ] {
+            assert_eq!(
+                query(&body, expression),
+                Err(error.to_string()),
+                "{expression}"
+            );
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! A lenient JSON parser which keeps track of the byte range of every value.
+//!
+//! Redacted bytes are usually not valid JSON (a redacted number becomes `XXX`), so wherever a
+//! value is expected, a run of redacted bytes is accepted as a [`JsonValue::Redacted`] value.
+
+use std::ops::Range;
+
+use serde_json::Value;
+
+/// Stands in for (partially) redacted values in [`JsonSpan::to_value`]
+pub const REDACTED_MARKER: &str = "\u{0}redacted\u{0}";
+
+/// Is `value`, or any value within it, not fully disclosed?
+pub fn contains_marker(value: &Value) -> bool {
+    match value {
+        Value::String(s) => s == REDACTED_MARKER,
+        Value::Array(items) => items.iter().any(contains_marker),
+        Value::Object(members) => members.values().any(contains_marker),
+        _ => false,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonSpan>),
+    Object(Vec<(String, JsonSpan)>),
+    Redacted,
+}
+
+/// A JSON value and the range of bytes it was parsed from
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonSpan {
+    pub value: JsonValue,
+    pub range: Range<usize>,
+}
+
+impl JsonSpan {
+    /// Does any byte of this value fall in one of the redacted ranges?
+    pub fn is_redacted(&self, redacted_ranges: &[Range<usize>]) -> bool {
+        redacted_ranges
+            .iter()
+            .any(|r| r.start < self.range.end && self.range.start < r.end)
+    }
+
+    /// Convert to a serde_json value, replacing everything that is not fully disclosed by
+    /// [`REDACTED_MARKER`]
+    pub fn to_value(&self, redacted_ranges: &[Range<usize>]) -> Value {
+        match &self.value {
+            JsonValue::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .map(|item| item.to_value(redacted_ranges))
+                    .collect(),
+            ),
+            JsonValue::Object(members) => Value::Object(
+                members
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.to_value(redacted_ranges)))
+                    .collect(),
+            ),
+            _ if self.is_redacted(redacted_ranges) => Value::String(REDACTED_MARKER.to_string()),
+            JsonValue::Null | JsonValue::Redacted => Value::Null,
+            JsonValue::Bool(b) => Value::Bool(*b),
+            JsonValue::Number(n) => serde_json::from_str(n).unwrap_or(Value::Null),
+            JsonValue::String(s) => Value::String(s.clone()),
+        }
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    redacted_ranges: &'a [Range<usize>],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error<T>(&self, message: &str) -> Result<T, String> {
+        Err(format!("{} at byte {}", message, self.pos))
+    }
+
+    fn is_redacted(&self, pos: usize) -> bool {
+        self.redacted_ranges.iter().any(|r| r.contains(&pos))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("expected '{}'", byte as char))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonSpan, String> {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        if self.is_redacted(start) {
+            while self.pos < self.bytes.len() && self.is_redacted(self.pos) {
+                self.pos += 1;
+            }
+            return Ok(JsonSpan {
+                value: JsonValue::Redacted,
+                range: start..self.pos,
+            });
+        }
+
+        let value = match self.bytes.get(start) {
+            None => return self.error("unexpected end of input"),
+            Some(b'{') => self.parse_object()?,
+            Some(b'[') => self.parse_array()?,
+            Some(b'"') => JsonValue::String(self.parse_string()?),
+            Some(b't') => self.parse_literal("true", JsonValue::Bool(true))?,
+            Some(b'f') => self.parse_literal("false", JsonValue::Bool(false))?,
+            Some(b'n') => self.parse_literal("null", JsonValue::Null)?,
+            Some(b'-' | b'0'..=b'9') => {
+                // a partially redacted number is still one value
+                while self.pos < self.bytes.len()
+                    && (matches!(
+                        self.bytes[self.pos],
+                        b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'
+                    ) || self.is_redacted(self.pos))
+                {
+                    self.pos += 1;
+                }
+                JsonValue::Number(String::from_utf8_lossy(&self.bytes[start..self.pos]).to_string())
+            }
+            Some(_) => return self.error("unexpected character"),
+        };
+
+        Ok(JsonSpan {
+            value,
+            range: start..self.pos,
+        })
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+        if self.bytes[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            self.error("unexpected character")
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut string = Vec::new();
+        loop {
+            match self.bytes.get(self.pos) {
+                None => return self.error("unterminated string"),
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(String::from_utf8_lossy(&string).to_string());
+                }
+                Some(b'\\') => {
+                    let escaped = match self.bytes.get(self.pos + 1) {
+                        Some(b'n') => '\n',
+                        Some(b't') => '\t',
+                        Some(b'r') => '\r',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'u') => {
+                            let hex = self.bytes.get(self.pos + 2..self.pos + 6);
+                            let code = hex
+                                .and_then(|hex| std::str::from_utf8(hex).ok())
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+                            match code {
+                                Some(code) => {
+                                    self.pos += 4;
+                                    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                                }
+                                None => return self.error("invalid unicode escape"),
+                            }
+                        }
+                        Some(&c) => c as char,
+                        None => return self.error("unterminated string"),
+                    };
+                    let mut buffer = [0; 4];
+                    string.extend_from_slice(escaped.encode_utf8(&mut buffer).as_bytes());
+                    self.pos += 2;
+                }
+                Some(&byte) => {
+                    string.push(byte);
+                    self.pos += 1;
+                }
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, String> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Array(items));
+                }
+                _ => return self.error("expected ',' or ']'"),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, String> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            members.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Object(members));
+                }
+                _ => return self.error("expected ',' or '}'"),
+            }
+        }
+    }
+}
+
+/// Parse a JSON document, `redacted_ranges` are relative to the start of `bytes`
+pub fn parse(bytes: &[u8], redacted_ranges: &[Range<usize>]) -> Result<JsonSpan, String> {
+    let mut parser = Parser {
+        bytes,
+        redacted_ranges,
+        pos: 0,
+    };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos < bytes.len() {
+        return parser.error("trailing characters");
+    }
+    Ok(value)
+}
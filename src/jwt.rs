@@ -0,0 +1,103 @@
+//! Decoding of JSON Web Tokens, to show which identity data a proof exposes.
+//! Signatures are NOT verified: the key is usually unknown and the Notary does not vouch for it.
+
+use base64::{engine::general_purpose, Engine as _};
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token {
+    pub header: Value,
+    pub claims: Value,
+    pub signature_len: usize,
+}
+
+fn decode_part(part: &[u8]) -> Result<Value, String> {
+    let json = general_purpose::URL_SAFE_NO_PAD
+        .decode(part.strip_suffix(b"=").unwrap_or(part))
+        .map_err(|e| format!("invalid base64url: {}", e))?;
+    serde_json::from_slice(&json).map_err(|e| format!("invalid JSON: {}", e))
+}
+
+pub fn decode(token: &[u8]) -> Result<Token, String> {
+    let parts: Vec<&[u8]> = token.split(|&b| b == b'.').collect();
+    let [header, claims, signature] = parts[..] else {
+        return Err(format!("expected 3 parts, found {}", parts.len()));
+    };
+    Ok(Token {
+        header: decode_part(header).map_err(|e| format!("header: {}", e))?,
+        claims: decode_part(claims).map_err(|e| format!("claims: {}", e))?,
+        signature_len: general_purpose::URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_or(0, |signature| signature.len()),
+    })
+}
+
+/// The meaning of the registered claims (RFC 7519) and a few common ones
+pub fn claim_description(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "iss" => "issuer",
+        "sub" => "subject, usually the user id",
+        "aud" => "audience",
+        "exp" => "expires at",
+        "nbf" => "not valid before",
+        "iat" => "issued at",
+        "jti" => "token id",
+        "email" => "email address",
+        "name" => "full name",
+        "scope" | "scp" => "granted permissions",
+        _ => return None,
+    })
+}
+
+/// Claims holding a timestamp, shown as a date
+pub fn is_time_claim(name: &str) -> bool {
+    matches!(name, "exp" | "nbf" | "iat" | "auth_time")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const HEADER: &str = "eyJhbGciOiJIUzI1NiJ9";
+    const CLAIMS: &str = "eyJzdWIiOiIxMjM0IiwiZXhwIjoxNzE0NTIxNjAwfQ";
+    const SIGNATURE: &str = "dozjgNryP4J3jVmNHl0w5N_XgL0n3I9PlFUP0THsR8U";
+
+    fn token(header: &str, claims: &str, signature: &str) -> Result<Token, String> {
+        decode(format!("{}.{}.{}", header, claims, signature).as_bytes())
+    }
+
+    #[test]
+    fn valid_token() {
+        let token = token(HEADER, CLAIMS, SIGNATURE).unwrap();
+        assert_eq!(token.header, json!({"alg": "HS256"}));
+        assert_eq!(token.claims, json!({"sub": "1234", "exp": 1714521600}));
+        assert_eq!(token.signature_len, 32);
+    }
+
+    #[test]
+    fn bad_base64() {
+        let error = token(HEADER, "eyJzdWIi!OiIxMjM0In0", SIGNATURE).unwrap_err();
+        assert!(error.starts_with("claims: invalid base64url"), "{error}");
+        let error = token("bm90IGpzb24", CLAIMS, SIGNATURE).unwrap_err();
+        assert!(error.starts_with("header: invalid JSON"), "{error}");
+        assert_eq!(
+            decode(format!("{}.{}", HEADER, CLAIMS).as_bytes()),
+            Err("expected 3 parts, found 2".to_string())
+        );
+    }
+
+    #[test]
+    fn partly_redacted_token() {
+        // a redacted signature still shows the claims
+        let redacted = "X".repeat(SIGNATURE.len());
+        let token_without_signature = token(HEADER, CLAIMS, &redacted).unwrap();
+        assert_eq!(token_without_signature.claims["sub"], "1234");
+        assert_eq!(token_without_signature.signature_len, 0);
+
+        // redacted claims can not be decoded
+        let claims = format!("{}XXXXXXXX{}", &CLAIMS[..12], &CLAIMS[20..]);
+        let error = token(HEADER, &claims, SIGNATURE).unwrap_err();
+        assert!(error.starts_with("claims: "), "{error}");
+    }
+}
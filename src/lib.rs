@@ -0,0 +1,76 @@
+extern crate base64;
+
+use wasm_bindgen::prelude::*;
+
+pub mod app;
+mod assertions;
+mod body_checks;
+mod card_order;
+mod certificate;
+mod clipboard;
+mod components;
+mod config;
+mod coverage;
+mod deep_link;
+mod diagnostics;
+mod embed;
+mod endpoints;
+pub mod error_explainer;
+mod events;
+mod exchanges;
+mod export;
+mod fixtures;
+pub mod formats;
+mod forms;
+mod handoff;
+mod history;
+mod http_status;
+pub mod i18n;
+mod input;
+mod json_query;
+mod json_spans;
+mod jwt;
+mod line_protocol;
+mod mail;
+mod network_guard;
+pub mod notary_key;
+mod notary_registry;
+mod notary_signature;
+mod offline;
+pub mod parsed_proof;
+mod policy;
+mod privacy_lints;
+pub mod proof_digest;
+mod proof_list;
+mod proof_schema;
+mod qr;
+mod redaction_semantics;
+mod renderers;
+pub mod report;
+mod route;
+mod self_test;
+mod session_header;
+mod settings;
+mod tampering;
+mod theme;
+mod time_checks;
+mod tour;
+mod transcript_text;
+pub mod verify;
+
+/// Verify a proof for JavaScript callers, without the UI.
+///
+/// `pem` is the Notary public key in any format the key input accepts. Returns the report of
+/// [`report::to_json`]: `verified`, and either the server name, time and transcripts, or an
+/// `error` with a diagnosis.
+#[wasm_bindgen]
+pub fn verify_proof_json(proof: &str, pem: &str) -> JsValue {
+    let report = match notary_key::NotaryKey::parse(pem) {
+        Ok(key) => {
+            let proof = parsed_proof::ParsedProof::parse(proof.as_bytes());
+            report::to_json(&proof, key, &proof.verify(key, &Default::default()).result)
+        }
+        Err(err) => report::error(&format!("invalid Notary key: {}", err)),
+    };
+    js_sys::JSON::parse(&report.to_string()).expect("a report is valid JSON")
+}
@@ -0,0 +1,112 @@
+//! A line-oriented view of transcripts which are not HTTP, e.g. Redis, FTP or custom APIs: both
+//! directions split into lines and merged into one conversation.
+//!
+//! The transcript does not record when bytes were sent, so the merge is a heuristic on relative
+//! offsets, the offset divided by the length of the direction: a sent line goes before a received
+//! line which ends after it starts, as a request precedes the response that answers it.
+
+use std::ops::Range;
+
+use crate::components::redacted_bytes_component::Direction;
+use crate::verify::VerifiedOutput;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line {
+    pub direction: Direction,
+    /// 1-based, counted per direction
+    pub number: usize,
+    /// Position in the transcript of `direction`, without the line ending
+    pub range: Range<usize>,
+}
+
+/// The lines of `bytes`, ending with `\r\n` or a bare `\n`
+pub fn split(bytes: &[u8]) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        let (end, next) = match bytes[start..].iter().position(|&b| b == b'\n') {
+            Some(pos) => (start + pos, start + pos + 1),
+            None => (bytes.len(), bytes.len()),
+        };
+        let end = if end > start && bytes[end - 1] == b'\r' {
+            end - 1
+        } else {
+            end
+        };
+        lines.push(start..end);
+        start = next;
+    }
+    lines
+}
+
+fn numbered(bytes: &[u8], direction: Direction) -> Vec<Line> {
+    split(bytes)
+        .into_iter()
+        .enumerate()
+        .map(|(index, range)| Line {
+            direction,
+            number: index + 1,
+            range,
+        })
+        .collect()
+}
+
+/// Does the sent line start before the received line ends, relative to their directions?
+fn sent_first(sent: &Line, sent_len: usize, recv: &Line, recv_len: usize) -> bool {
+    // `sent.start / sent_len <= recv.end / recv_len`, without rounding
+    sent.range.start * recv_len <= recv.range.end * sent_len
+}
+
+/// The lines of both directions merged, see the module documentation for the order
+pub fn lines(verified: &VerifiedOutput) -> Vec<Line> {
+    let (sent_len, recv_len) = (verified.sent.bytes.len(), verified.recv.bytes.len());
+    let mut sent = numbered(&verified.sent.bytes, Direction::Sent)
+        .into_iter()
+        .peekable();
+    let mut recv = numbered(&verified.recv.bytes, Direction::Received)
+        .into_iter()
+        .peekable();
+    let mut lines = Vec::new();
+    loop {
+        let next = match (sent.peek(), recv.peek()) {
+            (Some(s), Some(r)) if sent_first(s, sent_len, r, recv_len) => sent.next(),
+            (Some(_), Some(_)) => recv.next(),
+            (Some(_), None) => sent.next(),
+            (None, Some(_)) => recv.next(),
+            (None, None) => break,
+        };
+        lines.extend(next);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_lines() {
+        assert_eq!(split(b"a\r\nbc\nd"), [0..1, 3..5, 6..7]);
+        assert_eq!(split(b"\r\n\r\n"), [0..0, 2..2]);
+        assert!(split(b"").is_empty());
+    }
+
+    #[test]
+    fn interleave() {
+        let verified = VerifiedOutput::for_test("PING\r\nGET key\r\n", "+PONG\r\n$5\r\nvalue\r\n");
+        let order: Vec<(Direction, usize)> = lines(&verified)
+            .iter()
+            .map(|line| (line.direction, line.number))
+            .collect();
+        assert_eq!(
+            order,
+            [
+                (Direction::Sent, 1),
+                (Direction::Received, 1),
+                (Direction::Sent, 2),
+                (Direction::Received, 2),
+                (Direction::Received, 3),
+            ]
+        );
+    }
+}
@@ -0,0 +1,558 @@
+//! Email sessions: SMTP and IMAP transcripts split into commands and their responses, and the
+//! messages they carry decoded from MIME, so they are not shown as one raw blob.
+//!
+//! Works on the transcript as disclosed: redacted bytes stay in place, and parts whose encoded
+//! content is (partially) redacted are not decoded.
+
+use std::ops::Range;
+
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::components::redacted_bytes_component::Direction;
+use crate::forms;
+use crate::verify::{Transcript, VerifiedOutput};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Protocol {
+    Smtp,
+    Imap,
+}
+
+impl Protocol {
+    pub fn name(self) -> &'static str {
+        match self {
+            Protocol::Smtp => "SMTP",
+            Protocol::Imap => "IMAP",
+        }
+    }
+}
+
+/// A line of the transcript, without its line ending
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+/// A command and the response lines answering it. The greeting of the server has no command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Exchange {
+    pub command: Option<Line>,
+    pub response: Vec<Line>,
+}
+
+/// A MIME leaf part of a message
+#[derive(Clone, Debug, PartialEq)]
+pub struct Part {
+    pub content_type: String,
+    /// Position of the encoded content in the transcript
+    pub range: Range<usize>,
+    /// The decoded text, `None` for binary content or content which can not be decoded
+    pub text: Option<String>,
+    /// Some of the content is redacted
+    pub redacted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    pub direction: Direction,
+    pub range: Range<usize>,
+    /// With encoded words decoded
+    pub headers: Vec<(String, String)>,
+    pub parts: Vec<Part>,
+}
+
+impl Message {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Session {
+    pub protocol: Protocol,
+    pub exchanges: Vec<Exchange>,
+    pub messages: Vec<Message>,
+}
+
+const SMTP_COMMANDS: &[&str] = &["EHLO", "HELO", "LHLO", "AUTH", "MAIL", "STARTTLS"];
+const IMAP_COMMANDS: &[&str] = &[
+    "CAPABILITY",
+    "LOGIN",
+    "AUTHENTICATE",
+    "ID",
+    "SELECT",
+    "EXAMINE",
+    "FETCH",
+    "UID",
+    "LIST",
+    "NOOP",
+];
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// The lines of `bytes`, ending with `\n` or `\r\n`
+fn lines(bytes: &[u8]) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        let (line, next) = next_line(bytes, start);
+        lines.push(line);
+        start = next;
+    }
+    lines
+}
+
+/// The line at `start`, and where the next one starts
+fn next_line(bytes: &[u8], start: usize) -> (Line, usize) {
+    let newline = bytes[start..].iter().position(|&b| b == b'\n');
+    let (end, next) = match newline {
+        Some(pos) => (start + pos, start + pos + 1),
+        None => (bytes.len(), bytes.len()),
+    };
+    let end = if end > start && bytes[end - 1] == b'\r' {
+        end - 1
+    } else {
+        end
+    };
+    let line = Line {
+        range: start..end,
+        text: String::from_utf8_lossy(&bytes[start..end]).to_string(),
+    };
+    (line, next)
+}
+
+fn first_word(text: &str, index: usize) -> String {
+    text.split_whitespace()
+        .nth(index)
+        .unwrap_or_default()
+        .to_ascii_uppercase()
+}
+
+/// Recognise an SMTP or IMAP session from its first lines
+pub fn detect(verified: &VerifiedOutput) -> Option<Protocol> {
+    let sent = lines(&verified.sent.bytes);
+    let recv = lines(&verified.recv.bytes);
+    let (sent, recv) = (
+        sent.first().map_or("", |line| line.text.as_str()),
+        recv.first().map_or("", |line| line.text.as_str()),
+    );
+    if recv.starts_with("* OK") || recv.starts_with("* PREAUTH") {
+        return Some(Protocol::Imap);
+    }
+    if SMTP_COMMANDS.contains(&first_word(sent, 0).as_str())
+        || (recv.starts_with("220") && recv.contains("SMTP"))
+    {
+        return Some(Protocol::Smtp);
+    }
+    IMAP_COMMANDS
+        .contains(&first_word(sent, 1).as_str())
+        .then_some(Protocol::Imap)
+}
+
+/// SMTP replies: lines up to one whose code is followed by a space instead of a `-`
+fn smtp_replies(recv: &[u8]) -> Vec<Vec<Line>> {
+    let mut replies = Vec::new();
+    let mut reply = Vec::new();
+    for line in lines(recv) {
+        let last = line.text.as_bytes().get(3) != Some(&b'-');
+        reply.push(line);
+        if last {
+            replies.push(std::mem::take(&mut reply));
+        }
+    }
+    if !reply.is_empty() {
+        replies.push(reply);
+    }
+    replies
+}
+
+fn parse_smtp(verified: &VerifiedOutput) -> (Vec<Exchange>, Vec<Message>) {
+    let mut replies = smtp_replies(&verified.recv.bytes).into_iter().peekable();
+    let mut exchanges = Vec::new();
+    let mut messages = Vec::new();
+
+    // Implicit TLS starts with the greeting, STARTTLS with the client's EHLO
+    if replies
+        .peek()
+        .is_some_and(|reply| reply[0].text.starts_with("220"))
+    {
+        exchanges.push(Exchange {
+            command: None,
+            response: replies.next().unwrap_or_default(),
+        });
+    }
+
+    let sent = lines(&verified.sent.bytes);
+    let mut sent = sent.into_iter();
+    while let Some(command) = sent.next() {
+        let response = replies.next().unwrap_or_default();
+        let is_data = first_word(&command.text, 0) == "DATA"
+            && response
+                .first()
+                .is_some_and(|line| line.text.starts_with("354"));
+        exchanges.push(Exchange {
+            command: Some(command),
+            response,
+        });
+        if is_data {
+            // The message, up to a line with a single dot
+            let mut data = Vec::new();
+            let mut end = None;
+            for line in sent.by_ref() {
+                if line.text == "." {
+                    end = Some(line);
+                    break;
+                }
+                data.push(line);
+            }
+            if let (Some(first), Some(last)) = (data.first(), data.last()) {
+                let range = first.range.start..last.range.end;
+                messages.push(parse_message(&verified.sent, range, Direction::Sent));
+            }
+            if let Some(end) = end {
+                exchanges.push(Exchange {
+                    command: Some(end),
+                    response: replies.next().unwrap_or_default(),
+                });
+            }
+        }
+    }
+    (exchanges, messages)
+}
+
+/// The length of the literal announced at the end of an IMAP line, `{42}` or `{42+}`
+fn literal_len(text: &str) -> Option<usize> {
+    let (_, literal) = text.strip_suffix('}')?.rsplit_once('{')?;
+    literal.trim_end_matches('+').parse().ok()
+}
+
+/// IMAP response lines, and the literals of messages fetched
+fn imap_responses(recv: &[u8]) -> (Vec<Line>, Vec<Range<usize>>) {
+    let mut lines = Vec::new();
+    let mut literals = Vec::new();
+    let mut start = 0;
+    while start < recv.len() {
+        let (line, mut next) = next_line(recv, start);
+        if let Some(len) = literal_len(&line.text) {
+            let end = (next + len).min(recv.len());
+            let upper = line.text.to_ascii_uppercase();
+            if upper.contains("BODY[") || upper.contains("RFC822") {
+                literals.push(next..end);
+            }
+            next = end;
+        }
+        lines.push(line);
+        start = next;
+    }
+    (lines, literals)
+}
+
+fn parse_imap(verified: &VerifiedOutput) -> (Vec<Exchange>, Vec<Message>) {
+    let (responses, literals) = imap_responses(&verified.recv.bytes);
+    let mut responses = responses.into_iter().peekable();
+    let mut exchanges = Vec::new();
+
+    if responses
+        .peek()
+        .is_some_and(|line| line.text.starts_with("* OK") || line.text.starts_with("* PREAUTH"))
+    {
+        exchanges.push(Exchange {
+            command: None,
+            response: responses.next().into_iter().collect(),
+        });
+    }
+
+    for command in lines(&verified.sent.bytes) {
+        // Up to the tagged completion of the command
+        let tag = format!("{} ", command.text.split(' ').next().unwrap_or_default());
+        let mut response = Vec::new();
+        for line in responses.by_ref() {
+            let done = line.text.starts_with(&tag);
+            response.push(line);
+            if done {
+                break;
+            }
+        }
+        exchanges.push(Exchange {
+            command: Some(command),
+            response,
+        });
+    }
+
+    let messages = literals
+        .into_iter()
+        .map(|range| parse_message(&verified.recv, range, Direction::Received))
+        .collect();
+    (exchanges, messages)
+}
+
+/// Split an email session into its commands and messages, `None` if it is not SMTP or IMAP
+pub fn parse(verified: &VerifiedOutput) -> Option<Session> {
+    let protocol = detect(verified)?;
+    let (exchanges, messages) = match protocol {
+        Protocol::Smtp => parse_smtp(verified),
+        Protocol::Imap => parse_imap(verified),
+    };
+    Some(Session {
+        protocol,
+        exchanges,
+        messages,
+    })
+}
+
+/// The headers of a message or part, unfolded, and where its body starts
+fn parse_headers(bytes: &[u8]) -> (Vec<(String, String)>, usize) {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        let (line, next) = next_line(bytes, start);
+        start = next;
+        if line.text.is_empty() {
+            break;
+        }
+        if line.text.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.text.trim());
+            }
+        } else if let Some((name, value)) = line.text.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    let headers = headers
+        .into_iter()
+        .map(|(name, value)| (name, decode_words(&value)))
+        .collect();
+    (headers, start)
+}
+
+fn decode_quoted_printable(bytes: &[u8], underscore_is_space: bool) -> Vec<u8> {
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'=' if bytes[i + 1..].starts_with(b"\r\n") => i += 2,
+            b'=' if bytes[i + 1..].starts_with(b"\n") => i += 1,
+            b'=' => {
+                let hex = bytes
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                match hex {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'='),
+                }
+            }
+            b'_' if underscore_is_space => decoded.push(b' '),
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    decoded
+}
+
+fn decode_base64(bytes: &[u8]) -> Option<Vec<u8>> {
+    let encoded: Vec<u8> = bytes
+        .iter()
+        .copied()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect();
+    general_purpose::STANDARD.decode(encoded).ok()
+}
+
+/// Decode the encoded words of a header value, `=?UTF-8?B?...?=` and `=?UTF-8?Q?...?=`. Other
+/// charsets are read as UTF-8 as well.
+fn decode_words(value: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("=?") {
+        let word = &rest[start + 2..];
+        let parsed = word.split_once('?').and_then(|(_, word)| {
+            let (encoding, word) = word.split_once('?')?;
+            let (text, after) = word.split_once("?=")?;
+            let bytes = match encoding.to_ascii_uppercase().as_str() {
+                "B" => decode_base64(text.as_bytes())?,
+                "Q" => decode_quoted_printable(text.as_bytes(), true),
+                _ => return None,
+            };
+            Some((String::from_utf8_lossy(&bytes).to_string(), after))
+        });
+        match parsed {
+            Some((text, after)) => {
+                let before = &rest[..start];
+                // Whitespace between encoded words is not part of the text
+                if !(decoded.ends_with(char::is_alphanumeric) && before.trim().is_empty()) {
+                    decoded.push_str(before);
+                }
+                decoded.push_str(&text);
+                rest = after;
+            }
+            None => {
+                decoded.push_str(&rest[..start + 2]);
+                rest = &rest[start + 2..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The leaf parts of the entity with `headers` and its content at `range`
+fn parse_parts(
+    transcript: &Transcript,
+    headers: &[(String, String)],
+    range: Range<usize>,
+) -> Vec<Part> {
+    let content_type = header(headers, "content-type")
+        .unwrap_or("text/plain")
+        .to_string();
+    let boundary = forms::header_param(&content_type, "boundary");
+    if let (true, Some(boundary)) = (
+        content_type.to_ascii_lowercase().starts_with("multipart/"),
+        boundary,
+    ) {
+        let body = &transcript.bytes[range.clone()];
+        return forms::decode_multipart(body, &boundary)
+            .into_iter()
+            .flat_map(|part| {
+                let (headers, _) = parse_headers(&body[part.headers.clone()]);
+                let content = range.start + part.content.start..range.start + part.content.end;
+                parse_parts(transcript, &headers, content)
+            })
+            .collect();
+    }
+
+    let redacted = !transcript.redactions_within(&range).is_empty();
+    let encoding = header(headers, "content-transfer-encoding")
+        .unwrap_or("7bit")
+        .to_ascii_lowercase();
+    let bytes = &transcript.bytes[range.clone()];
+    let is_text = content_type.to_ascii_lowercase().starts_with("text/");
+    let text = match encoding.as_str() {
+        // Redacted bytes stand for encoded ones, decoding would garble the rest
+        _ if !is_text => None,
+        "base64" | "quoted-printable" if redacted => None,
+        "base64" => decode_base64(bytes),
+        "quoted-printable" => Some(decode_quoted_printable(bytes, false)),
+        _ => Some(bytes.to_vec()),
+    }
+    .map(|bytes| String::from_utf8_lossy(&bytes).to_string());
+    vec![Part {
+        content_type,
+        range,
+        text,
+        redacted,
+    }]
+}
+
+fn parse_message(transcript: &Transcript, range: Range<usize>, direction: Direction) -> Message {
+    let (headers, body_start) = parse_headers(&transcript.bytes[range.clone()]);
+    let parts = parse_parts(transcript, &headers, range.start + body_start..range.end);
+    Message {
+        direction,
+        range,
+        headers,
+        parts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcript(text: &str) -> Transcript {
+        Transcript {
+            bytes: text.as_bytes().to_vec(),
+            redacted_ranges: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn smtp() {
+        let sent = "EHLO me\r\nMAIL FROM:<a@example.com>\r\nDATA\r\nFrom: a@example.com\r\nSubject: =?UTF-8?Q?Gr=C3=BC=C3=9Fe?=\r\nContent-Transfer-Encoding: quoted-printable\r\n\r\nHallo=\r\n Welt =3D 1\r\n.\r\nQUIT\r\n";
+        let recv = "220 mail.example.com ESMTP\r\n250-mail.example.com\r\n250 SIZE 1000\r\n250 OK\r\n354 go ahead\r\n250 queued\r\n221 bye\r\n";
+        let session = parse(&VerifiedOutput::for_test(sent, recv)).unwrap();
+        assert_eq!(session.protocol, Protocol::Smtp);
+        let commands: Vec<(Option<&str>, usize)> = session
+            .exchanges
+            .iter()
+            .map(|exchange| {
+                let command = exchange.command.as_ref().map(|line| line.text.as_str());
+                (command, exchange.response.len())
+            })
+            .collect();
+        assert_eq!(
+            commands,
+            [
+                (None, 1),
+                (Some("EHLO me"), 2),
+                (Some("MAIL FROM:<a@example.com>"), 1),
+                (Some("DATA"), 1),
+                (Some("."), 1),
+                (Some("QUIT"), 1),
+            ]
+        );
+        let message = &session.messages[0];
+        assert_eq!(message.header("subject"), Some("Grüße"));
+        assert_eq!(message.parts[0].text.as_deref(), Some("Hallo Welt = 1"));
+    }
+
+    #[test]
+    fn imap() {
+        let body = "Subject: hi\r\nContent-Type: multipart/alternative; boundary=\"b\"\r\n\r\n--b\r\nContent-Type: text/plain\r\nContent-Transfer-Encoding: base64\r\n\r\naGVsbG8=\r\n--b\r\nContent-Type: text/html\r\n\r\n<p>hello</p>\r\n--b--\r\n";
+        let recv = format!(
+            "* OK IMAP ready\r\na1 OK LOGIN done\r\n* 1 FETCH (BODY[] {{{}}}\r\n{})\r\na2 OK FETCH done\r\n",
+            body.len(),
+            body
+        );
+        let session = parse(&VerifiedOutput::for_test(
+            "a1 LOGIN me XXXX\r\na2 FETCH 1 BODY[]\r\n",
+            &recv,
+        ))
+        .unwrap();
+        assert_eq!(session.protocol, Protocol::Imap);
+        assert_eq!(session.exchanges.len(), 3);
+        assert_eq!(session.exchanges[2].response.len(), 3);
+        let parts: Vec<(&str, Option<&str>)> = session.messages[0]
+            .parts
+            .iter()
+            .map(|part| (part.content_type.as_str(), part.text.as_deref()))
+            .collect();
+        assert_eq!(
+            parts,
+            [
+                ("text/plain", Some("hello")),
+                ("text/html", Some("<p>hello</p>"))
+            ]
+        );
+    }
+
+    #[test]
+    fn not_mail() {
+        assert_eq!(
+            detect(&VerifiedOutput::for_test(
+                "GET / HTTP/1.1\r\n\r\n",
+                "HTTP/1.1 200 OK\r\n\r\n"
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn redacted_parts() {
+        let mut transcript = transcript("Content-Transfer-Encoding: base64\r\n\r\naGVsbG8=");
+        transcript.redacted_ranges.push(38..40);
+        let message = parse_message(&transcript, 0..transcript.bytes.len(), Direction::Received);
+        assert!(message.parts[0].redacted);
+        assert_eq!(message.parts[0].text, None);
+    }
+}
@@ -0,0 +1,111 @@
+//! Makes the claim that proofs are verified locally auditable: `fetch` and `XMLHttpRequest` are
+//! wrapped to record every request the page makes, tagged with whether a verification was
+//! running. Optionally all requests are blocked.
+
+use std::cell::{Cell, RefCell};
+
+use gloo::storage::{LocalStorage, Storage};
+use gloo::utils::window;
+use js_sys::{Function, Reflect};
+use wasm_bindgen::prelude::*;
+
+const STORAGE_KEY: &str = "proof_viz.block_network";
+
+// Wraps `original` so `record` sees the URL (argument `index`) of every call first. `record`
+// throws to block the request, which `fetch` reports as a rejected promise like a network error.
+const WRAPPER: &str = "
+    return function () {
+        var url = arguments[index];
+        try {
+            record(String(url && url.url || url));
+        } catch (error) {
+            if (index === 0) return Promise.reject(error);
+            throw error;
+        }
+        return original.apply(this, arguments);
+    };";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NetworkRequest {
+    pub url: String,
+    /// Made while a proof was being verified
+    pub during_verification: bool,
+    pub blocked: bool,
+}
+
+thread_local! {
+    static REQUESTS: RefCell<Vec<NetworkRequest>> = const { RefCell::new(Vec::new()) };
+    static VERIFYING: Cell<bool> = const { Cell::new(false) };
+    static BLOCKING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn record(url: String) -> Result<(), JsValue> {
+    let blocked = BLOCKING.get();
+    REQUESTS.with_borrow_mut(|requests| {
+        requests.push(NetworkRequest {
+            url: url.clone(),
+            during_verification: VERIFYING.get(),
+            blocked,
+        })
+    });
+    if blocked {
+        Err(js_sys::TypeError::new(&format!("network request to {} blocked", url)).into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Replace the function `name` of `target` by one recording the URL in argument `index`
+fn wrap(target: &JsValue, name: &str, index: u32) -> Result<(), JsValue> {
+    let original = Reflect::get(target, &name.into())?;
+    let record = Closure::<dyn Fn(String) -> Result<(), JsValue>>::new(record);
+    let wrapper = Function::new_with_args("original, record, index", WRAPPER).call3(
+        &JsValue::NULL,
+        &original,
+        record.as_ref(),
+        &index.into(),
+    )?;
+    // The wrapper stays installed for the lifetime of the page
+    record.forget();
+    Reflect::set(target, &name.into(), &wrapper)?;
+    Ok(())
+}
+
+/// Start recording (and, if enabled, blocking) the page's network requests. Call once, before
+/// anything else makes requests.
+pub fn install() {
+    BLOCKING.set(LocalStorage::get(STORAGE_KEY).unwrap_or(false));
+    let window = window();
+    if let Err(err) = wrap(&window, "fetch", 0) {
+        tracing::warn!(error = ?err, "could not watch fetch");
+    }
+    let open = Reflect::get(&window, &"XMLHttpRequest".into())
+        .and_then(|xhr| Reflect::get(&xhr, &"prototype".into()))
+        .and_then(|prototype| wrap(&prototype, "open", 1));
+    if let Err(err) = open {
+        tracing::warn!(error = ?err, "could not watch XMLHttpRequest");
+    }
+}
+
+/// Run `verify`, attributing the requests made meanwhile to the verification
+pub fn watch<T>(verify: impl FnOnce() -> T) -> T {
+    VERIFYING.set(true);
+    let result = verify();
+    VERIFYING.set(false);
+    result
+}
+
+/// Every request the page made so far
+pub fn requests() -> Vec<NetworkRequest> {
+    REQUESTS.with_borrow(|requests| requests.clone())
+}
+
+pub fn is_blocking() -> bool {
+    BLOCKING.get()
+}
+
+/// Block (or allow again) all requests, e.g. fetching proofs by URL and the Notary registry
+pub fn set_blocking(blocking: bool) {
+    BLOCKING.set(blocking);
+    let _ = LocalStorage::set(STORAGE_KEY, blocking);
+}
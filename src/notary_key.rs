@@ -0,0 +1,273 @@
+use std::fmt;
+
+use base64::{engine::general_purpose, Engine as _};
+use elliptic_curve::pkcs8::{DecodePublicKey, EncodePublicKey, LineEnding};
+use elliptic_curve::sec1::ToEncodedPoint;
+use p256::ecdsa::signature::Verifier;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Public key of a Notary, in one of the supported signature schemes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NotaryKey {
+    P256(p256::PublicKey),
+    Ed25519(ed25519_dalek::VerifyingKey),
+}
+
+impl NotaryKey {
+    /// Parse a public key as pasted by a user.
+    ///
+    /// Besides PEM this accepts hex or base64 encoded DER SubjectPublicKeyInfo blobs,
+    /// SEC1 encoded P-256 points (33 or 65 bytes) and raw Ed25519 keys (32 bytes).
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        if input.starts_with("-----BEGIN") {
+            return Self::from_public_key_pem(input);
+        }
+
+        let compact: String = input.split_whitespace().collect();
+        let bytes = decode_hex(&compact)
+            .or_else(|| decode_base64(&compact))
+            .ok_or_else(|| "key is not valid PEM, hex or base64".to_string())?;
+
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parse a binary key: a SEC1 point, a raw Ed25519 key or a DER SubjectPublicKeyInfo
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        match bytes.len() {
+            33 | 65 => p256::PublicKey::from_sec1_bytes(bytes)
+                .map(NotaryKey::P256)
+                .map_err(|_| "key is not a valid SEC1 encoded P-256 point".to_string()),
+            32 => {
+                let bytes: &[u8; 32] = bytes.try_into().expect("length is checked");
+                ed25519_dalek::VerifyingKey::from_bytes(bytes)
+                    .map(NotaryKey::Ed25519)
+                    .map_err(|_| "key is not a valid Ed25519 public key".to_string())
+            }
+            _ => match p256::PublicKey::from_public_key_der(bytes) {
+                Ok(key) => Ok(NotaryKey::P256(key)),
+                Err(p256_err) => ed25519_dalek::VerifyingKey::from_public_key_der(bytes)
+                    .map(NotaryKey::Ed25519)
+                    .map_err(|_| p256_err.to_string()),
+            },
+        }
+    }
+
+    /// Parse a PEM encoded SubjectPublicKeyInfo, trying every supported scheme
+    pub fn from_public_key_pem(pem: &str) -> Result<Self, String> {
+        match p256::PublicKey::from_public_key_pem(pem) {
+            Ok(key) => Ok(NotaryKey::P256(key)),
+            Err(p256_err) => ed25519_dalek::VerifyingKey::from_public_key_pem(pem)
+                .map(NotaryKey::Ed25519)
+                // Report the P-256 error, that is still the most common key type
+                .map_err(|_| p256_err.to_string()),
+        }
+    }
+
+    /// PEM encoded SubjectPublicKeyInfo
+    pub fn to_public_key_pem(self) -> String {
+        match self {
+            NotaryKey::P256(key) => key.to_public_key_pem(LineEnding::LF),
+            NotaryKey::Ed25519(key) => key.to_public_key_pem(LineEnding::LF),
+        }
+        .expect("a valid public key can be encoded")
+    }
+
+    /// Compressed SEC1 point or raw Ed25519 key, the inverse of `from_bytes`
+    pub fn to_bytes(self) -> Vec<u8> {
+        match self {
+            NotaryKey::P256(key) => key.to_encoded_point(true).as_bytes().to_vec(),
+            NotaryKey::Ed25519(key) => key.as_bytes().to_vec(),
+        }
+    }
+
+    /// Hex encoded SHA-256 of the DER SubjectPublicKeyInfo, as `openssl pkey -pubin -outform DER | sha256sum` prints it
+    pub fn fingerprint(self) -> String {
+        let der = match self {
+            NotaryKey::P256(key) => key.to_public_key_der(),
+            NotaryKey::Ed25519(key) => key.to_public_key_der(),
+        }
+        .expect("a valid public key can be encoded");
+        hex::encode(Sha256::digest(der.as_bytes()))
+    }
+
+    /// Whether `signature` over `message` was made with this key: an ECDSA (SHA-256) signature in
+    /// DER or fixed size encoding for P-256, a plain signature for Ed25519
+    pub fn verifies(self, message: &[u8], signature: &[u8]) -> bool {
+        match self {
+            NotaryKey::P256(key) => p256::ecdsa::Signature::from_der(signature)
+                .or_else(|_| p256::ecdsa::Signature::from_slice(signature))
+                .is_ok_and(|signature| {
+                    p256::ecdsa::VerifyingKey::from(key)
+                        .verify(message, &signature)
+                        .is_ok()
+                }),
+            NotaryKey::Ed25519(key) => ed25519_dalek::Signature::from_slice(signature)
+                .is_ok_and(|signature| key.verify(message, &signature).is_ok()),
+        }
+    }
+
+    pub fn scheme(&self) -> &'static str {
+        match self {
+            NotaryKey::P256(_) => "P-256",
+            NotaryKey::Ed25519(_) => "Ed25519",
+        }
+    }
+}
+
+/// Where the Notary key in use came from, so results can state which key was trusted
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum KeySource {
+    /// The TLSNotary test fixture key, the default unless the build configures another one
+    Fixture,
+    /// The default key configured for this deployment
+    Configured,
+    /// Picked from the Notary registry
+    Registry { name: String, url: String },
+    /// Entered by the user
+    Custom,
+}
+
+impl fmt::Display for NotaryKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.scheme())
+    }
+}
+
+fn decode_hex(input: &str) -> Option<Vec<u8>> {
+    let input = input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+        .unwrap_or(input);
+    hex::decode(input).ok()
+}
+
+fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    general_purpose::STANDARD
+        .decode(input)
+        .or_else(|_| general_purpose::URL_SAFE.decode(input))
+        .or_else(|_| general_purpose::STANDARD_NO_PAD.decode(input))
+        .or_else(|_| general_purpose::URL_SAFE_NO_PAD.decode(input))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FIXTURE_PEM;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const P384_PEM: &str = "-----BEGIN PUBLIC KEY-----
+MHYwEAYHKoZIzj0CAQYFK4EEACIDYgAESW6PL75TbZR74bm/IF0lFWwYEVGgMYrP
+eOW1hMcBFV5QVi7HpR8fh9DSe8bKRZBosHsEvZenX64rJpD/+PKofbOujmP3Rxxv
+1gfH1RNcA0nXKNopl9Rvz8YndQ+iGPE7
+-----END PUBLIC KEY-----";
+
+    /// The fixture key, and its compressed and uncompressed SEC1 points and SPKI DER
+    fn fixture() -> (NotaryKey, Vec<u8>, Vec<u8>, Vec<u8>) {
+        let key = NotaryKey::parse(FIXTURE_PEM).unwrap();
+        let NotaryKey::P256(point) = key else {
+            panic!("the fixture key is a P-256 key")
+        };
+        let uncompressed = point.to_encoded_point(false).as_bytes().to_vec();
+        let der = point.to_public_key_der().unwrap().as_bytes().to_vec();
+        (key, key.to_bytes(), uncompressed, der)
+    }
+
+    #[test]
+    fn p256_encodings() {
+        let (key, compressed, uncompressed, der) = fixture();
+        let wrapped = general_purpose::STANDARD.encode(&der);
+        let inputs = [
+            ("SPKI PEM", FIXTURE_PEM.to_string()),
+            ("compressed SEC1 hex", hex::encode(&compressed)),
+            (
+                "uncompressed SEC1 hex",
+                format!("0x{}", hex::encode(&uncompressed)),
+            ),
+            (
+                "compressed SEC1 base64",
+                general_purpose::STANDARD.encode(&compressed),
+            ),
+            (
+                "uncompressed SEC1 base64url",
+                general_purpose::URL_SAFE_NO_PAD.encode(&uncompressed),
+            ),
+            ("SPKI DER hex", hex::encode_upper(&der)),
+            (
+                "SPKI DER base64 over two lines",
+                format!("{}\n{}", &wrapped[..64], &wrapped[64..]),
+            ),
+        ];
+        for (what, input) in inputs {
+            assert_eq!(NotaryKey::parse(&input), Ok(key), "{what}");
+        }
+    }
+
+    #[test]
+    fn invalid_keys() {
+        let (_, _, uncompressed, der) = fixture();
+        let mut off_curve = uncompressed.clone();
+        off_curve[64] ^= 1;
+        let inputs = [
+            ("truncated SEC1 point", hex::encode(&uncompressed[..64])),
+            ("truncated SPKI DER", hex::encode(&der[..der.len() - 1])),
+            (
+                "truncated PEM",
+                FIXTURE_PEM[..FIXTURE_PEM.len() - 40].to_string(),
+            ),
+            ("point off the curve", hex::encode(&off_curve)),
+            ("P-384 PEM", P384_PEM.to_string()),
+            ("garbage", "not a key!".to_string()),
+            ("empty", String::new()),
+        ];
+        for (what, input) in inputs {
+            assert!(NotaryKey::parse(&input).is_err(), "{what}");
+        }
+    }
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    #[test]
+    fn ed25519_keys() {
+        let key = signing_key().verifying_key();
+        let inputs = [
+            ("SPKI PEM", key.to_public_key_pem(LineEnding::LF).unwrap()),
+            (
+                "SPKI DER hex",
+                hex::encode(key.to_public_key_der().unwrap()),
+            ),
+            ("raw hex", hex::encode(key.as_bytes())),
+            (
+                "raw base64",
+                general_purpose::STANDARD.encode(key.as_bytes()),
+            ),
+        ];
+        for (what, input) in inputs {
+            assert_eq!(
+                NotaryKey::parse(&input),
+                Ok(NotaryKey::Ed25519(key)),
+                "{what}"
+            );
+        }
+    }
+
+    #[test]
+    fn schemes_are_told_apart() {
+        let p256 = NotaryKey::parse(FIXTURE_PEM).unwrap();
+        let ed25519 = NotaryKey::Ed25519(signing_key().verifying_key());
+        assert_eq!((p256.scheme(), ed25519.scheme()), ("P-256", "Ed25519"));
+        for key in [p256, ed25519] {
+            assert_eq!(NotaryKey::parse(&key.to_public_key_pem()), Ok(key));
+            assert_eq!(NotaryKey::from_bytes(&key.to_bytes()), Ok(key));
+        }
+
+        let signature = signing_key().sign(b"header").to_bytes();
+        assert!(ed25519.verifies(b"header", &signature));
+        assert!(!ed25519.verifies(b"another header", &signature));
+        assert!(!p256.verifies(b"header", &signature));
+    }
+}
@@ -0,0 +1,355 @@
+//! Well-known Notary deployments, bundled with the app, so their keys can be picked by name and
+//! verified proofs show which Notary signed them.
+//!
+//! Deployments can also point the app at a registry published by the maintainers, so new Notaries
+//! appear without redeploying the site. It is signed, with the signature checked against a
+//! maintainer key pinned at build time (see `config`):
+//!
+//! ```text
+//! { "registry": "<the registry JSON, as a string>", "signature": "<base64 signature of it>" }
+//! ```
+//!
+//! Every registry has a `version`, raised with each publication. A registry older than the one
+//! in use is refused, so an old signed registry, e.g. one still listing a compromised key, can
+//! not be served again.
+
+use base64::{engine::general_purpose, Engine as _};
+use gloo::net::http::Request;
+use gloo::storage::{LocalStorage, Storage};
+use serde::Deserialize;
+use yew::Callback;
+
+use crate::config;
+use crate::notary_key::NotaryKey;
+
+const BUNDLED: &str = include_str!("notary_registry.json");
+/// The public Notary run by PSE, which most proofs are notarized by
+pub const PSE_NOTARY_URL: &str = "https://notary.pse.dev";
+/// The last fetched signed registry
+const STORAGE_KEY: &str = "proof_viz.notary_registry";
+
+#[derive(Deserialize)]
+struct SignedRegistry {
+    registry: String,
+    signature: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct NotaryEntry {
+    pub name: String,
+    /// Where the Notary is run or documented
+    pub url: String,
+    /// The key in any format the key input accepts, usually PEM
+    pub public_key: String,
+    /// Hex encoded SHA-256 of the DER encoded key, see `NotaryKey::fingerprint`
+    pub fingerprint: String,
+}
+
+impl NotaryEntry {
+    pub fn key(&self) -> NotaryKey {
+        NotaryKey::parse(&self.public_key).expect("registry keys are checked when parsing")
+    }
+}
+
+/// How a key compares to the registry entry of the Notary it is meant to belong to
+#[derive(Debug, PartialEq)]
+pub enum KeyCheck<'a> {
+    /// The key is the one in the registry
+    Match(&'a NotaryEntry),
+    /// The registry has another key for this Notary: someone may be intercepting the connection
+    /// to it, or it rotated its key
+    Mismatch(&'a NotaryEntry),
+    /// The Notary is not in the registry
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Registry {
+    /// Raised with each publication
+    pub version: u64,
+    pub notaries: Vec<NotaryEntry>,
+}
+
+impl Registry {
+    /// Parse a registry, checking that every key is valid and matches its fingerprint
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let registry: Registry = serde_json::from_str(json).map_err(|err| err.to_string())?;
+        for entry in &registry.notaries {
+            let key = NotaryKey::parse(&entry.public_key)
+                .map_err(|err| format!("invalid key of {}: {}", entry.name, err))?;
+            if !key.fingerprint().eq_ignore_ascii_case(&entry.fingerprint) {
+                return Err(format!(
+                    "the fingerprint of {} does not match its key",
+                    entry.name
+                ));
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Parse a signed registry, which has to be signed with `maintainer_key` and be at least
+    /// `min_version`
+    pub fn parse_signed(
+        json: &str,
+        maintainer_key: NotaryKey,
+        min_version: u64,
+    ) -> Result<Self, String> {
+        let signed: SignedRegistry = serde_json::from_str(json).map_err(|err| err.to_string())?;
+        let signature = general_purpose::STANDARD
+            .decode(signed.signature.trim())
+            .map_err(|err| format!("invalid signature encoding: {}", err))?;
+        if !maintainer_key.verifies(signed.registry.as_bytes(), &signature) {
+            return Err("the registry is not signed with the maintainer key".to_string());
+        }
+        let registry = Self::parse(&signed.registry)?;
+        if registry.version < min_version {
+            return Err(format!(
+                "the registry has version {}, older than version {} in use",
+                registry.version, min_version
+            ));
+        }
+        Ok(registry)
+    }
+
+    /// The registry shipped with the app
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED).expect("the bundled registry is valid")
+    }
+
+    /// The last fetched registry if it is still signed with the pinned key and not older than
+    /// the bundled one, the bundled one otherwise
+    pub fn load() -> Self {
+        let bundled = Self::bundled();
+        config::registry_source()
+            .map(|(_, key)| key)
+            .zip(LocalStorage::get::<String>(STORAGE_KEY).ok())
+            .and_then(|(key, json)| Self::parse_signed(&json, key, bundled.version).ok())
+            .unwrap_or(bundled)
+    }
+
+    /// Fetch the published registry, if the build has one configured, and emit it once its
+    /// signature checks out and it is not older than the registry in use. A failure keeps the
+    /// registry in use, it is only logged.
+    pub fn refresh(callback: Callback<Registry>) {
+        let Some((url, key)) = config::registry_source() else {
+            return;
+        };
+        let min_version = Self::load().version;
+        yew::platform::spawn_local(async move {
+            let result = match Request::get(url).send().await {
+                Ok(response) if response.ok() => response.text().await.map_err(|e| e.to_string()),
+                Ok(response) => Err(format!(
+                    "HTTP {} {}",
+                    response.status(),
+                    response.status_text()
+                )),
+                Err(err) => Err(err.to_string()),
+            }
+            .and_then(|json| {
+                Self::parse_signed(&json, key, min_version).map(|registry| (json, registry))
+            });
+            match result {
+                Ok((json, registry)) => {
+                    // Not being able to cache it only means fetching it again next time
+                    let _ = LocalStorage::set(STORAGE_KEY, json);
+                    callback.emit(registry);
+                }
+                Err(err) => {
+                    tracing::warn!(%url, error = %err, "could not refresh the Notary registry")
+                }
+            }
+        });
+    }
+
+    /// The entry of the Notary at `url`, ignoring the scheme, case and a trailing slash
+    pub fn find_by_url(&self, url: &str) -> Option<&NotaryEntry> {
+        let url = normalize_url(url);
+        self.notaries
+            .iter()
+            .find(|entry| normalize_url(&entry.url) == url)
+    }
+
+    /// Compare `key` with the registry entry of the Notary at `url`
+    pub fn check_key(&self, url: &str, key: NotaryKey) -> KeyCheck<'_> {
+        match self.find_by_url(url) {
+            Some(entry) if entry.fingerprint.eq_ignore_ascii_case(&key.fingerprint()) => {
+                KeyCheck::Match(entry)
+            }
+            Some(entry) => KeyCheck::Mismatch(entry),
+            None => KeyCheck::Unknown,
+        }
+    }
+
+    /// The entry of the Notary using `key`, if it is a known one
+    pub fn find(&self, key: NotaryKey) -> Option<&NotaryEntry> {
+        let fingerprint = key.fingerprint();
+        self.notaries
+            .iter()
+            .find(|entry| entry.fingerprint.eq_ignore_ascii_case(&fingerprint))
+    }
+}
+
+fn normalize_url(url: &str) -> String {
+    let url = url.trim().to_ascii_lowercase();
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(&url);
+    url.trim_end_matches('/').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const PSE_KEY: &str = "-----BEGIN PUBLIC KEY-----
+MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAExpX/4R4z40gI6C/j9zAM39u58LJu
+3Cx5tXTuqhhu/tirnBi5GniMmspOTEsps4ANnPLpMmMSfhJ+IFHbc3qVOA==
+-----END PUBLIC KEY-----";
+    const OTHER_KEY: &str = "-----BEGIN PUBLIC KEY-----
+MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEBv36FI4ZFszJa0DQFJ3wWCXvVLFr
+cRzMG5kaTeHGoSzDu6cFqx3uEWYpFGo6C0EOUgf+mEgbktLrXocv5yHzKg==
+-----END PUBLIC KEY-----";
+
+    fn key(pem: &str) -> NotaryKey {
+        NotaryKey::parse(pem).unwrap()
+    }
+
+    fn registry() -> Registry {
+        Registry {
+            version: 1,
+            notaries: vec![NotaryEntry {
+                name: "PSE notary".to_string(),
+                url: "https://notary.pse.dev".to_string(),
+                public_key: PSE_KEY.to_string(),
+                fingerprint: key(PSE_KEY).fingerprint(),
+            }],
+        }
+    }
+
+    #[test]
+    fn matching_key() {
+        let registry = registry();
+        assert_eq!(
+            registry.check_key("https://notary.pse.dev", key(PSE_KEY)),
+            KeyCheck::Match(&registry.notaries[0])
+        );
+    }
+
+    #[test]
+    fn mismatching_key() {
+        let registry = registry();
+        assert_eq!(
+            registry.check_key("https://notary.pse.dev", key(OTHER_KEY)),
+            KeyCheck::Mismatch(&registry.notaries[0])
+        );
+    }
+
+    #[test]
+    fn url_is_normalized() {
+        let registry = registry();
+        for url in [
+            "notary.pse.dev",
+            "HTTPS://Notary.PSE.dev/",
+            " http://notary.pse.dev ",
+        ] {
+            assert_eq!(
+                registry.check_key(url, key(OTHER_KEY)),
+                KeyCheck::Mismatch(&registry.notaries[0])
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_notary() {
+        assert_eq!(
+            registry().check_key("https://notary.example.com", key(PSE_KEY)),
+            KeyCheck::Unknown
+        );
+    }
+
+    #[test]
+    fn bundled_registry_is_valid() {
+        let registry = Registry::bundled();
+        assert!(!registry.notaries.is_empty());
+        for entry in &registry.notaries {
+            assert!(matches!(
+                registry.check_key(&entry.url, entry.key()),
+                KeyCheck::Match(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn fingerprint_has_to_match_the_key() {
+        let json = serde_json::json!({
+            "version": 1,
+            "notaries": [{
+                "name": "PSE notary",
+                "url": "https://notary.pse.dev",
+                "public_key": PSE_KEY,
+                "fingerprint": key(OTHER_KEY).fingerprint(),
+            }]
+        });
+        assert!(Registry::parse(&json.to_string()).is_err());
+    }
+
+    // The registry of `registry()` as published with `version`, signed with `signing_key`
+    fn signed(version: u64, signing_key: &SigningKey) -> (String, String) {
+        let registry = serde_json::json!({
+            "version": version,
+            "notaries": [{
+                "name": "PSE notary",
+                "url": "https://notary.pse.dev",
+                "public_key": PSE_KEY,
+                "fingerprint": key(PSE_KEY).fingerprint(),
+            }]
+        })
+        .to_string();
+        let signature =
+            general_purpose::STANDARD.encode(signing_key.sign(registry.as_bytes()).to_bytes());
+        (registry, signature)
+    }
+
+    fn publish(registry: &str, signature: &str) -> String {
+        serde_json::json!({ "registry": registry, "signature": signature }).to_string()
+    }
+
+    fn maintainer() -> (SigningKey, NotaryKey) {
+        let signing_key = SigningKey::from_bytes(&[7; 32]);
+        let key = NotaryKey::Ed25519(signing_key.verifying_key());
+        (signing_key, key)
+    }
+
+    #[test]
+    fn signed_registry() {
+        let (signing_key, maintainer_key) = maintainer();
+        let (body, signature) = signed(2, &signing_key);
+        let parsed = Registry::parse_signed(&publish(&body, &signature), maintainer_key, 1);
+        assert_eq!(parsed.unwrap().notaries, registry().notaries);
+
+        // Another body under the same signature
+        let tampered = body.replace("PSE notary", "PSE n0tary");
+        assert!(
+            Registry::parse_signed(&publish(&tampered, &signature), maintainer_key, 1).is_err()
+        );
+
+        // Signed by someone else
+        let other_key = NotaryKey::Ed25519(SigningKey::from_bytes(&[8; 32]).verifying_key());
+        assert!(Registry::parse_signed(&publish(&body, &signature), other_key, 1).is_err());
+
+        let unsigned = serde_json::json!({ "registry": body }).to_string();
+        assert!(Registry::parse_signed(&unsigned, maintainer_key, 1).is_err());
+    }
+
+    #[test]
+    fn older_registry_is_refused() {
+        let (signing_key, maintainer_key) = maintainer();
+        let (body, signature) = signed(2, &signing_key);
+        let json = publish(&body, &signature);
+        assert!(Registry::parse_signed(&json, maintainer_key, 2).is_ok());
+        let err = Registry::parse_signed(&json, maintainer_key, 3).unwrap_err();
+        assert!(err.contains("older"), "{}", err);
+    }
+}
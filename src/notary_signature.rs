@@ -0,0 +1,142 @@
+//! The Notary's signature over the session header, checked independently of tlsn-core so
+//! a failing verification can be narrowed down to the key.
+
+use ecdsa::RecoveryId;
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use serde_json::Value;
+
+use crate::notary_key::NotaryKey;
+use crate::session_header::{self, SessionHeader};
+
+/// The signature of a proof, with the message it signs
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotarySignature {
+    /// Name of the scheme, as tagged in the proof
+    pub scheme: String,
+    pub bytes: Vec<u8>,
+    /// The BCS serialized session header
+    pub message: Vec<u8>,
+}
+
+impl NotarySignature {
+    /// Read the signature and the signed header from a proof
+    pub fn parse(proof: &Value) -> Result<Self, String> {
+        let (scheme, signature) = proof
+            .pointer("/session/signature")
+            .and_then(Value::as_object)
+            .and_then(|signature| signature.iter().next())
+            .ok_or_else(|| "proof contains no Notary signature".to_string())?;
+        let bytes = signature
+            .as_str()
+            .and_then(|hex_str| hex::decode(hex_str).ok())
+            .ok_or_else(|| format!("{} signature is not hex encoded", scheme))?;
+        let header = session_header::session_header(proof)?;
+
+        Ok(NotarySignature {
+            scheme: scheme.clone(),
+            bytes,
+            message: signed_message(&header)?,
+        })
+    }
+
+    /// Human readable signature algorithm
+    pub fn algorithm(&self) -> &str {
+        match self.scheme.as_str() {
+            "P256" => "ECDSA with NIST P-256 and SHA-256",
+            scheme => scheme,
+        }
+    }
+
+    fn p256_signature(&self) -> Option<Signature> {
+        if self.scheme != "P256" {
+            return None;
+        }
+        Signature::from_slice(&self.bytes).ok()
+    }
+
+    /// Whether the signature verifies against `key`
+    pub fn verifies_with(&self, key: &NotaryKey) -> bool {
+        match (key, self.p256_signature()) {
+            (NotaryKey::P256(key), Some(signature)) => VerifyingKey::from(key)
+                .verify(&self.message, &signature)
+                .is_ok(),
+            _ => false,
+        }
+    }
+
+    /// The keys which could have made this signature, ECDSA allows recovering up to two candidates
+    pub fn recover_keys(&self) -> Vec<NotaryKey> {
+        let Some(signature) = self.p256_signature() else {
+            return Vec::new();
+        };
+        (0..=1)
+            .filter_map(RecoveryId::from_byte)
+            .filter_map(|id| VerifyingKey::recover_from_msg(&self.message, &signature, id).ok())
+            .map(|key| NotaryKey::P256(key.into()))
+            .collect()
+    }
+}
+
+// BCS serialization of the header: fixed size integers and arrays as is, sequences and enum
+// variants prefixed with a ULEB128 length or index
+fn signed_message(header: &SessionHeader) -> Result<Vec<u8>, String> {
+    let summary = &header.handshake_summary;
+    // tlsn-core alpha.6 only knows this one group
+    if summary.server_public_key.group != "secp256r1" {
+        return Err(format!(
+            "unsupported key exchange group {}",
+            summary.server_public_key.group
+        ));
+    }
+
+    let mut message = Vec::new();
+    message.extend(&header.encoder_seed);
+    message.extend(&header.merkle_root);
+    message.extend((header.sent_len as u64).to_le_bytes());
+    message.extend((header.recv_len as u64).to_le_bytes());
+    message.extend(summary.time.to_le_bytes());
+    push_uleb128(&mut message, 0);
+    push_uleb128(&mut message, summary.server_public_key.key.len());
+    message.extend(&summary.server_public_key.key);
+    message.extend(&summary.handshake_commitment);
+    Ok(message)
+}
+
+fn push_uleb128(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+
+    fn fixture() -> (Value, NotaryKey) {
+        let proof = std::fs::read_to_string("test-input/proof.json").unwrap();
+        let key = NotaryKey::parse(config::FIXTURE_PEM).unwrap();
+        (serde_json::from_str(&proof).unwrap(), key)
+    }
+
+    #[test]
+    fn fixture_proof_verifies() {
+        let (proof, key) = fixture();
+        let signature = NotarySignature::parse(&proof).unwrap();
+        assert_eq!(signature.scheme, "P256");
+        assert!(signature.verifies_with(&key));
+        assert!(signature.recover_keys().contains(&key));
+    }
+
+    #[test]
+    fn changed_header_fails() {
+        let (mut proof, key) = fixture();
+        let seed = &mut proof["session"]["header"]["encoder_seed"][0];
+        *seed = (seed.as_u64().unwrap() ^ 1).into();
+        let signature = NotarySignature::parse(&proof).unwrap();
+        assert!(!signature.verifies_with(&key));
+        assert!(!signature.recover_keys().contains(&key));
+    }
+}
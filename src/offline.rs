@@ -0,0 +1,48 @@
+//! Working offline: the service worker generated by `pwa/service_worker.sh` caches the app on
+//! the first visit, after which it loads and verifies proofs without a network connection. The
+//! browser's offer to install the app is kept, to make it from the navigation instead.
+
+use gloo::events::{EventListener, EventListenerOptions};
+use gloo::utils::window;
+use js_sys::{Function, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Event;
+use yew::Callback;
+
+/// Register the service worker, browsers without support (or in private modes) go without
+pub fn register() {
+    let navigator = window().navigator();
+    if Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        // Relative to the `<base>` set by Trunk's public URL, the scope is the whole app
+        let _ = navigator.service_worker().register("sw.js");
+    }
+}
+
+/// The deferred `beforeinstallprompt` event, which shows the browser's install dialog
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstallPrompt(Event);
+
+impl InstallPrompt {
+    /// Show the install dialog, an event can only do so once
+    pub fn prompt(&self) {
+        if let Ok(prompt) = Reflect::get(&self.0, &JsValue::from_str("prompt")) {
+            if let Some(prompt) = prompt.dyn_ref::<Function>() {
+                let _ = prompt.call0(&self.0);
+            }
+        }
+    }
+}
+
+/// Emits the install prompt when the browser offers to install the app, instead of showing its
+/// own banner
+pub fn listen_install_prompt(callback: Callback<InstallPrompt>) -> EventListener {
+    EventListener::new_with_options(
+        &window(),
+        "beforeinstallprompt",
+        EventListenerOptions::enable_prevent_default(),
+        move |event| {
+            event.prevent_default();
+            callback.emit(InstallPrompt(event.clone()));
+        },
+    )
+}
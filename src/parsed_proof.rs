@@ -0,0 +1,130 @@
+//! A proof file parsed once, shared by the views which read parts of it, so none of them
+//! parses the file again. The raw bytes are not kept once parsed.
+
+use serde_json::Value;
+
+use crate::formats;
+use crate::i18n::Locale;
+use crate::input::classify::{self, Detected};
+use crate::notary_key::NotaryKey;
+use crate::proof_digest;
+use crate::verify::{self, Verification, VerifyOptions};
+
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsedProof {
+    /// What the file looks like, only JSON is parsed and verified
+    pub detected: Detected,
+    /// The proof JSON, a session proof on its own wrapped like the session of a full proof.
+    /// `Null` if the file is not JSON.
+    pub value: Value,
+    /// Why a file detected as JSON could not be parsed
+    pub error: Option<String>,
+    /// Size of the file in bytes
+    pub size: usize,
+    /// Hex encoded SHA-256 of the file as uploaded
+    pub file_digest: String,
+    /// Hex encoded SHA-256 of the canonical JSON, `None` if the file is not JSON
+    pub canonical_digest: Option<String>,
+}
+
+impl ParsedProof {
+    pub fn parse(data: &[u8]) -> Self {
+        let detected = classify::classify(data);
+        let json = data.strip_prefix(UTF8_BOM).unwrap_or(data);
+        let (value, error) = match detected {
+            Detected::Json => match serde_json::from_slice::<Value>(json) {
+                Ok(value) => (Some(value), None),
+                Err(err) => (None, Some(err.to_string())),
+            },
+            _ => (None, None),
+        };
+        ParsedProof {
+            detected,
+            // Of the proof as uploaded, like the digest of reports
+            canonical_digest: value.as_ref().map(proof_digest::value_digest),
+            value: value.map_or(Value::Null, formats::wrap_session_value),
+            error,
+            size: data.len(),
+            file_digest: proof_digest::file_digest(data),
+        }
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.detected.is_json()
+    }
+
+    /// Verify the proof, a file which is not JSON fails to parse with what it is instead
+    pub fn verify(&self, pem: NotaryKey, options: &VerifyOptions) -> Verification {
+        match (self.detected, &self.error) {
+            (Detected::Json, None) => verify::verify_value(&self.value, pem, options),
+            (Detected::Json, Some(error)) => Verification::unparsed(error.clone()),
+            (detected, _) => Verification::unparsed(detected.describe(Locale::En)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::ProofFormat;
+    use crate::verify::{Step, VerifyError};
+
+    #[test]
+    fn session_proof_is_wrapped() {
+        let parsed = ParsedProof::parse(b"\xef\xbb\xbf{\"header\": {}, \"server_name\": \"a\"}");
+        assert_eq!(
+            parsed.value.pointer("/session/server_name"),
+            Some(&"a".into())
+        );
+        assert_eq!(formats::detect(&parsed.value), Some(ProofFormat::Alpha6));
+        assert_eq!(
+            parsed.canonical_digest,
+            proof_digest::canonical_digest(b"{\"header\":{},\"server_name\":\"a\"}")
+        );
+        // Full proofs and anything else are left alone
+        let proof = r#"{"session": {"header": {}, "server_name": "a"}, "substrings": {}}"#;
+        assert_eq!(
+            ParsedProof::parse(proof.as_bytes()).value,
+            serde_json::from_str::<Value>(proof).unwrap()
+        );
+        assert_eq!(
+            ParsedProof::parse(b"{\"header\": 1}").value,
+            serde_json::json!({ "header": 1 })
+        );
+
+        let parsed = ParsedProof::parse(b"not json");
+        assert_eq!(parsed.detected, Detected::Text);
+        assert_eq!(parsed.value, Value::Null);
+        assert_eq!(parsed.canonical_digest, None);
+        assert_eq!(parsed.file_digest, proof_digest::file_digest(b"not json"));
+        assert_eq!(parsed.size, 8);
+        // Nor are files which are not UTF-8
+        let parsed = ParsedProof::parse(b"{\xff}");
+        assert_eq!(parsed.detected, Detected::NotUtf8(1));
+        assert_eq!(parsed.error, None);
+    }
+
+    #[test]
+    fn unparsed_files_fail_to_parse() {
+        let pem = NotaryKey::parse(crate::config::FIXTURE_PEM).unwrap();
+        let options = VerifyOptions::default();
+
+        let parsed = ParsedProof::parse(br#"{"session": "#);
+        assert!(parsed.is_json());
+        assert!(parsed.error.as_deref().unwrap().contains("line 1"));
+        let verification = parsed.verify(pem, &options);
+        assert_eq!(verification.steps.first_failure(), Some(Step::Parse));
+        assert_eq!(
+            verification.result.err(),
+            parsed.error.map(VerifyError::Parse)
+        );
+
+        let parsed = ParsedProof::parse(b"");
+        assert_eq!(
+            parsed.verify(pem, &options).result.err(),
+            Some(VerifyError::Parse(Detected::Empty.describe(Locale::En)))
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! Verification policies: a JSON file of expectations every proof is checked against.
+//!
+//! ```json
+//! {
+//!   "expected_server_name": "api.example.com",
+//!   "max_age_days": 30,
+//!   "required_disclosed_paths": ["$.data.balance"],
+//!   "forbidden_disclosed_headers": ["cookie", "authorization"]
+//! }
+//! ```
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use spansy::http::{parse_request, parse_response, Header};
+use spansy::Spanned;
+
+use crate::assertions::{server_name_matches, Outcome};
+use crate::json_query;
+use crate::json_spans::{self, contains_marker};
+use crate::time_checks;
+use crate::verify::{Transcript, VerifiedOutput};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+    /// The domain the proofs must be for, `*.example.com` matches one subdomain level
+    #[serde(default)]
+    pub expected_server_name: Option<String>,
+    /// Proofs notarized longer ago than this are not accepted
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+    /// JSONPath expressions into the response body which must be present and fully disclosed
+    #[serde(default)]
+    pub required_disclosed_paths: Vec<String>,
+    /// Request or response headers whose value must be redacted, e.g. `cookie`
+    #[serde(default)]
+    pub forbidden_disclosed_headers: Vec<String>,
+}
+
+impl Policy {
+    pub fn parse(json: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(json).map_err(|e| e.to_string())
+    }
+}
+
+/// The response body as JSON, with redacted values replaced by the marker
+fn response_json(recv: &Transcript) -> Result<Value, String> {
+    let response = parse_response(&recv.bytes).map_err(|e| e.to_string())?;
+    let body = response.body.ok_or("the response has no body")?;
+    let range = body
+        .span()
+        .indices()
+        .iter_ranges()
+        .next()
+        .ok_or("the response has no body")?;
+    let redacted = recv.redactions_within(&range);
+    let json = json_spans::parse(&recv.bytes[range], &redacted)?;
+    Ok(json.to_value(&redacted))
+}
+
+fn disclosed_headers<'a>(
+    headers: &'a [Header],
+    transcript: &'a Transcript,
+) -> impl Iterator<Item = String> + 'a {
+    headers
+        .iter()
+        .filter(|header| {
+            header
+                .value
+                .span()
+                .indices()
+                .iter_ranges()
+                .any(|range| transcript.is_disclosed(&range))
+        })
+        .map(|header| header.name.as_str().to_ascii_lowercase())
+}
+
+fn check_forbidden_headers(forbidden: &[String], verified: &VerifiedOutput) -> Vec<Outcome> {
+    let mut disclosed = Vec::new();
+    let mut errors = Vec::new();
+    match parse_request(&verified.sent.bytes) {
+        Ok(request) => disclosed.extend(disclosed_headers(&request.headers, &verified.sent)),
+        Err(err) => errors.push(format!("request can not be parsed: {}", err)),
+    }
+    match parse_response(&verified.recv.bytes) {
+        Ok(response) => disclosed.extend(disclosed_headers(&response.headers, &verified.recv)),
+        Err(err) => errors.push(format!("response can not be parsed: {}", err)),
+    }
+
+    forbidden
+        .iter()
+        .map(|name| {
+            let leaked = disclosed.contains(&name.to_ascii_lowercase());
+            Outcome {
+                description: format!("Header {} is not disclosed", name),
+                passed: !leaked && errors.is_empty(),
+                detail: if leaked {
+                    "its value is disclosed".to_string()
+                } else {
+                    errors.join(", ")
+                },
+            }
+        })
+        .collect()
+}
+
+fn check_required_paths(paths: &[String], verified: &VerifiedOutput) -> Vec<Outcome> {
+    let json = response_json(&verified.recv);
+
+    paths
+        .iter()
+        .map(|path| {
+            let (passed, detail) = match &json {
+                Err(err) => (false, format!("the body can not be read as JSON: {}", err)),
+                Ok(json) => match json_query::query(json, path) {
+                    Ok(values) if values.is_empty() => (false, "no such field".to_string()),
+                    Ok(values) if values.iter().any(|value| contains_marker(value)) => {
+                        (false, "(partially) redacted".to_string())
+                    }
+                    Ok(_) => (true, String::new()),
+                    Err(err) => (false, format!("invalid path: {}", err)),
+                },
+            };
+            Outcome {
+                description: format!("{} is disclosed", path),
+                passed,
+                detail,
+            }
+        })
+        .collect()
+}
+
+/// Evaluate a policy against a verified proof, one outcome per requirement
+pub fn evaluate(policy: &Policy, verified: &VerifiedOutput) -> Vec<Outcome> {
+    evaluate_at(policy, verified, Utc::now())
+}
+
+fn evaluate_at(policy: &Policy, verified: &VerifiedOutput, now: DateTime<Utc>) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+
+    if let Some(expected) = &policy.expected_server_name {
+        outcomes.push(Outcome {
+            description: format!("Server name is {}", expected),
+            passed: server_name_matches(expected, &verified.server_name),
+            detail: verified.server_name.clone(),
+        });
+    }
+
+    if let Some(max_age_days) = policy.max_age_days {
+        let warning = time_checks::check(verified.time, now, max_age_days);
+        outcomes.push(Outcome {
+            description: format!("Notarized in the last {} days", max_age_days),
+            passed: warning.is_none(),
+            detail: warning.map(|w| w.to_string()).unwrap_or_default(),
+        });
+    }
+
+    outcomes.extend(check_required_paths(
+        &policy.required_disclosed_paths,
+        verified,
+    ));
+    outcomes.extend(check_forbidden_headers(
+        &policy.forbidden_disclosed_headers,
+        verified,
+    ));
+
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn passed(outcomes: &[Outcome]) -> Vec<bool> {
+        outcomes.iter().map(|outcome| outcome.passed).collect()
+    }
+
+    #[test]
+    fn parse() {
+        let policy = Policy::parse(br#"{"expected_server_name": "example.com"}"#).unwrap();
+        assert_eq!(policy.expected_server_name.as_deref(), Some("example.com"));
+        assert!(policy.required_disclosed_paths.is_empty());
+        // a misspelled rule is an error rather than silently not checked
+        assert!(Policy::parse(br#"{"max_age": 30}"#).is_err());
+    }
+
+    #[test]
+    fn server_name() {
+        let verified = VerifiedOutput::for_test("", "");
+        let outcomes = |expected: &str| {
+            let policy = Policy {
+                expected_server_name: Some(expected.to_string()),
+                ..Default::default()
+            };
+            passed(&evaluate(&policy, &verified))
+        };
+        assert_eq!(outcomes("example.com"), [true]);
+        assert_eq!(outcomes("Example.COM"), [true]);
+        assert_eq!(outcomes("api.example.com"), [false]);
+        assert_eq!(outcomes("*.example.com"), [false]);
+        assert!(evaluate(&Policy::default(), &verified).is_empty());
+    }
+
+    #[test]
+    fn max_age() {
+        let policy = Policy {
+            max_age_days: Some(30),
+            ..Default::default()
+        };
+        let verified = VerifiedOutput::for_test("", "");
+        let outcomes =
+            |age: Duration| passed(&evaluate_at(&policy, &verified, verified.time + age));
+        assert_eq!(outcomes(Duration::zero()), [true]);
+        assert_eq!(outcomes(Duration::days(30)), [true]);
+        assert_eq!(outcomes(Duration::days(30) + Duration::seconds(1)), [false]);
+        // notarized in the future, beyond the allowed clock skew
+        assert_eq!(outcomes(Duration::minutes(-5)), [true]);
+        assert_eq!(outcomes(Duration::minutes(-6)), [false]);
+    }
+
+    #[test]
+    fn unreadable_transcript_fails_the_transcript_rules() {
+        let policy = Policy {
+            required_disclosed_paths: vec!["$.balance".to_string()],
+            forbidden_disclosed_headers: vec!["cookie".to_string()],
+            ..Default::default()
+        };
+        let verified = VerifiedOutput::for_test("not http", "not http either");
+        let outcomes = evaluate(&policy, &verified);
+        assert_eq!(passed(&outcomes), [false, false]);
+        assert!(outcomes[0]
+            .detail
+            .starts_with("the body can not be read as JSON"));
+        assert!(outcomes[1].detail.contains("request can not be parsed"));
+    }
+}
@@ -0,0 +1,68 @@
+//! SHA-256 digests which identify a proof: of the file as uploaded, and of its canonical JSON,
+//! which stays the same when the file is reformatted or its keys are reordered.
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::parsed_proof::ParsedProof;
+
+/// Hex encoded SHA-256 of the proof file
+pub fn file_digest(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// Hex encoded SHA-256 of the canonical JSON of the proof, if it is JSON
+pub fn canonical_digest(data: &[u8]) -> Option<String> {
+    let value: Value = serde_json::from_slice(data).ok()?;
+    Some(value_digest(&value))
+}
+
+/// Hex encoded SHA-256 of the canonical JSON of a parsed proof
+pub fn value_digest(value: &Value) -> String {
+    file_digest(canonical_json(value).as_bytes())
+}
+
+/// Both digests of a parsed proof, as included in reports
+pub fn to_json(proof: &ParsedProof) -> Value {
+    json!({
+        "file_sha256": proof.file_digest,
+        "canonical_sha256": proof.canonical_digest,
+    })
+}
+
+/// `value` serialized without whitespace and with the keys of every object sorted
+pub fn canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| *key);
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        leaf => out.push_str(&leaf.to_string()),
+    }
+}
@@ -0,0 +1,303 @@
+//! Filtering and sorting of the loaded proofs, chosen in the toolbar above the results. Works on
+//! the outcomes of the verifications done so far: a proof which is not verified yet is always
+//! listed, so it gets verified.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::app::FileDetails;
+use crate::card_order::CardOrder;
+use crate::verify::Verification;
+
+/// What is known about a loaded proof once it is verified
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub verified: bool,
+    /// The claimed one if the proof did not verify
+    pub server_name: Option<String>,
+    /// Notarization time, the claimed one if the proof did not verify
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl Outcome {
+    pub fn new(verification: &Verification) -> Self {
+        let output = verification
+            .result
+            .as_ref()
+            .ok()
+            .or(verification.claimed.as_ref());
+        Self {
+            verified: verification.result.is_ok(),
+            server_name: output.map(|output| output.server_name.clone()),
+            time: output.map(|output| output.time),
+        }
+    }
+}
+
+/// Outcomes by file digest
+pub type Outcomes = BTreeMap<String, Outcome>;
+
+/// The outcome of `file`, `None` while it is not verified yet
+fn outcome(file: &FileDetails, outcomes: &Outcomes) -> Option<Outcome> {
+    match outcomes.get(&file.digest) {
+        Some(outcome) => Some(outcome.clone()),
+        None if file.proof.is_json() => None,
+        // Files which are not JSON are not verified at all, they count as failed
+        None => Some(Outcome {
+            verified: false,
+            server_name: None,
+            time: None,
+        }),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StatusFilter {
+    #[default]
+    All,
+    Verified,
+    Failed,
+}
+
+impl StatusFilter {
+    pub const ALL: [StatusFilter; 3] = [
+        StatusFilter::All,
+        StatusFilter::Verified,
+        StatusFilter::Failed,
+    ];
+
+    /// Untranslated
+    pub fn name(self) -> &'static str {
+        match self {
+            StatusFilter::All => "All proofs",
+            StatusFilter::Verified => "Only verified",
+            StatusFilter::Failed => "Only failed",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    /// Dragged and pinned by the user, new proofs first
+    #[default]
+    Custom,
+    Time,
+    Upload,
+    Size,
+}
+
+impl SortBy {
+    pub const ALL: [SortBy; 4] = [SortBy::Custom, SortBy::Time, SortBy::Upload, SortBy::Size];
+
+    /// Untranslated
+    pub fn name(self) -> &'static str {
+        match self {
+            SortBy::Custom => "Custom order",
+            SortBy::Time => "Notarization time, newest first",
+            SortBy::Upload => "Upload order, newest first",
+            SortBy::Size => "Size, largest first",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListView {
+    pub status: StatusFilter,
+    /// Only list proofs of this server
+    pub server: Option<String>,
+    pub sort: SortBy,
+    /// List the proofs by server
+    pub group: bool,
+}
+
+impl ListView {
+    pub fn is_filtered(&self) -> bool {
+        self.status != StatusFilter::All || self.server.is_some()
+    }
+
+    fn shows(&self, file: &FileDetails, outcomes: &Outcomes) -> bool {
+        let Some(outcome) = outcome(file, outcomes) else {
+            // Listed, so it gets verified
+            return true;
+        };
+        let status = match self.status {
+            StatusFilter::All => true,
+            StatusFilter::Verified => outcome.verified,
+            StatusFilter::Failed => !outcome.verified,
+        };
+        let server = match &self.server {
+            Some(server) => outcome.server_name.as_ref() == Some(server),
+            None => true,
+        };
+        status && server
+    }
+
+    /// Indices into `files` of the proofs to list, in order. Pinned proofs stay on top.
+    pub fn apply(
+        &self,
+        files: &[FileDetails],
+        order: &CardOrder,
+        outcomes: &Outcomes,
+    ) -> Vec<usize> {
+        let mut shown: Vec<(usize, &FileDetails)> = order
+            .arrange(files)
+            .into_iter()
+            .filter(|(_, file)| self.shows(file, outcomes))
+            .collect();
+        let unpinned = |file: &FileDetails| !order.is_pinned(&file.digest);
+        // The sorts are stable, ties keep the custom order
+        match self.sort {
+            SortBy::Custom => {}
+            SortBy::Time => shown.sort_by_key(|(_, file)| {
+                let time = outcomes.get(&file.digest).and_then(|o| o.time);
+                // Newest first, unknown times last
+                (unpinned(file), std::cmp::Reverse(time))
+            }),
+            SortBy::Upload => {
+                shown.sort_by_key(|(index, file)| (unpinned(file), std::cmp::Reverse(*index)))
+            }
+            SortBy::Size => {
+                shown.sort_by_key(|(_, file)| (unpinned(file), std::cmp::Reverse(file.proof.size)))
+            }
+        }
+        shown.into_iter().map(|(index, _)| index).collect()
+    }
+}
+
+/// The proofs of one server
+#[derive(Clone, Debug, PartialEq)]
+pub struct Group {
+    /// `None` for files without a server, e.g. not proofs or not verified yet
+    pub server_name: Option<String>,
+    /// Indices into the files, in display order
+    pub indices: Vec<usize>,
+    pub verified: usize,
+    pub failed: usize,
+}
+
+impl Group {
+    /// How many of the proofs are not verified yet
+    pub fn pending(&self) -> usize {
+        self.indices.len() - self.verified - self.failed
+    }
+}
+
+/// The proofs `shown` grouped by server, in the order of the server names. Files without a
+/// server come last.
+pub fn groups(shown: &[usize], files: &[FileDetails], outcomes: &Outcomes) -> Vec<Group> {
+    let mut groups: BTreeMap<(bool, Option<String>), Group> = BTreeMap::new();
+    for index in shown {
+        let outcome = outcome(&files[*index], outcomes);
+        let server_name = outcome.as_ref().and_then(|o| o.server_name.clone());
+        let group = groups
+            .entry((server_name.is_none(), server_name.clone()))
+            .or_insert_with(|| Group {
+                server_name,
+                indices: Vec::new(),
+                verified: 0,
+                failed: 0,
+            });
+        group.indices.push(*index);
+        match outcome {
+            Some(outcome) if outcome.verified => group.verified += 1,
+            Some(_) => group.failed += 1,
+            None => {}
+        }
+    }
+    groups.into_values().collect()
+}
+
+/// The servers of the proofs, verified or claimed, to filter on
+pub fn servers(outcomes: &Outcomes) -> Vec<String> {
+    let mut servers: Vec<String> = outcomes
+        .values()
+        .filter_map(|outcome| outcome.server_name.clone())
+        .collect();
+    servers.sort();
+    servers.dedup();
+    servers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(name: &str, data: &str) -> FileDetails {
+        FileDetails::new(name.to_string(), String::new(), data.as_bytes().to_vec())
+    }
+
+    fn outcome(verified: bool, server: &str, time: &str) -> Outcome {
+        Outcome {
+            verified,
+            server_name: Some(server.to_string()),
+            time: time.parse().ok(),
+        }
+    }
+
+    #[test]
+    fn filter_and_sort() {
+        let files = vec![
+            file("a", "{\"a\": 1}"),
+            file("b", "{\"bb\": 1}"),
+            file("c", "{\"ccc\": 1}"),
+            file("d", "not a proof"),
+            file("e", "{\"e\": 1}"),
+        ];
+        let mut order = CardOrder::default();
+        files.iter().for_each(|file| order.insert(&file.digest));
+        let mut outcomes = Outcomes::new();
+        outcomes.insert(
+            files[0].digest.clone(),
+            outcome(true, "example.com", "2023-10-19T13:30:13Z"),
+        );
+        outcomes.insert(
+            files[1].digest.clone(),
+            outcome(false, "example.com", "2024-01-02T08:00:00Z"),
+        );
+        outcomes.insert(
+            files[2].digest.clone(),
+            outcome(true, "api.x.com", "2023-12-24T18:00:00Z"),
+        );
+        // "e" is not verified yet, it is always listed
+
+        let mut view = ListView::default();
+        assert_eq!(view.apply(&files, &order, &outcomes), [4, 3, 2, 1, 0]);
+        view.status = StatusFilter::Failed;
+        assert_eq!(view.apply(&files, &order, &outcomes), [4, 3, 1]);
+        view.status = StatusFilter::Verified;
+        assert_eq!(view.apply(&files, &order, &outcomes), [4, 2, 0]);
+        view.server = Some("example.com".to_string());
+        assert_eq!(view.apply(&files, &order, &outcomes), [4, 0]);
+        assert!(view.is_filtered());
+
+        let mut view = ListView {
+            sort: SortBy::Time,
+            ..ListView::default()
+        };
+        assert_eq!(view.apply(&files, &order, &outcomes), [1, 2, 0, 4, 3]);
+        order.toggle_pin(&files[3].digest);
+        assert_eq!(view.apply(&files, &order, &outcomes), [3, 1, 2, 0, 4]);
+        view.sort = SortBy::Size;
+        assert_eq!(view.apply(&files, &order, &outcomes), [3, 2, 1, 4, 0]);
+
+        assert_eq!(servers(&outcomes), ["api.x.com", "example.com"]);
+
+        let groups = groups(&[4, 3, 2, 1, 0], &files, &outcomes);
+        let summary: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                let counts = (group.verified, group.failed, group.pending());
+                (group.server_name.as_deref(), group.indices.clone(), counts)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Some("api.x.com"), vec![2], (1, 0, 0)),
+                (Some("example.com"), vec![1, 0], (1, 1, 0)),
+                (None, vec![4, 3], (0, 1, 1)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Explanations of the fields of a serialized `TlsProof`, for the raw proof inspector.
+
+/// Field paths, `*` matches any key or index
+const FIELDS: [(&str, &str); 29] = [
+    (
+        "session",
+        "The session proof: what the Notary signed, and the data to check it against the server",
+    ),
+    ("session/header", "The session header signed by the Notary"),
+    (
+        "session/header/encoder_seed",
+        "Seed for the encodings the Prover committed to, revealed by the Notary after the session",
+    ),
+    (
+        "session/header/merkle_root",
+        "Root of the Merkle tree of all the Prover's commitments to the transcript",
+    ),
+    (
+        "session/header/sent_len",
+        "Number of bytes the Prover sent to the server",
+    ),
+    (
+        "session/header/recv_len",
+        "Number of bytes the Prover received from the server",
+    ),
+    (
+        "session/header/handshake_summary",
+        "What the Notary saw of the TLS handshake",
+    ),
+    (
+        "session/header/handshake_summary/time",
+        "Unix time of the handshake, as seen by the Notary",
+    ),
+    (
+        "session/header/handshake_summary/server_public_key",
+        "The server's ephemeral key exchange public key",
+    ),
+    (
+        "session/header/handshake_summary/handshake_commitment",
+        "Commitment to the handshake data, opened by handshake_data_decommitment",
+    ),
+    (
+        "session/server_name",
+        "The server the Prover claims to have talked to, checked against the certificate",
+    ),
+    (
+        "session/signature",
+        "The Notary's signature over the session header",
+    ),
+    (
+        "session/handshake_data_decommitment",
+        "Opening of the handshake commitment, reveals the server's certificates",
+    ),
+    (
+        "session/handshake_data_decommitment/nonce",
+        "Random nonce which hides the handshake data in the commitment",
+    ),
+    (
+        "session/handshake_data_decommitment/data",
+        "The handshake data",
+    ),
+    (
+        "session/handshake_data_decommitment/data/server_cert_details",
+        "The server's certificates, verified against trusted root certificates",
+    ),
+    (
+        "session/handshake_data_decommitment/data/server_cert_details/cert_chain",
+        "DER encoded certificate chain, the server's own certificate first",
+    ),
+    (
+        "session/handshake_data_decommitment/data/server_cert_details/ocsp_response",
+        "Stapled OCSP response about the certificate's revocation status",
+    ),
+    (
+        "session/handshake_data_decommitment/data/server_cert_details/scts",
+        "Signed certificate timestamps from certificate transparency logs",
+    ),
+    (
+        "session/handshake_data_decommitment/data/server_kx_details",
+        "The server's signed key exchange parameters",
+    ),
+    (
+        "session/handshake_data_decommitment/data/server_kx_details/kx_sig",
+        "The server's signature of the key exchange, made with the certificate's key",
+    ),
+    (
+        "session/handshake_data_decommitment/data/client_random",
+        "Random bytes chosen by the client in the handshake",
+    ),
+    (
+        "session/handshake_data_decommitment/data/server_random",
+        "Random bytes chosen by the server in the handshake",
+    ),
+    (
+        "substrings",
+        "The substrings proof: the parts of the transcript the Prover chose to disclose",
+    ),
+    (
+        "substrings/openings",
+        "Disclosed parts of the transcript, by commitment id",
+    ),
+    (
+        "substrings/openings/*/0",
+        "Commitment info: which bytes of which direction are disclosed",
+    ),
+    (
+        "substrings/openings/*/1",
+        "The opening: the disclosed bytes and the nonce hiding them in the commitment",
+    ),
+    (
+        "substrings/inclusion_proof",
+        "Merkle proof that the opened commitments are in the tree the Notary signed",
+    ),
+    (
+        "substrings/inclusion_proof/total_leaves",
+        "Total number of commitments the Prover made",
+    ),
+];
+
+/// Explanation of the field at `path`, the keys and indices from the root
+pub fn describe(path: &[String]) -> Option<&'static str> {
+    FIELDS.iter().find_map(|(pattern, description)| {
+        let pattern: Vec<&str> = pattern.split('/').collect();
+        let matches = pattern.len() == path.len()
+            && pattern
+                .iter()
+                .zip(path)
+                .all(|(part, key)| *part == "*" || part == key);
+        matches.then_some(*description)
+    })
+}
@@ -0,0 +1,26 @@
+//! What a scanned QR code refers to: a proof to fetch, or the hash of a proof.
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Scanned {
+    /// URL of a proof file
+    ProofUrl(String),
+    /// Hex encoded sha256 of a proof file, as shown in the history
+    ProofHash(String),
+    Unknown(String),
+}
+
+impl Scanned {
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim();
+        if text.starts_with("https://") || text.starts_with("http://") {
+            return Scanned::ProofUrl(text.to_string());
+        }
+
+        let hash = text.strip_prefix("sha256:").unwrap_or(text);
+        if hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            Scanned::ProofHash(hash.to_ascii_lowercase())
+        } else {
+            Scanned::Unknown(text.to_string())
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! What the Prover redacted, in terms of the HTTP messages: the redacted ranges are joined with
+//! the spans of the parsed requests and responses, so they can be summarized as e.g. the value
+//! of the `Authorization` request header or the `ssn` field of the response body.
+
+use std::ops::Range;
+
+use crate::components::redacted_bytes_component::Direction;
+use crate::exchanges::{self, HeaderInfo};
+use crate::i18n::Locale;
+use crate::json_spans::{self, JsonSpan, JsonValue};
+use crate::verify::{Transcript, VerifiedOutput};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RedactedPart {
+    /// The value of a header
+    Header(String),
+    /// A value in a JSON body, by its JSONPath
+    BodyField(String),
+    /// A body which is not JSON, or the parts of a JSON body around its values
+    Body,
+    /// The request or status line, or header names
+    Head,
+    /// Bytes after the last message which could be parsed, e.g. because its first line is
+    /// redacted
+    Unparsed,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Redaction {
+    /// `Sent` for a request, `Received` for a response
+    pub direction: Direction,
+    /// Index of the exchange the message belongs to, `None` for `Unparsed`
+    pub exchange: Option<usize>,
+    pub part: RedactedPart,
+    /// The redacted bytes of the part
+    pub ranges: Vec<Range<usize>>,
+    /// Whether all of the part is redacted, not only some of it
+    pub fully: bool,
+}
+
+impl Redaction {
+    /// The message the part belongs to, e.g. "Request 2"
+    pub fn message(&self, locale: Locale) -> String {
+        let number = self.exchange.map_or(0, |exchange| exchange + 1);
+        match (self.direction, self.exchange) {
+            (Direction::Sent, Some(_)) => locale.format("Request {number}", &[("number", &number)]),
+            (Direction::Received, Some(_)) => {
+                locale.format("Response {number}", &[("number", &number)])
+            }
+            (Direction::Sent, None) => locale.t("Sent data which is not HTTP").to_string(),
+            (Direction::Received, None) => locale.t("Received data which is not HTTP").to_string(),
+        }
+    }
+
+    /// What was redacted, e.g. "header Authorization (redacted)"
+    pub fn describe(&self, locale: Locale) -> String {
+        let part = match (&self.part, self.direction) {
+            (RedactedPart::Header(name), _) => locale.format("header {name}", &[("name", name)]),
+            (RedactedPart::BodyField(path), _) => {
+                locale.format("body field {path}", &[("path", path)])
+            }
+            (RedactedPart::Body, _) => locale.t("body").to_string(),
+            (RedactedPart::Head, Direction::Sent) => {
+                locale.t("request line or header names").to_string()
+            }
+            (RedactedPart::Head, Direction::Received) => {
+                locale.t("status line or header names").to_string()
+            }
+            (RedactedPart::Unparsed, _) => locale.t("unparsed bytes").to_string(),
+        };
+        let status = if self.fully {
+            locale.t("redacted")
+        } else {
+            locale.t("partially redacted")
+        };
+        format!("{} ({})", part, status)
+    }
+}
+
+/// The parts of one request or response
+struct Message<'a> {
+    range: Range<usize>,
+    headers: &'a [HeaderInfo],
+    content_type: Option<&'a str>,
+    body: Option<Range<usize>>,
+}
+
+/// The redacted ranges within `range`, positioned in the transcript
+fn redacted_in(transcript: &Transcript, range: &Range<usize>) -> Vec<Range<usize>> {
+    transcript
+        .redactions_within(range)
+        .into_iter()
+        .map(|r| r.start + range.start..r.end + range.start)
+        .collect()
+}
+
+/// The parts of `ranges` outside of all `covered` ranges
+fn subtract(ranges: &[Range<usize>], covered: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut covered = covered.to_vec();
+    covered.sort_by_key(|range| range.start);
+    let mut rest = Vec::new();
+    for range in ranges {
+        let mut start = range.start;
+        for cover in covered
+            .iter()
+            .filter(|c| c.start < range.end && range.start < c.end)
+        {
+            if cover.start > start {
+                rest.push(start..cover.start);
+            }
+            start = start.max(cover.end);
+        }
+        if start < range.end {
+            rest.push(start..range.end);
+        }
+    }
+    rest
+}
+
+/// The redacted values of a JSON document with their JSONPath, `redacted_ranges` are relative to
+/// the document like the spans
+fn json_fields(
+    span: &JsonSpan,
+    path: String,
+    redacted_ranges: &[Range<usize>],
+    fields: &mut Vec<(String, Range<usize>)>,
+) {
+    match &span.value {
+        JsonValue::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                json_fields(item, format!("{}[{}]", path, i), redacted_ranges, fields);
+            }
+        }
+        JsonValue::Object(members) => {
+            for (key, value) in members {
+                json_fields(value, format!("{}.{}", path, key), redacted_ranges, fields);
+            }
+        }
+        _ if span.is_redacted(redacted_ranges) => fields.push((path, span.range.clone())),
+        _ => {}
+    }
+}
+
+fn message_redactions(
+    transcript: &Transcript,
+    direction: Direction,
+    exchange: usize,
+    message: Message,
+) -> Vec<Redaction> {
+    let redaction = |part, range: &Range<usize>| {
+        let ranges = redacted_in(transcript, range);
+        let len: usize = ranges.iter().map(|r| r.len()).sum();
+        (len > 0).then(|| Redaction {
+            direction,
+            exchange: Some(exchange),
+            part,
+            fully: len == range.len(),
+            ranges,
+        })
+    };
+    let mut redactions = Vec::new();
+    let mut covered = Vec::new();
+
+    for header in message.headers {
+        redactions.extend(redaction(
+            RedactedPart::Header(header.name.clone()),
+            &header.value,
+        ));
+        covered.push(header.value.clone());
+    }
+
+    if let Some(body) = &message.body {
+        let is_json = message
+            .content_type
+            .is_some_and(|content_type| content_type.to_ascii_lowercase().contains("json"));
+        let relative = transcript.redactions_within(body);
+        let json = if is_json {
+            json_spans::parse(&transcript.bytes[body.clone()], &relative).ok()
+        } else {
+            None
+        };
+        let mut fields = Vec::new();
+        if let Some(json) = &json {
+            json_fields(json, "$".to_string(), &relative, &mut fields);
+        }
+        let mut field_ranges = Vec::new();
+        for (path, range) in fields {
+            let range = range.start + body.start..range.end + body.start;
+            // A redacted document is a redacted body rather than a field
+            let part = if path == "$" {
+                RedactedPart::Body
+            } else {
+                RedactedPart::BodyField(path)
+            };
+            redactions.extend(redaction(part, &range));
+            field_ranges.push(range);
+        }
+        let rest = subtract(&redacted_in(transcript, body), &field_ranges);
+        if !rest.is_empty() {
+            redactions.push(Redaction {
+                direction,
+                exchange: Some(exchange),
+                part: RedactedPart::Body,
+                fully: field_ranges.is_empty() && rest == [body.clone()],
+                ranges: rest,
+            });
+        }
+        covered.push(body.clone());
+    }
+
+    let head = subtract(&redacted_in(transcript, &message.range), &covered);
+    if !head.is_empty() {
+        redactions.push(Redaction {
+            direction,
+            exchange: Some(exchange),
+            part: RedactedPart::Head,
+            fully: false,
+            ranges: head,
+        });
+    }
+    redactions
+}
+
+/// Every redacted part of the requests and responses, in the order of the exchanges
+pub fn summarize(verified: &VerifiedOutput) -> Vec<Redaction> {
+    let (sent, recv) = (&verified.sent, &verified.recv);
+    let split = exchanges::split(verified);
+    let mut redactions = Vec::new();
+
+    for (i, exchange) in split.exchanges.iter().enumerate() {
+        if let Some(request) = &exchange.request {
+            let message = Message {
+                range: request.range.clone(),
+                headers: &request.headers,
+                content_type: request.content_type.as_deref(),
+                body: request.body.clone(),
+            };
+            redactions.extend(message_redactions(sent, Direction::Sent, i, message));
+        }
+        if let Some(response) = &exchange.response {
+            let message = Message {
+                range: response.range.clone(),
+                headers: &response.headers,
+                content_type: response.content_type.as_deref(),
+                body: response.body.clone(),
+            };
+            redactions.extend(message_redactions(recv, Direction::Received, i, message));
+        }
+    }
+
+    for (direction, transcript, parsed) in [
+        (Direction::Sent, sent, split.sent_parsed),
+        (Direction::Received, recv, split.recv_parsed),
+    ] {
+        let rest = parsed..transcript.bytes.len();
+        let ranges = redacted_in(transcript, &rest);
+        if !ranges.is_empty() {
+            redactions.push(Redaction {
+                direction,
+                exchange: None,
+                part: RedactedPart::Unparsed,
+                fully: ranges == [rest],
+                ranges,
+            });
+        }
+    }
+    redactions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subtract_covered_ranges() {
+        assert_eq!(
+            subtract(&[0..4, 8..12], &[2..3, 12..14]),
+            [0..2, 3..4, 8..12]
+        );
+        assert_eq!(subtract(&[0..4, 8..12], &[0..4, 8..12]), []);
+        assert_eq!(subtract(&[0..4, 8..12], &[9..10, 2..9]), [0..2, 10..12]);
+        assert_eq!(subtract(&[0..4, 8..12], &[]), [0..4, 8..12]);
+    }
+
+    #[test]
+    fn redacted_json_fields() {
+        let body = br#"{"name":"Alice","ssn":"XXXXXXXXXXX","cards":[1,XXXX]}"#;
+        let redacted = [23..34, 47..51];
+        let json = json_spans::parse(body, &redacted).unwrap();
+        let mut fields = Vec::new();
+        json_fields(&json, "$".to_string(), &redacted, &mut fields);
+        let paths: Vec<_> = fields.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(paths, ["$.ssn", "$.cards[1]"]);
+    }
+}
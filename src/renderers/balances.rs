@@ -0,0 +1,170 @@
+//! Account balances of fintech APIs, for "proof of funds": the proven balance and the account
+//! it is of, with redactions honored.
+//!
+//! The API shapes are listed in `balances.json`, embedded at build time: the servers, the
+//! request path, and JSON pointers into the response to the balance, its currency and the
+//! account identifier. A response may be a single account, a list of accounts, or hold the list
+//! at the `accounts` pointer. Other APIs are supported by adding their shape.
+
+use serde::Deserialize;
+use serde_json::Value;
+use yew::prelude::*;
+
+use super::{field, matches_any, render_field, Field, JsonResponse, Renderer};
+use crate::i18n::Locale;
+
+const BUNDLED: &str = include_str!("balances.json");
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Shape {
+    pub name: String,
+    pub servers: Vec<String>,
+    /// Only responses to requests whose path contains this
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Where the list of accounts is, if not the whole response
+    #[serde(default)]
+    pub accounts: Option<String>,
+    pub amount: String,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Balance {
+    pub account: Field,
+    pub amount: Field,
+    pub currency: Field,
+}
+
+pub fn shapes() -> Vec<Shape> {
+    serde_json::from_str(BUNDLED).expect("the bundled balance shapes are valid")
+}
+
+impl Shape {
+    fn balance(&self, account: &Value) -> Balance {
+        let optional = |pointer: &Option<String>| match pointer {
+            Some(pointer) => field(account, pointer),
+            None => Field::Missing,
+        };
+        Balance {
+            account: optional(&self.account),
+            amount: field(account, &self.amount),
+            currency: optional(&self.currency),
+        }
+    }
+
+    /// The balances of the accounts in `response`, none if it is not of this shape
+    pub fn balances(&self, response: &JsonResponse) -> Vec<Balance> {
+        if let Some(path) = &self.path {
+            if !response.path.contains(path.as_str()) {
+                return Vec::new();
+            }
+        }
+        let accounts = match &self.accounts {
+            Some(pointer) => response.json.pointer(pointer),
+            None => Some(&response.json),
+        };
+        let accounts = match accounts {
+            Some(Value::Array(accounts)) => accounts.iter().collect(),
+            Some(account) => vec![account],
+            None => Vec::new(),
+        };
+        accounts
+            .into_iter()
+            .map(|account| self.balance(account))
+            .filter(|balance| balance.amount != Field::Missing)
+            .collect()
+    }
+}
+
+impl Renderer for Shape {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn claims(&self, server_name: &str) -> bool {
+        matches_any(&self.servers, server_name)
+    }
+
+    fn render(&self, locale: Locale, responses: &[JsonResponse]) -> Option<Html> {
+        let balances: Vec<Balance> = responses
+            .iter()
+            .flat_map(|response| self.balances(response))
+            .collect();
+        if balances.is_empty() {
+            return None;
+        }
+        Some(html! {
+            <ul class="flex flex-col gap-4">
+                {for balances.iter().map(|balance| html! {
+                    <li>
+                        <p class="text-3xl font-bold">
+                            {render_field(locale, &balance.amount)}{" "}{render_field(locale, &balance.currency)}
+                        </p>
+                        <p class="text-sm">
+                            {locale.t("Account: ")}
+                            <span class="font-mono">{render_field(locale, &balance.account)}</span>
+                        </p>
+                    </li>
+                })}
+            </ul>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_spans::REDACTED_MARKER;
+    use serde_json::json;
+
+    fn shape(name: &str) -> Shape {
+        shapes()
+            .into_iter()
+            .find(|shape| shape.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn plaid() {
+        let response = JsonResponse {
+            path: "/accounts/balance/get".to_string(),
+            json: json!({ "accounts": [
+                { "mask": REDACTED_MARKER, "balances": { "current": 1234.5, "iso_currency_code": "USD" } },
+                { "mask": "0000", "balances": { "current": REDACTED_MARKER, "iso_currency_code": "USD" } }
+            ]}),
+        };
+        let plaid = shape("Plaid");
+        assert!(plaid.claims("production.plaid.com"));
+        let balances = plaid.balances(&response);
+        assert_eq!(
+            balances[0],
+            Balance {
+                account: Field::Redacted,
+                amount: Field::Disclosed("1234.5".to_string()),
+                currency: Field::Disclosed("USD".to_string()),
+            }
+        );
+        assert_eq!(balances[1].amount, Field::Redacted);
+
+        let other = JsonResponse {
+            path: "/item/get".to_string(),
+            ..response
+        };
+        assert!(plaid.balances(&other).is_empty());
+    }
+
+    #[test]
+    fn single_account() {
+        let response = JsonResponse {
+            path: "/v1/borderless-accounts/1/balances".to_string(),
+            json: json!([{ "id": 7, "amount": { "value": 100, "currency": "EUR" } }]),
+        };
+        let balances = shape("Wise").balances(&response);
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].account, Field::Disclosed("7".to_string()));
+    }
+}
@@ -0,0 +1,167 @@
+//! The GitHub identity of `/user` and `/user/emails` responses of the REST API, shown as a
+//! profile card, for "prove you own this GitHub account".
+
+use serde_json::Value;
+use yew::prelude::*;
+
+use super::{field, matches_any, render_field, Field, JsonResponse, Renderer};
+use crate::i18n::Locale;
+
+pub struct GitHub;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Account {
+    pub login: Field,
+    pub id: Field,
+    pub name: Field,
+    /// The public email of the profile
+    pub email: Field,
+    pub created_at: Field,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Email {
+    pub email: Field,
+    pub verified: bool,
+    pub primary: bool,
+}
+
+/// The account of a `/user` (or `/users/{login}`) response
+pub fn account(json: &Value) -> Option<Account> {
+    // Accounts have a `type`, "User" or "Organization", which other responses lack
+    json.get("type")?;
+    let account = Account {
+        login: field(json, "/login"),
+        id: field(json, "/id"),
+        name: field(json, "/name"),
+        email: field(json, "/email"),
+        created_at: field(json, "/created_at"),
+    };
+    (account.login != Field::Missing).then_some(account)
+}
+
+/// The addresses of a `/user/emails` response
+pub fn emails(json: &Value) -> Vec<Email> {
+    let Some(emails) = json.as_array() else {
+        return Vec::new();
+    };
+    emails
+        .iter()
+        .filter(|email| email.get("email").is_some())
+        .map(|email| Email {
+            email: field(email, "/email"),
+            // Only a disclosed `true` counts
+            verified: email.get("verified") == Some(&Value::Bool(true)),
+            primary: email.get("primary") == Some(&Value::Bool(true)),
+        })
+        .collect()
+}
+
+fn render_email(locale: Locale, email: &Email) -> Html {
+    let t = |message| locale.t(message);
+    html! {
+        <li class="font-mono">
+            {render_field(locale, &email.email)}
+            if email.verified {
+                <span class="ml-2 text-green-500">{t("✓ verified")}</span>
+            }
+            if email.primary {
+                <span class="ml-2 text-gray-400">{t("primary")}</span>
+            }
+        </li>
+    }
+}
+
+impl Renderer for GitHub {
+    fn name(&self) -> &str {
+        "GitHub"
+    }
+
+    fn claims(&self, server_name: &str) -> bool {
+        matches_any(&["api.github.com", "github.com"], server_name)
+    }
+
+    fn render(&self, locale: Locale, responses: &[JsonResponse]) -> Option<Html> {
+        let t = |message| locale.t(message);
+        let account = responses
+            .iter()
+            .find_map(|response| account(&response.json));
+        let emails: Vec<Email> = responses
+            .iter()
+            .flat_map(|response| emails(&response.json))
+            .collect();
+        if account.is_none() && emails.is_empty() {
+            return None;
+        }
+        let initial = match account.as_ref().map(|account| &account.login) {
+            Some(Field::Disclosed(login)) => login.chars().next().unwrap_or('?'),
+            _ => '?',
+        };
+
+        Some(html! {
+            <div class="flex gap-4 items-start">
+                <div class="w-16 h-16 shrink-0 rounded-full bg-gray-700 text-white text-3xl flex items-center justify-center" aria-hidden="true">
+                    {initial.to_uppercase().to_string()}
+                </div>
+                <div class="flex flex-col gap-1">
+                    if let Some(account) = &account {
+                        <p class="text-xl font-bold">{render_field(locale, &account.name)}</p>
+                        <p class="font-mono">{"@"}{render_field(locale, &account.login)}</p>
+                        <p class="text-sm">{t("Account id: ")}{render_field(locale, &account.id)}</p>
+                        if account.created_at != Field::Missing {
+                            <p class="text-sm">{t("Member since: ")}{render_field(locale, &account.created_at)}</p>
+                        }
+                        if account.email != Field::Missing {
+                            <p class="text-sm">{t("Public email: ")}{render_field(locale, &account.email)}</p>
+                        }
+                    }
+                    if !emails.is_empty() {
+                        <p class="text-sm">{t("Email addresses:")}</p>
+                        <ul class="text-sm">
+                            {for emails.iter().map(|email| render_email(locale, email))}
+                        </ul>
+                    }
+                </div>
+            </div>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_spans::REDACTED_MARKER;
+    use serde_json::json;
+
+    #[test]
+    fn user() {
+        let json =
+            json!({ "login": "octocat", "id": 583231, "type": "User", "name": REDACTED_MARKER });
+        let account = account(&json).unwrap();
+        assert_eq!(account.login, Field::Disclosed("octocat".to_string()));
+        assert_eq!(account.id, Field::Disclosed("583231".to_string()));
+        assert_eq!(account.name, Field::Redacted);
+        assert_eq!(account.email, Field::Missing);
+        assert_eq!(super::account(&json!({ "login": "octocat" })), None);
+    }
+
+    #[test]
+    fn user_emails() {
+        let json = json!([
+            { "email": "octocat@github.com", "primary": true, "verified": true },
+            { "email": REDACTED_MARKER, "primary": false, "verified": REDACTED_MARKER }
+        ]);
+        let emails = emails(&json);
+        assert_eq!(
+            emails[0],
+            Email {
+                email: Field::Disclosed("octocat@github.com".to_string()),
+                verified: true,
+                primary: true,
+            }
+        );
+        assert_eq!(emails[1].email, Field::Redacted);
+        assert!(!emails[1].verified);
+        assert!(super::emails(&json!([1, 2])).is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+//! Site-specific summaries of a transcript, e.g. "@alice sent a DM to @bob", shown above the
+//! generic view.
+//!
+//! A renderer implements [`Renderer`]: it claims transcripts by server name and builds its
+//! summary from the JSON responses, with redacted values shown as such. The first claiming
+//! renderer with a summary wins; one which can not make sense of a transcript returns `None`,
+//! and only the generic view is shown.
+
+mod balances;
+mod github;
+mod twitter;
+
+use serde_json::Value;
+use yew::prelude::*;
+
+use crate::assertions::server_name_matches;
+use crate::exchanges;
+use crate::i18n::Locale;
+use crate::json_spans;
+use crate::verify::VerifiedOutput;
+
+/// A response of the transcript with a JSON body
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonResponse {
+    /// The path of the request, empty if it is not known
+    pub path: String,
+    /// Values which are not fully disclosed are [`json_spans::REDACTED_MARKER`]
+    pub json: Value,
+}
+
+/// A value of a response, as renderers show it
+#[derive(Clone, Debug, PartialEq)]
+pub enum Field {
+    Disclosed(String),
+    Redacted,
+    Missing,
+}
+
+/// The value at the JSON pointer `pointer`, strings as they are and anything else as JSON
+pub fn field(json: &Value, pointer: &str) -> Field {
+    match json.pointer(pointer) {
+        None | Some(Value::Null) => Field::Missing,
+        Some(value) if json_spans::contains_marker(value) => Field::Redacted,
+        Some(Value::String(text)) => Field::Disclosed(text.clone()),
+        Some(value) => Field::Disclosed(value.to_string()),
+    }
+}
+
+pub fn render_field(locale: Locale, field: &Field) -> Html {
+    match field {
+        Field::Disclosed(text) => html! { <span>{text.clone()}</span> },
+        Field::Redacted => html! { <span class="text-red-500">{locale.t("[redacted]")}</span> },
+        Field::Missing => html! { <span class="text-gray-400">{"–"}</span> },
+    }
+}
+
+pub trait Renderer {
+    /// The site, e.g. "GitHub"
+    fn name(&self) -> &str;
+
+    /// Is `server_name` one of the site?
+    fn claims(&self, server_name: &str) -> bool;
+
+    /// The summary of the responses, `None` if there is nothing this renderer recognises
+    fn render(&self, locale: Locale, responses: &[JsonResponse]) -> Option<Html>;
+}
+
+/// Does `server_name` match one of `patterns`, with `*.` wildcards as in the assertions?
+fn matches_any<S: AsRef<str>>(patterns: &[S], server_name: &str) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| server_name_matches(pattern.as_ref(), server_name))
+}
+
+/// The built-in renderers, tried in order
+pub fn renderers() -> Vec<Box<dyn Renderer>> {
+    let mut renderers: Vec<Box<dyn Renderer>> =
+        vec![Box::new(twitter::Twitter), Box::new(github::GitHub)];
+    renderers.extend(
+        balances::shapes()
+            .into_iter()
+            .map(|shape| Box::new(shape) as Box<dyn Renderer>),
+    );
+    renderers
+}
+
+/// The responses of `verified` with a JSON body, honoring the redactions
+pub fn json_responses(verified: &VerifiedOutput) -> Vec<JsonResponse> {
+    exchanges::split(verified)
+        .exchanges
+        .into_iter()
+        .filter_map(|exchange| {
+            let body = exchange.response?.body?;
+            let redacted = verified.recv.redactions_within(&body);
+            let json = json_spans::parse(&verified.recv.bytes[body], &redacted).ok()?;
+            Some(JsonResponse {
+                path: exchange
+                    .request
+                    .map(|request| request.path)
+                    .unwrap_or_default(),
+                json: json.to_value(&redacted),
+            })
+        })
+        .collect()
+}
+
+/// The name of the site and its summary of `verified`, if a renderer claims it
+pub fn render(locale: Locale, verified: &VerifiedOutput) -> Option<(String, Html)> {
+    let claiming: Vec<Box<dyn Renderer>> = renderers()
+        .into_iter()
+        .filter(|renderer| renderer.claims(&verified.server_name))
+        .collect();
+    if claiming.is_empty() {
+        return None;
+    }
+    let responses = json_responses(verified);
+    claiming.iter().find_map(|renderer| {
+        Some((
+            renderer.name().to_string(),
+            renderer.render(locale, &responses)?,
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_spans::REDACTED_MARKER;
+    use serde_json::json;
+
+    #[test]
+    fn fields() {
+        let json = json!({ "login": "alice", "id": 42, "email": REDACTED_MARKER, "plan": { "name": REDACTED_MARKER } });
+        assert_eq!(
+            field(&json, "/login"),
+            Field::Disclosed("alice".to_string())
+        );
+        assert_eq!(field(&json, "/id"), Field::Disclosed("42".to_string()));
+        assert_eq!(field(&json, "/email"), Field::Redacted);
+        assert_eq!(field(&json, "/plan"), Field::Redacted);
+        assert_eq!(field(&json, "/name"), Field::Missing);
+    }
+}
@@ -0,0 +1,155 @@
+//! Direct messages of Twitter/X, from the `dm/conversation` and `dm/inbox_initial_state`
+//! responses of the web client, shown as a chat.
+
+use chrono::DateTime;
+use serde_json::Value;
+use yew::prelude::*;
+
+use super::{field, matches_any, render_field, Field, JsonResponse, Renderer};
+use crate::i18n::Locale;
+
+pub struct Twitter;
+
+/// A direct message, with the screen names of sender and recipient where they are disclosed
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    pub sender: Field,
+    pub recipient: Field,
+    pub text: Field,
+    /// Milliseconds since the epoch
+    pub time: Field,
+}
+
+// The screen name of a user id, from the users the response includes
+fn screen_name(users: Option<&Value>, id: Field) -> Field {
+    let Field::Disclosed(id) = id else {
+        return id;
+    };
+    match users {
+        Some(users) => match field(users, &format!("/{}/screen_name", id)) {
+            Field::Disclosed(name) => Field::Disclosed(format!("@{}", name)),
+            Field::Missing => Field::Disclosed(id),
+            Field::Redacted => Field::Redacted,
+        },
+        None => Field::Disclosed(id),
+    }
+}
+
+/// When a message was sent, from its time in milliseconds since the epoch
+pub fn time_text(time: &Field) -> Field {
+    match time {
+        Field::Disclosed(millis) => millis
+            .parse::<i64>()
+            .ok()
+            .and_then(|millis| DateTime::from_timestamp(millis / 1000, 0))
+            .map(|time| Field::Disclosed(time.format("%Y-%m-%d %H:%M UTC").to_string()))
+            .unwrap_or_else(|| time.clone()),
+        time => time.clone(),
+    }
+}
+
+/// The messages of a DM response, oldest first
+pub fn messages(json: &Value) -> Vec<Message> {
+    let timeline = [
+        "conversation_timeline",
+        "inbox_initial_state",
+        "user_events",
+    ]
+    .iter()
+    .find_map(|key| json.get(*key))
+    .unwrap_or(json);
+    let users = timeline.get("users");
+    let Some(entries) = timeline.get("entries").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    let mut messages: Vec<Message> = entries
+        .iter()
+        .filter_map(|entry| entry.pointer("/message/message_data"))
+        .map(|data| Message {
+            sender: screen_name(users, field(data, "/sender_id")),
+            recipient: screen_name(users, field(data, "/recipient_id")),
+            text: field(data, "/text"),
+            time: field(data, "/time"),
+        })
+        .collect();
+    // The timeline lists the newest first
+    messages.reverse();
+    messages
+}
+
+impl Renderer for Twitter {
+    fn name(&self) -> &str {
+        "Twitter/X"
+    }
+
+    fn claims(&self, server_name: &str) -> bool {
+        matches_any(
+            &["twitter.com", "*.twitter.com", "x.com", "*.x.com"],
+            server_name,
+        )
+    }
+
+    fn render(&self, locale: Locale, responses: &[JsonResponse]) -> Option<Html> {
+        let messages: Vec<Message> = responses
+            .iter()
+            .flat_map(|response| messages(&response.json))
+            .collect();
+        if messages.is_empty() {
+            return None;
+        }
+        // The sender of the first message on the left, the other side on the right
+        let left = messages[0].sender.clone();
+        Some(html! {
+            <ol class="flex flex-col gap-2" aria-label={locale.t("Direct messages")}>
+                {for messages.iter().map(|message| {
+                    let is_left = message.sender == left;
+                    html! {
+                        <li class={classes!("flex", "flex-col", "max-w-prose", if is_left { "self-start items-start" } else { "self-end items-end" })}>
+                            <span class="text-xs text-gray-400">
+                                {render_field(locale, &message.sender)}
+                                {" → "}
+                                {render_field(locale, &message.recipient)}
+                            </span>
+                            <p class={classes!("px-3", "py-2", "rounded-2xl", "whitespace-pre-wrap", "break-words", if is_left { "bg-gray-200 dark:bg-zinc-700" } else { "bg-sky-500 text-white" })}>
+                                {render_field(locale, &message.text)}
+                            </p>
+                            <span class="text-xs text-gray-400">{render_field(locale, &time_text(&message.time))}</span>
+                        </li>
+                    }
+                })}
+            </ol>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_spans::REDACTED_MARKER;
+    use serde_json::json;
+
+    #[test]
+    fn conversation() {
+        let json = json!({ "conversation_timeline": {
+            "entries": [
+                { "message": { "message_data": { "sender_id": "2", "recipient_id": "1", "text": REDACTED_MARKER, "time": "1700000060000" } } },
+                { "message": { "message_data": { "sender_id": "1", "recipient_id": "2", "text": "hi bob", "time": "1700000000000" } } },
+                { "trust_conversation": {} }
+            ],
+            "users": { "1": { "screen_name": "alice" }, "2": { "screen_name": REDACTED_MARKER } }
+        }});
+        let messages = messages(&json);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].sender, Field::Disclosed("@alice".to_string()));
+        assert_eq!(messages[0].recipient, Field::Redacted);
+        assert_eq!(messages[0].text, Field::Disclosed("hi bob".to_string()));
+        assert_eq!(messages[1].text, Field::Redacted);
+        assert!(super::messages(&json!({ "errors": [] })).is_empty());
+
+        assert_eq!(
+            time_text(&messages[0].time),
+            Field::Disclosed("2023-11-14 22:13 UTC".to_string())
+        );
+        assert_eq!(time_text(&Field::Redacted), Field::Redacted);
+    }
+}
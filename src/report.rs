@@ -0,0 +1,97 @@
+//! The outcome of a verification as structured data, for the command line and JavaScript APIs.
+
+use serde_json::{json, Value};
+
+use crate::config;
+use crate::error_explainer;
+use crate::notary_key::NotaryKey;
+use crate::parsed_proof::ParsedProof;
+use crate::proof_digest;
+use crate::verify::{VerifiedOutput, VerifyError};
+
+/// The report of a failure before verification even started, e.g. an unreadable key
+pub fn error(summary: &str) -> Value {
+    json!({
+        "verified": false,
+        "error": {
+            "summary": summary,
+            "suggestions": [],
+            "detail": summary,
+        },
+    })
+}
+
+/// The report of verifying `proof` with the Notary key `pem`, failures come with a
+/// diagnosis. Both include the digests of the proof, to reference it by, and the key, so a
+/// verification against the public test key is told apart from one against a real Notary.
+pub fn to_json(
+    proof: &ParsedProof,
+    pem: NotaryKey,
+    result: &Result<VerifiedOutput, VerifyError>,
+) -> Value {
+    let mut report = match result {
+        Ok(verified) => json!({
+            "verified": true,
+            "cert_chain_verified": verified.cert_chain_verified,
+            "format": verified.format.to_string(),
+            "server_name": verified.server_name,
+            "time": verified.time.to_rfc3339(),
+            "header_only": verified.header_only,
+            "sent": {
+                "data": String::from_utf8_lossy(&verified.sent.bytes),
+                "redacted_ranges": verified.sent.redacted_ranges,
+            },
+            "recv": {
+                "data": String::from_utf8_lossy(&verified.recv.bytes),
+                "redacted_ranges": verified.recv.redacted_ranges,
+            },
+        }),
+        Err(err) => {
+            let diagnosis = error_explainer::explain(err, proof);
+            json!({
+                "verified": false,
+                "cert_chain_verified": false,
+                "error": {
+                    "summary": diagnosis.summary,
+                    "suggestions": diagnosis.suggestions,
+                    "detail": diagnosis.detail,
+                },
+            })
+        }
+    };
+    report["notary_key"] = json!({
+        "scheme": pem.scheme(),
+        "fingerprint": pem.fingerprint(),
+    });
+    report["test_key"] = json!(config::is_test_key(pem));
+    report["digests"] = proof_digest::to_json(proof);
+    report
+}
+
+/// Did the report's verification prove the server's identity to a real Notary: the certificate
+/// chain was checked, and the key is not the public test key anyone can sign with?
+pub fn is_full_assurance(report: &Value) -> bool {
+    let is = |key: &str| report.get(key) == Some(&Value::Bool(true));
+    is("verified")
+        && is("cert_chain_verified")
+        && report.get("test_key") == Some(&Value::Bool(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_is_reported() {
+        let pem = NotaryKey::parse(config::FIXTURE_PEM).unwrap();
+        let report = to_json(
+            &ParsedProof::parse(b"{}"),
+            pem,
+            &Err(VerifyError::Parse("bad".to_string())),
+        );
+        assert_eq!(report["test_key"], true);
+        assert_eq!(report["cert_chain_verified"], false);
+        assert_eq!(report["notary_key"]["fingerprint"], pem.fingerprint());
+        assert!(!is_full_assurance(&report));
+    }
+}
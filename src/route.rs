@@ -0,0 +1,34 @@
+use yew_router::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Routable)]
+pub enum Route {
+    /// Upload proofs
+    #[at("/")]
+    Home,
+    /// Verification results of the loaded proofs
+    #[at("/verify")]
+    Verify,
+    #[at("/history")]
+    History,
+    /// Notary key management
+    #[at("/keys")]
+    Keys,
+    #[at("/about")]
+    About,
+    /// Verifies bundled known-good and known-bad proofs
+    #[at("/self-test")]
+    SelfTest,
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+impl Route {
+    /// The pages listed in the navigation bar
+    pub const NAVIGATION: [(&'static str, Route); 4] = [
+        ("Verify", Route::Verify),
+        ("History", Route::History),
+        ("Keys", Route::Keys),
+        ("About", Route::About),
+    ];
+}
@@ -0,0 +1,109 @@
+//! Known-good and known-bad proofs, verified on request so users can confirm that a deployed
+//! verifier accepts what it should and rejects what it should. Each bad proof fails at a known
+//! step.
+
+use crate::config::FIXTURE_PEM;
+use crate::fixtures;
+use crate::notary_key::NotaryKey;
+use crate::notary_registry::{Registry, PSE_NOTARY_URL};
+use crate::verify::{verify_proof_steps, Step, VerifyOptions};
+
+/// What verifying a case has to result in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expected {
+    Valid,
+    /// The first step to fail
+    FailsAt(Step),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Case {
+    /// What the case is, untranslated
+    pub name: &'static str,
+    pub proof: String,
+    pub key: NotaryKey,
+    pub expected: Expected,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaseResult {
+    pub case: Case,
+    pub actual: Expected,
+}
+
+impl CaseResult {
+    pub fn passed(&self) -> bool {
+        self.case.expected == self.actual
+    }
+}
+
+pub fn cases() -> Vec<Case> {
+    let fixture_key = NotaryKey::parse(FIXTURE_PEM).expect("the fixture key is valid");
+    let mut cases = vec![
+        Case {
+            name: "Valid proof",
+            proof: fixtures::EXAMPLE_COM.to_string(),
+            key: fixture_key,
+            expected: Expected::Valid,
+        },
+        Case {
+            name: "Tampered Notary signature",
+            proof: fixtures::tampered_signature(),
+            key: fixture_key,
+            expected: Expected::FailsAt(Step::NotarySignature),
+        },
+        Case {
+            name: "Tampered transcript",
+            proof: fixtures::tampered_transcript(),
+            key: fixture_key,
+            expected: Expected::FailsAt(Step::Substrings),
+        },
+        Case {
+            name: "Server name not in the certificate",
+            proof: fixtures::INVALID_SERVER_NAME.to_string(),
+            key: fixture_key,
+            expected: Expected::FailsAt(Step::ServerName),
+        },
+    ];
+    if let Some(pse) = Registry::bundled().find_by_url(PSE_NOTARY_URL) {
+        cases.push(Case {
+            name: "Valid proof with another Notary's key",
+            proof: fixtures::EXAMPLE_COM.to_string(),
+            key: pse.key(),
+            expected: Expected::FailsAt(Step::NotarySignature),
+        });
+    }
+    cases
+}
+
+/// Verify `case` with the default options: the user's options, e.g. skipping the certificate
+/// chain, would change the outcomes
+pub fn run(case: Case) -> CaseResult {
+    let verification = verify_proof_steps(&case.proof, case.key, &VerifyOptions::default());
+    let actual = match (&verification.result, verification.steps.first_failure()) {
+        (Ok(_), _) => Expected::Valid,
+        (Err(_), Some(step)) => Expected::FailsAt(step),
+        // An error without a failed step is a bug, it shows up as a failure to parse
+        (Err(_), None) => Expected::FailsAt(Step::Parse),
+    };
+    CaseResult { case, actual }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cases_have_their_expected_outcome() {
+        for case in cases() {
+            let result = run(case);
+            assert!(
+                result.passed(),
+                "{}: expected {:?}, got {:?}",
+                result.case.name,
+                result.case.expected,
+                result.actual
+            );
+        }
+    }
+}
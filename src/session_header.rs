@@ -0,0 +1,34 @@
+//! The fields of the session header signed by the Notary, read from the proof JSON.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct ServerPublicKey {
+    pub group: String,
+    pub key: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct HandshakeSummary {
+    pub time: u64,
+    pub server_public_key: ServerPublicKey,
+    pub handshake_commitment: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SessionHeader {
+    pub encoder_seed: Vec<u8>,
+    pub merkle_root: Vec<u8>,
+    pub sent_len: usize,
+    pub recv_len: usize,
+    pub handshake_summary: HandshakeSummary,
+}
+
+/// The session header of a proof
+pub fn session_header(proof: &Value) -> Result<SessionHeader, String> {
+    let header = proof
+        .pointer("/session/header")
+        .ok_or_else(|| "proof contains no session header".to_string())?;
+    SessionHeader::deserialize(header).map_err(|e| e.to_string())
+}
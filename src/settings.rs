@@ -0,0 +1,76 @@
+//! Settings kept in localStorage, so reloading the page does not reset them. The theme, language
+//! and maximum proof age are persisted by their own modules.
+
+use base64::{engine::general_purpose, Engine as _};
+use gloo::storage::{LocalStorage, Storage};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::notary_key::{KeySource, NotaryKey};
+use crate::verify::VerifyOptions;
+
+const NOTARY_KEY: &str = "proof_viz.notary_key";
+const VERIFY_OPTIONS_KEY: &str = "proof_viz.verify_options";
+const VIEW_KEY_PREFIX: &str = "proof_viz.view.";
+
+#[derive(Serialize, Deserialize)]
+struct StoredNotaryKey {
+    pem: String,
+    source: KeySource,
+}
+
+/// The Notary key last used and where it came from, if it was changed from the default
+pub fn load_notary_key() -> Option<(NotaryKey, KeySource)> {
+    let stored = LocalStorage::get::<StoredNotaryKey>(NOTARY_KEY).ok()?;
+    Some((NotaryKey::parse(&stored.pem).ok()?, stored.source))
+}
+
+pub fn save_notary_key(key: NotaryKey, source: &KeySource) {
+    let stored = StoredNotaryKey {
+        pem: key.to_public_key_pem(),
+        source: source.clone(),
+    };
+    // Not being able to persist a setting is not worth bothering the user with
+    let _ = LocalStorage::set(NOTARY_KEY, stored);
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredVerifyOptions {
+    /// Base64 encoded DER certificates
+    extra_roots: Vec<String>,
+    skip_cert_verification: bool,
+}
+
+pub fn load_verify_options() -> VerifyOptions {
+    let Ok(stored) = LocalStorage::get::<StoredVerifyOptions>(VERIFY_OPTIONS_KEY) else {
+        return VerifyOptions::default();
+    };
+    VerifyOptions {
+        extra_roots: stored
+            .extra_roots
+            .iter()
+            .filter_map(|root| general_purpose::STANDARD.decode(root).ok())
+            .collect(),
+        skip_cert_verification: stored.skip_cert_verification,
+    }
+}
+
+pub fn save_verify_options(options: &VerifyOptions) {
+    let stored = StoredVerifyOptions {
+        extra_roots: options
+            .extra_roots
+            .iter()
+            .map(|root| general_purpose::STANDARD.encode(root))
+            .collect(),
+        skip_cert_verification: options.skip_cert_verification,
+    };
+    let _ = LocalStorage::set(VERIFY_OPTIONS_KEY, stored);
+}
+
+/// A view mode of the components, like showing the raw proof, `default` if it was never changed
+pub fn load_view<T: DeserializeOwned>(name: &str, default: T) -> T {
+    LocalStorage::get(format!("{}{}", VIEW_KEY_PREFIX, name)).unwrap_or(default)
+}
+
+pub fn save_view<T: Serialize>(name: &str, value: T) {
+    let _ = LocalStorage::set(format!("{}{}", VIEW_KEY_PREFIX, name), value);
+}
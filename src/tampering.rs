@@ -0,0 +1,170 @@
+//! Changes to a proof for the tampering playground: flipping a bit of the Notary signature, the
+//! notarization time or a disclosed transcript byte, to see which check catches it. Works on
+//! the JSON of tlsn-core v0.1.0-alpha.6 proofs.
+
+use serde_json::Value;
+
+const SIGNATURE: &str = "/session/signature/P256";
+const TIME: &str = "/session/header/handshake_summary/time";
+const OPENINGS: &str = "/substrings/openings";
+// Only the low bits of the time are flipped, higher ones make it overflow a date
+const TIME_BITS: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Target {
+    Signature,
+    Time,
+    Transcript,
+}
+
+impl Target {
+    pub const ALL: [Target; 3] = [Target::Signature, Target::Time, Target::Transcript];
+
+    /// Untranslated
+    pub fn name(self) -> &'static str {
+        match self {
+            Target::Signature => "Notary signature",
+            Target::Time => "Notarization time",
+            Target::Transcript => "Disclosed transcript",
+        }
+    }
+}
+
+/// One bit flipped, at `position` of the target: a hex digit of the signature, one of the low
+/// bits of the time, or a disclosed byte
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mutation {
+    pub target: Target,
+    pub position: usize,
+}
+
+/// A value before and after a mutation, for display
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub before: String,
+    pub after: String,
+}
+
+/// The disclosed bytes of the openings, in the order of their commitment ids
+fn disclosed_bytes(json: &mut Value) -> Vec<&mut Value> {
+    let Some(Value::Object(openings)) = json.pointer_mut(OPENINGS) else {
+        return Vec::new();
+    };
+    let mut openings: Vec<(usize, &mut Value)> = openings
+        .iter_mut()
+        .filter_map(|(id, opening)| Some((id.parse().ok()?, opening)))
+        .collect();
+    openings.sort_by_key(|(id, _)| *id);
+    openings
+        .into_iter()
+        .filter_map(|(_, opening)| opening.pointer_mut("/1/Blake3/data"))
+        .filter_map(|data| data.as_array_mut())
+        .flatten()
+        .collect()
+}
+
+fn byte_text(byte: u64) -> String {
+    match u8::try_from(byte) {
+        Ok(byte) if byte.is_ascii_graphic() => format!("0x{:02X} '{}'", byte, byte as char),
+        _ => format!("0x{:02X}", byte),
+    }
+}
+
+/// How many positions `target` of `proof` has, `None` if the proof has no such value
+pub fn positions(proof: &Value, target: Target) -> Option<usize> {
+    match target {
+        Target::Signature => proof.pointer(SIGNATURE)?.as_str().map(str::len),
+        Target::Time => proof.pointer(TIME)?.as_u64().map(|_| TIME_BITS),
+        Target::Transcript => {
+            let bytes = disclosed_bytes(&mut proof.clone()).len();
+            (bytes > 0).then_some(bytes)
+        }
+    }
+}
+
+/// Apply `mutation` to `proof`, returning what changed
+pub fn apply(proof: &mut Value, mutation: Mutation) -> Result<Change, String> {
+    let missing = || format!("the proof has no {}", mutation.target.name().to_lowercase());
+    match mutation.target {
+        Target::Signature => {
+            let value = proof.pointer_mut(SIGNATURE).ok_or_else(missing)?;
+            let signature = value.as_str().ok_or_else(missing)?.to_string();
+            let mut digits: Vec<char> = signature.chars().collect();
+            let digit = digits.get_mut(mutation.position).ok_or_else(missing)?;
+            let flipped = digit.to_digit(16).ok_or_else(missing)? ^ 1;
+            let before = *digit;
+            *digit = char::from_digit(flipped, 16)
+                .unwrap_or('0')
+                .to_ascii_uppercase();
+            let after = *digit;
+            *value = Value::String(digits.into_iter().collect());
+            Ok(Change {
+                before: before.to_string(),
+                after: after.to_string(),
+            })
+        }
+        Target::Time => {
+            let value = proof.pointer_mut(TIME).ok_or_else(missing)?;
+            let time = value.as_u64().ok_or_else(missing)?;
+            let flipped = time ^ (1 << (mutation.position % TIME_BITS));
+            *value = Value::from(flipped);
+            Ok(Change {
+                before: time.to_string(),
+                after: flipped.to_string(),
+            })
+        }
+        Target::Transcript => {
+            let value = disclosed_bytes(proof)
+                .into_iter()
+                .nth(mutation.position)
+                .ok_or_else(missing)?;
+            let byte = value.as_u64().ok_or_else(missing)?;
+            *value = Value::from(byte ^ 1);
+            Ok(Change {
+                before: byte_text(byte),
+                after: byte_text(byte ^ 1),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn flip_a_disclosed_byte() {
+        let mut proof: Value = serde_json::from_str(fixtures::EXAMPLE_COM).unwrap();
+        assert!(positions(&proof, Target::Transcript).is_some_and(|n| n > 3));
+        let mutation = Mutation {
+            target: Target::Transcript,
+            position: 0,
+        };
+        let change = apply(&mut proof, mutation).unwrap();
+        // The request starts with "GET"
+        assert_eq!(change.before, "0x47 'G'");
+        assert_eq!(change.after, "0x46 'F'");
+        assert_eq!(
+            proof.pointer(fixtures::TRANSCRIPT_BYTE_POINTER),
+            Some(&Value::from(b'F'))
+        );
+    }
+
+    #[test]
+    fn flip_signature_and_time() {
+        let mut proof: Value = serde_json::from_str(fixtures::EXAMPLE_COM).unwrap();
+        let first = |target: Target| Mutation {
+            target,
+            position: 0,
+        };
+        // The signature starts with "4E3A"
+        let change = apply(&mut proof, first(Target::Signature)).unwrap();
+        assert_eq!((change.before.as_str(), change.after.as_str()), ("4", "5"));
+        let change = apply(&mut proof, first(Target::Time)).unwrap();
+        assert_eq!(change.before, "1697722213");
+        assert_eq!(change.after, "1697722212");
+        assert_eq!(positions(&proof, Target::Time), Some(32));
+        assert_eq!(apply(&mut Value::Null, first(Target::Time)).ok(), None);
+    }
+}
@@ -0,0 +1,60 @@
+use gloo::storage::{LocalStorage, Storage};
+
+const STORAGE_KEY: &str = "proof_viz.theme";
+
+/// Theme aware Tailwind classes shared by the components
+pub const CARD: &str =
+    "bg-zinc-200 dark:bg-zinc-700 border border-zinc-500 dark:border-white border-dashed rounded-2xl";
+pub const CODE: &str = "bg-zinc-100 text-black dark:bg-black dark:text-white";
+pub const INPUT: &str =
+    "bg-white dark:bg-zinc-700 border border-gray-400 dark:border-gray-600 rounded";
+
+/// Responsive layout: full width on phones, narrower columns from medium screens on
+pub const PAGE: &str = "w-full px-2 md:w-4/5 md:px-0 m-auto";
+pub const PANEL: &str = "p-2 md:p-4 w-full md:w-5/6";
+/// Summary of a collapsible panel, with a hit area big enough for touch
+pub const SUMMARY: &str = "cursor-pointer py-2 select-none";
+/// Toolbar which stays in view while scrolling through long content, on the card background
+pub const STICKY: &str = "sticky top-0 z-10 bg-zinc-200 dark:bg-zinc-700";
+
+/// Color theme, provided to all components as a yew context
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+impl Theme {
+    /// The persisted theme, dark if none was chosen yet
+    pub fn load() -> Self {
+        match LocalStorage::get::<String>(STORAGE_KEY).as_deref() {
+            Ok("light") => Theme::Light,
+            _ => Theme::Dark,
+        }
+    }
+
+    pub fn save(self) {
+        let value = match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        };
+        // Not being able to persist the theme is not worth bothering the user with
+        let _ = LocalStorage::set(STORAGE_KEY, value);
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::Light,
+        }
+    }
+
+    /// Tailwind's `dark:` variants are enabled by the `dark` class on the root element
+    pub fn apply(self) {
+        let class = match self {
+            Theme::Light => "",
+            Theme::Dark => "dark",
+        };
+        gloo::utils::document_element().set_class_name(class);
+    }
+}
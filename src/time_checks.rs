@@ -0,0 +1,127 @@
+//! Sanity checks and formatting for the notarization time of a proof.
+
+use chrono::{DateTime, Duration, Local, Utc};
+use gloo::storage::{LocalStorage, Storage};
+
+const STORAGE_KEY: &str = "proof_viz.max_age_days";
+pub const DEFAULT_MAX_AGE_DAYS: u32 = 90;
+
+// Clocks are never perfectly in sync, a Notary slightly ahead is not suspicious
+const ALLOWED_CLOCK_SKEW_MINUTES: i64 = 5;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeWarning {
+    /// The proof claims to be notarized in the future
+    InFuture,
+    /// The proof is older than the configured maximum age
+    TooOld { max_age_days: u32 },
+}
+
+impl std::fmt::Display for TimeWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TimeWarning::InFuture => {
+                write!(
+                    f,
+                    "Notarization time is in the future, check your clock or the Notary's"
+                )
+            }
+            TimeWarning::TooOld { max_age_days } => {
+                write!(f, "Proof is older than {} days", max_age_days)
+            }
+        }
+    }
+}
+
+pub fn check(time: DateTime<Utc>, now: DateTime<Utc>, max_age_days: u32) -> Option<TimeWarning> {
+    if time > now + Duration::minutes(ALLOWED_CLOCK_SKEW_MINUTES) {
+        Some(TimeWarning::InFuture)
+    } else if now - time > Duration::days(max_age_days.into()) {
+        Some(TimeWarning::TooOld { max_age_days })
+    } else {
+        None
+    }
+}
+
+/// Human friendly distance between `time` and `now`, e.g. "3 days ago" or "in 2 hours"
+pub fn relative(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - time).num_seconds();
+    let abs = seconds.unsigned_abs();
+    if abs < 60 {
+        return "just now".to_string();
+    }
+
+    let (count, unit) = [
+        (365 * 24 * 3600, "year"),
+        (30 * 24 * 3600, "month"),
+        (24 * 3600, "day"),
+        (3600, "hour"),
+        (60, "minute"),
+    ]
+    .into_iter()
+    .find(|(unit_seconds, _)| abs >= *unit_seconds)
+    .map(|(unit_seconds, unit)| (abs / unit_seconds, unit))
+    .expect("abs is at least a minute");
+
+    let plural = if count == 1 { "" } else { "s" };
+    if seconds > 0 {
+        format!("{} {}{} ago", count, unit, plural)
+    } else {
+        format!("in {} {}{}", count, unit, plural)
+    }
+}
+
+/// The time in the viewer's timezone
+pub fn local(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M:%S %:z")
+        .to_string()
+}
+
+pub fn load_max_age_days() -> u32 {
+    LocalStorage::get(STORAGE_KEY).unwrap_or(DEFAULT_MAX_AGE_DAYS)
+}
+
+pub fn save_max_age_days(days: u32) {
+    let _ = LocalStorage::set(STORAGE_KEY, days);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1714521600 + seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn future_dated() {
+        let skew = ALLOWED_CLOCK_SKEW_MINUTES * 60;
+        assert_eq!(check(at(skew), at(0), 30), None);
+        assert_eq!(check(at(skew + 1), at(0), 30), Some(TimeWarning::InFuture));
+    }
+
+    #[test]
+    fn too_old() {
+        let days = 24 * 3600;
+        assert_eq!(check(at(0), at(0), 0), None);
+        assert_eq!(check(at(0), at(30 * days), 30), None);
+        assert_eq!(
+            check(at(0), at(30 * days + 1), 30),
+            Some(TimeWarning::TooOld { max_age_days: 30 })
+        );
+        assert_eq!(
+            check(at(0), at(1), 0),
+            Some(TimeWarning::TooOld { max_age_days: 0 })
+        );
+    }
+
+    #[test]
+    fn relative_times() {
+        assert_eq!(relative(at(0), at(59)), "just now");
+        assert_eq!(relative(at(0), at(-59)), "just now");
+        assert_eq!(relative(at(0), at(60)), "1 minute ago");
+        assert_eq!(relative(at(0), at(3 * 24 * 3600)), "3 days ago");
+        assert_eq!(relative(at(2 * 3600 + 5), at(0)), "in 2 hours");
+    }
+}
@@ -0,0 +1,125 @@
+//! The guided tour through a proof: what each part of the proof is, how it was checked
+//! and with what outcome. Each step points at the section of the proof's card (marked with
+//! `data-tour`) which shows that part.
+
+use crate::i18n::Locale;
+use crate::notary_key::NotaryKey;
+use crate::verify::{Step, StepOutcome, Transcript, Verification};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TourStep {
+    /// The `data-tour` value of the section the step explains
+    pub target: &'static str,
+    pub title: String,
+    pub text: String,
+    /// The checks the step explains, with their outcome
+    pub outcomes: Vec<(Step, StepOutcome)>,
+}
+
+/// How many of the bytes of `transcript` are disclosed
+fn disclosed(transcript: &Transcript) -> usize {
+    let redacted: usize = transcript.redacted_ranges.iter().map(|r| r.len()).sum();
+    transcript.bytes.len() - redacted
+}
+
+fn transcript_text(locale: Locale, transcript: Option<&Transcript>) -> String {
+    let openings = locale.t("Each disclosed range of bytes comes with a Merkle opening: the bytes, encoded, hash to a leaf of the Merkle tree whose root the Notary signed. So the bytes are exactly the ones exchanged with the server, the redacted ones stay hidden.");
+    match transcript {
+        Some(transcript) => format!(
+            "{} {}",
+            openings,
+            locale.format(
+                "{disclosed} of {total} bytes are disclosed.",
+                &[
+                    ("disclosed", &disclosed(transcript)),
+                    ("total", &transcript.bytes.len()),
+                ],
+            )
+        ),
+        None => openings.to_string(),
+    }
+}
+
+/// The steps of the tour through `verification`, verified with `pem`
+pub fn steps(locale: Locale, verification: &Verification, pem: NotaryKey) -> Vec<TourStep> {
+    let t = |message| locale.t(message).to_string();
+    let check = |step| (step, verification.steps.outcome(step));
+    // What the proof claims, even if it is not verified, makes the explanations concrete
+    let output = verification
+        .result
+        .as_ref()
+        .ok()
+        .or(verification.claimed.as_ref());
+
+    let session = match output {
+        Some(output) => locale.format(
+            "The Prover connected to {server} on {time}, with a Notary taking part in the TLS session without seeing its content.",
+            &[
+                ("server", &output.server_name),
+                ("time", &output.time.format("%Y-%m-%d %H:%M UTC")),
+            ],
+        ),
+        None => t("The Prover connected to a server, with a Notary taking part in the TLS session without seeing its content."),
+    };
+
+    vec![
+        TourStep {
+            target: "summary",
+            title: t("The proof"),
+            text: format!(
+                "{} {}",
+                session,
+                t("The proof has two parts: the session proof, which the Notary signed, and the substrings proof, which discloses parts of the transcript.")
+            ),
+            outcomes: vec![check(Step::Parse)],
+        },
+        TourStep {
+            target: "header",
+            title: t("The session header"),
+            text: t("This is what the Notary signed: the lengths of the transcript, the root of a Merkle tree over the encoded transcript, the time and a commitment to the TLS handshake. Everything else is checked against it."),
+            outcomes: Vec::new(),
+        },
+        TourStep {
+            target: "signature",
+            title: t("The Notary's signature"),
+            text: locale.format(
+                "The signature over the header was checked with the {scheme} Notary key {fingerprint}. It only means something if that key belongs to a Notary you trust.",
+                &[
+                    ("scheme", &pem.scheme()),
+                    ("fingerprint", &&pem.fingerprint()[..16]),
+                ],
+            ),
+            outcomes: vec![check(Step::NotarySignature)],
+        },
+        TourStep {
+            target: "certificate",
+            title: t("The server's certificate"),
+            text: t("The handshake commitment opens to the certificate chain the server sent. It has to be issued for the server name and lead to a trusted root, like in a browser."),
+            outcomes: vec![check(Step::ServerName), check(Step::CertChain)],
+        },
+        TourStep {
+            target: "sent",
+            title: t("The bytes sent"),
+            text: transcript_text(locale, output.map(|output| &output.sent)),
+            outcomes: vec![check(Step::Substrings)],
+        },
+        TourStep {
+            target: "received",
+            title: t("The bytes received"),
+            text: transcript_text(locale, output.map(|output| &output.recv)),
+            outcomes: vec![check(Step::Substrings)],
+        },
+        TourStep {
+            target: "redactions",
+            title: t("What was redacted"),
+            text: t("Redacted bytes are committed to in the Merkle tree but not disclosed: neither the Notary nor the proof reveals them. This lists which headers and fields they belong to."),
+            outcomes: Vec::new(),
+        },
+        TourStep {
+            target: "steps",
+            title: t("What was checked"),
+            text: t("All checks at a glance. The proof is only valid if every one of them passed."),
+            outcomes: Step::ALL.iter().map(|step| check(*step)).collect(),
+        },
+    ]
+}
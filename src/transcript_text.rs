@@ -0,0 +1,109 @@
+//! Transcript bytes as text, keeping every byte in its place. A redaction can split a multi-byte
+//! UTF-8 character: its disclosed bytes are shown as hex escapes, one per byte, instead of
+//! replacement characters which would blur where the redaction starts and ends.
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Piece {
+    /// Valid UTF-8
+    Text(String),
+    /// A byte which is not part of a complete UTF-8 character
+    Byte(u8),
+}
+
+impl Piece {
+    /// The byte as shown, e.g. `\xE2`
+    pub fn escape(byte: u8) -> String {
+        format!("\\x{:02X}", byte)
+    }
+}
+
+/// Split `bytes` into runs of valid UTF-8 and the bytes in between
+pub fn pieces(bytes: &[u8]) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    for chunk in bytes.utf8_chunks() {
+        if !chunk.valid().is_empty() {
+            pieces.push(Piece::Text(chunk.valid().to_string()));
+        }
+        pieces.extend(chunk.invalid().iter().map(|byte| Piece::Byte(*byte)));
+    }
+    pieces
+}
+
+/// `bytes` as text, with the bytes which are not part of a complete character escaped
+pub fn escaped(bytes: &[u8]) -> String {
+    pieces(bytes)
+        .into_iter()
+        .map(|piece| match piece {
+            Piece::Text(text) => text,
+            Piece::Byte(byte) => Piece::escape(byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The number of bytes the pieces stand for
+    fn byte_len(pieces: &[Piece]) -> usize {
+        pieces
+            .iter()
+            .map(|piece| match piece {
+                Piece::Text(text) => text.len(),
+                Piece::Byte(_) => 1,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn valid_text_is_kept() {
+        assert_eq!(
+            pieces("naïve € 😀".as_bytes()),
+            [Piece::Text("naïve € 😀".into())]
+        );
+        assert_eq!(pieces(b""), []);
+    }
+
+    #[test]
+    fn split_character_is_escaped() {
+        // "€" is E2 82 AC, a redaction of its middle byte leaves one byte on either side
+        let bytes = "a€b".as_bytes();
+        assert_eq!(escaped(&bytes[..2]), "a\\xE2");
+        assert_eq!(escaped(&bytes[3..]), "\\xACb");
+        assert_eq!(
+            pieces(&bytes[3..]),
+            [Piece::Byte(0xac), Piece::Text("b".into())]
+        );
+    }
+
+    #[test]
+    fn every_split_keeps_every_byte() {
+        for fixture in ["é", "€", "😀", "x€y😀z", "日本語"] {
+            let bytes = fixture.as_bytes();
+            for start in 0..bytes.len() {
+                for end in start..=bytes.len() {
+                    // the disclosed bytes around a redaction of start..end
+                    let before = pieces(&bytes[..start]);
+                    let after = pieces(&bytes[end..]);
+                    assert_eq!(byte_len(&before), start, "{} {}..{}", fixture, start, end);
+                    assert_eq!(
+                        byte_len(&after),
+                        bytes.len() - end,
+                        "{} {}..{}",
+                        fixture,
+                        start,
+                        end
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn four_byte_character_split_in_the_middle() {
+        // "😀" is F0 9F 98 80
+        let bytes = "😀".as_bytes();
+        assert_eq!(escaped(&bytes[..2]), "\\xF0\\x9F");
+        assert_eq!(escaped(&bytes[2..]), "\\x98\\x80");
+    }
+}
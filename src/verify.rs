@@ -0,0 +1,317 @@
+use std::fmt;
+use std::ops::Range;
+
+use web_time::{Duration, Instant};
+
+use serde_json::Value;
+
+use crate::formats::{self, ProofFormat};
+use crate::notary_key::NotaryKey;
+use crate::parsed_proof::ParsedProof;
+
+/// Byte used to replace the parts of the transcript which the Prover chose not to disclose
+pub const REDACTED_BYTE: u8 = b'X';
+
+/// One direction of the transcript, as disclosed by the Prover
+#[derive(Clone, PartialEq)]
+pub struct Transcript {
+    pub bytes: Vec<u8>,
+    pub redacted_ranges: Vec<Range<usize>>,
+}
+
+impl Transcript {
+    /// The redacted ranges within `range`, relative to its start
+    pub fn redactions_within(&self, range: &Range<usize>) -> Vec<Range<usize>> {
+        self.redacted_ranges
+            .iter()
+            .filter(|r| r.start < range.end && range.start < r.end)
+            .map(|r| r.start.max(range.start) - range.start..r.end.min(range.end) - range.start)
+            .collect()
+    }
+
+    /// The bytes in `range` as a transcript of their own
+    pub fn slice(&self, range: &Range<usize>) -> Transcript {
+        Transcript {
+            bytes: self.bytes[range.clone()].to_vec(),
+            redacted_ranges: self.redactions_within(range),
+        }
+    }
+
+    /// A transcript of `len` bytes of which none is disclosed
+    pub fn undisclosed(len: usize) -> Self {
+        Transcript {
+            bytes: vec![REDACTED_BYTE; len],
+            redacted_ranges: std::iter::once(0..len)
+                .filter(|range| !range.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Is any byte of `range` disclosed?
+    pub fn is_disclosed(&self, range: &Range<usize>) -> bool {
+        let redacted: usize = self.redactions_within(range).iter().map(|r| r.len()).sum();
+        redacted < range.len()
+    }
+}
+
+/// Everything a successfully verified proof tells us
+#[derive(Clone, PartialEq)]
+pub struct VerifiedOutput {
+    pub server_name: String,
+    pub time: chrono::DateTime<chrono::Utc>,
+    pub sent: Transcript,
+    pub recv: Transcript,
+    /// `false` if the certificate chain check was skipped, the server's identity is then unproven
+    pub cert_chain_verified: bool,
+    /// The tlsn-core version the proof was verified as
+    pub format: ProofFormat,
+    /// The proof has no substrings proof, so none of the transcript is disclosed: `sent` and
+    /// `recv` are all redacted, with the lengths from the session header
+    pub header_only: bool,
+    pub timings: Timings,
+}
+
+#[cfg(test)]
+impl VerifiedOutput {
+    /// A verified proof of example.com with both directions fully disclosed, for tests to
+    /// adjust
+    pub(crate) fn for_test(sent: impl AsRef<[u8]>, recv: impl AsRef<[u8]>) -> Self {
+        let disclosed = |bytes: &[u8]| Transcript {
+            bytes: bytes.to_vec(),
+            redacted_ranges: Vec::new(),
+        };
+        VerifiedOutput {
+            server_name: "example.com".to_string(),
+            time: chrono::DateTime::UNIX_EPOCH,
+            sent: disclosed(sent.as_ref()),
+            recv: disclosed(recv.as_ref()),
+            cert_chain_verified: true,
+            format: ProofFormat::Alpha6,
+            header_only: false,
+            timings: Timings::default(),
+        }
+    }
+}
+
+/// The range of the first `needle` in `text`, for tests
+#[cfg(test)]
+pub(crate) fn find(text: &str, needle: &str) -> Range<usize> {
+    let start = text.find(needle).expect("the needle is in the text");
+    start..start + needle.len()
+}
+
+/// How long the expensive checks of a verification took, `None` for the checks not done
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timings {
+    /// The Notary's signature over the session header
+    pub signature: Option<Duration>,
+    /// The server name and the certificate chain
+    pub certificate: Option<Duration>,
+    /// The openings of the disclosed transcript
+    pub substrings: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        [self.signature, self.certificate, self.substrings]
+            .into_iter()
+            .flatten()
+            .sum()
+    }
+}
+
+/// Run `check`, measuring how long it took
+pub fn timed<T>(check: impl FnOnce() -> T) -> (T, Duration) {
+    let started = Instant::now();
+    let result = check();
+    (result, started.elapsed())
+}
+
+/// Settings which change how proofs are verified
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VerifyOptions {
+    /// DER encoded root certificates to trust besides the webpki roots, e.g. of a private CA
+    pub extra_roots: Vec<Vec<u8>>,
+    /// Only check the Notary signature and the handshake commitment, not the certificate chain.
+    /// For testing proofs of servers with self-signed or expired certificates.
+    pub skip_cert_verification: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum VerifyError {
+    /// The file is not a valid TlsProof
+    Parse(String),
+    /// The session proof does not verify against the Notary key
+    Session(String),
+    /// The substrings proof does not verify against the session header
+    Substrings(String),
+}
+
+/// The checks a proof has to pass, in the order they are done
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Step {
+    /// The file is a proof in a supported format
+    Parse,
+    /// The Notary signed the session header, which commits to the handshake and the transcript
+    NotarySignature,
+    /// The server's certificate is issued for the server name in the proof
+    ServerName,
+    /// The server's certificate chain leads to a trusted root
+    CertChain,
+    /// The disclosed parts of the transcript open the commitments in the session header
+    Substrings,
+}
+
+impl Step {
+    pub const ALL: [Step; 5] = [
+        Step::Parse,
+        Step::NotarySignature,
+        Step::ServerName,
+        Step::CertChain,
+        Step::Substrings,
+    ];
+
+    /// What the step checks, untranslated
+    pub fn description(&self) -> &'static str {
+        match self {
+            Step::Parse => "The file is a TLSNotary proof in a supported format",
+            Step::NotarySignature => {
+                "The Notary's signature over the session header verifies with the Notary key"
+            }
+            Step::ServerName => "The server certificate is issued for the server name in the proof",
+            Step::CertChain => {
+                "The server certificate chain leads to a trusted root (the webpki roots and your extra roots)"
+            }
+            Step::Substrings => {
+                "The Merkle openings of the disclosed transcript match the commitments in the session header"
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StepOutcome {
+    Passed,
+    Failed(String),
+    /// Turned off in the verification options
+    Skipped,
+    /// An earlier step failed
+    NotChecked,
+    /// The proof has nothing to check, e.g. no disclosed transcript
+    NotApplicable,
+}
+
+/// The outcome of every step of the verification of a proof
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VerificationSteps(Vec<(Step, StepOutcome)>);
+
+impl VerificationSteps {
+    pub fn record(&mut self, step: Step, outcome: StepOutcome) {
+        match &outcome {
+            StepOutcome::Failed(err) => tracing::warn!(?step, error = %err, "step failed"),
+            outcome => tracing::debug!(?step, ?outcome),
+        }
+        self.0.retain(|(recorded, _)| *recorded != step);
+        self.0.push((step, outcome));
+    }
+
+    /// Record the outcome of a check, passing its result on
+    pub fn check<T>(&mut self, step: Step, result: Result<T, String>) -> Result<T, String> {
+        let outcome = match &result {
+            Ok(_) => StepOutcome::Passed,
+            Err(err) => StepOutcome::Failed(err.clone()),
+        };
+        self.record(step, outcome);
+        result
+    }
+
+    pub fn outcome(&self, step: Step) -> StepOutcome {
+        self.0
+            .iter()
+            .find(|(recorded, _)| *recorded == step)
+            .map_or(StepOutcome::NotChecked, |(_, outcome)| outcome.clone())
+    }
+
+    /// The first step, in the order of `Step::ALL`, which failed
+    pub fn first_failure(&self) -> Option<Step> {
+        Step::ALL
+            .into_iter()
+            .find(|step| matches!(self.outcome(*step), StepOutcome::Failed(_)))
+    }
+}
+
+/// The result of verifying a proof, with how it came about
+#[derive(Clone, PartialEq)]
+pub struct Verification {
+    pub result: Result<VerifiedOutput, VerifyError>,
+    pub steps: VerificationSteps,
+    /// What the Prover claims, if a check of the session failed but the transcript could still
+    /// be opened. Nothing proves it.
+    pub claimed: Option<VerifiedOutput>,
+}
+
+impl Verification {
+    /// A verification which failed before the transcript could be opened
+    pub fn failed(error: VerifyError, steps: VerificationSteps) -> Self {
+        Verification {
+            result: Err(error),
+            steps,
+            claimed: None,
+        }
+    }
+
+    /// A verification of a file which is not a proof at all
+    pub fn unparsed(error: String) -> Self {
+        let mut steps = VerificationSteps::default();
+        steps.record(Step::Parse, StepOutcome::Failed(error.clone()));
+        Verification::failed(VerifyError::Parse(error), steps)
+    }
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VerifyError::Parse(err) => write!(f, "Parsing failed {}", err),
+            VerifyError::Session(err) | VerifyError::Substrings(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Parse and verify a proof, see [`verify_proof_steps`]
+pub fn verify_proof(
+    json_str: &str,
+    pem: NotaryKey,
+    options: &VerifyOptions,
+) -> Result<VerifiedOutput, VerifyError> {
+    verify_proof_steps(json_str, pem, options).result
+}
+
+/// Parse and verify a proof, see [`verify_value`]
+pub fn verify_proof_steps(json_str: &str, pem: NotaryKey, options: &VerifyOptions) -> Verification {
+    ParsedProof::parse(json_str.as_bytes()).verify(pem, options)
+}
+
+/// Verify a parsed proof, with the verifier of each supported tlsn-core version in turn,
+/// starting with the detected one, recording the outcome of each step
+pub fn verify_value(proof: &Value, pem: NotaryKey, options: &VerifyOptions) -> Verification {
+    let detected = formats::detect(proof);
+    tracing::debug!(format = ?detected, "detected proof format");
+    let mut verifiers = formats::verifiers();
+    verifiers.sort_by_key(|verifier| Some(verifier.format()) != detected);
+
+    let mut first_failure = None;
+    for verifier in verifiers {
+        let verification = tracing::debug_span!("verify", format = ?verifier.format())
+            .in_scope(|| verifier.verify(proof, pem, options));
+        match verification.result {
+            Ok(_) => return verification,
+            // A proof in a known format which fails to verify is invalid, not of another version
+            Err(_) if Some(verifier.format()) == detected => return verification,
+            Err(_) => {
+                first_failure.get_or_insert(verification);
+            }
+        }
+    }
+    first_failure
+        .unwrap_or_else(|| Verification::unparsed("no proof format is supported".to_string()))
+}